@@ -0,0 +1,153 @@
+//! Measures the time and the heap allocations spent to render the queries, run with `cargo bench --bench render`.
+//!
+//! The allocations are counted by a global allocator that wraps the system allocator, so the numbers are exact and
+//! don't depend on the machine, the time is only a rough reference. Each query is compared with a baseline that
+//! concatenates the same clauses into a string, the ratio to the baseline is comparable between machines.
+
+use sql_query_builder as sql;
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  sync::atomic::{AtomicUsize, Ordering},
+  time::Instant,
+};
+
+const ITERATIONS: usize = 10_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+    System.realloc(ptr, layout, new_size)
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+  let conditions = (0..20)
+    .map(|index| format!("o.field_{index} = {index}"))
+    .collect::<Vec<_>>();
+  let rows = (0..50)
+    .map(|index| format!("('user_{index}', 'User {index}', 'user_{index}@example.com')"))
+    .collect::<Vec<_>>();
+  let assignments = (0..20)
+    .map(|index| format!("field_{index} = {index}"))
+    .collect::<Vec<_>>();
+
+  let select = conditions.iter().fold(
+    sql::Select::new()
+      .select("u.id, u.login, u.name, u.email")
+      .from("users u")
+      .inner_join("orders o ON o.user_id = u.id")
+      .where_clause("u.active = true")
+      .group_by("u.id")
+      .order_by("u.login"),
+    |select, condition| select.where_and(condition),
+  );
+  let insert = rows.iter().fold(
+    sql::Insert::new().insert_into("users (login, name, email)"),
+    |insert, row| insert.values(row),
+  );
+  let update = assignments.iter().fold(
+    sql::Update::new().update("users").where_clause("id = $1"),
+    |update, assignment| update.set(assignment),
+  );
+
+  // the baseline concatenates the same clauses into a string, the least work any rendering can do
+  let select_baseline = || {
+    let mut query = String::from("SELECT u.id, u.login, u.name, u.email FROM users u ");
+    query.push_str("INNER JOIN orders o ON o.user_id = u.id WHERE u.active = true");
+    for condition in &conditions {
+      query.push_str(" AND ");
+      query.push_str(condition);
+    }
+    query.push_str(" GROUP BY u.id ORDER BY u.login");
+    query
+  };
+  let insert_baseline = || format!("INSERT INTO users (login, name, email) VALUES {}", rows.join(", "));
+  let update_baseline = || format!("UPDATE users SET {} WHERE id = $1", assignments.join(", "));
+
+  println!(
+    "{:<8} {:<10} {:>12} {:>12} {:>14} {:>14}",
+    "query", "method", "ns/query", "x baseline", "allocs/query", "bytes/query"
+  );
+  run(
+    "select",
+    select_baseline,
+    || select.as_string(),
+    |buffer| select.write_sql(buffer).unwrap(),
+  );
+  run(
+    "insert",
+    insert_baseline,
+    || insert.as_string(),
+    |buffer| insert.write_sql(buffer).unwrap(),
+  );
+  run(
+    "update",
+    update_baseline,
+    || update.as_string(),
+    |buffer| update.write_sql(buffer).unwrap(),
+  );
+}
+
+fn run(name: &str, baseline: impl Fn() -> String, as_string: impl Fn() -> String, write_sql: impl Fn(&mut String)) {
+  assert_eq!(baseline(), as_string(), "the baseline must render the same query");
+  let mut total_len = 0;
+
+  let baseline_stats = measure(|| total_len += baseline().len());
+  report(name, "baseline", baseline_stats, baseline_stats.0);
+
+  let stats = measure(|| total_len += as_string().len());
+  report(name, "as_string", stats, baseline_stats.0);
+
+  // the query is rendered into a string and then copied into the reused buffer, so the allocations
+  // are the same of as_string, the copy is the only difference
+  let mut buffer = String::new();
+  let stats = measure(|| {
+    buffer.clear();
+    write_sql(&mut buffer);
+    total_len += buffer.len();
+  });
+  report(name, "write_sql", stats, baseline_stats.0);
+
+  // uses the rendered text, so the rendering can't be optimized away
+  assert!(total_len > 0);
+}
+
+fn measure(mut render: impl FnMut()) -> (u128, usize, usize) {
+  let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+  let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+  let start = Instant::now();
+
+  for _ in 0..ITERATIONS {
+    render();
+  }
+
+  let elapsed = start.elapsed().as_nanos() / ITERATIONS as u128;
+  let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ITERATIONS;
+  let allocated_bytes = (ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes) / ITERATIONS;
+
+  (elapsed, allocations, allocated_bytes)
+}
+
+fn report(name: &str, method: &str, (elapsed, allocations, allocated_bytes): (u128, usize, usize), baseline: u128) {
+  let ratio = elapsed as f64 / baseline.max(1) as f64;
+  println!("{name:<8} {method:<10} {elapsed:>12} {ratio:>12.1} {allocations:>14} {allocated_bytes:>14}");
+}
@@ -17,12 +17,21 @@ echo "\n-- ---------------------------------------------------------------------
 echo "-- Testing SQLite syntax"
 echo "-- ------------------------------------------------------------------------------\n"
 cargo test $test_names --features sqlite
-cargo test $test_names --features mysql
 
 echo "\n-- ------------------------------------------------------------------------------"
 echo "-- Testing MySQL syntax"
 echo "-- ------------------------------------------------------------------------------\n"
 cargo test $test_names --features mysql
 
+echo "\n-- ------------------------------------------------------------------------------"
+echo "-- Testing multiple dialects in the same build"
+echo "-- ------------------------------------------------------------------------------\n"
+cargo test $test_names --features postgresql,sqlite,mysql
+
+echo "\n-- ------------------------------------------------------------------------------"
+echo "-- Testing all features, the same build used by docs.rs"
+echo "-- ------------------------------------------------------------------------------\n"
+cargo test $test_names --all-features
+
 # run only one test
 # cargo test --features sqlite --test name_of_the_test_file name_of_the_test -- --nocapture --color always
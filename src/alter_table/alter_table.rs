@@ -1,11 +1,22 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{AlterTable, AlterTableAction, AlterTableActionItem, AlterTableOrderedAction},
-  utils::push_unique,
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{
+    AlterTable, AlterTableAction, AlterTableActionItem, AlterTableOrderedAction, AlterTableTypedAction, BuildError,
+    DataType, Dialect, FormatOptions, Fragment,
+  },
+  utils::{self, push_unique},
 };
 
+#[cfg(any(doc, feature = "sqlite"))]
+use crate::structure::{CreateTable, Transaction};
+
+#[cfg(feature = "sqlite")]
+use super::alter_table_internal::rebuild_table_names;
+
+#[cfg(feature = "sqlite")]
+use crate::structure::{DropTable, Insert, Select};
+
 impl TransactionQuery for AlterTable {}
 
 impl AlterTable {
@@ -54,12 +65,161 @@ impl AlterTable {
   /// ADD COLUMN login varchar not null,
   /// ADD CONSTRAINT login_unique unique(login)
   /// ```
-  pub fn add(mut self, add_exp: &str) -> Self {
-    let action = AlterTableActionItem(AlterTableOrderedAction::Add, add_exp.trim().to_string());
+  ///
+  /// The column can be defined with the [ColumnDef](crate::ColumnDef) builder to be rendered in the syntax of the dialect,
+  /// the constraint builders like [ForeignKey](crate::ForeignKey) can be added directly
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let login = sql::ColumnDef::new("login", sql::DataType::Varchar(40)).not_null();
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add(sql::Fragment::from("COLUMN ").push(&login))
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN login VARCHAR(40) NOT NULL";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN login VARCHAR(40) NOT NULL
+  /// ```
+  pub fn add(mut self, add_exp: impl Into<Fragment>) -> Self {
+    let action = AlterTableActionItem(AlterTableOrderedAction::Add, add_exp.into().trim());
     push_unique(&mut self._ordered_actions, action);
     self
   }
 
+  /// Adds a column, the definition can be a string or a [ColumnDef](crate::ColumnDef) builder rendered in the syntax of the dialect.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let login = sql::ColumnDef::new("login", sql::DataType::Varchar(40)).not_null();
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column(&login)
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN login VARCHAR(40) NOT NULL";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN login VARCHAR(40) NOT NULL
+  /// ```
+  pub fn add_column(self, column_def: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::AddColumn(column_def.into().trim()))
+  }
+
+  /// Adds a table constraint, the constraint can be a string with the name and the definition
+  /// or a constraint builder like [ForeignKey](crate::ForeignKey).
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_constraint("users_login_key UNIQUE (login)")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ADD CONSTRAINT users_login_key UNIQUE (login)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD CONSTRAINT users_login_key UNIQUE (login)
+  /// ```
+  pub fn add_constraint(self, constraint: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::AddConstraint(constraint.into().trim()))
+  }
+
+  /// Changes the data type of a column, the type is rendered in the syntax of the dialect.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column_type("age", sql::DataType::BigInt);
+  ///
+  /// let standard_query = alter_table.to_sql(sql::Dialect::Standard);
+  /// let mysql_query = alter_table.to_sql(sql::Dialect::Mysql);
+  ///
+  /// # assert_eq!("ALTER TABLE users ALTER COLUMN age SET DATA TYPE BIGINT", standard_query);
+  /// # assert_eq!("ALTER TABLE users MODIFY COLUMN age BIGINT", mysql_query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// -- Standard
+  /// ALTER TABLE users ALTER COLUMN age SET DATA TYPE BIGINT
+  ///
+  /// -- MySQL
+  /// ALTER TABLE users MODIFY COLUMN age BIGINT
+  /// ```
+  pub fn alter_column_type(self, column_name: impl Into<Fragment>, data_type: DataType) -> Self {
+    self.alter_column_type_using(column_name, data_type, "")
+  }
+
+  /// Changes the data type of a column converting the current values with the `USING` expression,
+  /// only Postgres supports the expression, use [AlterTable::try_to_sql] to detect the other dialects.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column_type_using("age", sql::DataType::Integer, "age::integer")
+  ///   .to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # let expected = "ALTER TABLE users ALTER COLUMN age TYPE INTEGER USING age::integer";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ALTER COLUMN age TYPE INTEGER USING age::integer
+  /// ```
+  pub fn alter_column_type_using(
+    self,
+    column_name: impl Into<Fragment>,
+    data_type: DataType,
+    expression: impl Into<Fragment>,
+  ) -> Self {
+    self.push_typed_action(AlterTableTypedAction::AlterColumnType(
+      column_name.into().trim(),
+      data_type,
+      expression.into().trim(),
+    ))
+  }
+
   /// Defines the name of the table to be altered, this method overrides the previous value
   ///
   /// ### Example
@@ -79,11 +239,47 @@ impl AlterTable {
   /// ```sql
   /// ALTER TABLE users
   /// ```
-  pub fn alter_table(mut self, table_name: &str) -> Self {
-    self._alter_table = table_name.trim().to_string();
+  pub fn alter_table(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._alter_table = table_name.into().trim();
     self
   }
 
+  /// Gets the current state of the [AlterTable] and returns it as string formatted by the [FormatOptions], the
+  /// actions after the first one are indented in their own lines when they don't fit in the `max_line_length`
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   max_line_length: 30,
+  ///   ..Default::default()
+  /// };
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add("COLUMN age int")
+  ///   .drop("COLUMN name")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # ALTER TABLE users ADD COLUMN age int,\n  \
+  /// # DROP COLUMN name";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int,
+  ///   DROP COLUMN name
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [AlterTable] and returns it as string
   ///
   /// ### Example
@@ -108,8 +304,109 @@ impl AlterTable {
   /// ALTER TABLE users RENAME TO users_old
   /// ```
   pub fn as_string(&self) -> String {
-    let fmts = fmt::one_line();
-    self.concat(&fmts)
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [AlterTable] rendered in the specified SQL [Dialect], returns a [BuildError]
+  /// when the [AlterTable::validate_for] method fails, like when the dialect doesn't support one of the actions
+  /// or cannot execute all the actions in a single statement
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age integer")
+  ///   .drop_column("login");
+  ///
+  /// let error = alter_table.try_to_sql(sql::Dialect::Sqlite).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::IncompatibleActions {
+  /// #   dialect: sql::Dialect::Sqlite,
+  /// #   actions: vec!["ADD COLUMN".to_string(), "DROP COLUMN".to_string()],
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Gets the actions of the [AlterTable] that have content, each kind of action is listed once. The raw SQL added
+  /// by the methods [AlterTable::raw], [AlterTable::raw_before] and [AlterTable::raw_after] is not considered.
+  /// The typed actions like [AlterTable::add_column] and [AlterTable::drop_column] are listed as
+  /// [AlterTableAction::Add] and [AlterTableAction::Drop]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("bio")
+  ///   .add_column("age int");
+  ///
+  /// let actions = alter_table.clauses();
+  ///
+  /// # let expected = vec![
+  /// #   sql::AlterTableAction::AlterTable,
+  /// #   sql::AlterTableAction::Add,
+  /// #   sql::AlterTableAction::Drop,
+  /// # ];
+  /// # assert_eq!(expected, actions);
+  /// ```
+  pub fn clauses(&self) -> Vec<AlterTableAction> {
+    let mut actions = vec![];
+
+    if self._alter_table.is_empty() == false {
+      actions.push(AlterTableAction::AlterTable);
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      if self._rename.is_empty() == false {
+        actions.push(AlterTableAction::Rename);
+      }
+      if self._rename_to.is_empty() == false {
+        actions.push(AlterTableAction::RenameTo);
+      }
+    }
+
+    let has_action = |is_kind: fn(&AlterTableOrderedAction) -> bool| {
+      self
+        ._ordered_actions
+        .iter()
+        .any(|AlterTableActionItem(action, _)| is_kind(action))
+    };
+    let has_add = has_action(|action| {
+      matches!(
+        action,
+        AlterTableOrderedAction::Add
+          | AlterTableOrderedAction::Typed(AlterTableTypedAction::AddColumn(_))
+          | AlterTableOrderedAction::Typed(AlterTableTypedAction::AddConstraint(_))
+      )
+    });
+    let has_drop = has_action(|action| {
+      matches!(
+        action,
+        AlterTableOrderedAction::Drop
+          | AlterTableOrderedAction::Typed(AlterTableTypedAction::DropColumn(_))
+          | AlterTableOrderedAction::Typed(AlterTableTypedAction::DropConstraint(_))
+      )
+    });
+    if has_add {
+      actions.push(AlterTableAction::Add);
+    }
+    if has_drop {
+      actions.push(AlterTableAction::Drop);
+    }
+
+    actions
   }
 
   /// Prints the current state of the [AlterTable] to the standard output in a more ease to read version.
@@ -136,7 +433,7 @@ impl AlterTable {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -184,12 +481,128 @@ impl AlterTable {
   /// ```sql
   /// DROP column login, DROP constraint login_unique
   /// ```
-  pub fn drop(mut self, drop_exp: &str) -> Self {
-    let action = AlterTableActionItem(AlterTableOrderedAction::Drop, drop_exp.trim().to_string());
+  pub fn drop(mut self, drop_exp: impl Into<Fragment>) -> Self {
+    let action = AlterTableActionItem(AlterTableOrderedAction::Drop, drop_exp.into().trim());
     push_unique(&mut self._ordered_actions, action);
     self
   }
 
+  /// Drops a column.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("login")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users DROP COLUMN login";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN login
+  /// ```
+  pub fn drop_column(self, column_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::DropColumn(column_name.into().trim()))
+  }
+
+  /// Drops a table constraint.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_constraint("users_login_key")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users DROP CONSTRAINT users_login_key";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP CONSTRAINT users_login_key
+  /// ```
+  pub fn drop_constraint(self, constraint_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::DropConstraint(constraint_name.into().trim()))
+  }
+
+  /// Drops the default value of a column.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_default("active")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ALTER COLUMN active DROP DEFAULT";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ALTER COLUMN active DROP DEFAULT
+  /// ```
+  pub fn drop_default(self, column_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::DropDefault(column_name.into().trim()))
+  }
+
+  /// Drops the `NOT NULL` constraint of a column.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_not_null("login")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ALTER COLUMN login DROP NOT NULL";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ALTER COLUMN login DROP NOT NULL
+  /// ```
+  pub fn drop_not_null(self, column_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::DropNotNull(column_name.into().trim()))
+  }
+
+  /// Gets the table of the `alter table` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("users")), alter_table.get_alter_table());
+  /// ```
+  pub fn get_alter_table(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._alter_table)
+  }
+
   /// Creates instance of the [AlterTable] command
   pub fn new() -> Self {
     Self::default()
@@ -199,7 +612,7 @@ impl AlterTable {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -223,8 +636,8 @@ impl AlterTable {
   /// ```sql
   /// ALTER TABLE IF EXISTS users DROP legacy_column
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -250,8 +663,8 @@ impl AlterTable {
   /// ```sql
   /// ALTER TABLE users ADD COLUMN name varchar(100) not null
   /// ```
-  pub fn raw_after(mut self, param: AlterTableAction, raw_sql: &str) -> Self {
-    self._raw_after.push((param, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, param: AlterTableAction, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
     self
   }
 
@@ -277,10 +690,259 @@ impl AlterTable {
   /// ```sql
   /// /* alter table command */ ALTER TABLE users
   /// ```
-  pub fn raw_before(mut self, action: AlterTableAction, raw_sql: &str) -> Self {
-    self._raw_before.push((action, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, action: AlterTableAction, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((action, raw_sql.into().trim()));
     self
   }
+
+  /// Renames a column, the SQL Standard has no syntax to rename a column.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_column("address", "city")
+  ///   .to_sql(sql::Dialect::Sqlite);
+  ///
+  /// # let expected = "ALTER TABLE users RENAME COLUMN address TO city";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users RENAME COLUMN address TO city
+  /// ```
+  pub fn rename_column(self, column_name: impl Into<Fragment>, new_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::RenameColumn(
+      column_name.into().trim(),
+      new_name.into().trim(),
+    ))
+  }
+
+  /// Sets the default value of a column.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .set_default("active", "true")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ALTER COLUMN active SET DEFAULT true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ALTER COLUMN active SET DEFAULT true
+  /// ```
+  pub fn set_default(self, column_name: impl Into<Fragment>, expression: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::SetDefault(
+      column_name.into().trim(),
+      expression.into().trim(),
+    ))
+  }
+
+  /// Sets the `NOT NULL` constraint of a column.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .set_not_null("login")
+  ///   .to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "ALTER TABLE users ALTER COLUMN login SET NOT NULL";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users ALTER COLUMN login SET NOT NULL
+  /// ```
+  pub fn set_not_null(self, column_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::SetNotNull(column_name.into().trim()))
+  }
+
+  /// Gets the current state of the [AlterTable] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add("COLUMN age int")
+  ///   .drop("COLUMN login");
+  ///
+  /// let standard_query = alter_table.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = alter_table.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("ALTER TABLE users DROP COLUMN login", standard_query);
+  /// # assert_eq!("ALTER TABLE users ADD COLUMN age int, DROP COLUMN login", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// ALTER TABLE users DROP COLUMN login
+  ///
+  /// -- Postgres
+  /// ALTER TABLE users
+  ///   ADD COLUMN age int,
+  ///   DROP COLUMN login
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [AlterTable] and returns it as string, returns a [BuildError] when
+  /// the [AlterTable::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add("COLUMN age int")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("ALTER TABLE users ADD COLUMN age int", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Checks the clauses of the [AlterTable], returns a [BuildError] when the table name or the actions
+  /// are missing. The actions are checked like the [AlterTable::try_to_sql] method does, in the dialect
+  /// used by the [AlterTable::as_string] method
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users");
+  ///
+  /// let error = alter_table.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "ALTER TABLE".to_string(),
+  /// #   clause: "ACTION".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [AlterTable] like the [AlterTable::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users");
+  ///
+  /// let error = alter_table.validate_for(sql::Dialect::Standard).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "ALTER TABLE".to_string(),
+  /// #   clause: "ACTION".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// Writes the current state of the [AlterTable] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_to("users_old");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// alter_table.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nALTER TABLE users RENAME TO users_old";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [AlterTable] into the [std::io::Write], like a file or a socket,
+  /// the same way the [AlterTable::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_to("users_old");
+  ///
+  /// let mut output = Vec::new();
+  /// alter_table.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "ALTER TABLE users RENAME TO users_old";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// # }
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(doc, feature = "postgresql", feature = "sqlite", feature = "mysql"))]
@@ -326,7 +988,7 @@ impl AlterTable {
   ///   .alter_table("users")
   ///   .rename("TO users_old")
   ///   .rename("COLUMN name TO full_name")
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "ALTER TABLE users RENAME TO users_old, RENAME COLUMN name TO full_name";
   /// # assert_eq!(expected, query);
@@ -340,15 +1002,16 @@ impl AlterTable {
   ///   RENAME TO users_old,
   ///   RENAME COLUMN name TO full_name
   /// ```
-  pub fn rename(mut self, action: &str) -> Self {
+  pub fn rename(mut self, action: impl Into<Fragment>) -> Self {
+    let action = action.into().trim();
     #[cfg(feature = "mysql")]
     {
-      let action = AlterTableActionItem(AlterTableOrderedAction::Rename, action.trim().to_string());
+      let action = AlterTableActionItem(AlterTableOrderedAction::Rename, action.clone());
       push_unique(&mut self._ordered_actions, action);
     }
-    #[cfg(not(feature = "mysql"))]
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
-      self._rename = action.trim().to_string();
+      self._rename = action;
     }
 
     self
@@ -382,8 +1045,8 @@ impl AlterTable {
   /// ```sql
   /// ALTER TABLE users RENAME TO users_old
   /// ```
-  pub fn rename_to(mut self, table_name: &str) -> Self {
-    self._rename_to = table_name.trim().to_string();
+  pub fn rename_to(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._rename_to = table_name.into().trim();
     self
   }
 }
@@ -415,11 +1078,214 @@ impl AlterTable {
   /// ```sql
   /// ALTER COLUMN created_at SET DEFAULT now()
   /// ```
-  pub fn alter(mut self, alter_exp: &str) -> Self {
-    let action = AlterTableActionItem(AlterTableOrderedAction::Alter, alter_exp.trim().to_string());
+  pub fn alter(mut self, alter_exp: impl Into<Fragment>) -> Self {
+    let action = AlterTableActionItem(AlterTableOrderedAction::Alter, alter_exp.into().trim());
     push_unique(&mut self._ordered_actions, action);
     self
   }
+
+  /// Changes the tablespace of the table
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .set_tablespace("fast_storage");
+  ///
+  /// let postgresql_query = alter_table.to_sql(sql::Dialect::Postgresql);
+  /// let mysql_query = alter_table.to_sql(sql::Dialect::Mysql);
+  ///
+  /// # assert_eq!("ALTER TABLE users SET TABLESPACE fast_storage", postgresql_query);
+  /// # assert_eq!("ALTER TABLE users TABLESPACE fast_storage", mysql_query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// -- Postgres
+  /// ALTER TABLE users SET TABLESPACE fast_storage
+  ///
+  /// -- MySQL
+  /// ALTER TABLE users TABLESPACE fast_storage
+  /// ```
+  pub fn set_tablespace(self, tablespace: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::SetTablespace(tablespace.into().trim()))
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl AlterTable {
+  /// Changes the owner of the table
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .owner_to("admin")
+  ///   .to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # let expected = "ALTER TABLE users OWNER TO admin";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users OWNER TO admin
+  /// ```
+  pub fn owner_to(self, role: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::OwnerTo(role.into().trim()))
+  }
+
+  /// Moves the table to another schema, Postgres doesn't allow to combine this action with others
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .set_schema("archive")
+  ///   .to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # let expected = "ALTER TABLE users SET SCHEMA archive";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE users SET SCHEMA archive
+  /// ```
+  pub fn set_schema(self, schema: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::SetSchema(schema.into().trim()))
+  }
+
+  /// Validates a constraint previously added with `NOT VALID`.
+  /// Multiples call of this method will build the SQL respecting the order of the calls
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("orders")
+  ///   .validate_constraint("orders_user_id_fkey")
+  ///   .to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # let expected = "ALTER TABLE orders VALIDATE CONSTRAINT orders_user_id_fkey";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// ALTER TABLE orders VALIDATE CONSTRAINT orders_user_id_fkey
+  /// ```
+  pub fn validate_constraint(self, constraint_name: impl Into<Fragment>) -> Self {
+    self.push_typed_action(AlterTableTypedAction::ValidateConstraint(constraint_name.into().trim()))
+  }
+}
+
+#[cfg(any(doc, feature = "sqlite"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+impl AlterTable {
+  /// Emulates the [AlterTable] in SQLite rebuilding the table, the SQLite has no syntax to drop constraints,
+  /// change the type of the columns or reorder them. The argument is the definition of the table after the changes,
+  /// the method returns a [Transaction] that creates the new table, copies the rows of the current table,
+  /// drops the current table and renames the new table.
+  ///
+  /// The columns of the new table are copied from the columns with the same name, the columns renamed by
+  /// [AlterTable::rename_column] are copied from the old name and the columns added by [AlterTable::add_column]
+  /// or [AlterTable::add] are not copied. The indexes and triggers of the table can be recreated adding them
+  /// to the returned transaction, and the foreign keys enforcement should be disabled outside the transaction as described in the
+  /// [SQLite documentation](https://www.sqlite.org/lang_altertable.html#otheralter)
+  ///
+  /// ### Example
+  ///
+  ///```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id INTEGER PRIMARY KEY")
+  ///   .column("full_name TEXT NOT NULL")
+  ///   .column("age INTEGER");
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_column("name", "full_name")
+  ///   .set_not_null("full_name")
+  ///   .add_column("age INTEGER")
+  ///   .sqlite_rebuild(users)
+  ///   .to_sql(sql::Dialect::Sqlite);
+  ///
+  /// # let expected = "\
+  /// #   BEGIN; \
+  /// #   CREATE TABLE new_users (id INTEGER PRIMARY KEY, full_name TEXT NOT NULL, age INTEGER); \
+  /// #   INSERT INTO new_users (id, full_name) SELECT id, name FROM users; \
+  /// #   DROP TABLE users; \
+  /// #   ALTER TABLE new_users RENAME TO users; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// BEGIN;
+  /// CREATE TABLE new_users (
+  ///   id INTEGER PRIMARY KEY,
+  ///   full_name TEXT NOT NULL,
+  ///   age INTEGER
+  /// );
+  /// INSERT INTO new_users (id, full_name) SELECT id, name FROM users;
+  /// DROP TABLE users;
+  /// ALTER TABLE new_users RENAME TO users;
+  /// COMMIT;
+  /// ```
+  pub fn sqlite_rebuild(&self, create_table: CreateTable) -> Transaction {
+    let (new_table_name, table_name) = rebuild_table_names(&create_table._create_table.to_sql(Dialect::Sqlite));
+    let (target_columns, source_columns): (Vec<_>, Vec<_>) = self.rebuild_columns(&create_table).into_iter().unzip();
+
+    let new_table = CreateTable {
+      _create_table: Fragment::from(&new_table_name),
+      ..create_table
+    };
+    let copy_rows = Insert::new()
+      .insert_into(format!("{new_table_name} ({})", target_columns.join(", ")))
+      .select(Select::new().select(source_columns.join(", ")).from(&self._alter_table));
+    let drop_table = DropTable::new().drop_table(&self._alter_table);
+    let rename_table = AlterTable::new().alter_table(&new_table_name).rename_to(&table_name);
+
+    Transaction::new()
+      .begin("")
+      .create_table(new_table)
+      .insert(copy_rows)
+      .drop_table(drop_table)
+      .alter_table(rename_table)
+      .commit("")
+  }
 }
 
 impl std::fmt::Display for AlterTable {
@@ -431,6 +1297,6 @@ impl std::fmt::Display for AlterTable {
 impl std::fmt::Debug for AlterTable {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
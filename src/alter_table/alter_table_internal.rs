@@ -1,136 +1,585 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{AlterTable, AlterTableAction, AlterTableOrderedAction},
+  structure::{
+    AlterTable, AlterTableAction, AlterTableActionItem, AlterTableOrderedAction, AlterTableTypedAction, BuildError,
+    Dialect, Fragment,
+  },
+  utils::{self, push_unique},
 };
+use std::fmt::Write;
+
+#[cfg(feature = "sqlite")]
+use crate::structure::CreateTable;
 
 impl Concat for AlterTable {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    query = self.concat_alter_table(query, &fmts);
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_alter_table(w, &fmts)?;
 
-    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-    {
-      query = self.concat_rename(query, &fmts);
-      query = self.concat_rename_to(query, &fmts);
+    match fmts.dialect {
+      Dialect::Standard => {
+        self.concat_single_action(w, &fmts)?;
+      }
+
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        self.concat_rename(w, &fmts)?;
+        self.concat_rename_to(w, &fmts)?;
+        self.concat_multiple_actions(w, &fmts)?;
+      }
+
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => {
+        self.concat_rename(w, &fmts)?;
+        self.concat_rename_to(w, &fmts)?;
+        self.concat_single_action(w, &fmts)?;
+      }
+
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => {
+        self.concat_multiple_actions(w, &fmts)?;
+      }
     }
-    query = self.concat_ordered_actions(query, &fmts);
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl AlterTable {
-  fn concat_alter_table(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_alter_table(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
-    let sql = if self._alter_table.is_empty() == false {
-      let table_name = &self._alter_table;
-      format!("ALTER TABLE{space}{table_name}{space}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       AlterTableAction::AlterTable,
-      sql,
+      |w| {
+        if self._alter_table.is_empty() == false {
+          write!(w, "ALTER TABLE{space}")?;
+          w.write_fragment(&self._alter_table)?;
+          w.write_str(space)?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_ordered_actions(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let actions = self._ordered_actions.iter().filter(|item| item.1.is_empty() == false);
-
-    #[cfg(any(feature = "postgresql", feature = "mysql"))]
-    {
-      use crate::structure::AlterTableActionItem;
-
-      let fmt::Formatter {
-        comma,
-        lb,
-        indent,
-        space,
-        ..
-      } = fmts;
-
-      let sql = actions
-        .map(|item| {
-          let AlterTableActionItem(action, content) = item;
-          match action {
-            AlterTableOrderedAction::Add => format!("{lb}{indent}ADD{space}{content}"),
-            AlterTableOrderedAction::Drop => format!("{lb}{indent}DROP{space}{content}"),
-            AlterTableOrderedAction::Alter => format!("{lb}{indent}ALTER{space}{content}"),
-            #[cfg(feature = "mysql")]
-            AlterTableOrderedAction::Rename => format!("{lb}{indent}RENAME{space}{content}"),
-          }
-        })
-        .collect::<Vec<_>>()
-        .join(comma)
-        .to_string();
-
-      format!("{query}{sql}{space}")
+  fn concat_single_action(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    if let Some((action, sql)) = self.actions(fmts).pop() {
+      let write_action = |w: &mut fmt::Writer| {
+        w.write_fragment(&sql)?;
+        write!(w, "{space}{lb}")
+      };
+      return match action {
+        AlterTableOrderedAction::Add => concat_raw_before_after(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          fmts,
+          AlterTableAction::Add,
+          write_action,
+        ),
+        AlterTableOrderedAction::Drop => concat_raw_before_after(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          fmts,
+          AlterTableAction::Drop,
+          write_action,
+        ),
+        _ => write_action(w),
+      };
     }
 
-    #[cfg(not(any(feature = "postgresql", feature = "mysql")))]
-    {
-      let fmt::Formatter { lb, space, .. } = fmts;
+    Ok(())
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  fn concat_multiple_actions(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter {
+      comma,
+      lb,
+      indent,
+      space,
+      ..
+    } = fmts;
 
-      if let Some(item) = actions.last() {
-        let (sql, clause) = match item.0 {
-          AlterTableOrderedAction::Add => (format!("ADD{space}{}{space}{lb}", item.1), AlterTableAction::Add),
-          AlterTableOrderedAction::Drop => (format!("DROP{space}{}{space}{lb}", item.1), AlterTableAction::Drop),
+    for (index, (_, sql)) in self.actions(fmts).into_iter().enumerate() {
+      if index > 0 {
+        w.write_str(comma)?;
+      }
+      write!(w, "{lb}{indent}")?;
+      w.write_fragment(&sql)?;
+    }
+    w.write_str(space)
+  }
+
+  /// The actions supported by the dialect in the order of the calls, each one with its SQL
+  fn actions(&self, fmts: &fmt::Formatter) -> Vec<(&AlterTableOrderedAction, Fragment)> {
+    self
+      ._ordered_actions
+      .iter()
+      .filter_map(|AlterTableActionItem(action, content)| {
+        let sql = match action {
+          AlterTableOrderedAction::Typed(typed_action) => typed_action.render(fmts.dialect)?,
+          _ if content.is_empty() => return None,
+          AlterTableOrderedAction::Add => Fragment::from("ADD ").push(content),
+          AlterTableOrderedAction::Drop => Fragment::from("DROP ").push(content),
+          #[cfg(any(feature = "postgresql", feature = "mysql"))]
+          AlterTableOrderedAction::Alter => match fmts.dialect {
+            Dialect::Standard => return None,
+            #[cfg(feature = "sqlite")]
+            Dialect::Sqlite => return None,
+            _ => Fragment::from("ALTER ").push(content),
+          },
+          #[cfg(feature = "mysql")]
+          AlterTableOrderedAction::Rename if fmts.dialect == Dialect::Mysql => Fragment::from("RENAME ").push(content),
+          #[cfg(feature = "mysql")]
+          AlterTableOrderedAction::Rename => return None,
         };
+        Some((action, sql))
+      })
+      .collect()
+  }
+
+  /// The names of the actions supported by the dialect, including the renames that are not ordered actions
+  pub(crate) fn action_names(&self, dialect: Dialect) -> Vec<String> {
+    #[allow(unused_mut)]
+    let mut names = vec![];
 
-        return concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, clause, sql);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if matches!(dialect, Dialect::Standard) == false {
+      if self._rename.is_empty() == false {
+        names.push("RENAME".to_string());
+      }
+      if self._rename_to.is_empty() == false {
+        names.push("RENAME TO".to_string());
       }
+    }
+
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let actions = self.actions(&fmts).into_iter().map(|(action, _)| match action {
+      AlterTableOrderedAction::Add => "ADD".to_string(),
+      AlterTableOrderedAction::Drop => "DROP".to_string(),
+      #[cfg(any(feature = "postgresql", feature = "mysql"))]
+      AlterTableOrderedAction::Alter => "ALTER".to_string(),
+      #[cfg(feature = "mysql")]
+      AlterTableOrderedAction::Rename => "RENAME".to_string(),
+      AlterTableOrderedAction::Typed(typed_action) => typed_action.name().to_string(),
+    });
+    names.extend(actions);
+
+    names
+  }
+
+  /// Checks if the dialect supports all typed actions and can execute all actions in a single statement
+  pub(crate) fn check_actions(&self, dialect: Dialect) -> Result<(), BuildError> {
+    let unsupported_feature = self._ordered_actions.iter().find_map(|item| match &item.0 {
+      AlterTableOrderedAction::Typed(typed_action) => typed_action.unsupported_feature(dialect),
+      _ => None,
+    });
+
+    if let Some(feature) = unsupported_feature {
+      return Err(BuildError::UnsupportedFeature {
+        dialect,
+        feature: feature.to_string(),
+      });
+    }
+
+    let definitions = self._ordered_actions.iter().filter_map(|item| match &item.0 {
+      AlterTableOrderedAction::Typed(typed_action) => typed_action.render(dialect),
+      _ => Some(item.1.clone()),
+    });
+    fmt::check_data_types(&definitions.collect::<Vec<_>>(), dialect)?;
+
+    let actions = self.action_names(dialect);
+    let is_compatible = match dialect {
+      Dialect::Standard => actions.len() <= 1,
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        let standalone_actions = ["RENAME", "RENAME TO", "RENAME COLUMN", "SET SCHEMA"];
+        actions.len() <= 1
+          || actions
+            .iter()
+            .all(|action| standalone_actions.contains(&action.as_str()) == false)
+      }
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => actions.len() <= 1,
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => true,
+    };
 
-      query
+    if is_compatible == false {
+      return Err(BuildError::IncompatibleActions { dialect, actions });
     }
+
+    Ok(())
+  }
+
+  pub(crate) fn push_typed_action(mut self, action: AlterTableTypedAction) -> Self {
+    let action = AlterTableActionItem(AlterTableOrderedAction::Typed(action), Fragment::new());
+    push_unique(&mut self._ordered_actions, action);
+    self
   }
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  fn concat_rename(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_rename(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._rename.is_empty() == false {
-      let action = &self._rename;
-
-      format!("RENAME{space}{action}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       AlterTableAction::Rename,
-      sql,
+      |w| {
+        if self._rename.is_empty() == false {
+          write!(w, "RENAME{space}")?;
+          w.write_fragment(&self._rename)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  fn concat_rename_to(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_rename_to(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._rename_to.is_empty() == false {
-      let table_name = &self._rename_to;
-
-      format!("RENAME TO{space}{table_name}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       AlterTableAction::RenameTo,
-      sql,
+      |w| {
+        if self._rename_to.is_empty() == false {
+          write!(w, "RENAME TO{space}")?;
+          w.write_fragment(&self._rename_to)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
+
+impl AlterTableTypedAction {
+  /// The name of the action used to report the errors
+  pub(crate) fn name(&self) -> &'static str {
+    match self {
+      Self::AddColumn(..) => "ADD COLUMN",
+      Self::AddConstraint(..) => "ADD CONSTRAINT",
+      Self::AlterColumnType(..) => "ALTER COLUMN TYPE",
+      Self::DropColumn(..) => "DROP COLUMN",
+      Self::DropConstraint(..) => "DROP CONSTRAINT",
+      Self::DropDefault(..) => "ALTER COLUMN DROP DEFAULT",
+      Self::DropNotNull(..) => "ALTER COLUMN DROP NOT NULL",
+      Self::RenameColumn(..) => "RENAME COLUMN",
+      Self::SetDefault(..) => "ALTER COLUMN SET DEFAULT",
+      Self::SetNotNull(..) => "ALTER COLUMN SET NOT NULL",
+      #[cfg(feature = "postgresql")]
+      Self::OwnerTo(..) => "OWNER TO",
+      #[cfg(feature = "postgresql")]
+      Self::SetSchema(..) => "SET SCHEMA",
+      #[cfg(any(feature = "postgresql", feature = "mysql"))]
+      Self::SetTablespace(..) => "SET TABLESPACE",
+      #[cfg(feature = "postgresql")]
+      Self::ValidateConstraint(..) => "VALIDATE CONSTRAINT",
+      #[cfg(feature = "mysql")]
+      Self::ModifyColumn(..) => "MODIFY COLUMN",
+    }
+  }
+
+  /// The feature of the action that the dialect doesn't support, the whole action or only a part of it
+  pub(crate) fn unsupported_feature(&self, dialect: Dialect) -> Option<&'static str> {
+    if self.render(dialect).is_none() {
+      return Some(self.name());
+    }
+
+    match (self, dialect) {
+      #[cfg(feature = "postgresql")]
+      (Self::AlterColumnType(..), Dialect::Postgresql) => None,
+      (Self::AlterColumnType(_, _, using), _) if using.is_empty() == false => Some("ALTER COLUMN TYPE USING"),
+      _ => None,
+    }
+  }
+
+  /// Renders the action in the syntax of the dialect, returns `None` when the dialect doesn't support the action
+  pub(crate) fn render(&self, dialect: Dialect) -> Option<Fragment> {
+    let is_postgres = match dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => true,
+      _ => false,
+    };
+
+    let sql = match self {
+      Self::AddColumn(column) => Fragment::from("ADD COLUMN ").push(column),
+      Self::AddConstraint(constraint) => match dialect {
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        _ if constraint.is_variants() => Fragment::from("ADD ").push(constraint),
+        _ => Fragment::from("ADD CONSTRAINT ").push(constraint),
+      },
+      Self::AlterColumnType(column, data_type, using) if is_postgres && using.is_empty() == false => {
+        Fragment::from("ALTER COLUMN ")
+          .push(column)
+          .push(" TYPE ")
+          .push(data_type.definition(dialect))
+          .push(" USING ")
+          .push(using)
+      }
+      Self::AlterColumnType(column, data_type, _) => match dialect {
+        Dialect::Standard => Fragment::from("ALTER COLUMN ")
+          .push(column)
+          .push(" SET DATA TYPE ")
+          .push(data_type.definition(dialect)),
+        #[cfg(feature = "postgresql")]
+        Dialect::Postgresql => Fragment::from("ALTER COLUMN ")
+          .push(column)
+          .push(" TYPE ")
+          .push(data_type.definition(dialect)),
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        #[cfg(feature = "mysql")]
+        Dialect::Mysql => Fragment::from("MODIFY COLUMN ")
+          .push(column)
+          .push(" ")
+          .push(data_type.definition(dialect)),
+      },
+      Self::DropColumn(column) => Fragment::from("DROP COLUMN ").push(column),
+      Self::DropConstraint(constraint) => match dialect {
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        _ => Fragment::from("DROP CONSTRAINT ").push(constraint),
+      },
+      Self::DropDefault(column) => match dialect {
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        _ => Fragment::from("ALTER COLUMN ").push(column).push(" DROP DEFAULT"),
+      },
+      Self::DropNotNull(column) => match dialect {
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        #[cfg(feature = "mysql")]
+        Dialect::Mysql => return None,
+        _ => Fragment::from("ALTER COLUMN ").push(column).push(" DROP NOT NULL"),
+      },
+      Self::RenameColumn(column, new_name) if dialect != Dialect::Standard => Fragment::from("RENAME COLUMN ")
+        .push(column)
+        .push(" TO ")
+        .push(new_name),
+      Self::RenameColumn(..) => return None,
+      Self::SetDefault(column, expression) => match dialect {
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        _ => Fragment::from("ALTER COLUMN ")
+          .push(column)
+          .push(" SET DEFAULT ")
+          .push(expression),
+      },
+      Self::SetNotNull(column) => match dialect {
+        #[cfg(feature = "sqlite")]
+        Dialect::Sqlite => return None,
+        #[cfg(feature = "mysql")]
+        Dialect::Mysql => return None,
+        _ => Fragment::from("ALTER COLUMN ").push(column).push(" SET NOT NULL"),
+      },
+      #[cfg(feature = "postgresql")]
+      Self::OwnerTo(role) => match dialect {
+        Dialect::Postgresql => Fragment::from("OWNER TO ").push(role),
+        _ => return None,
+      },
+      #[cfg(feature = "postgresql")]
+      Self::SetSchema(schema) => match dialect {
+        Dialect::Postgresql => Fragment::from("SET SCHEMA ").push(schema),
+        _ => return None,
+      },
+      #[cfg(any(feature = "postgresql", feature = "mysql"))]
+      Self::SetTablespace(tablespace) => match dialect {
+        #[cfg(feature = "postgresql")]
+        Dialect::Postgresql => Fragment::from("SET TABLESPACE ").push(tablespace),
+        #[cfg(feature = "mysql")]
+        Dialect::Mysql => Fragment::from("TABLESPACE ").push(tablespace),
+        _ => return None,
+      },
+      #[cfg(feature = "postgresql")]
+      Self::ValidateConstraint(constraint) => match dialect {
+        Dialect::Postgresql => Fragment::from("VALIDATE CONSTRAINT ").push(constraint),
+        _ => return None,
+      },
+      #[cfg(feature = "mysql")]
+      Self::ModifyColumn(column_def) => match dialect {
+        Dialect::Mysql => Fragment::from("MODIFY COLUMN ").push(column_def),
+        _ => return None,
+      },
+    };
+
+    Some(sql)
+  }
+}
+
+#[cfg(feature = "sqlite")]
+impl AlterTable {
+  /// The columns copied by the SQLite rebuild, each target column of the new table with its source
+  /// column of the current table. The columns added by the actions have no source and are not copied
+  pub(crate) fn rebuild_columns(&self, create_table: &CreateTable) -> Vec<(String, String)> {
+    let added_columns = self
+      ._ordered_actions
+      .iter()
+      .filter_map(|AlterTableActionItem(action, content)| match action {
+        AlterTableOrderedAction::Typed(AlterTableTypedAction::AddColumn(column_def)) => {
+          Some(column_name(&column_def.to_sql(Dialect::Sqlite)))
+        }
+        AlterTableOrderedAction::Add => {
+          let content = content.to_sql(Dialect::Sqlite);
+          let keyword = content.split_whitespace().next()?;
+          if keyword.eq_ignore_ascii_case("column") {
+            return Some(column_name(content.trim_start()[keyword.len()..].trim_start()));
+          }
+          let is_constraint = TABLE_CONSTRAINTS
+            .iter()
+            .any(|constraint| keyword.eq_ignore_ascii_case(constraint));
+          (is_constraint == false).then(|| column_name(&content))
+        }
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+
+    create_table
+      ._column
+      .iter()
+      .filter(|column_def| column_def.is_empty() == false)
+      .map(|column_def| column_name(&column_def.to_sql(Dialect::Sqlite)))
+      .filter(|column| {
+        added_columns
+          .iter()
+          .any(|added_column| same_column(added_column, column))
+          == false
+      })
+      .map(|column| {
+        let source = self._ordered_actions.iter().rev().find_map(|item| match &item.0 {
+          AlterTableOrderedAction::Typed(AlterTableTypedAction::RenameColumn(old_name, new_name))
+            if same_column(&new_name.to_sql(Dialect::Sqlite), &column) =>
+          {
+            Some(old_name.to_sql(Dialect::Sqlite))
+          }
+          _ => None,
+        });
+        let source = source.unwrap_or_else(|| column.clone());
+        (column, source)
+      })
+      .collect()
+  }
+}
+
+/// The keywords that start a table constraint in the `ADD` action, any other text added is a column definition
+/// as the `COLUMN` keyword is optional
+#[cfg(feature = "sqlite")]
+const TABLE_CONSTRAINTS: [&str; 6] = ["CONSTRAINT", "PRIMARY", "UNIQUE", "FOREIGN", "CHECK", "INDEX"];
+
+/// The name of the new table of the SQLite rebuild and the name it's renamed to. The `new_` prefix is added
+/// to the table name without the schema and inside the quotes, the schema is kept only in the name of the new table
+#[cfg(feature = "sqlite")]
+pub(crate) fn rebuild_table_names(table_name: &str) -> (String, String) {
+  let table_name = ["IF", "NOT", "EXISTS"]
+    .iter()
+    .try_fold(table_name.trim(), |text, keyword| {
+      let word = text.split_whitespace().next()?;
+      word
+        .eq_ignore_ascii_case(keyword)
+        .then(|| text[word.len()..].trim_start())
+    })
+    .unwrap_or(table_name.trim());
+
+  let mut closing_quote = None;
+  let mut name_start = 0;
+  for (index, c) in table_name.char_indices() {
+    match (closing_quote, c) {
+      (Some(quote), c) if c == quote => closing_quote = None,
+      (Some(_), _) => {}
+      (None, '"') => closing_quote = Some('"'),
+      (None, '`') => closing_quote = Some('`'),
+      (None, '[') => closing_quote = Some(']'),
+      (None, '.') => name_start = index + 1,
+      _ => {}
+    }
+  }
+
+  let (schema, name) = table_name.split_at(name_start);
+  let new_table_name = match name.chars().next() {
+    Some(quote @ ('"' | '`' | '[')) => format!("{schema}{quote}new_{}", &name[1..]),
+    _ => format!("{schema}new_{name}"),
+  };
+
+  (new_table_name, name.to_string())
+}
+
+/// Whether two column names are the same column. SQLite matches the names ignoring the ASCII case,
+/// quoted or not, so `"Login"` and `login` are the same column
+#[cfg(feature = "sqlite")]
+fn same_column(name: &str, other_name: &str) -> bool {
+  fn unquote(name: &str) -> &str {
+    let name = name.trim();
+    match (name.chars().next(), name.chars().last()) {
+      (Some('"'), Some('"')) | (Some('`'), Some('`')) | (Some('['), Some(']')) if name.len() > 1 => {
+        &name[1..name.len() - 1]
+      }
+      _ => name,
+    }
+  }
+
+  unquote(name).eq_ignore_ascii_case(unquote(other_name))
+}
+
+/// The name of the column from its definition, the quoted names can contain spaces
+#[cfg(feature = "sqlite")]
+fn column_name(column_def: &str) -> String {
+  let column_def = column_def.trim();
+
+  let closing_quote = match column_def.chars().next() {
+    Some('"') => Some('"'),
+    Some('`') => Some('`'),
+    Some('[') => Some(']'),
+    _ => None,
+  };
+  let name_end = closing_quote
+    .and_then(|quote| column_def.char_indices().skip(1).find(|(_, c)| *c == quote))
+    .map(|(index, c)| index + c.len_utf8())
+    .or_else(|| column_def.find(char::is_whitespace))
+    .unwrap_or(column_def.len());
+
+  column_def[..name_end].to_string()
+}
+
+impl Validate for AlterTable {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_actions(dialect)?;
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) == false {
+      #[cfg(not(any(feature = "postgresql", feature = "sqlite")))]
+      let has_action = self._ordered_actions.is_empty() == false;
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      let has_action = self._ordered_actions.is_empty() == false
+        || self._rename.is_empty() == false
+        || self._rename_to.is_empty() == false;
+
+      utils::require_clause("ALTER TABLE", "ALTER TABLE", self._alter_table.is_empty())?;
+      utils::require_clause("ALTER TABLE", "ACTION", has_action == false)?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,8 +1,38 @@
-use crate::concat::Concat;
+use crate::{
+  concat::Concat,
+  structure::{BuildError, Dialect, TokenKind, Value},
+};
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+use crate::introspection::CollectTables;
+
+/// Represents the values that can be bound to the placeholders of a clause,
+/// a single value or a tuple of values, each one bound to a `?` in the order they appear
+pub trait BindValues {
+  fn into_values(self) -> Vec<Value>;
+}
+
+/// Represents the output of the [highlight](crate::highlight) function, the backend decorates the text of
+/// each token of the query and the decorated tokens are concatenated in the order they appear
+pub trait HighlightBackend {
+  fn paint(&self, kind: TokenKind, text: &str) -> String;
+}
 
 /// Represents all commands that can be used in a transaction
-pub trait TransactionQuery: Concat {}
+pub trait TransactionQuery: Concat + Validate {}
+
+/// Represents the commands that check its clauses before rendering, the clauses not supported
+/// by the dialect are reported as well
+pub trait Validate {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError>;
+}
 
 /// Represents all commands that can be used inside the with method
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-pub trait WithQuery: Concat {}
+pub trait WithQuery: Concat + Validate + CollectTables {}
+
+/// Represents the values that can be rendered as a SQL literal, escaped in the syntax of each dialect.
+/// Use [Literal](crate::Literal) to compose the literal inside a clause
+pub trait ToSqlLiteral {
+  fn to_sql_literal(&self, dialect: Dialect) -> String;
+}
@@ -0,0 +1,155 @@
+use crate::{
+  behavior::BindValues,
+  structure::{Fragment, Part, Value},
+};
+
+/// Replaces each `?` of the clause, except the ones inside quoted strings and quoted identifiers, by the respective
+/// value. The Postgres operators `?|` and `?&` are kept and the escaped `??` is written as a single `?`, like the
+/// operator `?` of the jsonb type. When the number of values doesn't match the placeholders the remaining `?` are kept,
+/// the extra values are discarded and the mismatch is kept in the fragment to be reported by the validation
+/// of the command and by the methods that render the command returning a result
+pub(crate) fn bind(clause: impl Into<Fragment>, values: impl BindValues) -> Fragment {
+  let values = values.into_values();
+  let found = values.len();
+  let mut values = values.into_iter();
+  let mut expected = 0;
+  let mut quote = None;
+  let mut result = Fragment::new();
+
+  for part in clause.into().trim()._parts {
+    let text = match part {
+      Part::Text(text) => text,
+      part => {
+        result.push_part(part);
+        continue;
+      }
+    };
+
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+      match (quote, c, chars.peek()) {
+        (None, '\'' | '"' | '`', _) => quote = Some(c),
+        (Some(q), _, _) if q == c => quote = None,
+        (None, '?', Some((_, '?'))) => {
+          result.push_part(Part::Text(text[start..=index].to_string()));
+          chars.next();
+          start = index + 2;
+        }
+        (None, '?', Some((_, '|' | '&'))) => {}
+        (None, '?', _) => {
+          expected += 1;
+          if let Some(value) = values.next() {
+            result.push_part(Part::Text(text[start..index].to_string()));
+            result.push_part(Part::Value(value));
+            start = index + c.len_utf8();
+          }
+        }
+        _ => {}
+      }
+    }
+    result.push_part(Part::Text(text[start..].to_string()));
+  }
+
+  if expected != found {
+    result.push_part(Part::BindMismatch { expected, found });
+  }
+
+  result
+}
+
+impl<T: Into<Value>> BindValues for T {
+  fn into_values(self) -> Vec<Value> {
+    vec![self.into()]
+  }
+}
+
+impl<T: Into<Value>, const N: usize> BindValues for [T; N] {
+  fn into_values(self) -> Vec<Value> {
+    self.into_iter().map(|value| value.into()).collect()
+  }
+}
+
+impl BindValues for Vec<Value> {
+  fn into_values(self) -> Vec<Value> {
+    self
+  }
+}
+
+macro_rules! bind_values_tuple {
+  ($($name:ident),+) => {
+    impl<$($name: Into<Value>),+> BindValues for ($($name,)+) {
+      #[allow(non_snake_case)]
+      fn into_values(self) -> Vec<Value> {
+        let ($($name,)+) = self;
+        vec![$($name.into()),+]
+      }
+    }
+  };
+}
+
+bind_values_tuple!(A, B);
+bind_values_tuple!(A, B, C);
+bind_values_tuple!(A, B, C, D);
+bind_values_tuple!(A, B, C, D, E);
+bind_values_tuple!(A, B, C, D, E, F);
+
+macro_rules! value_from {
+  ($variant:ident, $cast:ty, $($source:ty),+) => {
+    $(
+      impl From<$source> for Value {
+        fn from(value: $source) -> Self {
+          Value::$variant(value as $cast)
+        }
+      }
+    )+
+  };
+}
+
+value_from!(Int, i64, i8, i16, i32, i64, u8, u16, u32);
+value_from!(Float, f64, f32, f64);
+
+impl From<bool> for Value {
+  fn from(value: bool) -> Self {
+    Value::Bool(value)
+  }
+}
+
+impl From<&str> for Value {
+  fn from(value: &str) -> Self {
+    Value::Text(value.to_string())
+  }
+}
+
+impl From<String> for Value {
+  fn from(value: String) -> Self {
+    Value::Text(value)
+  }
+}
+
+impl From<&String> for Value {
+  fn from(value: &String) -> Self {
+    Value::Text(value.clone())
+  }
+}
+
+impl From<Vec<u8>> for Value {
+  fn from(value: Vec<u8>) -> Self {
+    Value::Bytes(value)
+  }
+}
+
+impl From<&[u8]> for Value {
+  fn from(value: &[u8]) -> Self {
+    Value::Bytes(value.to_vec())
+  }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => value.into(),
+      None => Value::Null,
+    }
+  }
+}
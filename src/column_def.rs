@@ -0,0 +1,398 @@
+use crate::{
+  structure::{ColumnDef, DataType, Dialect, Fragment},
+  utils,
+};
+
+impl ColumnDef {
+  /// Creates the definition of a column with its name and data type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("login", sql::DataType::Varchar(40));
+  ///
+  /// # assert_eq!("login VARCHAR(40)", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// login VARCHAR(40)
+  /// ```
+  pub fn new(name: impl Into<Fragment>, data_type: DataType) -> Self {
+    Self {
+      _check: Fragment::new(),
+      _data_type: data_type,
+      _default: Fragment::new(),
+      _generated: Fragment::new(),
+      _generated_stored: false,
+      _identity: false,
+      _name: name.into().trim(),
+      _nullable: None,
+      _primary_key: false,
+      _references: Fragment::new(),
+      _unique: false,
+    }
+  }
+
+  /// Gets the column definition in the syntax of the default dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("active", sql::DataType::Boolean);
+  ///
+  /// # assert_eq!(column.to_sql(sql::Dialect::default()), column.as_string());
+  /// ```
+  pub fn as_string(&self) -> String {
+    self.to_sql(Dialect::default())
+  }
+
+  /// Defines a check constraint of the column, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("age", sql::DataType::Integer).check("age >= 0");
+  ///
+  /// # assert_eq!("age INTEGER CHECK (age >= 0)", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// age INTEGER CHECK (age >= 0)
+  /// ```
+  pub fn check(mut self, condition: impl Into<Fragment>) -> Self {
+    self._check = condition.into().trim();
+    self
+  }
+
+  /// Defines the default value of the column, the expression is written as is, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("status", sql::DataType::Text).default(&sql::Literal::new("active"));
+  ///
+  /// # assert_eq!("status TEXT DEFAULT 'active'", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// status TEXT DEFAULT 'active'
+  /// ```
+  pub fn default(mut self, expression: impl Into<Fragment>) -> Self {
+    self._default = expression.into().trim();
+    self
+  }
+
+  /// Defines the column as computed from the expression every time it's read, this method overrides the previous value.
+  /// Postgres only supports stored generated columns, so the column is always rendered as stored in that dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("total", sql::DataType::Numeric(10, 2)).generated("price * quantity");
+  ///
+  /// # let expected = "total NUMERIC(10, 2) GENERATED ALWAYS AS (price * quantity)";
+  /// # assert_eq!(expected, column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// total NUMERIC(10, 2) GENERATED ALWAYS AS (price * quantity)
+  /// ```
+  pub fn generated(mut self, expression: impl Into<Fragment>) -> Self {
+    self._generated = expression.into().trim();
+    self._generated_stored = false;
+    self
+  }
+
+  /// Defines the column as computed from the expression when the row is written, this method overrides the previous value.
+  /// The SQL Standard has no syntax for the storage of the generated columns, so the keyword `STORED` is omitted in that dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("total", sql::DataType::Integer).generated_stored("price * quantity");
+  ///
+  /// # let expected = "total INTEGER GENERATED ALWAYS AS (price * quantity) STORED";
+  /// # assert_eq!(expected, column.to_sql(sql::Dialect::Sqlite));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- SQLite
+  /// total INTEGER GENERATED ALWAYS AS (price * quantity) STORED
+  /// ```
+  pub fn generated_stored(mut self, expression: impl Into<Fragment>) -> Self {
+    self._generated = expression.into().trim();
+    self._generated_stored = true;
+    self
+  }
+
+  /// Defines the column as auto-incremented by the database, rendered as an identity column in the SQL Standard
+  /// and Postgres and as `AUTO_INCREMENT` in MySQL. In SQLite only the rowid can be auto-incremented,
+  /// so the column is rendered as `INTEGER PRIMARY KEY AUTOINCREMENT` whatever the data type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("id", sql::DataType::BigInt).identity().primary_key();
+  ///
+  /// # assert_eq!("id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY", column.to_sql(sql::Dialect::Standard));
+  /// # assert_eq!("id INTEGER PRIMARY KEY AUTOINCREMENT", column.to_sql(sql::Dialect::Sqlite));
+  /// # assert_eq!("id BIGINT AUTO_INCREMENT PRIMARY KEY", column.to_sql(sql::Dialect::Mysql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY
+  ///
+  /// -- SQLite
+  /// id INTEGER PRIMARY KEY AUTOINCREMENT
+  ///
+  /// -- MySQL
+  /// id BIGINT AUTO_INCREMENT PRIMARY KEY
+  /// ```
+  pub fn identity(mut self) -> Self {
+    self._identity = true;
+    self
+  }
+
+  /// Defines the column as not nullable, this method overrides the previous call of [ColumnDef::null]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("login", sql::DataType::Text).not_null();
+  ///
+  /// # assert_eq!("login TEXT NOT NULL", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// login TEXT NOT NULL
+  /// ```
+  pub fn not_null(mut self) -> Self {
+    self._nullable = Some(false);
+    self
+  }
+
+  /// Defines the column as nullable explicitly, this method overrides the previous call of [ColumnDef::not_null]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("bio", sql::DataType::Text).null();
+  ///
+  /// # assert_eq!("bio TEXT NULL", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// bio TEXT NULL
+  /// ```
+  pub fn null(mut self) -> Self {
+    self._nullable = Some(true);
+    self
+  }
+
+  /// Defines the column as the primary key of the table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("code", sql::DataType::Varchar(3)).primary_key();
+  ///
+  /// # assert_eq!("code VARCHAR(3) PRIMARY KEY", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// code VARCHAR(3) PRIMARY KEY
+  /// ```
+  pub fn primary_key(mut self) -> Self {
+    self._primary_key = true;
+    self
+  }
+
+  /// Defines the table and the column referenced by the column, when the column is empty the primary key
+  /// of the referenced table is used, this method overrides the previous value.
+  /// Note that MySQL parses but ignores the references written in the column definition,
+  /// use the table constraint of [CreateTable::foreign_key](crate::CreateTable::foreign_key) instead
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("user_id", sql::DataType::Integer).references("users", "id");
+  ///
+  /// # assert_eq!("user_id INTEGER REFERENCES users (id)", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// user_id INTEGER REFERENCES users (id)
+  /// ```
+  pub fn references(mut self, table: impl Into<Fragment>, column: impl Into<Fragment>) -> Self {
+    let (table, column) = (table.into().trim(), column.into().trim());
+    self._references = if column.is_empty() {
+      table
+    } else {
+      table.push(" (").push(column).push(")")
+    };
+    self
+  }
+
+  /// Gets the column definition in the syntax of the specified SQL [Dialect]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("id", sql::DataType::Integer).identity();
+  ///
+  /// # assert_eq!("id INTEGER GENERATED BY DEFAULT AS IDENTITY", column.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// id INTEGER GENERATED BY DEFAULT AS IDENTITY
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    self.definition(dialect).to_sql(dialect)
+  }
+
+  /// Defines the column as unique
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let column = sql::ColumnDef::new("email", sql::DataType::Text).unique();
+  ///
+  /// # assert_eq!("email TEXT UNIQUE", column.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// email TEXT UNIQUE
+  /// ```
+  pub fn unique(mut self) -> Self {
+    self._unique = true;
+    self
+  }
+
+  pub(crate) fn definition(&self, dialect: Dialect) -> Fragment {
+    #[cfg(feature = "sqlite")]
+    let rowid = self._identity && dialect == Dialect::Sqlite;
+    #[cfg(not(feature = "sqlite"))]
+    let rowid = false;
+
+    let data_type = if rowid {
+      DataType::Integer.definition(dialect)
+    } else {
+      self._data_type.definition(dialect)
+    };
+    let mut definition = vec![self._name.clone(), data_type];
+
+    if self._generated.is_empty() == false {
+      let stored = match dialect {
+        Dialect::Standard => false,
+        #[cfg(feature = "postgresql")]
+        Dialect::Postgresql => true,
+        #[cfg(any(feature = "sqlite", feature = "mysql"))]
+        _ => self._generated_stored,
+      };
+      let storage = if stored { " STORED" } else { "" };
+      definition.push(
+        Fragment::from("GENERATED ALWAYS AS (")
+          .push(&self._generated)
+          .push(format!("){storage}")),
+      );
+    }
+
+    if self._identity && rowid == false {
+      let identity = match dialect {
+        #[cfg(feature = "mysql")]
+        Dialect::Mysql => "AUTO_INCREMENT",
+        _ => "GENERATED BY DEFAULT AS IDENTITY",
+      };
+      definition.push(Fragment::from(identity));
+    }
+
+    match self._nullable {
+      Some(false) => definition.push(Fragment::from("NOT NULL")),
+      Some(true) => definition.push(Fragment::from("NULL")),
+      None => {}
+    }
+
+    if self._default.is_empty() == false {
+      definition.push(Fragment::from("DEFAULT ").push(&self._default));
+    }
+
+    if rowid {
+      definition.push(Fragment::from("PRIMARY KEY AUTOINCREMENT"));
+    } else if self._primary_key {
+      definition.push(Fragment::from("PRIMARY KEY"));
+    }
+
+    if self._unique {
+      definition.push(Fragment::from("UNIQUE"));
+    }
+
+    if self._check.is_empty() == false {
+      definition.push(Fragment::from("CHECK (").push(&self._check).push(")"));
+    }
+
+    if self._references.is_empty() == false {
+      definition.push(Fragment::from("REFERENCES ").push(&self._references));
+    }
+
+    utils::join(&definition, " ")
+  }
+}
+
+impl std::fmt::Display for ColumnDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for ColumnDef {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
@@ -1,45 +1,64 @@
-use crate::fmt;
+use crate::{fmt, structure::Fragment};
+use std::fmt::Write;
 
 pub(crate) mod mysql;
 pub(crate) mod non_standard;
 pub(crate) mod sql_standard;
 pub(crate) mod sqlite;
 
+// Each step of the rendering writes its clause straight to the output, this way the text of the query
+// is never copied to a new string as the clauses are added
 pub trait Concat {
-  fn concat(&self, fmts: &fmt::Formatter) -> String;
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result;
 
-  fn concat_raw(&self, query: String, fmts: &fmt::Formatter, items: &Vec<String>) -> String {
+  fn concat_raw(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter, items: &Vec<Fragment>) -> fmt::Result {
     if items.is_empty() {
-      return query;
+      return Ok(());
     }
     let fmt::Formatter { lb, space, .. } = fmts;
-    let raw_sql = items.join(space).trim().to_string();
-
-    format!("{query}{raw_sql}{space}{lb}")
+    w.begin_raw();
+    push_joined(w, items.iter(), space)?;
+    w.write_str(space)?;
+    w.write_str(lb)
   }
 }
 
 pub(crate) fn concat_raw_before_after<Clause: PartialEq>(
-  items_before: &Vec<(Clause, String)>,
-  items_after: &Vec<(Clause, String)>,
-  query: String,
+  items_before: &Vec<(Clause, Fragment)>,
+  items_after: &Vec<(Clause, Fragment)>,
+  w: &mut fmt::Writer,
   fmts: &fmt::Formatter,
   clause: Clause,
-  sql: String,
-) -> String {
+  write: impl FnOnce(&mut fmt::Writer) -> fmt::Result,
+) -> fmt::Result {
   let fmt::Formatter { space, .. } = fmts;
-  let raw_before = raw_queries(items_before, &clause).join(space).trim().to_string();
-  let raw_after = raw_queries(items_after, &clause).join(space).trim().to_string();
-  let space_after = if raw_after.is_empty() == false { space } else { "" };
-  let space_before = if raw_before.is_empty() == false { space } else { "" };
+  let raw_before = items_before.iter().filter(|item| item.0 == clause).map(|item| &item.1);
+  if push_joined(w, raw_before, space)? {
+    w.write_str(space)?;
+  }
+  write(w)?;
+  let raw_after = items_after.iter().filter(|item| item.0 == clause).map(|item| &item.1);
+  if push_joined(w, raw_after, space)? {
+    w.write_str(space)?;
+  }
 
-  format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}")
+  Ok(())
 }
 
-pub(crate) fn raw_queries<Clause: PartialEq>(raw_list: &Vec<(Clause, String)>, clause: &Clause) -> Vec<String> {
-  raw_list
-    .iter()
-    .filter(|item| item.0 == *clause)
-    .map(|item| item.1.clone())
-    .collect::<Vec<_>>()
+/// Writes the items joined by the separator, returns true when something was written
+pub(crate) fn push_joined<'a>(
+  w: &mut fmt::Writer,
+  items: impl Iterator<Item = &'a Fragment>,
+  sep: &str,
+) -> Result<bool, std::fmt::Error> {
+  let start = w.mark();
+  for item in items {
+    if w.has_written_since(start) {
+      w.write_str(sep)?;
+    }
+    w.write_fragment(item)?;
+  }
+  w.trim_end_since(start);
+
+  Ok(w.has_written_since(start))
 }
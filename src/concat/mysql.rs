@@ -1,31 +1,29 @@
 #[cfg(feature = "mysql")]
-use crate::{concat::concat_raw_before_after, fmt, utils};
+use crate::{concat::concat_raw_before_after, fmt, pretty::Body, structure::Fragment};
+#[cfg(feature = "mysql")]
+use std::fmt::Write;
 
 #[cfg(feature = "mysql")]
 pub(crate) trait ConcatPartition<Clause: PartialEq> {
   fn concat_partition(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Fragment>,
+  ) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
 
-    let sql = if items.is_empty() == false {
-      let column_names = utils::join(items, comma);
-
-      if column_names.is_empty() == false {
-        format!("PARTITION{space}({column_names}){space}{lb}")
-      } else {
-        "".to_string()
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if items.iter().any(|item| item.is_empty() == false) {
+        w.write_clause("PARTITION", Body::Text)?;
+        write!(w, "{space}(")?;
+        w.write_joined(items, comma)?;
+        write!(w, "){space}{lb}")?;
       }
-    } else {
-      "".to_string()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+      Ok(())
+    })
   }
 }
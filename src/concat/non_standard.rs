@@ -1,25 +1,30 @@
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-use crate::{behavior::WithQuery, concat::concat_raw_before_after, fmt, utils};
+use crate::{behavior::WithQuery, concat::concat_raw_before_after, fmt, pretty::Body, structure::Fragment};
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+use std::fmt::Write;
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 pub(crate) trait ConcatLimit<Clause: PartialEq> {
   fn concat_limit(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    limit: &str,
-  ) -> String {
+    limit: &Fragment,
+  ) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if limit.is_empty() == false {
-      format!("LIMIT{space}{limit}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if limit.is_empty() == false {
+        w.write_clause("LIMIT", Body::Text)?;
+        w.write_str(space)?;
+        w.write_fragment(limit)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
 
@@ -27,22 +32,24 @@ pub(crate) trait ConcatLimit<Clause: PartialEq> {
 pub(crate) trait ConcatReturning<Clause: PartialEq> {
   fn concat_returning(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Fragment>,
+  ) -> fmt::Result {
     let fmt::Formatter { lb, space, comma, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let output_names = utils::join(items, comma);
-      format!("RETURNING{space}{output_names}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if items.is_empty() == false {
+        w.write_clause("RETURNING", Body::List)?;
+        w.write_str(space)?;
+        w.write_items(items, comma)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
 
@@ -50,13 +57,13 @@ pub(crate) trait ConcatReturning<Clause: PartialEq> {
 pub(crate) trait ConcatWith<Clause: PartialEq> {
   fn concat_with(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<(String, std::sync::Arc<dyn WithQuery + Send + Sync>)>,
-  ) -> String {
+    items: &Vec<(Fragment, std::sync::Arc<dyn WithQuery + Send + Sync>)>,
+  ) -> fmt::Result {
     let fmt::Formatter {
       comma,
       lb,
@@ -64,33 +71,38 @@ pub(crate) trait ConcatWith<Clause: PartialEq> {
       space,
       ..
     } = fmts;
-    let sql = if items.is_empty() == false {
-      let with = items.iter().fold("".to_string(), |acc, item| {
-        let (name, query) = item;
-        let inner_lb = format!("{lb}{indent}");
-        let inner_fmts = fmt::Formatter {
-          comma,
-          lb: inner_lb.as_str(),
-          indent,
-          space,
-          ..*fmts
-        };
-        let query_string = query.concat(&inner_fmts);
+    let inner_lb = format!("{lb}{indent}");
+    let inner_fmts = fmt::Formatter {
+      comma,
+      lb: inner_lb.as_str(),
+      indent,
+      space,
+      ..*fmts
+    };
 
-        if query_string.is_empty() == false {
-          format!("{acc}{name}{space}AS{space}({lb}{indent}{query_string}{lb}){comma}{lb}")
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      let start = w.mark();
+      let queries = items
+        .iter()
+        .filter(|(_, query)| fmt::renders_empty(query.as_ref(), &inner_fmts) == false);
+      for (index, (name, query)) in queries.enumerate() {
+        if index == 0 {
+          w.write_clause("WITH", Body::List)?;
+          write!(w, "{space}{lb}")?;
         } else {
-          acc
+          w.write_separator(comma)?;
+          w.write_str(lb)?;
         }
-      });
-      let with = &with[..with.len() - comma.len() - lb.len()];
-
-      format!("WITH{space}{lb}{with}{space}{lb}")
-    } else {
-      "".to_string()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+        w.write_fragment(name)?;
+        write!(w, "{space}AS{space}({lb}{indent}")?;
+        w.write_sub_query(|w| query.concat(w, &inner_fmts))?;
+        write!(w, "{lb})")?;
+      }
+      if w.has_written_since(start) {
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
 
@@ -98,26 +110,22 @@ pub(crate) trait ConcatWith<Clause: PartialEq> {
 pub(crate) trait ConcatColumn<Clause: PartialEq> {
   fn concat_column(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Fragment>,
+  ) -> fmt::Result {
     let fmt::Formatter { lb, comma, space, .. } = fmts;
 
-    let sql = if items.is_empty() == false {
-      let column_names = utils::join(&items, comma);
-      if column_names.is_empty() == false {
-        format!("({column_names}){space}{lb}")
-      } else {
-        "".to_string()
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if items.iter().any(|item| item.is_empty() == false) {
+        w.write_str("(")?;
+        w.write_joined(items, comma)?;
+        write!(w, "){space}{lb}")?;
       }
-    } else {
-      "".to_string()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+      Ok(())
+    })
   }
 }
@@ -1,121 +1,133 @@
-use crate::{concat::concat_raw_before_after, fmt, structure::LogicalOperator, utils};
+use crate::{
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  pretty::Body,
+  structure::{Fragment, Join, LogicalOperator},
+};
+use std::fmt::Write;
 
 pub(crate) trait ConcatFrom<Clause: PartialEq> {
   fn concat_from(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Fragment>,
+  ) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let tables = utils::join(items, comma);
-      format!("FROM{space}{tables}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if items.is_empty() == false {
+        w.write_clause("FROM", Body::List)?;
+        w.write_str(space)?;
+        w.write_items(items, comma)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
 
 pub(crate) trait ConcatJoin<Clause: PartialEq> {
   fn concat_join(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Join>,
+  ) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let joins = items.join(format!("{space}{lb}").as_str());
-      format!("{joins}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      for join in items {
+        let mark = w.mark();
+        join.concat(w, fmts)?;
+        if w.has_written_since(mark) {
+          write!(w, "{space}{lb}")?;
+        }
+      }
+      Ok(())
+    })
   }
 }
 
 pub(crate) trait ConcatOrderBy<Clause: PartialEq> {
   fn concat_order_by(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Fragment>,
+  ) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let columns = utils::join(items, comma);
 
-      format!("ORDER BY{space}{columns}{space}{lb}")
-    } else {
-      "".to_string()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if items.is_empty() == false {
+        w.write_clause("ORDER BY", Body::List)?;
+        w.write_str(space)?;
+        w.write_items(items, comma)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
 
 pub(crate) trait ConcatSet<Clause: PartialEq> {
   fn concat_set(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &Vec<Fragment>,
+  ) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let values = utils::join(items, comma);
-      format!("SET{space}{values}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if items.is_empty() == false {
+        w.write_clause("SET", Body::List)?;
+        w.write_str(space)?;
+        w.write_items(items, comma)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
 
 pub(crate) trait ConcatWhere<Clause: PartialEq> {
   fn concat_where(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &Vec<(Clause, Fragment)>,
+    items_raw_after: &Vec<(Clause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<(LogicalOperator, String)>,
-  ) -> String {
+    items: &Vec<(LogicalOperator, Fragment)>,
+  ) -> fmt::Result {
     let fmt::Formatter { lb, space, indent, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let filtered_items = items
-        .iter()
-        .filter(|item| item.1.is_empty() == false)
-        .collect::<Vec<_>>();
-      let ((_, cond), tail) = filtered_items.split_first().unwrap();
-      let first_condition = format!("{indent}{cond}");
-      let conditions = tail.iter().fold(first_condition, |acc, (log_op, condition)| {
-        format!("{acc}{space}{lb}{indent}{log_op}{space}{condition}")
-      });
-
-      format!("WHERE{lb}{space}{conditions}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      let mut conditions = items.iter().filter(|item| item.1.is_empty() == false);
+      if let Some((_, cond)) = conditions.next() {
+        w.write_clause("WHERE", Body::Conditions)?;
+        write!(w, "{lb}{space}{indent}")?;
+        w.write_fragment(cond)?;
+        for (log_op, condition) in conditions {
+          w.write_operator(log_op, |w| write!(w, "{space}{lb}{indent}{log_op}{space}"))?;
+          w.write_fragment(condition)?;
+        }
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
@@ -2,31 +2,36 @@
 use crate::{
   concat::concat_raw_before_after,
   fmt,
-  structure::{UpdateClause, UpdateVars},
+  pretty::Body,
+  structure::{Fragment, UpdateClause, UpdateVars},
 };
+#[cfg(feature = "sqlite")]
+use std::fmt::Write;
 
 #[cfg(feature = "sqlite")]
 pub(crate) trait ConcatUpdate {
   fn concat_update(
     &self,
-    items_raw_before: &Vec<(UpdateClause, String)>,
-    items_raw_after: &Vec<(UpdateClause, String)>,
-    query: String,
+    items_raw_before: &Vec<(UpdateClause, Fragment)>,
+    items_raw_after: &Vec<(UpdateClause, Fragment)>,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
-    update: &(UpdateVars, String),
-  ) -> String {
+    update: &(UpdateVars, Fragment),
+  ) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let (clause, sql) = match update {
-      (UpdateVars::Update, table_name) if table_name.is_empty() => (UpdateClause::Update, "".to_string()),
-      (UpdateVars::Update, table_name) => (UpdateClause::Update, format!("UPDATE{space}{table_name}{space}{lb}")),
-
-      (UpdateVars::UpdateOr, expression) if expression.is_empty() => (UpdateClause::UpdateOr, "".to_string()),
-      (UpdateVars::UpdateOr, expression) => (
-        UpdateClause::UpdateOr,
-        format!("UPDATE OR{space}{expression}{space}{lb}"),
-      ),
+    let (clause, keyword, argument) = match update {
+      (UpdateVars::Update, table_name) => (UpdateClause::Update, "UPDATE", table_name),
+      (UpdateVars::UpdateOr, expression) => (UpdateClause::UpdateOr, "UPDATE OR", expression),
     };
 
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, w, fmts, clause, |w| {
+      if argument.is_empty() == false {
+        w.write_clause(keyword, Body::Text)?;
+        w.write_str(space)?;
+        w.write_fragment(argument)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
   }
 }
@@ -0,0 +1,821 @@
+use crate::{
+  structure::{Check, Deferrability, Dialect, ForeignKey, Fragment, MatchType, PrimaryKey, ReferentialAction, Unique},
+  utils,
+};
+
+#[cfg(feature = "postgresql")]
+use crate::structure::Exclude;
+
+impl Check {
+  /// Creates a check constraint with the condition, the condition is written as is
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let check = sql::Check::new("price > 0");
+  ///
+  /// # assert_eq!("CHECK (price > 0)", check.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CHECK (price > 0)
+  /// ```
+  pub fn new(condition: impl Into<Fragment>) -> Self {
+    Self {
+      _condition: condition.into().trim(),
+      _name: Fragment::new(),
+    }
+  }
+
+  /// Defines the name of the constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let check = sql::Check::new("price > 0").name("products_price_check");
+  ///
+  /// # assert_eq!("CONSTRAINT products_price_check CHECK (price > 0)", check.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CONSTRAINT products_price_check CHECK (price > 0)
+  /// ```
+  pub fn name(mut self, name: impl Into<Fragment>) -> Self {
+    self._name = name.into().trim();
+    self
+  }
+
+  pub(crate) fn definition(&self, _dialect: Dialect) -> Fragment {
+    constraint_name(&self._name)
+      .push("CHECK (")
+      .push(&self._condition)
+      .push(")")
+  }
+}
+
+#[cfg(feature = "postgresql")]
+impl Exclude {
+  /// Creates an exclude constraint without elements
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new().element("during", "&&");
+  ///
+  /// # let expected = "EXCLUDE (during WITH &&)";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXCLUDE (during WITH &&)
+  /// ```
+  pub fn new() -> Self {
+    Self {
+      _deferrable: Deferrability::None,
+      _element: vec![],
+      _name: Fragment::new(),
+      _using: Fragment::new(),
+      _where: Fragment::new(),
+    }
+  }
+
+  /// Makes the constraint deferrable, the check can be postponed until the end of the transaction
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new().element("during", "&&").deferrable();
+  ///
+  /// # let expected = "EXCLUDE (during WITH &&) DEFERRABLE";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXCLUDE (during WITH &&) DEFERRABLE
+  /// ```
+  pub fn deferrable(mut self) -> Self {
+    self._deferrable = Deferrability::Deferrable;
+    self
+  }
+
+  /// Adds an element of the constraint, the column or expression compared with the operator,
+  /// multiples call will concatenates all elements
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new()
+  ///   .element("room_id", "=")
+  ///   .element("tsrange(starts_at, ends_at)", "&&");
+  ///
+  /// # let expected = "EXCLUDE (room_id WITH =, tsrange(starts_at, ends_at) WITH &&)";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXCLUDE (room_id WITH =, tsrange(starts_at, ends_at) WITH &&)
+  /// ```
+  pub fn element(mut self, element: impl Into<Fragment>, operator: impl Into<Fragment>) -> Self {
+    self._element.push((element.into().trim(), operator.into().trim()));
+    self
+  }
+
+  /// Makes the constraint deferrable and postpones the check until the end of the transaction by default
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new().element("during", "&&").initially_deferred();
+  ///
+  /// # let expected = "EXCLUDE (during WITH &&) DEFERRABLE INITIALLY DEFERRED";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXCLUDE (during WITH &&) DEFERRABLE INITIALLY DEFERRED
+  /// ```
+  pub fn initially_deferred(mut self) -> Self {
+    self._deferrable = Deferrability::InitiallyDeferred;
+    self
+  }
+
+  /// Defines the name of the constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new().name("bookings_during_excl").element("during", "&&");
+  ///
+  /// # let expected = "CONSTRAINT bookings_during_excl EXCLUDE (during WITH &&)";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CONSTRAINT bookings_during_excl EXCLUDE (during WITH &&)
+  /// ```
+  pub fn name(mut self, name: impl Into<Fragment>) -> Self {
+    self._name = name.into().trim();
+    self
+  }
+
+  /// Defines the index method of the constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new().using("gist").element("during", "&&");
+  ///
+  /// # let expected = "EXCLUDE USING gist (during WITH &&)";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXCLUDE USING gist (during WITH &&)
+  /// ```
+  pub fn using(mut self, index_method: impl Into<Fragment>) -> Self {
+    self._using = index_method.into().trim();
+    self
+  }
+
+  /// Defines the predicate of a partial constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let exclude = sql::Exclude::new()
+  ///   .using("gist")
+  ///   .element("room_id", "=")
+  ///   .element("during", "&&")
+  ///   .where_clause("cancelled = false");
+  ///
+  /// # let expected = "EXCLUDE USING gist (room_id WITH =, during WITH &&) WHERE (cancelled = false)";
+  /// # assert_eq!(expected, exclude.to_sql(sql::Dialect::Postgresql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXCLUDE USING gist (room_id WITH =, during WITH &&) WHERE (cancelled = false)
+  /// ```
+  pub fn where_clause(mut self, condition: impl Into<Fragment>) -> Self {
+    self._where = condition.into().trim();
+    self
+  }
+
+  pub(crate) fn definition(&self, dialect: Dialect) -> Fragment {
+    let using = if self._using.is_empty() {
+      Fragment::new()
+    } else {
+      Fragment::from(" USING ").push(&self._using)
+    };
+    let elements = self
+      ._element
+      .iter()
+      .map(|(element, operator)| element.clone().push(" WITH ").push(operator))
+      .collect::<Vec<_>>();
+    let where_clause = if self._where.is_empty() {
+      Fragment::new()
+    } else {
+      Fragment::from(" WHERE (").push(&self._where).push(")")
+    };
+    let deferrable = deferrable(self._deferrable, dialect == Dialect::Postgresql);
+
+    constraint_name(&self._name)
+      .push("EXCLUDE")
+      .push(using)
+      .push(" (")
+      .push(utils::join(&elements, ", "))
+      .push(")")
+      .push(where_clause)
+      .push(deferrable)
+  }
+}
+
+impl ForeignKey {
+  /// Creates a foreign key constraint with the columns of the table that reference the other table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"]).references_table("users");
+  ///
+  /// # assert_eq!("FOREIGN KEY (user_id) REFERENCES users", foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users
+  /// ```
+  pub fn new<T: Into<Fragment> + Clone>(columns: &[T]) -> Self {
+    Self {
+      _column: to_fragments(columns),
+      _deferrable: Deferrability::None,
+      _match: None,
+      _name: Fragment::new(),
+      _on_delete: None,
+      _on_update: None,
+      _references: Fragment::new(),
+      _references_column: vec![],
+    }
+  }
+
+  /// Makes the constraint deferrable, the check can be postponed until the end of the transaction.
+  /// MySQL doesn't support deferrable constraints, so the clause is omitted in that dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"]).references("users", &["id"]).deferrable();
+  ///
+  /// # let expected = "FOREIGN KEY (user_id) REFERENCES users (id) DEFERRABLE";
+  /// # assert_eq!(expected, foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users (id) DEFERRABLE
+  /// ```
+  pub fn deferrable(mut self) -> Self {
+    self._deferrable = Deferrability::Deferrable;
+    self
+  }
+
+  /// Makes the constraint deferrable and postpones the check until the end of the transaction by default.
+  /// MySQL doesn't support deferrable constraints, so the clause is omitted in that dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"]).references("users", &["id"]).initially_deferred();
+  ///
+  /// # let expected = "FOREIGN KEY (user_id) REFERENCES users (id) DEFERRABLE INITIALLY DEFERRED";
+  /// # assert_eq!(expected, foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users (id) DEFERRABLE INITIALLY DEFERRED
+  /// ```
+  pub fn initially_deferred(mut self) -> Self {
+    self._deferrable = Deferrability::InitiallyDeferred;
+    self
+  }
+
+  /// Defines how the columns are matched with the referenced columns when some of them are null,
+  /// this method overrides the previous value.
+  /// MySQL ignores the referential actions when the match type is written, so the clause is omitted in that dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["country", "city"])
+  ///   .references("cities", &["country", "name"])
+  ///   .match_type(sql::MatchType::Full);
+  ///
+  /// # let expected = "FOREIGN KEY (country, city) REFERENCES cities (country, name) MATCH FULL";
+  /// # assert_eq!(expected, foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (country, city) REFERENCES cities (country, name) MATCH FULL
+  /// ```
+  pub fn match_type(mut self, match_type: MatchType) -> Self {
+    self._match = Some(match_type);
+    self
+  }
+
+  /// Defines the name of the constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"]).name("orders_user_fk").references("users", &["id"]);
+  ///
+  /// # let expected = "CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users (id)";
+  /// # assert_eq!(expected, foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users (id)
+  /// ```
+  pub fn name(mut self, name: impl Into<Fragment>) -> Self {
+    self._name = name.into().trim();
+    self
+  }
+
+  /// Defines the action executed when the referenced row is deleted, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"])
+  ///   .references("users", &["id"])
+  ///   .on_delete(sql::ReferentialAction::SetNull);
+  ///
+  /// # let expected = "FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL";
+  /// # assert_eq!(expected, foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE SET NULL
+  /// ```
+  pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+    self._on_delete = Some(action);
+    self
+  }
+
+  /// Defines the action executed when the referenced columns are updated, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"])
+  ///   .references("users", &["id"])
+  ///   .on_update(sql::ReferentialAction::Cascade);
+  ///
+  /// # let expected = "FOREIGN KEY (user_id) REFERENCES users (id) ON UPDATE CASCADE";
+  /// # assert_eq!(expected, foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users (id) ON UPDATE CASCADE
+  /// ```
+  pub fn on_update(mut self, action: ReferentialAction) -> Self {
+    self._on_update = Some(action);
+    self
+  }
+
+  /// Defines the referenced table and columns, when the columns are empty the primary key of the referenced table
+  /// is used, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"]).references("users", &["id"]);
+  ///
+  /// # assert_eq!("FOREIGN KEY (user_id) REFERENCES users (id)", foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users (id)
+  /// ```
+  pub fn references<T: Into<Fragment> + Clone>(mut self, table: impl Into<Fragment>, columns: &[T]) -> Self {
+    self._references = table.into().trim();
+    self._references_column = to_fragments(columns);
+    self
+  }
+
+  /// Defines the referenced table without columns, the primary key of the referenced table is used,
+  /// this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let foreign_key = sql::ForeignKey::new(&["user_id"]).references_table("users");
+  ///
+  /// # assert_eq!("FOREIGN KEY (user_id) REFERENCES users", foreign_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FOREIGN KEY (user_id) REFERENCES users
+  /// ```
+  pub fn references_table(mut self, table: impl Into<Fragment>) -> Self {
+    self._references = table.into().trim();
+    self._references_column = vec![];
+    self
+  }
+
+  pub(crate) fn definition(&self, dialect: Dialect) -> Fragment {
+    let is_mysql = match dialect {
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => true,
+      _ => false,
+    };
+
+    let mut definition = constraint_name(&self._name)
+      .push("FOREIGN KEY (")
+      .push(utils::join(&self._column, ", "))
+      .push(") REFERENCES ")
+      .push(&self._references);
+
+    if self._references_column.is_empty() == false {
+      definition = definition
+        .push(" (")
+        .push(utils::join(&self._references_column, ", "))
+        .push(")");
+    }
+
+    if let (Some(match_type), false) = (self._match, is_mysql) {
+      let match_type = match match_type {
+        MatchType::Full => "FULL",
+        MatchType::Partial => "PARTIAL",
+        MatchType::Simple => "SIMPLE",
+      };
+      definition = definition.push(format!(" MATCH {match_type}"));
+    }
+
+    if let Some(action) = self._on_delete {
+      definition = definition.push(format!(" ON DELETE {}", referential_action(action)));
+    }
+
+    if let Some(action) = self._on_update {
+      definition = definition.push(format!(" ON UPDATE {}", referential_action(action)));
+    }
+
+    definition.push(deferrable(self._deferrable, is_mysql == false))
+  }
+}
+
+impl PrimaryKey {
+  /// Creates a primary key constraint with the columns
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let primary_key = sql::PrimaryKey::new(&["id"]);
+  ///
+  /// # assert_eq!("PRIMARY KEY (id)", primary_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// PRIMARY KEY (id)
+  /// ```
+  pub fn new<T: Into<Fragment> + Clone>(columns: &[T]) -> Self {
+    Self {
+      _column: to_fragments(columns),
+      _deferrable: Deferrability::None,
+      _name: Fragment::new(),
+    }
+  }
+
+  /// Makes the constraint deferrable, the check can be postponed until the end of the transaction.
+  /// Only the SQL Standard and Postgres support deferrable primary keys, the clause is omitted in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let primary_key = sql::PrimaryKey::new(&["id"]).deferrable();
+  ///
+  /// # assert_eq!("PRIMARY KEY (id) DEFERRABLE", primary_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// PRIMARY KEY (id) DEFERRABLE
+  /// ```
+  pub fn deferrable(mut self) -> Self {
+    self._deferrable = Deferrability::Deferrable;
+    self
+  }
+
+  /// Makes the constraint deferrable and postpones the check until the end of the transaction by default.
+  /// Only the SQL Standard and Postgres support deferrable primary keys, the clause is omitted in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let primary_key = sql::PrimaryKey::new(&["id"]).initially_deferred();
+  ///
+  /// # let expected = "PRIMARY KEY (id) DEFERRABLE INITIALLY DEFERRED";
+  /// # assert_eq!(expected, primary_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// PRIMARY KEY (id) DEFERRABLE INITIALLY DEFERRED
+  /// ```
+  pub fn initially_deferred(mut self) -> Self {
+    self._deferrable = Deferrability::InitiallyDeferred;
+    self
+  }
+
+  /// Defines the name of the constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let primary_key = sql::PrimaryKey::new(&["id"]).name("users_pk");
+  ///
+  /// # assert_eq!("CONSTRAINT users_pk PRIMARY KEY (id)", primary_key.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CONSTRAINT users_pk PRIMARY KEY (id)
+  /// ```
+  pub fn name(mut self, name: impl Into<Fragment>) -> Self {
+    self._name = name.into().trim();
+    self
+  }
+
+  pub(crate) fn definition(&self, dialect: Dialect) -> Fragment {
+    constraint_name(&self._name)
+      .push("PRIMARY KEY (")
+      .push(utils::join(&self._column, ", "))
+      .push(")")
+      .push(deferrable(self._deferrable, supports_deferrable_keys(dialect)))
+  }
+}
+
+impl Unique {
+  /// Creates a unique constraint with the columns
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let unique = sql::Unique::new(&["email"]);
+  ///
+  /// # assert_eq!("UNIQUE (email)", unique.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UNIQUE (email)
+  /// ```
+  pub fn new<T: Into<Fragment> + Clone>(columns: &[T]) -> Self {
+    Self {
+      _column: to_fragments(columns),
+      _deferrable: Deferrability::None,
+      _name: Fragment::new(),
+    }
+  }
+
+  /// Makes the constraint deferrable, the check can be postponed until the end of the transaction.
+  /// Only the SQL Standard and Postgres support deferrable unique constraints, the clause is omitted in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let unique = sql::Unique::new(&["position"]).deferrable();
+  ///
+  /// # assert_eq!("UNIQUE (position) DEFERRABLE", unique.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UNIQUE (position) DEFERRABLE
+  /// ```
+  pub fn deferrable(mut self) -> Self {
+    self._deferrable = Deferrability::Deferrable;
+    self
+  }
+
+  /// Makes the constraint deferrable and postpones the check until the end of the transaction by default.
+  /// Only the SQL Standard and Postgres support deferrable unique constraints, the clause is omitted in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let unique = sql::Unique::new(&["position"]).initially_deferred();
+  ///
+  /// # let expected = "UNIQUE (position) DEFERRABLE INITIALLY DEFERRED";
+  /// # assert_eq!(expected, unique.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UNIQUE (position) DEFERRABLE INITIALLY DEFERRED
+  /// ```
+  pub fn initially_deferred(mut self) -> Self {
+    self._deferrable = Deferrability::InitiallyDeferred;
+    self
+  }
+
+  /// Defines the name of the constraint, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let unique = sql::Unique::new(&["email"]).name("users_email_key");
+  ///
+  /// # assert_eq!("CONSTRAINT users_email_key UNIQUE (email)", unique.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CONSTRAINT users_email_key UNIQUE (email)
+  /// ```
+  pub fn name(mut self, name: impl Into<Fragment>) -> Self {
+    self._name = name.into().trim();
+    self
+  }
+
+  pub(crate) fn definition(&self, dialect: Dialect) -> Fragment {
+    constraint_name(&self._name)
+      .push("UNIQUE (")
+      .push(utils::join(&self._column, ", "))
+      .push(")")
+      .push(deferrable(self._deferrable, supports_deferrable_keys(dialect)))
+  }
+}
+
+/// Implements the rendering and the composition shared by the constraint builders
+macro_rules! constraint {
+  ($constraint:ident) => {
+    impl $constraint {
+      /// Gets the constraint in the syntax of the default dialect
+      pub fn as_string(&self) -> String {
+        self.to_sql(Dialect::default())
+      }
+
+      /// Gets the constraint in the syntax of the specified SQL [Dialect]
+      pub fn to_sql(&self, dialect: Dialect) -> String {
+        self.definition(dialect).to_sql(dialect)
+      }
+    }
+
+    impl std::fmt::Display for $constraint {
+      fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_string())
+      }
+    }
+
+    impl std::fmt::Debug for $constraint {
+      fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_string())
+      }
+    }
+  };
+}
+
+constraint!(Check);
+constraint!(ForeignKey);
+constraint!(PrimaryKey);
+constraint!(Unique);
+
+#[cfg(feature = "postgresql")]
+constraint!(Exclude);
+
+#[cfg(feature = "postgresql")]
+impl Default for Exclude {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+fn constraint_name(name: &Fragment) -> Fragment {
+  if name.is_empty() {
+    return Fragment::new();
+  }
+  Fragment::from("CONSTRAINT ").push(name).push(" ")
+}
+
+fn deferrable(deferrable: Deferrability, supported: bool) -> String {
+  match deferrable {
+    Deferrability::Deferrable if supported => " DEFERRABLE".to_string(),
+    Deferrability::InitiallyDeferred if supported => " DEFERRABLE INITIALLY DEFERRED".to_string(),
+    _ => "".to_string(),
+  }
+}
+
+/// SQLite and MySQL check the primary keys and the unique constraints immediately
+fn supports_deferrable_keys(dialect: Dialect) -> bool {
+  match dialect {
+    Dialect::Standard => true,
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql => true,
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    _ => false,
+  }
+}
+
+fn referential_action(action: ReferentialAction) -> &'static str {
+  match action {
+    ReferentialAction::Cascade => "CASCADE",
+    ReferentialAction::NoAction => "NO ACTION",
+    ReferentialAction::Restrict => "RESTRICT",
+    ReferentialAction::SetDefault => "SET DEFAULT",
+    ReferentialAction::SetNull => "SET NULL",
+  }
+}
+
+fn to_fragments<T: Into<Fragment> + Clone>(columns: &[T]) -> Vec<Fragment> {
+  columns.iter().map(|column| column.clone().into().trim()).collect()
+}
@@ -1,14 +1,46 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{CreateIndex, CreateIndexParams},
-  utils::push_unique,
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{BuildError, CreateIndex, CreateIndexParams, Dialect, FormatOptions, Fragment},
+  utils::{self, push_unique},
 };
 
 impl TransactionQuery for CreateIndex {}
 
 impl CreateIndex {
+  /// Gets the current state of the [CreateIndex] and returns it as string formatted by the [FormatOptions]
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   keyword_case: sql::KeywordCase::Lower,
+  ///   ..Default::default()
+  /// };
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # create index users_login_idx on users (login)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// create index users_login_idx on users (login)
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [CreateIndex] and returns it as string
   ///
   /// ### Example
@@ -32,8 +64,86 @@ impl CreateIndex {
   /// CREATE INDEX users_name_idx
   /// ```
   pub fn as_string(&self) -> String {
-    let fmts = fmt::one_line();
-    self.concat(&fmts)
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the params of the [CreateIndex] that have content. The raw SQL added by the methods
+  /// [CreateIndex::raw], [CreateIndex::raw_before] and [CreateIndex::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name");
+  ///
+  /// let params = create_index.clauses();
+  ///
+  /// # let expected = vec![
+  /// #   sql::CreateIndexParams::CreateIndex,
+  /// #   sql::CreateIndexParams::On,
+  /// #   sql::CreateIndexParams::Column,
+  /// # ];
+  /// # assert_eq!(expected, params);
+  /// # }
+  /// ```
+  pub fn clauses(&self) -> Vec<CreateIndexParams> {
+    let mut params = vec![];
+
+    if self._unique {
+      params.push(CreateIndexParams::Unique);
+    }
+    #[cfg(feature = "mysql")]
+    {
+      if self._fulltext {
+        params.push(CreateIndexParams::Fulltext);
+      }
+      if self._spatial {
+        params.push(CreateIndexParams::Spatial);
+      }
+    }
+    if self._create_index {
+      params.push(CreateIndexParams::CreateIndex);
+    }
+    #[cfg(feature = "postgresql")]
+    {
+      if self._concurrently {
+        params.push(CreateIndexParams::Concurrently);
+      }
+      if self._only {
+        params.push(CreateIndexParams::Only);
+      }
+    }
+    if self._on.is_empty() == false {
+      params.push(CreateIndexParams::On);
+    }
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    if self._using.is_empty() == false {
+      params.push(CreateIndexParams::Using);
+    }
+    if self._column.is_empty() == false {
+      params.push(CreateIndexParams::Column);
+    }
+    #[cfg(feature = "postgresql")]
+    if self._include.is_empty() == false {
+      params.push(CreateIndexParams::Include);
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if self._where.is_empty() == false {
+      params.push(CreateIndexParams::Where);
+    }
+    #[cfg(feature = "mysql")]
+    if self._lock.is_empty() == false {
+      params.push(CreateIndexParams::Lock);
+    }
+
+    params
   }
 
   /// Defines the column of the table used to create the index
@@ -60,8 +170,8 @@ impl CreateIndex {
   /// ```sql
   /// ON users (login, name)
   /// ```
-  pub fn column(mut self, column_name: &str) -> Self {
-    push_unique(&mut self._column, column_name.trim().to_string());
+  pub fn column(mut self, column_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._column, column_name.into().trim());
     self
   }
 
@@ -88,8 +198,8 @@ impl CreateIndex {
   /// ```sql
   /// CREATE INDEX orders_product_name_idx
   /// ```
-  pub fn create_index(mut self, index_name: &str) -> Self {
-    self._index_name = index_name.trim().to_string();
+  pub fn create_index(mut self, index_name: impl Into<Fragment>) -> Self {
+    self._index_name = index_name.into().trim();
     self._create_index = true;
     self
   }
@@ -128,10 +238,42 @@ impl CreateIndex {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
+  /// Gets the columns of the index
+  pub fn get_column(&self) -> &[Fragment] {
+    &self._column
+  }
+
+  /// Gets the name of the index, `None` when the name is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("users_name_idx")), create_index.get_index_name());
+  /// # assert_eq!(Some(&sql::Fragment::from("users")), create_index.get_on());
+  /// # assert_eq!(["name"], create_index.get_column());
+  /// # }
+  /// ```
+  pub fn get_index_name(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._index_name)
+  }
+
+  /// Gets the table of the `on` clause, `None` when the clause is not defined
+  pub fn get_on(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._on)
+  }
+
   /// Creates instance of the [CreateIndex] command
   pub fn new() -> Self {
     Self::default()
@@ -160,8 +302,8 @@ impl CreateIndex {
   /// ```sql
   /// ON orders
   /// ```
-  pub fn on(mut self, table_name: &str) -> Self {
-    self._on = table_name.trim().to_string();
+  pub fn on(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._on = table_name.into().trim();
     self
   }
 
@@ -169,7 +311,7 @@ impl CreateIndex {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -196,8 +338,8 @@ impl CreateIndex {
   /// ```sql
   /// /* start index command */ CREATE INDEX users_name_idx
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -236,8 +378,8 @@ impl CreateIndex {
   /// /* after create index */
   /// ON users (name)
   /// ```
-  pub fn raw_after(mut self, param: CreateIndexParams, raw_sql: &str) -> Self {
-    self._raw_after.push((param, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, param: CreateIndexParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
     self
   }
 
@@ -276,11 +418,158 @@ impl CreateIndex {
   /// CREATE INDEX users_name_idx
   /// ON users (name)
   /// ```
-  pub fn raw_before(mut self, param: CreateIndexParams, raw_sql: &str) -> Self {
-    self._raw_before.push((param, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, param: CreateIndexParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((param, raw_sql.into().trim()));
     self
   }
 
+  /// Gets the current state of the [CreateIndex] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name");
+  ///
+  /// let sqlite_query = create_index.to_sql(sql::Dialect::Sqlite);
+  ///
+  /// # assert_eq!("CREATE INDEX users_name_idx ON users (name)", sqlite_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_name_idx ON users (name)
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [CreateIndex] and returns it as string, returns a [BuildError] when
+  /// the [CreateIndex::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("CREATE INDEX users_login_idx ON users (login)", query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [CreateIndex] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [CreateIndex::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name")
+  ///   .try_to_sql(sql::Dialect::default())
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("CREATE INDEX users_name_idx ON users (name)", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_name_idx ON users (name)
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [CreateIndex], returns a [BuildError] when the `on` clause or the columns
+  /// are missing, or when more than one kind of index is set. The required clauses are not checked when
+  /// the raw SQL is set
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .column("login");
+  ///
+  /// let error = create_index.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "CREATE INDEX".to_string(),
+  /// #   clause: "ON".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [CreateIndex] like the [CreateIndex::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("bio_idx")
+  ///   .fulltext()
+  ///   .on("users")
+  ///   .column("bio");
+  ///
+  /// let error = create_index.validate_for(sql::Dialect::Postgresql).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Postgresql,
+  /// #   feature: "FULLTEXT".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
   /// Defines the `unique` parameter
   ///
   /// ### Example
@@ -314,10 +603,66 @@ impl CreateIndex {
     }
     self
   }
+
+  /// Writes the current state of the [CreateIndex] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// create_index.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nCREATE INDEX users_name_idx ON users (name)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [CreateIndex] into the [std::io::Write], like a file or a socket,
+  /// the same way the [CreateIndex::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name");
+  ///
+  /// let mut output = Vec::new();
+  /// create_index.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "CREATE INDEX users_name_idx ON users (name)";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// # }
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-use crate::structure::LogicalOperator;
+use crate::{structure::LogicalOperator, where_group};
+
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+use crate::structure::WhereGroup;
 
 #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
@@ -346,8 +691,8 @@ impl CreateIndex {
   /// ```sql
   /// CREATE INDEX IF NOT EXISTS orders_product_name_idx
   /// ```
-  pub fn create_index_if_not_exists(mut self, index_name: &str) -> Self {
-    self._index_name = index_name.trim().to_string();
+  pub fn create_index_if_not_exists(mut self, index_name: impl Into<Fragment>) -> Self {
+    self._index_name = index_name.into().trim();
     self._create_index = true;
     self._if_not_exists = true;
     self
@@ -384,7 +729,7 @@ impl CreateIndex {
   /// ON users (name)
   /// WHERE created_at >= $1
   /// ```
-  pub fn where_and(self, condition: &str) -> Self {
+  pub fn where_and(self, condition: impl Into<Fragment>) -> Self {
     self.where_clause(condition)
   }
 
@@ -420,8 +765,8 @@ impl CreateIndex {
   /// ON users (name)
   /// WHERE status = 'active'
   /// ```
-  pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+  pub fn where_clause(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, condition.into().trim()));
     self
   }
 
@@ -462,8 +807,76 @@ impl CreateIndex {
   ///   created_at >= $1
   ///   OR status = 'active'
   /// ```
-  pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+  pub fn where_or(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, condition.into().trim()));
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `and` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name")
+  ///   .where_clause("a = 1")
+  ///   .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_name_idx ON users (name) WHERE a = 1 AND (b = 2 OR c = 3)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_name_idx
+  /// ON users (name)
+  /// WHERE a = 1 AND (b = 2 OR c = 3)
+  /// ```
+  pub fn where_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::And, group);
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `or` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_name_idx")
+  ///   .on("users")
+  ///   .column("name")
+  ///   .where_clause("a = 1")
+  ///   .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_name_idx ON users (name) WHERE a = 1 OR (b = 2 AND c = 3)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_name_idx
+  /// ON users (name)
+  /// WHERE a = 1 OR (b = 2 AND c = 3)
+  /// ```
+  pub fn where_or_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::Or, group);
     self
   }
 }
@@ -494,8 +907,8 @@ impl CreateIndex {
   /// ```sql
   /// USING btree
   /// ```
-  pub fn using(mut self, index_method: &str) -> Self {
-    self._using = index_method.trim().to_string();
+  pub fn using(mut self, index_method: impl Into<Fragment>) -> Self {
+    self._using = index_method.into().trim();
     self
   }
 }
@@ -554,8 +967,8 @@ impl CreateIndex {
   /// ```sql
   /// INCLUDE (login, name)
   /// ```
-  pub fn include(mut self, column_name: &str) -> Self {
-    push_unique(&mut self._include, column_name.trim().to_string());
+  pub fn include(mut self, column_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._include, column_name.into().trim());
     self
   }
 
@@ -602,7 +1015,7 @@ impl CreateIndex {
   /// let query = sql::CreateIndex::new()
   ///   .create_index("idx_users")
   ///   .fulltext()
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "CREATE FULLTEXT INDEX idx_users";
   /// # assert_eq!(expected, query);
@@ -632,7 +1045,7 @@ impl CreateIndex {
   /// let query = sql::CreateIndex::new()
   ///   .create_index("idx_users")
   ///   .lock("exclusive")
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "CREATE INDEX idx_users LOCK exclusive";
   /// # assert_eq!(expected, query);
@@ -644,8 +1057,8 @@ impl CreateIndex {
   /// ```sql
   /// CREATE INDEX idx_users LOCK exclusive
   /// ```
-  pub fn lock(mut self, lock_option: &str) -> Self {
-    self._lock = lock_option.trim().to_string();
+  pub fn lock(mut self, lock_option: impl Into<Fragment>) -> Self {
+    self._lock = lock_option.into().trim();
     self
   }
 
@@ -660,7 +1073,7 @@ impl CreateIndex {
   /// let query = sql::CreateIndex::new()
   ///   .create_index("idx_users")
   ///   .spatial()
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "CREATE SPATIAL INDEX idx_users";
   /// # assert_eq!(expected, query);
@@ -689,6 +1102,6 @@ impl std::fmt::Display for CreateIndex {
 impl std::fmt::Debug for CreateIndex {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
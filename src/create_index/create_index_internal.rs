@@ -1,89 +1,87 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{CreateIndex, CreateIndexParams},
+  structure::{BuildError, CreateIndex, CreateIndexParams, Dialect},
+  utils,
 };
+use std::fmt::Write;
 
 impl Concat for CreateIndex {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
-
-    query = self.concat_raw(query, &fmts, &self._raw);
-
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_create_index_postgres(query, &fmts);
-      query = self.concat_on_postgres(query, &fmts);
-      query = self.concat_using(query, &fmts);
-      query = self.concat_column(query, &fmts);
-      query = self.concat_include(query, &fmts);
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        CreateIndexParams::Where,
-        &self._where,
-      );
-    }
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
+
+    self.concat_raw(w, &fmts, &self._raw)?;
+
+    match fmts.dialect {
+      // the SQL Standard doesn't specify the CREATE INDEX command, so only the raw SQL is rendered
+      Dialect::Standard => {}
+
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        self.concat_create_index_postgres(w, &fmts)?;
+        self.concat_on_postgres(w, &fmts)?;
+        self.concat_using(w, &fmts)?;
+        self.concat_column(w, &fmts)?;
+        self.concat_include(w, &fmts)?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          CreateIndexParams::Where,
+          &self._where,
+        )?;
+      }
 
-    #[cfg(feature = "sqlite")]
-    {
-      query = self.concat_create_index_sqlite(query, &fmts);
-      query = self.concat_on(query, &fmts);
-      query = self.concat_column(query, &fmts);
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        CreateIndexParams::Where,
-        &self._where,
-      );
-    }
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => {
+        self.concat_create_index_sqlite(w, &fmts)?;
+        self.concat_on(w, &fmts)?;
+        self.concat_column(w, &fmts)?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          CreateIndexParams::Where,
+          &self._where,
+        )?;
+      }
 
-    #[cfg(feature = "mysql")]
-    {
-      query = self.concat_create_index_mysql(query, &fmts);
-      query = self.concat_using(query, &fmts);
-      query = self.concat_on(query, &fmts);
-      query = self.concat_column(query, &fmts);
-      query = self.concat_lock(query, &fmts);
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => {
+        self.concat_create_index_mysql(w, &fmts)?;
+        self.concat_using(w, &fmts)?;
+        self.concat_on(w, &fmts)?;
+        self.concat_column(w, &fmts)?;
+        self.concat_lock(w, &fmts)?;
+      }
     }
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl CreateIndex {
-  fn concat_column(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_column(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, comma, space, .. } = fmts;
 
-    let sql = if self._column.is_empty() == false {
-      let column_names = self
-        ._column
-        .iter()
-        .filter(|column| column.is_empty() == false)
-        .map(|column| column.as_str())
-        .collect::<Vec<_>>()
-        .join(comma);
-
-      if column_names.is_empty() == false {
-        format!("({column_names}){space}{lb}")
-      } else {
-        "".to_string()
-      }
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::Column,
-      sql,
+      |w| {
+        if self._column.iter().any(|column| column.is_empty() == false) {
+          w.write_str("(")?;
+          w.write_joined(&self._column, comma)?;
+          write!(w, "){space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
@@ -96,309 +94,283 @@ impl ConcatWhere<CreateIndexParams> for CreateIndex {}
 
 #[cfg(any(feature = "postgresql", feature = "mysql"))]
 impl CreateIndex {
-  fn concat_using(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_using(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
 
-    let sql = if self._using.is_empty() == false {
-      let index_method = &self._using;
-      format!("USING{space}{index_method}{space}")
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::Using,
-      sql,
+      |w| {
+        if self._using.is_empty() == false {
+          write!(w, "USING{space}")?;
+          w.write_fragment(&self._using)?;
+          w.write_str(space)?;
+        }
+        Ok(())
+      },
     )
   }
 }
 
 #[cfg(any(feature = "sqlite", feature = "mysql"))]
 impl CreateIndex {
-  fn concat_on(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_on(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
 
-    let sql = if self._on.is_empty() == false {
-      let table_name = &self._on;
-
-      format!("ON{space}{table_name}{space}")
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::On,
-      sql,
+      |w| {
+        if self._on.is_empty() == false {
+          write!(w, "ON{space}")?;
+          w.write_fragment(&self._on)?;
+          w.write_str(space)?;
+        }
+        Ok(())
+      },
     )
   }
 }
 
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+impl CreateIndex {
+  /// Writes the modifier keyword with the raw SQL of its clause
+  fn concat_modifier(
+    &self,
+    w: &mut fmt::Writer,
+    fmts: &fmt::Formatter,
+    clause: CreateIndexParams,
+    keyword: &str,
+  ) -> fmt::Result {
+    let fmt::Formatter { space, .. } = fmts;
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, clause, |w| {
+      write!(w, "{keyword}{space}")
+    })
+  }
+}
+
 #[cfg(feature = "postgresql")]
 impl CreateIndex {
-  fn concat_create_index_postgres(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_create_index_postgres(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
 
-    let unique = if self._unique {
-      concat_raw_before_after(
-        &self._raw_before,
-        &self._raw_after,
-        "".to_string(),
-        fmts,
-        CreateIndexParams::Unique,
-        format!("UNIQUE{space}"),
-      )
-    } else {
-      "".to_string()
-    };
-
-    let if_not_exists = if self._if_not_exists {
-      format!("IF NOT EXISTS{space}")
-    } else {
-      "".to_string()
-    };
-
-    let index_name = if self._index_name.is_empty() == false {
-      format!("{}{space}", &self._index_name)
-    } else {
-      "".to_string()
-    };
-
-    let concurrently = if self._concurrently {
-      concat_raw_before_after(
-        &self._raw_before,
-        &self._raw_after,
-        "".to_string(),
-        fmts,
-        CreateIndexParams::Concurrently,
-        format!("CONCURRENTLY{space}"),
-      )
-    } else {
-      "".to_string()
-    };
-
-    let modifiers_not_called = self._create_index == false && unique.is_empty() && concurrently.is_empty();
-    let if_not_exists_without_index_name = self._if_not_exists && index_name.is_empty();
-
-    let sql = if modifiers_not_called || if_not_exists_without_index_name {
-      "".to_string()
-    } else {
-      format!("CREATE{space}{unique}INDEX{space}{concurrently}{if_not_exists}{index_name}{lb}")
-    };
+    let modifiers_not_called = self._create_index == false && self._unique == false && self._concurrently == false;
+    let if_not_exists_without_index_name = self._if_not_exists && self._index_name.is_empty();
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::CreateIndex,
-      sql,
+      |w| {
+        if modifiers_not_called || if_not_exists_without_index_name {
+          return Ok(());
+        }
+        write!(w, "CREATE{space}")?;
+        if self._unique {
+          self.concat_modifier(w, fmts, CreateIndexParams::Unique, "UNIQUE")?;
+        }
+        write!(w, "INDEX{space}")?;
+        if self._concurrently {
+          self.concat_modifier(w, fmts, CreateIndexParams::Concurrently, "CONCURRENTLY")?;
+        }
+        if self._if_not_exists {
+          write!(w, "IF NOT EXISTS{space}")?;
+        }
+        if self._index_name.is_empty() == false {
+          w.write_fragment(&self._index_name)?;
+          w.write_str(space)?;
+        }
+        w.write_str(lb)
+      },
     )
   }
 
-  fn concat_include(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_include(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
 
-    let sql = if self._include.is_empty() == false {
-      let column_names = self
-        ._include
-        .iter()
-        .filter(|column| column.is_empty() == false)
-        .map(|column| column.as_str())
-        .collect::<Vec<_>>()
-        .join(comma);
-
-      if column_names.is_empty() == false {
-        format!("INCLUDE{space}({column_names}){space}{lb}")
-      } else {
-        "".to_string()
-      }
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::Include,
-      sql,
+      |w| {
+        if self._include.iter().any(|column| column.is_empty() == false) {
+          write!(w, "INCLUDE{space}(")?;
+          w.write_joined(&self._include, comma)?;
+          write!(w, "){space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_on_postgres(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_on_postgres(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
 
-    let sql = if self._on.is_empty() == false {
-      let table_name = &self._on;
-
-      let only = if self._only {
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          CreateIndexParams::Only,
-          format!("ONLY{space}"),
-        )
-      } else {
-        "".to_string()
-      };
-
-      format!("ON{space}{only}{table_name}{space}")
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::On,
-      sql,
+      |w| {
+        if self._on.is_empty() == false {
+          write!(w, "ON{space}")?;
+          if self._only {
+            self.concat_modifier(w, fmts, CreateIndexParams::Only, "ONLY")?;
+          }
+          w.write_fragment(&self._on)?;
+          w.write_str(space)?;
+        }
+        Ok(())
+      },
     )
   }
 }
 
 #[cfg(feature = "sqlite")]
 impl CreateIndex {
-  fn concat_create_index_sqlite(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_create_index_sqlite(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
 
-    let unique = if self._unique {
-      concat_raw_before_after(
-        &self._raw_before,
-        &self._raw_after,
-        "".to_string(),
-        fmts,
-        CreateIndexParams::Unique,
-        format!("UNIQUE{space}"),
-      )
-    } else {
-      "".to_string()
-    };
-
-    let if_not_exists = if self._if_not_exists {
-      format!("IF NOT EXISTS{space}")
-    } else {
-      "".to_string()
-    };
-
-    let index_name = if self._index_name.is_empty() == false {
-      format!("{}{space}", &self._index_name)
-    } else {
-      "".to_string()
-    };
-
-    let sql = if index_name.is_empty() == false {
-      format!("CREATE{space}{unique}INDEX{space}{if_not_exists}{index_name}{lb}")
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::CreateIndex,
-      sql,
+      |w| {
+        if self._index_name.is_empty() {
+          return Ok(());
+        }
+        write!(w, "CREATE{space}")?;
+        if self._unique {
+          self.concat_modifier(w, fmts, CreateIndexParams::Unique, "UNIQUE")?;
+        }
+        write!(w, "INDEX{space}")?;
+        if self._if_not_exists {
+          write!(w, "IF NOT EXISTS{space}")?;
+        }
+        w.write_fragment(&self._index_name)?;
+        write!(w, "{space}{lb}")
+      },
     )
   }
 }
 
 #[cfg(feature = "mysql")]
 impl CreateIndex {
-  fn concat_create_index_mysql(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_create_index_mysql(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
 
-    let unique = if self._unique {
-      concat_raw_before_after(
-        &self._raw_before,
-        &self._raw_after,
-        "".to_string(),
-        fmts,
-        CreateIndexParams::Unique,
-        format!("UNIQUE{space}"),
-      )
-    } else {
-      "".to_string()
-    };
-
-    let fulltext = if self._fulltext {
-      concat_raw_before_after(
-        &self._raw_before,
-        &self._raw_after,
-        "".to_string(),
-        fmts,
-        CreateIndexParams::Fulltext,
-        format!("FULLTEXT{space}"),
-      )
-    } else {
-      "".to_string()
-    };
-
-    let spatial = if self._spatial {
-      concat_raw_before_after(
-        &self._raw_before,
-        &self._raw_after,
-        "".to_string(),
-        fmts,
-        CreateIndexParams::Spatial,
-        format!("SPATIAL{space}"),
-      )
-    } else {
-      "".to_string()
-    };
-
-    let index_name = if self._index_name.is_empty() == false {
-      format!("{}{space}", &self._index_name)
-    } else {
-      "".to_string()
-    };
-
-    let sql = if index_name.is_empty() == false {
-      format!("CREATE{space}{unique}{fulltext}{spatial}INDEX{space}{index_name}{lb}")
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::CreateIndex,
-      sql,
+      |w| {
+        if self._index_name.is_empty() {
+          return Ok(());
+        }
+        write!(w, "CREATE{space}")?;
+        if self._unique {
+          self.concat_modifier(w, fmts, CreateIndexParams::Unique, "UNIQUE")?;
+        }
+        if self._fulltext {
+          self.concat_modifier(w, fmts, CreateIndexParams::Fulltext, "FULLTEXT")?;
+        }
+        if self._spatial {
+          self.concat_modifier(w, fmts, CreateIndexParams::Spatial, "SPATIAL")?;
+        }
+        write!(w, "INDEX{space}")?;
+        w.write_fragment(&self._index_name)?;
+        write!(w, "{space}{lb}")
+      },
     )
   }
 
-  fn concat_lock(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_lock(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
 
-    let sql = if self._lock.is_empty() == false {
-      let lock_option = &self._lock;
-      format!("LOCK{space}{lock_option}{space}{lb}")
-    } else {
-      "".to_string()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateIndexParams::Lock,
-      sql,
+      |w| {
+        if self._lock.is_empty() == false {
+          write!(w, "LOCK{space}")?;
+          w.write_fragment(&self._lock)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
+
+impl Validate for CreateIndex {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_support(dialect)?;
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) {
+      return Ok(());
+    }
+
+    utils::require_clause("CREATE INDEX", "CREATE INDEX", self._create_index == false)?;
+    utils::require_clause("CREATE INDEX", "ON", self._on.is_empty())?;
+    utils::require_clause("CREATE INDEX", "COLUMN", self._column.is_empty())
+  }
+}
+
+impl CreateIndex {
+  /// Checks if the dialect supports the clauses specific to some dialects
+  fn check_support(&self, dialect: Dialect) -> Result<(), BuildError> {
+    utils::require_support(dialect, "CREATE INDEX", true, dialect != Dialect::Standard)?;
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      let is_supported = utils::is_postgres(dialect) || utils::is_sqlite(dialect);
+      utils::require_support(dialect, "IF NOT EXISTS", self._if_not_exists, is_supported)?;
+      utils::require_support(dialect, "WHERE", self._where.is_empty() == false, is_supported)?;
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    utils::require_support(
+      dialect,
+      "USING",
+      self._using.is_empty() == false,
+      utils::is_postgres(dialect) || utils::is_mysql(dialect),
+    )?;
+
+    #[cfg(feature = "postgresql")]
+    {
+      let is_supported = utils::is_postgres(dialect);
+      utils::require_support(dialect, "CONCURRENTLY", self._concurrently, is_supported)?;
+      utils::require_support(dialect, "INCLUDE", self._include.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "ONLY", self._only, is_supported)?;
+    }
+
+    #[cfg(feature = "mysql")]
+    {
+      let is_supported = utils::is_mysql(dialect);
+      utils::require_support(dialect, "FULLTEXT", self._fulltext, is_supported)?;
+      utils::require_support(dialect, "LOCK", self._lock.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "SPATIAL", self._spatial, is_supported)?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,14 +1,50 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{CreateTable, CreateTableParams},
-  utils::push_unique,
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{BuildError, CreateTable, CreateTableParams, Dialect, FormatOptions, Fragment},
+  utils::{self, push_unique},
 };
 
 impl TransactionQuery for CreateTable {}
 
 impl CreateTable {
+  /// Gets the current state of the [CreateTable] and returns it as string formatted by the [FormatOptions], the
+  /// column definitions are printed one per line when they don't fit in the `max_line_length`
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   max_line_length: 40,
+  ///   ..Default::default()
+  /// };
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .column("login varchar(40) not null")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # CREATE TABLE users (\n  \
+  /// # id serial PRIMARY KEY,\n  \
+  /// # login varchar(40) NOT NULL\n\
+  /// # )";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (
+  ///   id serial PRIMARY KEY,
+  ///   login varchar(40) NOT NULL
+  /// )
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [CreateTable] and returns it as string
   ///
   /// ### Example
@@ -36,8 +72,52 @@ impl CreateTable {
   /// )
   /// ```
   pub fn as_string(&self) -> String {
-    let fmts = fmt::one_line();
-    self.concat(&fmts)
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the params of the [CreateTable] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [CreateTable::raw], [CreateTable::raw_before] and [CreateTable::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .primary_key("(id)");
+  ///
+  /// let params = create_table.clauses();
+  ///
+  /// # let expected = vec![
+  /// #   sql::CreateTableParams::CreateTable,
+  /// #   sql::CreateTableParams::Column,
+  /// #   sql::CreateTableParams::PrimaryKey,
+  /// # ];
+  /// # assert_eq!(expected, params);
+  /// ```
+  pub fn clauses(&self) -> Vec<CreateTableParams> {
+    let mut params = vec![];
+
+    if self._create_table.is_empty() == false {
+      params.push(CreateTableParams::CreateTable);
+    }
+    if self._column.is_empty() == false {
+      params.push(CreateTableParams::Column);
+    }
+    if self._primary_key.is_empty() == false {
+      params.push(CreateTableParams::PrimaryKey);
+    }
+    if self._constraint.is_empty() == false {
+      params.push(CreateTableParams::Constraint);
+    }
+    if self._foreign_key.is_empty() == false {
+      params.push(CreateTableParams::ForeignKey);
+    }
+
+    params
   }
 
   /// Defines a column to be passed as arguments to the create table command, multiples call will concatenates all column parameters
@@ -66,12 +146,38 @@ impl CreateTable {
   ///   name varchar(100) not null
   /// )
   /// ```
-  pub fn column(mut self, column: &str) -> Self {
-    push_unique(&mut self._column, column.trim().to_string());
+  ///
+  /// The column can be defined with the [ColumnDef](crate::ColumnDef) builder to be rendered in the syntax of the dialect
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let id = sql::ColumnDef::new("id", sql::DataType::Integer).identity().primary_key();
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .column(&id)
+  ///   .to_sql(sql::Dialect::Mysql);
+  ///
+  /// # let expected = "(id INT AUTO_INCREMENT PRIMARY KEY)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// (id INT AUTO_INCREMENT PRIMARY KEY)
+  /// ```
+  pub fn column(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._column, column.into().trim());
     self
   }
 
-  /// Defines a table constraint, multiples call will concatenates all constraints
+  /// Defines a table constraint, multiples call will concatenates all constraints.
+  /// The constraint builders [Check](crate::Check) and [Unique](crate::Unique) are rendered as is
   ///
   /// ### Example
   ///
@@ -97,8 +203,8 @@ impl CreateTable {
   ///   CONSTRAINT users_login_key UNIQUE(login)
   /// )
   /// ```
-  pub fn constraint(mut self, column: &str) -> Self {
-    push_unique(&mut self._constraint, column.trim().to_string());
+  pub fn constraint(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._constraint, column.into().trim());
     self
   }
 
@@ -121,8 +227,8 @@ impl CreateTable {
   /// ```sql
   /// CREATE TABLE orders
   /// ```
-  pub fn create_table(mut self, table_name: &str) -> Self {
-    self._create_table = table_name.trim().to_string();
+  pub fn create_table(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._create_table = table_name.into().trim();
     self
   }
 
@@ -145,8 +251,8 @@ impl CreateTable {
   /// ```sql
   /// CREATE TABLE IF NOT EXISTS orders
   /// ```
-  pub fn create_table_if_not_exists(mut self, table_name: &str) -> Self {
-    self._create_table = format!("IF NOT EXISTS {}", table_name.trim());
+  pub fn create_table_if_not_exists(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._create_table = Fragment::from("IF NOT EXISTS ").push(table_name.into().trim());
     self
   }
 
@@ -179,11 +285,12 @@ impl CreateTable {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
-  /// Defines a foreign key constraint, multiples call will concatenates all foreign keys
+  /// Defines a foreign key constraint, multiples call will concatenates all foreign keys.
+  /// The constraint builder [ForeignKey](crate::ForeignKey) is rendered as is
   ///
   /// ### Example
   ///
@@ -209,17 +316,57 @@ impl CreateTable {
   ///   FOREIGN KEY(address_id) refereces address (id)
   /// )
   /// ```
-  pub fn foreign_key(mut self, column: &str) -> Self {
-    push_unique(&mut self._foreign_key, column.trim().to_string());
+  pub fn foreign_key(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._foreign_key, column.into().trim());
     self
   }
 
+  /// Gets the definitions of the columns
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .column("login varchar(40) not null");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("users")), create_table.get_create_table());
+  /// # assert_eq!(["id serial primary key", "login varchar(40) not null"], create_table.get_column());
+  /// ```
+  pub fn get_column(&self) -> &[Fragment] {
+    &self._column
+  }
+
+  /// Gets the table constraints
+  pub fn get_constraint(&self) -> &[Fragment] {
+    &self._constraint
+  }
+
+  /// Gets the table of the `create table` clause, `None` when the clause is not defined.
+  /// The `if not exists` modifier of the [CreateTable::create_table_if_not_exists] is returned together with the table
+  pub fn get_create_table(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._create_table)
+  }
+
+  /// Gets the foreign keys
+  pub fn get_foreign_key(&self) -> &[Fragment] {
+    &self._foreign_key
+  }
+
+  /// Gets the columns of the primary key, `None` when the primary key is not defined
+  pub fn get_primary_key(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._primary_key)
+  }
+
   /// Creates instance of the [CreateTable] command
   pub fn new() -> Self {
     Self::default()
   }
 
-  /// Defines a primary key constraint. Multiples calls will overrides the previous value
+  /// Defines a primary key constraint. Multiples calls will overrides the previous value.
+  /// The constraint builder [PrimaryKey](crate::PrimaryKey) is rendered as is
   ///
   /// ### Example
   ///
@@ -245,8 +392,8 @@ impl CreateTable {
   ///   PRIMARY KEY(id)
   /// )
   /// ```
-  pub fn primary_key(mut self, column: &str) -> Self {
-    self._primary_key = column.trim().to_string();
+  pub fn primary_key(mut self, column: impl Into<Fragment>) -> Self {
+    self._primary_key = column.into().trim();
     self
   }
 
@@ -254,7 +401,7 @@ impl CreateTable {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -284,8 +431,8 @@ impl CreateTable {
   ///   login VARCHAR(40) NOT NULL
   /// )
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -311,8 +458,8 @@ impl CreateTable {
   /// ```sql
   /// CREATE TABLE users (name varchar(100) not null)
   /// ```
-  pub fn raw_after(mut self, param: CreateTableParams, raw_sql: &str) -> Self {
-    self._raw_after.push((param, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, param: CreateTableParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
     self
   }
 
@@ -338,10 +485,182 @@ impl CreateTable {
   /// ```sql
   /// (name varchar(100) not null, login varchar(40) not null)
   /// ```
-  pub fn raw_before(mut self, param: CreateTableParams, raw_sql: &str) -> Self {
-    self._raw_before.push((param, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, param: CreateTableParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((param, raw_sql.into().trim()));
     self
   }
+
+  /// Gets the current state of the [CreateTable] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key");
+  ///
+  /// let standard_query = create_table.to_sql(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("CREATE TABLE users (id serial primary key)", standard_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial primary key)
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [CreateTable] and returns it as string, returns a [BuildError] when
+  /// the [CreateTable::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("login varchar(40)")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("CREATE TABLE users (login varchar(40))", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (login varchar(40))
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [CreateTable] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [CreateTable::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("login varchar(40)")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("CREATE TABLE users (login varchar(40))", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (login varchar(40))
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [CreateTable], returns a [BuildError] when the table name or the columns
+  /// are missing. Nothing is checked when the raw SQL is set
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users");
+  ///
+  /// let error = create_table.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "CREATE TABLE".to_string(),
+  /// #   clause: "COLUMN".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [CreateTable] like the [CreateTable::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users");
+  ///
+  /// let error = create_table.validate_for(sql::Dialect::Standard).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "CREATE TABLE".to_string(),
+  /// #   clause: "COLUMN".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// Writes the current state of the [CreateTable] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("name varchar(100) not null");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// create_table.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nCREATE TABLE users (name varchar(100) not null)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [CreateTable] into the [std::io::Write], like a file or a socket,
+  /// the same way the [CreateTable::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("name varchar(100) not null");
+  ///
+  /// let mut output = Vec::new();
+  /// create_table.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "CREATE TABLE users (name varchar(100) not null)";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 impl std::fmt::Display for CreateTable {
@@ -353,6 +672,6 @@ impl std::fmt::Display for CreateTable {
 impl std::fmt::Debug for CreateTable {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,65 +1,69 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{CreateTable, CreateTableParams},
+  structure::{BuildError, CreateTable, CreateTableParams, Dialect},
+  utils,
 };
+use std::fmt::Write;
 
 impl Concat for CreateTable {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    query = self.concat_create_table(query, &fmts);
-    query = self.concat_parameters(query, &fmts);
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_create_table(w, &fmts)?;
+    self.concat_parameters(w, &fmts)?;
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl CreateTable {
-  fn concat_create_table(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_create_table(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
-    let sql = if self._create_table.is_empty() == false {
-      let table_name = &self._create_table;
-      format!("CREATE TABLE{space}{table_name}{space}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       CreateTableParams::CreateTable,
-      sql,
+      |w| {
+        if self._create_table.is_empty() == false {
+          write!(w, "CREATE TABLE{space}")?;
+          w.write_fragment(&self._create_table)?;
+          w.write_str(space)?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_column(&self, fmts: &fmt::Formatter) -> String {
+  fn concat_column(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, indent, .. } = fmts;
 
-    let columns = self
-      ._column
-      .iter()
-      .filter(|column| column.is_empty() == false)
-      .map(|column| format!("{lb}{indent}{column}"))
-      .collect::<Vec<_>>()
-      .join(comma)
-      .trim_start()
-      .to_string();
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      "".to_string(),
+      w,
       fmts,
       CreateTableParams::Column,
-      columns,
+      |w| {
+        let columns = self._column.iter().filter(|column| column.is_empty() == false);
+        for (index, column) in columns.enumerate() {
+          if index > 0 {
+            write!(w, "{comma}{lb}{indent}")?;
+          }
+          w.write_fragment(column)?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_constraint(&self, fmts: &fmt::Formatter) -> String {
+  fn concat_constraint(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter {
       comma,
       lb,
@@ -68,63 +72,85 @@ impl CreateTable {
       ..
     } = fmts;
 
-    let constraints = self
-      ._constraint
-      .iter()
-      .filter(|constraint| constraint.is_empty() == false)
-      .map(|constraint| format!("{lb}{indent}CONSTRAINT{space}{constraint}"))
-      .collect::<Vec<_>>()
-      .join(comma);
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      "".to_string(),
+      w,
       fmts,
       CreateTableParams::Constraint,
-      constraints,
+      |w| {
+        let constraints = self
+          ._constraint
+          .iter()
+          .filter(|constraint| constraint.is_empty() == false);
+        for (index, constraint) in constraints.enumerate() {
+          if index > 0 {
+            w.write_str(comma)?;
+          }
+          write!(w, "{lb}{indent}")?;
+          if constraint.is_variants() == false {
+            write!(w, "CONSTRAINT{space}")?;
+          }
+          w.write_fragment(constraint)?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_foreign_key(&self, fmts: &fmt::Formatter) -> String {
+  fn concat_foreign_key(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, indent, .. } = fmts;
 
-    let foreign_keys = self
-      ._foreign_key
-      .iter()
-      .filter(|foreign_key| foreign_key.is_empty() == false)
-      .map(|foreign_key| format!("{lb}{indent}FOREIGN KEY{foreign_key}"))
-      .collect::<Vec<_>>()
-      .join(comma);
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      "".to_string(),
+      w,
       fmts,
       CreateTableParams::ForeignKey,
-      foreign_keys,
+      |w| {
+        let foreign_keys = self
+          ._foreign_key
+          .iter()
+          .filter(|foreign_key| foreign_key.is_empty() == false);
+        for (index, foreign_key) in foreign_keys.enumerate() {
+          if index > 0 {
+            w.write_str(comma)?;
+          }
+          write!(w, "{lb}{indent}")?;
+          if foreign_key.is_variants() == false {
+            w.write_str("FOREIGN KEY")?;
+          }
+          w.write_fragment(foreign_key)?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_primary_key(&self, fmts: &fmt::Formatter) -> String {
-    let primary_key = match &self._primary_key {
-      pk_exp if pk_exp.find('(').is_some() => format!("PRIMARY KEY{pk_exp}"),
-      pk_exp if pk_exp.is_empty() == false => format!("PRIMARY KEY({pk_exp})"),
-      _ => "".to_string(),
-    };
-
+  fn concat_primary_key(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      "".to_string(),
+      w,
       fmts,
       CreateTableParams::PrimaryKey,
-      primary_key,
+      |w| match &self._primary_key {
+        pk_exp if pk_exp.is_variants() => w.write_fragment(pk_exp),
+        pk_exp if pk_exp.as_string().contains('(') => {
+          w.write_str("PRIMARY KEY")?;
+          w.write_fragment(pk_exp)
+        }
+        pk_exp if pk_exp.is_empty() == false => {
+          w.write_str("PRIMARY KEY(")?;
+          w.write_fragment(pk_exp)?;
+          w.write_str(")")
+        }
+        _ => Ok(()),
+      },
     )
   }
 
-  fn concat_parameters(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_parameters(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter {
       comma,
       lb,
@@ -133,23 +159,48 @@ impl CreateTable {
       ..
     } = fmts;
 
-    let columns = self.concat_column(fmts);
-    let primary_keys = self.concat_primary_key(fmts);
-    let constraints = self.concat_constraint(fmts);
-    let foreign_keys = self.concat_foreign_key(fmts);
-
-    let params = [columns, primary_keys, constraints, foreign_keys]
-      .into_iter()
-      .filter(|item| item.is_empty() == false)
-      .collect::<Vec<_>>()
-      .join(comma)
-      .trim_end()
-      .to_string();
-
-    if params.is_empty() == false {
-      format!("{query}({lb}{indent}{params}{lb}){space}{lb}")
+    // the parentheses and the commas are written only when some parameter follows them
+    let start = w.mark();
+    w.write_lazy(&format!("({lb}{indent}"));
+    let params_start = w.mark();
+
+    let params: [fn(&Self, &mut fmt::Writer, &fmt::Formatter) -> fmt::Result; 4] = [
+      Self::concat_column,
+      Self::concat_primary_key,
+      Self::concat_constraint,
+      Self::concat_foreign_key,
+    ];
+    for concat_param in params {
+      let param_start = w.mark();
+      if w.has_written_since(params_start) {
+        w.write_lazy(comma);
+      }
+      concat_param(self, w, fmts)?;
+      if w.has_written_since(param_start) == false {
+        w.trim_end_since(param_start);
+      }
+    }
+
+    if w.has_written_since(params_start) {
+      w.trim_end_since(params_start);
+      write!(w, "{lb}){space}{lb}")?;
     } else {
-      query
+      w.trim_end_since(start);
     }
+
+    Ok(())
+  }
+}
+
+impl Validate for CreateTable {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    fmt::check_data_types(&self._column, dialect)?;
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) {
+      return Ok(());
+    }
+
+    utils::require_clause("CREATE TABLE", "CREATE TABLE", self._create_table.is_empty())?;
+    utils::require_clause("CREATE TABLE", "COLUMN", self._column.is_empty())
   }
 }
@@ -0,0 +1,683 @@
+use crate::{
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{BuildError, CreateView, CreateViewParams, Dialect, FormatOptions, Fragment, Select},
+  utils::{self, push_unique},
+};
+
+impl TransactionQuery for CreateView {}
+
+impl CreateView {
+  /// Defines the query of the view, this method overrides the previous value
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let paid_orders = sql::Select::new()
+  ///   .select("*")
+  ///   .from("orders")
+  ///   .where_clause("status = 'paid'");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("paid_orders")
+  ///   .as_select(paid_orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW paid_orders AS SELECT * FROM orders WHERE status = 'paid'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW paid_orders AS SELECT * FROM orders WHERE status = 'paid'
+  /// ```
+  pub fn as_select(mut self, select: Select) -> Self {
+    self._as_select = Some(Box::new(select));
+    self
+  }
+
+  /// Gets the current state of the [CreateView] and returns it as string formatted by the [FormatOptions], the
+  /// query of the view starts in the line after the `AS` keyword
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(
+  ///     sql::Select::new()
+  ///       .select("id")
+  ///       .from("users")
+  ///       .where_clause("active = true")
+  ///   )
+  ///   .as_pretty_string(&sql::FormatOptions::default());
+  ///
+  /// # let expected = "\
+  /// # CREATE VIEW active_users AS\n\
+  /// # SELECT id\n\
+  /// # FROM users\n\
+  /// # WHERE active = true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS
+  /// SELECT id
+  /// FROM users
+  /// WHERE active = true
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
+  /// Gets the current state of the [CreateView] and returns it as string
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the params of the [CreateView] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [CreateView::raw], [CreateView::raw_before] and [CreateView::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("*").from("users").where_clause("active = true"));
+  ///
+  /// let params = create_view.clauses();
+  ///
+  /// # assert_eq!(vec![sql::CreateViewParams::CreateView, sql::CreateViewParams::AsSelect], params);
+  /// ```
+  pub fn clauses(&self) -> Vec<CreateViewParams> {
+    let mut params = vec![];
+
+    if self._create_view.is_empty() == false {
+      params.push(CreateViewParams::CreateView);
+    }
+    if self._column.is_empty() == false {
+      params.push(CreateViewParams::Column);
+    }
+    if self._as_select.is_some() {
+      params.push(CreateViewParams::AsSelect);
+    }
+    if self._with_check_option {
+      params.push(CreateViewParams::WithCheckOption);
+    }
+
+    params
+  }
+
+  /// Defines the names of the columns of the view, by default the columns are named after the columns of the query
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .column("user_id")
+  ///   .column("user_login")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users (user_id, user_login)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users (user_id, user_login)
+  /// ```
+  pub fn column(mut self, column_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._column, column_name.into().trim());
+    self
+  }
+
+  /// Defines the name of the view to be created, this method overrides the previous value
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("users_view")
+  ///   .create_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users
+  /// ```
+  pub fn create_view(mut self, view_name: impl Into<Fragment>) -> Self {
+    self._create_view = view_name.into().trim();
+    self
+  }
+
+  /// Prints the current state of the [CreateView] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .where_clause("active = true");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(active_users)
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// CREATE VIEW active_users
+  /// AS
+  /// SELECT id, login
+  /// FROM users
+  /// WHERE active = true
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Gets the [Select] of the `as` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("*").from("users"));
+  ///
+  /// let tables = create_view.get_as_select().map(|select| select.tables()).unwrap_or_default();
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("active_users")), create_view.get_create_view());
+  /// # assert_eq!(vec!["users"], tables);
+  /// ```
+  pub fn get_as_select(&self) -> Option<&Select> {
+    self._as_select.as_deref()
+  }
+
+  /// Gets the columns of the view
+  pub fn get_column(&self) -> &[Fragment] {
+    &self._column
+  }
+
+  /// Gets the view of the `create view` clause, `None` when the clause is not defined
+  pub fn get_create_view(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._create_view)
+  }
+
+  /// Creates instance of the [CreateView] command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Defines the `or replace` modifier, the view is replaced when it already exists.
+  /// The modifier is ignored by SQLite that doesn't support it
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .or_replace()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE OR REPLACE VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE OR REPLACE VIEW active_users
+  /// ```
+  pub fn or_replace(mut self) -> Self {
+    self._or_replace = true;
+    self
+  }
+
+  /// Prints the current state of the [CreateView] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query. Is useful to create a more complex create view command.
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .raw("/* create command */")
+  ///   .create_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* create command */ CREATE VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* create command */ CREATE VIEW active_users
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified parameter.
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .raw_after(sql::CreateViewParams::CreateView, "AS SELECT * FROM users WHERE active = true")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT * FROM users WHERE active = true
+  /// ```
+  pub fn raw_after(mut self, param: CreateViewParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified parameter.
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .raw_before(sql::CreateViewParams::CreateView, "DROP VIEW IF EXISTS active_users;")
+  ///   .create_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW IF EXISTS active_users; CREATE VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW IF EXISTS active_users; CREATE VIEW active_users
+  /// ```
+  pub fn raw_before(mut self, param: CreateViewParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((param, raw_sql.into().trim()));
+    self
+  }
+
+  /// Defines the `temporary` modifier, the view is dropped at the end of the session.
+  /// The modifier is ignored by MySQL that doesn't support temporary views
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("recent_orders")
+  ///   .temporary()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TEMPORARY VIEW recent_orders";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TEMPORARY VIEW recent_orders
+  /// ```
+  pub fn temporary(mut self) -> Self {
+    self._temporary = true;
+    self
+  }
+
+  /// Gets the current state of the [CreateView] and returns it as string rendered in the specified SQL [Dialect],
+  /// the params not supported by the dialect are ignored
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("monthly_sales")
+  ///   .or_replace()
+  ///   .materialized();
+  ///
+  /// let standard_query = create_view.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = create_view.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("CREATE OR REPLACE VIEW monthly_sales", standard_query);
+  /// # assert_eq!("CREATE MATERIALIZED VIEW monthly_sales", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// CREATE OR REPLACE VIEW monthly_sales
+  ///
+  /// -- Postgres
+  /// CREATE MATERIALIZED VIEW monthly_sales
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [CreateView] and returns it as string, returns a [BuildError] when
+  /// the [CreateView::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("*").from("users").where_clause("active = true"))
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("CREATE VIEW active_users AS SELECT * FROM users WHERE active = true", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT * FROM users WHERE active = true
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [CreateView] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [CreateView::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("*").from("users"))
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("CREATE VIEW active_users AS SELECT * FROM users", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT * FROM users
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [CreateView], returns a [BuildError] when the view name or the select
+  /// are missing, the select is checked too. On Postgres a materialized view cannot be replaced, so
+  /// both options together are reported as conflicting clauses
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users");
+  ///
+  /// let error = create_view.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "CREATE VIEW".to_string(),
+  /// #   clause: "AS".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [CreateView] like the [CreateView::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .or_replace()
+  ///   .as_select(sql::Select::new().select("*").from("users"));
+  ///
+  /// let error = create_view.validate_for(sql::Dialect::Sqlite).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Sqlite,
+  /// #   feature: "OR REPLACE".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// Defines the `with check option` param, the inserts and updates through the view must satisfy its conditions.
+  /// The param is ignored by SQLite that doesn't support it
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("active = true");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(active_users)
+  ///   .with_check_option()
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true WITH CHECK OPTION";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT * FROM users WHERE active = true WITH CHECK OPTION
+  /// ```
+  pub fn with_check_option(mut self) -> Self {
+    self._with_check_option = true;
+    self
+  }
+
+  /// Writes the current state of the [CreateView] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("*").from("users"));
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// create_view.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nCREATE VIEW active_users AS SELECT * FROM users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [CreateView] into the [std::io::Write], like a file or a socket,
+  /// the same way the [CreateView::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(sql::Select::new().select("*").from("users"));
+  ///
+  /// let mut output = Vec::new();
+  /// create_view.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "CREATE VIEW active_users AS SELECT * FROM users";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl CreateView {
+  /// Defines the `materialized` modifier, the result of the query is stored in the view.
+  /// The materialized views don't support the `or replace` and `temporary` modifiers
+  /// neither the `with check option` param, they are ignored
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let monthly_sales = sql::Select::new()
+  ///   .select("date_trunc('month', created_at) AS month, sum(total) AS total")
+  ///   .from("orders")
+  ///   .group_by("1");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("monthly_sales")
+  ///   .materialized()
+  ///   .as_select(monthly_sales)
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   CREATE MATERIALIZED VIEW monthly_sales AS \
+  /// #   SELECT date_trunc('month', created_at) AS month, sum(total) AS total \
+  /// #   FROM orders \
+  /// #   GROUP BY 1\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE MATERIALIZED VIEW monthly_sales AS
+  /// SELECT date_trunc('month', created_at) AS month, sum(total) AS total
+  /// FROM orders
+  /// GROUP BY 1
+  /// ```
+  #[cfg(feature = "postgresql")]
+  pub fn materialized(mut self) -> Self {
+    self._materialized = true;
+    self
+  }
+}
+
+impl std::fmt::Display for CreateView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self, &fmts))
+  }
+}
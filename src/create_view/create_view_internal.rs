@@ -0,0 +1,178 @@
+use crate::{
+  behavior::Validate,
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{BuildError, CreateView, CreateViewParams, Dialect},
+  utils,
+};
+use std::fmt::Write;
+
+impl Concat for CreateView {
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
+
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_create_view(w, &fmts)?;
+    self.concat_column(w, &fmts)?;
+    self.concat_as_select(w, &fmts)?;
+    self.concat_with_check_option(w, &fmts)?;
+
+    w.trim_end_since(start);
+    Ok(())
+  }
+}
+
+impl CreateView {
+  fn concat_as_select(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      w,
+      fmts,
+      CreateViewParams::AsSelect,
+      |w| {
+        if let Some(select) = &self._as_select {
+          write!(w, "AS{space}{lb}")?;
+          w.write_query(|w| select.concat(w, fmts))?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+
+  fn concat_column(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      w,
+      fmts,
+      CreateViewParams::Column,
+      |w| {
+        if self._column.iter().any(|column| column.is_empty() == false) {
+          w.write_str("(")?;
+          w.write_joined(&self._column, comma)?;
+          write!(w, "){space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+
+  fn concat_create_view(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let materialized = self.is_materialized(fmts);
+
+    // SQLite has no `or replace` modifier and MySQL has no temporary views,
+    // none of them are available to the materialized views of Postgres
+    let or_replace = match fmts.dialect {
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => false,
+      _ => self._or_replace && materialized == false,
+    };
+    let temporary = match fmts.dialect {
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => false,
+      _ => self._temporary && materialized == false,
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      w,
+      fmts,
+      CreateViewParams::CreateView,
+      |w| {
+        if self._create_view.is_empty() == false {
+          write!(w, "CREATE{space}")?;
+          if or_replace {
+            write!(w, "OR REPLACE{space}")?;
+          }
+          if temporary {
+            write!(w, "TEMPORARY{space}")?;
+          }
+          if materialized {
+            write!(w, "MATERIALIZED{space}")?;
+          }
+          write!(w, "VIEW{space}")?;
+          w.write_fragment(&self._create_view)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+
+  fn concat_with_check_option(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    // SQLite and the materialized views of Postgres don't support the check option
+    let with_check_option = match fmts.dialect {
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => false,
+      _ => self._with_check_option && self.is_materialized(fmts) == false,
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      w,
+      fmts,
+      CreateViewParams::WithCheckOption,
+      |w| {
+        if with_check_option {
+          write!(w, "WITH CHECK OPTION{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+
+  fn is_materialized(&self, fmts: &fmt::Formatter) -> bool {
+    match fmts.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => self._materialized,
+      _ => false,
+    }
+  }
+}
+
+impl Validate for CreateView {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    utils::require_support(
+      dialect,
+      "OR REPLACE",
+      self._or_replace,
+      utils::is_sqlite(dialect) == false,
+    )?;
+    utils::require_support(dialect, "TEMPORARY", self._temporary, utils::is_mysql(dialect) == false)?;
+    utils::require_support(
+      dialect,
+      "WITH CHECK OPTION",
+      self._with_check_option,
+      utils::is_sqlite(dialect) == false,
+    )?;
+    #[cfg(feature = "postgresql")]
+    utils::require_support(dialect, "MATERIALIZED", self._materialized, utils::is_postgres(dialect))?;
+
+    #[cfg(feature = "postgresql")]
+    utils::exclusive_clauses(
+      "CREATE VIEW",
+      &[("OR REPLACE", self._or_replace), ("MATERIALIZED", self._materialized)],
+    )?;
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) == false {
+      utils::require_clause("CREATE VIEW", "CREATE VIEW", self._create_view.is_empty())?;
+      utils::require_clause("CREATE VIEW", "AS", self._as_select.is_none())?;
+    }
+
+    match &self._as_select {
+      Some(select) => select.check_clauses(dialect),
+      None => Ok(()),
+    }
+  }
+}
@@ -0,0 +1,172 @@
+#[cfg(feature = "mysql")]
+use crate::behavior::ToSqlLiteral;
+use crate::structure::{DataType, Dialect, Fragment, Part};
+
+impl DataType {
+  /// Gets the native type in the syntax of the specified SQL [Dialect], when the dialect has no equivalent type
+  /// the type is rendered with the Postgres name and the validation of the commands reports it.
+  /// Use [DataType::try_to_sql] to detect these types
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let data_type = sql::DataType::Timestamp;
+  ///
+  /// let standard_type = data_type.to_sql(sql::Dialect::Standard);
+  /// let mysql_type = data_type.to_sql(sql::Dialect::Mysql);
+  ///
+  /// # assert_eq!("TIMESTAMP", standard_type);
+  /// # assert_eq!("DATETIME", mysql_type);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// TIMESTAMP
+  ///
+  /// -- MySQL
+  /// DATETIME
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    self.try_to_sql(dialect).unwrap_or_else(|| self.postgres())
+  }
+
+  /// Gets the native type in the syntax of the specified SQL [Dialect], returns `None` when the dialect
+  /// has no equivalent type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let tags = sql::DataType::Array(Box::new(sql::DataType::Text));
+  ///
+  /// # assert_eq!(Some("TEXT ARRAY".to_string()), tags.try_to_sql(sql::Dialect::Standard));
+  /// # assert_eq!(None, tags.try_to_sql(sql::Dialect::Sqlite));
+  /// # }
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Option<String> {
+    match dialect {
+      Dialect::Standard => self.standard(),
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => Some(self.postgres()),
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => self.sqlite(),
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => self.mysql(),
+    }
+  }
+
+  /// The type written in the definitions of the columns, the type without equivalent in the dialect is written
+  /// with the Postgres name and marked, this way the validation of the command reports it
+  pub(crate) fn definition(&self, dialect: Dialect) -> Fragment {
+    match self.try_to_sql(dialect) {
+      Some(data_type) => Fragment::from(data_type),
+      None => {
+        let mut fragment = Fragment::from(self.postgres());
+        fragment.push_part(Part::UnsupportedType(self.clone()));
+        fragment
+      }
+    }
+  }
+
+  /// The target type of the `CAST` expression, MySQL only accepts a reduced set of types as target of the cast
+  pub(crate) fn cast_sql(&self, dialect: Dialect) -> String {
+    #[cfg(feature = "mysql")]
+    if dialect == Dialect::Mysql {
+      let cast_type = match self {
+        Self::SmallInt | Self::Integer | Self::BigInt | Self::Boolean => "SIGNED".to_string(),
+        Self::Varchar(length) => format!("CHAR({length})"),
+        Self::Text | Self::Uuid | Self::Enum(..) => "CHAR".to_string(),
+        Self::Timestamp | Self::TimestampTz => "DATETIME".to_string(),
+        Self::Blob => "BINARY".to_string(),
+        _ => self.to_sql(dialect),
+      };
+      return cast_type;
+    }
+
+    self.to_sql(dialect)
+  }
+
+  fn standard(&self) -> Option<String> {
+    let data_type = match self {
+      Self::TimestampTz => "TIMESTAMP WITH TIME ZONE".to_string(),
+      Self::Jsonb => "JSON".to_string(),
+      Self::Blob => "BLOB".to_string(),
+      Self::Array(data_type) => format!("{} ARRAY", data_type.standard()?),
+      Self::Uuid | Self::Enum(..) => return None,
+      _ => self.postgres(),
+    };
+    Some(data_type)
+  }
+
+  fn postgres(&self) -> String {
+    match self {
+      Self::SmallInt => "SMALLINT".to_string(),
+      Self::Integer => "INTEGER".to_string(),
+      Self::BigInt => "BIGINT".to_string(),
+      Self::Numeric(precision, scale) => format!("NUMERIC({precision}, {scale})"),
+      Self::Boolean => "BOOLEAN".to_string(),
+      Self::Text => "TEXT".to_string(),
+      Self::Varchar(length) => format!("VARCHAR({length})"),
+      Self::Date => "DATE".to_string(),
+      Self::Timestamp => "TIMESTAMP".to_string(),
+      Self::TimestampTz => "TIMESTAMPTZ".to_string(),
+      Self::Uuid => "UUID".to_string(),
+      Self::Json => "JSON".to_string(),
+      Self::Jsonb => "JSONB".to_string(),
+      Self::Blob => "BYTEA".to_string(),
+      Self::Array(data_type) => format!("{}[]", data_type.postgres()),
+      Self::Enum(name, _) => name.to_string(),
+    }
+  }
+
+  #[cfg(feature = "sqlite")]
+  fn sqlite(&self) -> Option<String> {
+    let data_type = match self {
+      Self::SmallInt | Self::Integer | Self::BigInt | Self::Boolean => "INTEGER",
+      Self::Numeric(..) => "NUMERIC",
+      Self::Text | Self::Varchar(_) | Self::Uuid | Self::Json | Self::Enum(..) => "TEXT",
+      Self::Date | Self::Timestamp | Self::TimestampTz => "TEXT",
+      Self::Jsonb | Self::Blob => "BLOB",
+      Self::Array(_) => return None,
+    };
+    Some(data_type.to_string())
+  }
+
+  #[cfg(feature = "mysql")]
+  fn mysql(&self) -> Option<String> {
+    let data_type = match self {
+      Self::Integer => "INT".to_string(),
+      Self::Numeric(precision, scale) => format!("DECIMAL({precision}, {scale})"),
+      Self::Timestamp => "DATETIME".to_string(),
+      Self::TimestampTz => "TIMESTAMP".to_string(),
+      Self::Uuid => "CHAR(36)".to_string(),
+      Self::Jsonb => "JSON".to_string(),
+      Self::Blob => "BLOB".to_string(),
+      Self::Array(_) => return None,
+      Self::Enum(_, labels) => {
+        let labels = labels
+          .iter()
+          .map(|label| label.to_sql_literal(Dialect::Mysql))
+          .collect::<Vec<_>>()
+          .join(", ");
+        format!("ENUM({labels})")
+      }
+      _ => self.postgres(),
+    };
+    Some(data_type)
+  }
+}
+
+impl std::fmt::Display for DataType {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.to_sql(Dialect::default()))
+  }
+}
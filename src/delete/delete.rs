@@ -1,14 +1,52 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{Delete, DeleteClause, LogicalOperator},
-  utils::push_unique,
+  behavior::{BindValues, TransactionQuery, Validate},
+  bind, fmt, introspection, parser, pretty,
+  structure::{
+    BuildError, Delete, DeleteClause, Dialect, FormatOptions, Fragment, LogicalOperator, ParseError, PreparedQuery,
+    Select, Value, WhereGroup,
+  },
+  utils::{self, push_unique},
+  where_group,
 };
+use std::sync::Arc;
 
 impl TransactionQuery for Delete {}
 
 impl Delete {
+  /// Gets the current state of the [Delete] and returns it as string formatted by the [FormatOptions], a sub-query
+  /// of the conditions is printed in its own lines
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id NOT IN (SELECT user_id FROM orders)")
+  ///   .as_pretty_string(&sql::FormatOptions::default());
+  ///
+  /// # let expected = "\
+  /// # DELETE FROM users\n\
+  /// # WHERE id NOT IN (\n  \
+  /// # SELECT user_id\n  \
+  /// # FROM orders\n\
+  /// # )";
+  /// # assert_eq!(expected, delete_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users
+  /// WHERE id NOT IN (
+  ///   SELECT user_id
+  ///   FROM orders
+  /// )
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [Delete] and returns it as string
   ///
   /// # Example
@@ -30,8 +68,99 @@ impl Delete {
   /// DELETE FROM users WHERE id = $1
   /// ```
   pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string together with the values bound
+  /// by the methods with the `_bind` suffix, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, values) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 42)
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "DELETE FROM users WHERE id = $1";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from(42)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE id = $1
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the clauses of the [Delete] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [Delete::raw], [Delete::raw_before] and [Delete::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .where_clause("id = 1")
+  ///   .delete_from("users");
+  ///
+  /// let clauses = delete.clauses();
+  ///
+  /// # assert_eq!(vec![sql::DeleteClause::DeleteFrom, sql::DeleteClause::Where], clauses);
+  /// ```
+  pub fn clauses(&self) -> Vec<DeleteClause> {
+    let mut clauses = vec![];
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    if self._with.is_empty() == false {
+      clauses.push(DeleteClause::With);
+    }
+    #[cfg(feature = "mysql")]
+    if self._delete.is_empty() == false {
+      clauses.push(DeleteClause::Delete);
+    }
+    if self._delete_from.is_empty() == false {
+      clauses.push(DeleteClause::DeleteFrom);
+    }
+    #[cfg(feature = "mysql")]
+    {
+      if self._from.is_empty() == false {
+        clauses.push(DeleteClause::From);
+      }
+      if self._join.is_empty() == false {
+        clauses.push(DeleteClause::Join);
+      }
+      if self._partition.is_empty() == false {
+        clauses.push(DeleteClause::Partition);
+      }
+    }
+    if self._where.is_empty() == false {
+      clauses.push(DeleteClause::Where);
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if self._returning.is_empty() == false {
+      clauses.push(DeleteClause::Returning);
+    }
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    if self._order_by.is_empty() == false {
+      clauses.push(DeleteClause::OrderBy);
+    }
+    #[cfg(feature = "mysql")]
+    if self._limit.is_empty() == false {
+      clauses.push(DeleteClause::Limit);
+    }
+
+    clauses
   }
 
   /// Prints the current state of the [Delete] to the standard output in a more ease to read version.
@@ -61,7 +190,7 @@ impl Delete {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -90,21 +219,147 @@ impl Delete {
   /// ```sql
   /// DELETE FROM orders
   /// ```
-  pub fn delete_from(mut self, table: &str) -> Self {
-    self._delete_from = table.trim().to_string();
+  pub fn delete_from(mut self, table: impl Into<Fragment>) -> Self {
+    self._delete_from = table.into().trim();
     self
   }
 
+  /// Gets the table of the `delete from` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("users")), delete.get_delete_from());
+  /// ```
+  pub fn get_delete_from(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._delete_from)
+  }
+
+  /// Gets the conditions of the `where` clause together with the [LogicalOperator] that joins each one
+  /// to the previous conditions
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("active = false")
+  ///   .where_or("banned = true");
+  ///
+  /// let conditions = delete.get_where();
+  ///
+  /// # let expected = [
+  /// #   (sql::LogicalOperator::And, sql::Fragment::from("active = false")),
+  /// #   (sql::LogicalOperator::Or, sql::Fragment::from("banned = true")),
+  /// # ];
+  /// # assert_eq!(expected, conditions);
+  /// ```
+  pub fn get_where(&self) -> &[(LogicalOperator, Fragment)] {
+    &self._where
+  }
+
   /// Creates instance of the Delete command
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// Returns the number of parameters of the [Delete], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound by the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = $1")
+  ///   .where_clause_bind("status = ?", "disabled");
+  ///
+  /// # let expected = "DELETE FROM users WHERE login = $1 AND status = $2";
+  /// # assert_eq!(expected, delete.as_string());
+  /// # assert_eq!(2, delete.param_count());
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE login = $1 AND status = $2
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
+  /// Parses the SQL text of a delete command into a builder, this way an existing query can be changed with
+  /// the builder methods. The conditions of the `where` clause are split at the `and` operator,
+  /// the conditions with the `or` operator are kept as a single condition wrapped in parentheses
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::parse("DELETE FROM orders WHERE created_at < $1")
+  ///   .unwrap()
+  ///   .where_clause("status = 'canceled'");
+  ///
+  /// # let expected = "DELETE FROM orders WHERE created_at < $1 AND status = 'canceled'";
+  /// # assert_eq!(expected, delete.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM orders
+  /// WHERE created_at < $1 AND status = 'canceled'
+  /// ```
+  pub fn parse(sql: &str) -> Result<Self, ParseError> {
+    parser::delete(sql)
+  }
+  /// Renders the [Delete] once in the syntax of the [Dialect] and returns a [PreparedQuery], the SQL and the number
+  /// of parameters are kept so the same query can be executed many times binding new values with the
+  /// [PreparedQuery::bind] method, without rendering the builder again
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 42);
+  ///
+  /// let prepared = delete.prepare(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("DELETE FROM users WHERE id = ?", prepared.sql());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE id = ?
+  /// ```
+  pub fn prepare(&self, dialect: Dialect) -> PreparedQuery {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (sql, values, param_count) = fmt::render_prepared(self, &fmts);
+    PreparedQuery::new(sql, values, param_count, dialect)
+  }
+
   /// Prints the current state of the [Delete] to the standard output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -130,8 +385,8 @@ impl Delete {
   /// ```sql
   /// delete from users WHERE login = 'foo'
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -157,8 +412,8 @@ impl Delete {
   /// ```sql
   /// DELETE FROM users where name = 'Foo'
   /// ```
-  pub fn raw_after(mut self, clause: DeleteClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, clause: DeleteClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim()));
     self
   }
 
@@ -184,11 +439,209 @@ impl Delete {
   /// ```sql
   /// delete from users WHERE name = 'Bar'
   /// ```
-  pub fn raw_before(mut self, clause: DeleteClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, clause: DeleteClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim()));
     self
   }
 
+  /// Gets the names of the tables written and read by the [Delete], the table of the `delete from` clause
+  /// and the tables of the sub-queries in the order they appear. The names of the common table expressions
+  /// are not included and the quoted names are returned without the quotes
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("orders")
+  ///   .where_exists(sql::Select::new().select("1").from("refunds").where_clause("refunds.order_id = orders.id"));
+  ///
+  /// let tables = delete.tables();
+  ///
+  /// # assert_eq!(vec!["orders", "refunds"], tables);
+  /// ```
+  pub fn tables(&self) -> Vec<String> {
+    introspection::tables(self)
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = $1")
+  ///   .returning("login");
+  ///
+  /// let standard_query = delete.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = delete.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("DELETE FROM users WHERE id = $1", standard_query);
+  /// # assert_eq!("DELETE FROM users WHERE id = $1 RETURNING login", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// DELETE FROM users WHERE id = $1
+  ///
+  /// -- Postgres
+  /// DELETE FROM users WHERE id = $1 RETURNING login
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound by the methods with the `_bind` suffix,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 42);
+  ///
+  /// let (standard_query, values) = delete.to_sql_with_binds(sql::Dialect::Standard);
+  /// let (postgresql_query, _) = delete.to_sql_with_binds(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("DELETE FROM users WHERE id = ?", standard_query);
+  /// # assert_eq!("DELETE FROM users WHERE id = $1", postgresql_query);
+  /// # assert_eq!(vec![sql::Value::from(42)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// DELETE FROM users WHERE id = ?
+  ///
+  /// -- Postgres
+  /// DELETE FROM users WHERE id = $1
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string, returns a [BuildError] when
+  /// the [Delete::validate] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = $1")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DELETE FROM users WHERE id = $1", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE id = $1
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [Delete] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Delete::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DELETE FROM users WHERE login = 'foo'", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE login = 'foo'
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [Delete], returns a [BuildError] when the table to delete from is missing,
+  /// or when the number of values of a method with the `_bind` suffix doesn't match the placeholders.
+  /// The required clauses are not checked when the raw SQL is set
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .where_clause("id = $1");
+  ///
+  /// let error = delete.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "DELETE".to_string(),
+  /// #   clause: "DELETE FROM".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [Delete] like the [Delete::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .returning("id");
+  ///
+  /// let error = delete.validate_for(sql::Dialect::Mysql).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Mysql,
+  /// #   feature: "RETURNING".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
   /// The method will concatenate multiples calls using the `and` operator. This method is un alias of `where_clause`.
   ///
   /// # Example
@@ -213,7 +666,7 @@ impl Delete {
   ///   AND product_id = $2
   ///   AND created_at >= current_date
   /// ```
-  pub fn where_and(self, condition: &str) -> Self {
+  pub fn where_and(self, condition: impl Into<Fragment>) -> Self {
     self.where_clause(condition)
   }
 
@@ -240,8 +693,39 @@ impl Delete {
   ///   login = $1
   ///   AND status = 'deactivated'
   /// ```
-  pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+  pub fn where_clause(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, condition.into().trim()));
+    self
+  }
+
+  /// The `where` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `and` operator.
+  /// The values are returned by the [Delete::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (delete_query, values) = sql::Delete::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_clause_bind("created_at between ? and ?", ("2024-01-01", "2024-12-31"))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "WHERE login = $1 AND created_at between $2 and $3";
+  /// # assert_eq!(expected, delete_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("2024-01-01"), sql::Value::from("2024-12-31")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE login = $1 AND created_at between $2 and $3
+  /// ```
+  pub fn where_clause_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, bind::bind(condition, values)));
     self
   }
 
@@ -268,10 +752,203 @@ impl Delete {
   ///   login = 'foo'
   ///   OR login = 'bar'
   /// ```
-  pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+  pub fn where_or(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, condition.into().trim()));
+    self
+  }
+
+  /// The `where` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `or` operator.
+  /// The values are returned by the [Delete::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (delete_query, values) = sql::Delete::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_or_bind("login = ?", "bar")
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "WHERE login = $1 OR login = $2";
+  /// # assert_eq!(expected, delete_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("bar")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE login = $1 OR login = $2
+  /// ```
+  pub fn where_or_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, bind::bind(condition, values)));
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `and` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .where_clause("a = 1")
+  ///   .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE a = 1 AND (b = 2 OR c = 3)";
+  /// # assert_eq!(expected, delete_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE a = 1 AND (b = 2 OR c = 3)
+  /// ```
+  pub fn where_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::And, group);
     self
   }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `or` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete_query = sql::Delete::new()
+  ///   .where_clause("a = 1")
+  ///   .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE a = 1 OR (b = 2 AND c = 3)";
+  /// # assert_eq!(expected, delete_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE a = 1 OR (b = 2 AND c = 3)
+  /// ```
+  pub fn where_or_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::Or, group);
+    self
+  }
+
+  /// The `where` clause with the `exists` operator and a sub-query, this method will concatenate multiples calls
+  /// using the `and` operator. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let orders = sql::Select::new()
+  ///   .select("1")
+  ///   .from("orders")
+  ///   .where_clause("orders.user_id = users.id");
+  ///
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_exists(orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+  /// # assert_eq!(expected, delete_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users
+  /// WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)
+  /// ```
+  pub fn where_exists(mut self, select: impl Into<Arc<Select>>) -> Self {
+    let condition = Fragment::from("EXISTS ").push(Fragment::sub_query(select));
+    push_unique(&mut self._where, (LogicalOperator::And, condition));
+    self
+  }
+
+  /// The `where` clause with the `in` operator and a sub-query, this method will concatenate multiples calls
+  /// using the `and` operator. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let admins = sql::Select::new()
+  ///   .select("user_id")
+  ///   .from("admins");
+  ///
+  /// let delete_query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_in("id", admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE id IN (SELECT user_id FROM admins)";
+  /// # assert_eq!(expected, delete_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users
+  /// WHERE id IN (SELECT user_id FROM admins)
+  /// ```
+  pub fn where_in(mut self, column: impl Into<Fragment>, select: impl Into<Arc<Select>>) -> Self {
+    let condition = column.into().trim().push(" IN ").push(Fragment::sub_query(select));
+    push_unique(&mut self._where, (LogicalOperator::And, condition));
+    self
+  }
+
+  /// Writes the current state of the [Delete] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// delete.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nDELETE FROM users WHERE id = 1";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Delete] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Delete::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1");
+  ///
+  /// let mut output = Vec::new();
+  /// delete.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "DELETE FROM users WHERE id = 1";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
@@ -329,8 +1006,8 @@ impl Delete {
   /// WHERE id in (select * from deactivated_users)
   /// ```
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-  pub fn with(mut self, name: &str, query: impl WithQuery + 'static + Send + Sync) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Fragment>, query: impl WithQuery + 'static + Send + Sync) -> Self {
+    self._with.push((name.into().trim(), std::sync::Arc::new(query)));
     self
   }
 }
@@ -339,6 +1016,11 @@ impl Delete {
 #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl Delete {
+  /// Gets the output names of the `returning` clause
+  pub fn get_returning(&self) -> &[Fragment] {
+    &self._returning
+  }
+
   /// The `returning` clause
   ///
   /// # Example
@@ -362,8 +1044,8 @@ impl Delete {
   /// ```sql
   /// DELETE FROM users RETURNING id, login
   /// ```
-  pub fn returning(mut self, output_name: &str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_string());
+  pub fn returning(mut self, output_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._returning, output_name.into().trim());
     self
   }
 }
@@ -372,19 +1054,24 @@ impl Delete {
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
 impl Delete {
+  /// Gets the columns of the `order by` clause
+  pub fn get_order_by(&self) -> &[Fragment] {
+    &self._order_by
+  }
+
   /// The `order by` clause.
   ///
   /// # Example
   ///
   /// ```
-  /// # #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  /// # #[cfg(feature = "sqlite")]
   /// # {
   /// # use sql_query_builder as sql;
   /// let delete = sql::Delete::new()
   ///   .order_by("created_at asc");
   ///
   /// # let expected = "ORDER BY created_at asc";
-  /// # assert_eq!(expected, delete.as_string());
+  /// # assert_eq!(expected, delete.to_sql(sql::Dialect::Sqlite));
   /// # }
   /// ```
   ///
@@ -395,12 +1082,15 @@ impl Delete {
   /// ```
   ///
   /// Note: For crate feature `sqlite` this clause is behind a flag at SQLite, [more info](https://sqlite.org/lang_delete.html#optional_limit_and_order_by_clauses).
-  pub fn order_by(mut self, column: &str) -> Self {
-    push_unique(&mut self._order_by, column.trim().to_string());
+  pub fn order_by(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._order_by, column.into().trim());
     self
   }
 }
 
+#[cfg(any(doc, feature = "mysql"))]
+use crate::structure::Join;
+
 #[cfg(any(doc, feature = "mysql"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
 impl Delete {
@@ -418,7 +1108,7 @@ impl Delete {
   ///   .where_clause("t1.id = '123'");
   ///
   /// # let expected = "DELETE low_priority FROM t1 WHERE t1.id = '123'";
-  /// # assert_eq!(expected, delete.to_string());
+  /// # assert_eq!(expected, delete.to_sql(sql::Dialect::Mysql));
   /// # }
   /// ```
   ///
@@ -473,7 +1163,7 @@ impl Delete {
   /// #     t1.id = t2.id \
   /// #     AND t2.id = t3.id\
   /// # ";
-  /// # assert_eq!(expected, delete.to_string());
+  /// # assert_eq!(expected, delete.to_sql(sql::Dialect::Mysql));
   /// # }
   /// ```
   ///
@@ -488,8 +1178,8 @@ impl Delete {
   ///   t1.id = t2.id
   ///   AND t2.id = t3.id
   /// ```
-  pub fn delete(mut self, table: &str) -> Self {
-    push_unique(&mut self._delete, table.trim().to_string());
+  pub fn delete(mut self, table: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._delete, table.into().trim());
     self
   }
 
@@ -503,7 +1193,7 @@ impl Delete {
   /// # use sql_query_builder as sql;
   /// let query = sql::Delete::new()
   ///   .from("users")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "FROM users";
   /// # assert_eq!(expected, query);
@@ -515,8 +1205,41 @@ impl Delete {
   /// ```sql
   /// FROM users
   /// ```
-  pub fn from(mut self, table: &str) -> Self {
-    push_unique(&mut self._from, table.trim().to_string());
+  pub fn from(mut self, table: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._from, table.into().trim());
+    self
+  }
+
+  /// The `join` clause with a [Join] builder, the table or sub-query and the conditions of the join are defined
+  /// separately. This method accumulates the joins in the same order of the `*_join` methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Delete::new()
+  ///   .delete("users")
+  ///   .from("users")
+  ///   .join(sql::Join::left("addresses").on("addresses.user_id = users.id"))
+  ///   .join(sql::Join::inner("roles").using("role_id"))
+  ///   .to_sql(sql::Dialect::Mysql);
+  ///
+  /// # let expected = "DELETE users FROM users LEFT JOIN addresses ON addresses.user_id = users.id INNER JOIN roles USING (role_id)";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE users FROM users
+  /// LEFT JOIN addresses ON addresses.user_id = users.id
+  /// INNER JOIN roles USING (role_id)
+  /// ```
+  pub fn join(mut self, join: Join) -> Self {
+    push_unique(&mut self._join, join);
     self
   }
 
@@ -530,7 +1253,7 @@ impl Delete {
   /// # use sql_query_builder as sql;
   /// let query = sql::Delete::new()
   ///   .cross_join("addresses")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "CROSS JOIN addresses";
   /// # assert_eq!(expected, query);
@@ -542,11 +1265,10 @@ impl Delete {
   /// ```sql
   /// CROSS JOIN addresses
   /// ```
-  pub fn cross_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn cross_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("CROSS JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::cross(table));
     }
     self
   }
@@ -561,7 +1283,7 @@ impl Delete {
   /// # use sql_query_builder as sql;
   /// let query = sql::Delete::new()
   ///   .inner_join("addresses on addresses.user_login = users.login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "INNER JOIN addresses on addresses.user_login = users.login";
   /// # assert_eq!(query, expected);
@@ -573,11 +1295,10 @@ impl Delete {
   /// ```sql
   /// INNER JOIN addresses on addresses.user_login = users.login
   /// ```
-  pub fn inner_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn inner_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("INNER JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::inner(table));
     }
     self
   }
@@ -592,7 +1313,7 @@ impl Delete {
   /// # use sql_query_builder as sql;
   /// let query = sql::Delete::new()
   ///   .left_join("addresses on addresses.user_login = users.login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "LEFT JOIN addresses on addresses.user_login = users.login";
   /// # assert_eq!(query, expected);
@@ -604,11 +1325,10 @@ impl Delete {
   /// ```sql
   /// LEFT JOIN addresses on addresses.user_login = users.login
   /// ```
-  pub fn left_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn left_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("LEFT JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::left(table));
     }
     self
   }
@@ -623,7 +1343,7 @@ impl Delete {
   /// # use sql_query_builder as sql;
   /// let query = sql::Delete::new()
   ///   .right_join("addresses on addresses.user_login = users.login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "RIGHT JOIN addresses on addresses.user_login = users.login";
   /// # assert_eq!(query, expected);
@@ -635,15 +1355,19 @@ impl Delete {
   /// ```sql
   /// RIGHT JOIN addresses on addresses.user_login = users.login
   /// ```
-  pub fn right_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn right_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("RIGHT JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::right(table));
     }
     self
   }
 
+  /// Gets the value of the `limit` clause, `None` when the clause is not defined
+  pub fn get_limit(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._limit)
+  }
+
   /// The `limit` clause, this method overrides the previous value
   ///
   /// # Example
@@ -660,7 +1384,7 @@ impl Delete {
   ///   .limit("123");
   ///
   /// # let expected = "LIMIT 123";
-  /// # assert_eq!(expected, delete.as_string());
+  /// # assert_eq!(expected, delete.to_sql(sql::Dialect::Mysql));
   /// # }
   /// ```
   ///
@@ -669,8 +1393,8 @@ impl Delete {
   /// ```sql
   /// LIMIT 123
   /// ```
-  pub fn limit(mut self, num: &str) -> Self {
-    self._limit = num.trim().to_string();
+  pub fn limit(mut self, num: impl Into<Fragment>) -> Self {
+    self._limit = num.into().trim();
     self
   }
 
@@ -685,7 +1409,7 @@ impl Delete {
   /// let query = sql::Delete::new()
   ///   .delete_from("employees")
   ///   .partition("p1")
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "DELETE FROM employees PARTITION (p1)";
   /// # assert_eq!(expected, query);
@@ -697,8 +1421,8 @@ impl Delete {
   /// ```sql
   /// DELETE FROM employees PARTITION (p1)
   /// ```
-  pub fn partition(mut self, name: &str) -> Self {
-    push_unique(&mut self._partition, name.trim().to_string());
+  pub fn partition(mut self, name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._partition, name.into().trim());
     self
   }
 }
@@ -712,6 +1436,6 @@ impl std::fmt::Display for Delete {
 impl std::fmt::Debug for Delete {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,173 +1,181 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, sql_standard::ConcatWhere, Concat},
   fmt,
-  structure::{Delete, DeleteClause},
+  introspection::{CollectTables, Tables},
+  pretty::Body,
+  structure::{BuildError, Delete, DeleteClause, Dialect},
+  utils,
 };
+use std::fmt::Write;
 
 impl ConcatWhere<DeleteClause> for Delete {}
 
 impl Concat for Delete {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_delete_from(query, &fmts);
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Where,
-        &self._where,
-      );
-    }
+    match fmts.dialect {
+      Dialect::Standard => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_delete_from(w, &fmts)?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Where,
+          &self._where,
+        )?;
+      }
 
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::With,
-        &self._with,
-      );
-      query = self.concat_delete_from(query, &fmts);
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Where,
-        &self._where,
-      );
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Returning,
-        &self._returning,
-      );
-    }
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::With,
+          &self._with,
+        )?;
+        self.concat_delete_from(w, &fmts)?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Where,
+          &self._where,
+        )?;
+        self.concat_returning(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Returning,
+          &self._returning,
+        )?;
+      }
 
-    #[cfg(feature = "sqlite")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::With,
-        &self._with,
-      );
-      query = self.concat_delete_from(query, &fmts);
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Where,
-        &self._where,
-      );
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Returning,
-        &self._returning,
-      );
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::OrderBy,
-        &self._order_by,
-      );
-    }
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::With,
+          &self._with,
+        )?;
+        self.concat_delete_from(w, &fmts)?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Where,
+          &self._where,
+        )?;
+        self.concat_returning(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Returning,
+          &self._returning,
+        )?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::OrderBy,
+          &self._order_by,
+        )?;
+      }
 
-    #[cfg(feature = "mysql")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::With,
-        &self._with,
-      );
-      query = self.concat_delete_from_mysql(query, &fmts);
-      query = self.concat_join(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Join,
-        &self._join,
-      );
-      query = self.concat_partition(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Partition,
-        &self._partition,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Where,
-        &self._where,
-      );
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::OrderBy,
-        &self._order_by,
-      );
-      query = self.concat_limit(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Limit,
-        &self._limit,
-      );
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::With,
+          &self._with,
+        )?;
+        self.concat_delete_from_mysql(w, &fmts)?;
+        self.concat_join(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Join,
+          &self._join,
+        )?;
+        self.concat_partition(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Partition,
+          &self._partition,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Where,
+          &self._where,
+        )?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::OrderBy,
+          &self._order_by,
+        )?;
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          DeleteClause::Limit,
+          &self._limit,
+        )?;
+      }
     }
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl Delete {
-  #[cfg(not(feature = "mysql"))]
-  fn concat_delete_from(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_delete_from(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._delete_from.is_empty() == false {
-      let table_name = &self._delete_from;
-      format!("DELETE FROM{space}{table_name}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       DeleteClause::DeleteFrom,
-      sql,
+      |w| {
+        if self._delete_from.is_empty() == false {
+          w.write_clause("DELETE FROM", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._delete_from)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
@@ -188,13 +196,10 @@ use crate::concat::sql_standard::ConcatOrderBy;
 impl ConcatOrderBy<DeleteClause> for Delete {}
 
 #[cfg(feature = "mysql")]
-use crate::{
-  concat::{
-    mysql::ConcatPartition,
-    non_standard::ConcatLimit,
-    sql_standard::{ConcatFrom, ConcatJoin},
-  },
-  utils,
+use crate::concat::{
+  mysql::ConcatPartition,
+  non_standard::ConcatLimit,
+  sql_standard::{ConcatFrom, ConcatJoin},
 };
 #[cfg(feature = "mysql")]
 impl ConcatFrom<DeleteClause> for Delete {}
@@ -207,134 +212,192 @@ impl ConcatPartition<DeleteClause> for Delete {}
 
 #[cfg(feature = "mysql")]
 impl Delete {
-  fn concat_delete_from_mysql(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_delete_from_mysql(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let delete_values = utils::join(&self._delete, comma);
-    let from_values = utils::join(&self._from, comma);
+    let has_delete_from = self._delete_from.is_empty() == false;
+    let has_delete = self._delete.iter().any(|item| item.is_empty() == false);
+    let has_from = self._from.iter().any(|item| item.is_empty() == false);
 
-    match (&self._delete_from, delete_values, from_values) {
-      (del_from, del, from) if del_from.is_empty() == false && del.is_empty() == false && from.is_empty() == false => {
-        let delete_clause = concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          DeleteClause::Delete,
-          format!("DELETE{space}{del}{space}"),
-        );
-
-        let from_clause = concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          DeleteClause::From,
-          format!("FROM{space}{del_from}{comma}{from}{space}{lb}"),
-        );
+    let concat_delete = |w: &mut fmt::Writer| {
+      concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        w,
+        fmts,
+        DeleteClause::Delete,
+        |w| {
+          w.write_clause("DELETE", Body::List)?;
+          w.write_str(space)?;
+          w.write_items(&self._delete, comma)?;
+          w.write_str(space)
+        },
+      )
+    };
+    let concat_from = |w: &mut fmt::Writer| {
+      concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, DeleteClause::From, |w| {
+        w.write_clause("FROM", Body::List)?;
+        w.write_str(space)?;
+        if has_delete_from {
+          w.write_fragment(&self._delete_from)?;
+          w.write_separator(comma)?;
+        }
+        w.write_items(&self._from, comma)?;
+        write!(w, "{space}{lb}")
+      })
+    };
 
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          query,
-          fmts,
-          DeleteClause::DeleteFrom,
-          format!("{delete_clause}{from_clause}"),
-        )
+    match (has_delete_from, has_delete, has_from) {
+      (true, true, true) => concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        w,
+        fmts,
+        DeleteClause::DeleteFrom,
+        |w| {
+          concat_delete(w)?;
+          concat_from(w)
+        },
+      ),
+      (true, true, false) => concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        w,
+        fmts,
+        DeleteClause::DeleteFrom,
+        |w| {
+          concat_delete(w)?;
+          w.write_clause("FROM", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._delete_from)?;
+          write!(w, "{space}{lb}")
+        },
+      ),
+      (true, false, true) => concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        w,
+        fmts,
+        DeleteClause::DeleteFrom,
+        |w| {
+          w.write_clause("DELETE", Body::Text)?;
+          w.write_str(space)?;
+          concat_from(w)
+        },
+      ),
+      (true, false, false) => concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        w,
+        fmts,
+        DeleteClause::DeleteFrom,
+        |w| {
+          w.write_clause("DELETE FROM", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._delete_from)?;
+          write!(w, "{space}{lb}")
+        },
+      ),
+      (false, true, true) => {
+        concat_delete(w)?;
+        concat_from(w)
       }
-      (del_from, del, from) if del_from.is_empty() == false && del.is_empty() == false && from.is_empty() => {
-        let delete_clause = concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          DeleteClause::Delete,
-          format!("DELETE{space}{del}{space}"),
-        );
+      (false, true, false) => concat_delete(w),
+      (false, false, true) => concat_from(w),
+      (false, false, false) => Ok(()),
+    }
+  }
+}
 
-        let sql = format!("{delete_clause}FROM{space}{del_from}{space}{lb}");
+impl Validate for Delete {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_support(dialect)?;
 
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          query,
-          fmts,
-          DeleteClause::DeleteFrom,
-          sql,
-        )
-      }
-      (del_from, del, from) if del_from.is_empty() == false && del.is_empty() && from.is_empty() == false => {
-        let from_clause = concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          DeleteClause::From,
-          format!("FROM{space}{del_from}{comma}{from}{space}{lb}"),
-        );
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for (_, query) in &self._with {
+      query.check_clauses(dialect)?;
+    }
 
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          query,
-          fmts,
-          DeleteClause::DeleteFrom,
-          format!("DELETE{space}{from_clause}"),
-        )
-      }
-      (del_from, del, from) if del_from.is_empty() == false && del.is_empty() && from.is_empty() => {
-        let sql = format!("DELETE{space}FROM{space}{del_from}{space}{lb}");
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) {
+      return Ok(());
+    }
 
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          query,
-          fmts,
-          DeleteClause::DeleteFrom,
-          sql,
-        )
-      }
-      (del_from, del, from) if del_from.is_empty() && del.is_empty() == false && from.is_empty() == false => {
-        let delete_clause = concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          DeleteClause::Delete,
-          format!("DELETE{space}{del}{space}"),
-        );
+    #[cfg(not(feature = "mysql"))]
+    let is_missing = self._delete_from.is_empty();
+    #[cfg(feature = "mysql")]
+    let is_missing = self._delete_from.is_empty() && self._from.is_empty();
 
-        let from_clause = concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          "".to_string(),
-          fmts,
-          DeleteClause::From,
-          format!("FROM{space}{from}{space}{lb}"),
-        );
+    utils::require_clause("DELETE", "DELETE FROM", is_missing)
+  }
+}
 
-        format!("{delete_clause}{from_clause}")
-      }
-      (del_from, del, from) if del_from.is_empty() && del.is_empty() == false && from.is_empty() => {
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          query,
-          fmts,
-          DeleteClause::Delete,
-          format!("DELETE{space}{del}{space}"),
-        )
-      }
-      (del_from, del, from) if del_from.is_empty() && del.is_empty() && from.is_empty() == false => {
-        concat_raw_before_after(
-          &self._raw_before,
-          &self._raw_after,
-          query,
-          fmts,
-          DeleteClause::From,
-          format!("FROM{space}{from}{space}{lb}"),
-        )
+impl Delete {
+  /// Checks if the dialect supports the clauses specific to some dialects
+  #[allow(unused_variables)]
+  fn check_support(&self, dialect: Dialect) -> Result<(), BuildError> {
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    utils::require_support(
+      dialect,
+      "RETURNING",
+      self._returning.is_empty() == false,
+      utils::is_postgres(dialect) || utils::is_sqlite(dialect),
+    )?;
+
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    utils::require_support(
+      dialect,
+      "ORDER BY",
+      self._order_by.is_empty() == false,
+      utils::is_sqlite(dialect) || utils::is_mysql(dialect),
+    )?;
+
+    #[cfg(feature = "mysql")]
+    {
+      let is_supported = utils::is_mysql(dialect);
+      utils::require_support(
+        dialect,
+        "LIMIT",
+        self._limit.is_empty() == false,
+        utils::is_sqlite(dialect) || is_supported,
+      )?;
+      utils::require_support(dialect, "DELETE", self._delete.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "JOIN", self._join.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "PARTITION", self._partition.is_empty() == false, is_supported)?;
+      for join in &self._join {
+        join.check_clauses(dialect)?;
       }
-      (_, _, _) => query,
     }
+
+    Ok(())
+  }
+}
+
+impl CollectTables for Delete {
+  fn collect_tables(&self, tables: &mut Tables) {
+    self._raw.iter().for_each(|raw| tables.raw(raw));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for (name, query) in self._with.iter() {
+      tables.cte(name);
+      query.collect_tables(tables);
+    }
+
+    tables.clause("FROM", &self._delete_from);
+
+    #[cfg(feature = "mysql")]
+    {
+      self._from.iter().for_each(|table| tables.clause("FROM", table));
+      self._join.iter().for_each(|join| join.collect_tables(tables));
+    }
+
+    self._where.iter().for_each(|(_, condition)| tables.expr(condition));
+
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    self._order_by.iter().for_each(|column| tables.expr(column));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    self._returning.iter().for_each(|column| tables.expr(column));
+
+    let raw_before_after = self._raw_before.iter().chain(self._raw_after.iter());
+    raw_before_after.for_each(|(_, raw)| tables.raw(raw));
   }
 }
@@ -1,14 +1,44 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{DropIndex, DropIndexParams},
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{BuildError, Dialect, DropIndex, DropIndexParams, FormatOptions, Fragment},
   utils::push_unique,
 };
 
 impl TransactionQuery for DropIndex {}
 
 impl DropIndex {
+  /// Gets the current state of the [DropIndex] and returns it as string formatted by the [FormatOptions]
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   keyword_case: sql::KeywordCase::Lower,
+  ///   ..Default::default()
+  /// };
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # drop index users_login_idx";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// drop index users_login_idx
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [DropIndex] and returns it as string
   ///
   /// ### Example
@@ -29,8 +59,21 @@ impl DropIndex {
   /// DROP INDEX users_name_idx
   /// ```
   pub fn as_string(&self) -> String {
-    let fmts = fmt::one_line();
-    self.concat(&fmts)
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the params of the [DropIndex] that have content. The raw SQL added by the methods
+  /// [DropIndex::raw], [DropIndex::raw_before] and [DropIndex::raw_after] is not considered
+  pub fn clauses(&self) -> Vec<DropIndexParams> {
+    let mut params = vec![];
+
+    if self._drop_index.is_empty() == false {
+      params.push(DropIndexParams::DropIndex);
+    }
+
+    params
   }
 
   /// Defines a drop index parameter, this method overrides the previous value
@@ -38,13 +81,13 @@ impl DropIndex {
   /// ### Example 1
   ///
   ///```
-  /// # #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  /// # #[cfg(feature = "sqlite")]
   /// # {
   /// # use sql_query_builder as sql;
   /// let query = sql::DropIndex::new()
   ///   .drop_index("users_name_idx")
   ///   .drop_index("orders_product_name_idx")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Sqlite);
   ///
   /// # let expected = "DROP INDEX orders_product_name_idx";
   /// # assert_eq!(expected, query);
@@ -80,8 +123,8 @@ impl DropIndex {
   /// ```sql
   /// DROP INDEX users_name_idx, orders_product_name_idx
   /// ```
-  pub fn drop_index(mut self, index_name: &str) -> Self {
-    push_unique(&mut self._drop_index, index_name.trim().to_string());
+  pub fn drop_index(mut self, index_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._drop_index, index_name.into().trim());
     self
   }
 
@@ -107,10 +150,34 @@ impl DropIndex {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
+  /// Gets the indexes of the `drop index` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx");
+  ///
+  /// # assert_eq!(["users_name_idx"], drop_index.get_drop_index());
+  /// # assert_eq!(false, drop_index.get_if_exists());
+  /// # }
+  /// ```
+  pub fn get_drop_index(&self) -> &[Fragment] {
+    &self._drop_index
+  }
+
+  /// Gets whether the `if exists` modifier is defined
+  pub fn get_if_exists(&self) -> bool {
+    self._if_exists
+  }
+
   /// Creates instance of the [DropIndex] command
   pub fn new() -> Self {
     Self::default()
@@ -120,7 +187,7 @@ impl DropIndex {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -144,8 +211,8 @@ impl DropIndex {
   /// ```sql
   /// /* drop index command */ DROP INDEX users_name_idx
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -171,8 +238,8 @@ impl DropIndex {
   /// ```sql
   /// DROP INDEX users_name_idx /* end drop index */
   /// ```
-  pub fn raw_after(mut self, param: DropIndexParams, raw_sql: &str) -> Self {
-    self._raw_after.push((param, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, param: DropIndexParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
     self
   }
 
@@ -200,10 +267,196 @@ impl DropIndex {
   /// ```sql
   /// /* drop index command */ DROP INDEX users_name_idx
   /// ```
-  pub fn raw_before(mut self, param: DropIndexParams, raw_sql: &str) -> Self {
-    self._raw_before.push((param, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, param: DropIndexParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((param, raw_sql.into().trim()));
     self
   }
+
+  /// Gets the current state of the [DropIndex] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx")
+  ///   .drop_index("users_login_idx");
+  ///
+  /// let standard_query = drop_index.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = drop_index.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("DROP INDEX users_login_idx", standard_query);
+  /// # assert_eq!("DROP INDEX users_name_idx, users_login_idx", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// DROP INDEX users_login_idx
+  ///
+  /// -- Postgres
+  /// DROP INDEX users_name_idx, users_login_idx
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [DropIndex] and returns it as string, returns a [BuildError] when
+  /// the [DropIndex::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DROP INDEX users_login_idx", query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_login_idx
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [DropIndex] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [DropIndex::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DROP INDEX users_name_idx", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_name_idx
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [DropIndex], returns a [BuildError] when there are no indexes and no raw SQL
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_index = sql::DropIndex::new();
+  ///
+  /// let error = drop_index.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "DROP INDEX".to_string(),
+  /// #   clause: "DROP INDEX".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [DropIndex] like the [DropIndex::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_index = sql::DropIndex::new();
+  ///
+  /// let error = drop_index.validate_for(sql::Dialect::Standard).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "DROP INDEX".to_string(),
+  /// #   clause: "DROP INDEX".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// Writes the current state of the [DropIndex] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// drop_index.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nDROP INDEX users_name_idx";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [DropIndex] into the [std::io::Write], like a file or a socket,
+  /// the same way the [DropIndex::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_name_idx");
+  ///
+  /// let mut output = Vec::new();
+  /// drop_index.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "DROP INDEX users_name_idx";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// # }
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -221,7 +474,7 @@ impl DropIndex {
   /// let query = sql::DropIndex::new()
   ///   .drop_index("users_name_idx")
   ///   .drop_index_if_exists("orders_product_name_idx")
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Sqlite);
   ///
   /// # let expected = "DROP INDEX IF EXISTS orders_product_name_idx";
   /// # assert_eq!(expected, query);
@@ -257,8 +510,8 @@ impl DropIndex {
   /// ```sql
   /// DROP INDEX IF EXISTS users_name_idx, orders_product_name_idx
   /// ```
-  pub fn drop_index_if_exists(mut self, index_name: &str) -> Self {
-    push_unique(&mut self._drop_index, index_name.trim().to_string());
+  pub fn drop_index_if_exists(mut self, index_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._drop_index, index_name.into().trim());
     self._if_exists = true;
     self
   }
@@ -273,6 +526,6 @@ impl std::fmt::Display for DropIndex {
 impl std::fmt::Debug for DropIndex {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,55 +1,65 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{DropIndex, DropIndexParams},
+  structure::{BuildError, Dialect, DropIndex, DropIndexParams},
+  utils,
 };
+use std::fmt::Write;
 
 impl Concat for DropIndex {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    query = self.concat_drop_index(query, &fmts);
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_drop_index(w, &fmts)?;
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl DropIndex {
-  fn concat_drop_index(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_drop_index(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
 
-    let sql = if self._drop_index.is_empty() == false {
-      let if_exists = if self._if_exists {
-        format!("IF EXISTS{space}")
-      } else {
-        "".to_string()
-      };
-
-      let index_names = if cfg!(any(feature = "postgresql")) {
-        self
-          ._drop_index
-          .iter()
-          .filter(|item| item.is_empty() == false)
-          .map(|item| item.as_str())
-          .collect::<Vec<_>>()
-          .join(comma)
-      } else {
-        self._drop_index.last().unwrap().to_string()
-      };
-
-      format!("DROP INDEX{space}{if_exists}{index_names}{space}{lb}")
-    } else {
-      "".to_string()
-    };
+    #[cfg(feature = "postgresql")]
+    let multiple_indexes = fmts.dialect == Dialect::Postgresql;
+    #[cfg(not(feature = "postgresql"))]
+    let multiple_indexes = false;
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       DropIndexParams::DropIndex,
-      sql,
+      |w| {
+        if let Some(last_index) = self._drop_index.last() {
+          write!(w, "DROP INDEX{space}")?;
+          if self._if_exists {
+            write!(w, "IF EXISTS{space}")?;
+          }
+          if multiple_indexes {
+            w.write_joined(&self._drop_index, comma)?;
+          } else {
+            w.write_fragment(last_index)?;
+          }
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+}
+
+impl Validate for DropIndex {
+  fn check_clauses(&self, _dialect: Dialect) -> Result<(), BuildError> {
+    let has_raw = utils::has_raw(&self._raw, &self._raw_before, &self._raw_after);
+    utils::require_clause(
+      "DROP INDEX",
+      "DROP INDEX",
+      has_raw == false && self._drop_index.is_empty(),
     )
   }
 }
@@ -1,14 +1,41 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{DropTable, DropTableParams},
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{BuildError, Dialect, DropTable, DropTableParams, FormatOptions, Fragment},
   utils::push_unique,
 };
 
 impl TransactionQuery for DropTable {}
 
 impl DropTable {
+  /// Gets the current state of the [DropTable] and returns it as string formatted by the [FormatOptions]
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   keyword_case: sql::KeywordCase::Lower,
+  ///   ..Default::default()
+  /// };
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # drop table users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// drop table users
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [DropTable] and returns it as string
   ///
   /// ### Example
@@ -29,8 +56,21 @@ impl DropTable {
   /// DROP TABLE users
   /// ```
   pub fn as_string(&self) -> String {
-    let fmts = fmt::one_line();
-    self.concat(&fmts)
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the params of the [DropTable] that have content. The raw SQL added by the methods
+  /// [DropTable::raw], [DropTable::raw_before] and [DropTable::raw_after] is not considered
+  pub fn clauses(&self) -> Vec<DropTableParams> {
+    let mut params = vec![];
+
+    if self._drop_table.is_empty() == false {
+      params.push(DropTableParams::DropTable);
+    }
+
+    params
   }
 
   /// Defines a drop table parameter, this method overrides the previous value
@@ -80,8 +120,8 @@ impl DropTable {
   /// ```sql
   /// DROP TABLE users, orders
   /// ```
-  pub fn drop_table(mut self, table_name: &str) -> Self {
-    push_unique(&mut self._drop_table, table_name.trim().to_string());
+  pub fn drop_table(mut self, table_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._drop_table, table_name.into().trim());
     self
   }
 
@@ -132,8 +172,8 @@ impl DropTable {
   /// ```sql
   /// DROP TABLE IF EXISTS users, orders
   /// ```
-  pub fn drop_table_if_exists(mut self, table_name: &str) -> Self {
-    push_unique(&mut self._drop_table, table_name.trim().to_string());
+  pub fn drop_table_if_exists(mut self, table_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._drop_table, table_name.into().trim());
     self._if_exists = true;
     self
   }
@@ -160,10 +200,32 @@ impl DropTable {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
+  /// Gets the tables of the `drop table` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table_if_exists("users");
+  ///
+  /// # assert_eq!(["users"], drop_table.get_drop_table());
+  /// # assert_eq!(true, drop_table.get_if_exists());
+  /// # assert_eq!(vec![sql::DropTableParams::DropTable], drop_table.clauses());
+  /// ```
+  pub fn get_drop_table(&self) -> &[Fragment] {
+    &self._drop_table
+  }
+
+  /// Gets whether the `if exists` modifier is defined
+  pub fn get_if_exists(&self) -> bool {
+    self._if_exists
+  }
+
   /// Creates instance of the [DropTable] command
   pub fn new() -> Self {
     Self::default()
@@ -173,7 +235,7 @@ impl DropTable {
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -197,8 +259,8 @@ impl DropTable {
   /// ```sql
   /// /* drop command */ DROP TABLE users_temp
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -224,8 +286,8 @@ impl DropTable {
   /// ```sql
   /// DROP TABLE users CASCADE
   /// ```
-  pub fn raw_after(mut self, param: DropTableParams, raw_sql: &str) -> Self {
-    self._raw_after.push((param, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, param: DropTableParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
     self
   }
 
@@ -253,10 +315,184 @@ impl DropTable {
   /// ```sql
   /// CREATE TABLE users_temp; DROP TABLE users_temp
   /// ```
-  pub fn raw_before(mut self, param: DropTableParams, raw_sql: &str) -> Self {
-    self._raw_before.push((param, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, param: DropTableParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((param, raw_sql.into().trim()));
     self
   }
+
+  /// Gets the current state of the [DropTable] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("films")
+  ///   .drop_table("series");
+  ///
+  /// let standard_query = drop_table.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = drop_table.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("DROP TABLE series", standard_query);
+  /// # assert_eq!("DROP TABLE films, series", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// DROP TABLE series
+  ///
+  /// -- Postgres
+  /// DROP TABLE films, series
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [DropTable] and returns it as string, returns a [BuildError] when
+  /// the [DropTable::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DROP TABLE users", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [DropTable] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [DropTable::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DROP TABLE users", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [DropTable], returns a [BuildError] when there are no tables and no raw SQL
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table = sql::DropTable::new();
+  ///
+  /// let error = drop_table.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "DROP TABLE".to_string(),
+  /// #   clause: "DROP TABLE".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [DropTable] like the [DropTable::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table = sql::DropTable::new();
+  ///
+  /// let error = drop_table.validate_for(sql::Dialect::Standard).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "DROP TABLE".to_string(),
+  /// #   clause: "DROP TABLE".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// Writes the current state of the [DropTable] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// drop_table.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nDROP TABLE users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [DropTable] into the [std::io::Write], like a file or a socket,
+  /// the same way the [DropTable::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users");
+  ///
+  /// let mut output = Vec::new();
+  /// drop_table.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "DROP TABLE users";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 impl std::fmt::Display for DropTable {
@@ -268,6 +504,6 @@ impl std::fmt::Display for DropTable {
 impl std::fmt::Debug for DropTable {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
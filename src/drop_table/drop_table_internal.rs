@@ -1,55 +1,68 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{DropTable, DropTableParams},
+  structure::{BuildError, Dialect, DropTable, DropTableParams},
+  utils,
 };
+use std::fmt::Write;
 
 impl Concat for DropTable {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    query = self.concat_drop_table(query, &fmts);
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_drop_table(w, &fmts)?;
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl DropTable {
-  fn concat_drop_table(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_drop_table(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
 
-    let sql = if self._drop_table.is_empty() == false {
-      let if_exists = if self._if_exists {
-        format!("IF EXISTS{space}")
-      } else {
-        "".to_string()
-      };
-
-      let table_names = if cfg!(any(feature = "postgresql", feature = "mysql")) {
-        self
-          ._drop_table
-          .iter()
-          .filter(|item| item.is_empty() == false)
-          .map(|item| item.as_str())
-          .collect::<Vec<_>>()
-          .join(comma)
-      } else {
-        self._drop_table.last().unwrap().to_string()
-      };
-
-      format!("DROP TABLE{space}{if_exists}{table_names}{space}{lb}")
-    } else {
-      "".to_string()
+    let multiple_tables = match fmts.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => true,
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => true,
+      _ => false,
     };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       DropTableParams::DropTable,
-      sql,
+      |w| {
+        if let Some(last_table) = self._drop_table.last() {
+          write!(w, "DROP TABLE{space}")?;
+          if self._if_exists {
+            write!(w, "IF EXISTS{space}")?;
+          }
+          if multiple_tables {
+            w.write_joined(&self._drop_table, comma)?;
+          } else {
+            w.write_fragment(last_table)?;
+          }
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+}
+
+impl Validate for DropTable {
+  fn check_clauses(&self, _dialect: Dialect) -> Result<(), BuildError> {
+    let has_raw = utils::has_raw(&self._raw, &self._raw_before, &self._raw_after);
+    utils::require_clause(
+      "DROP TABLE",
+      "DROP TABLE",
+      has_raw == false && self._drop_table.is_empty(),
     )
   }
 }
@@ -0,0 +1,604 @@
+use crate::{
+  behavior::{TransactionQuery, Validate},
+  fmt, pretty,
+  structure::{BuildError, Dialect, DropView, DropViewParams, FormatOptions, Fragment},
+  utils::push_unique,
+};
+
+impl TransactionQuery for DropView {}
+
+impl DropView {
+  /// Gets the current state of the [DropView] and returns it as string formatted by the [FormatOptions]
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   keyword_case: sql::KeywordCase::Lower,
+  ///   ..Default::default()
+  /// };
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # drop view active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// drop view active_users
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
+  /// Gets the current state of the [DropView] and returns it as string
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Defines the `cascade` drop behavior, the objects that depend on the view are dropped too.
+  /// This method overrides the [restrict](DropView::restrict) method and it's ignored by SQLite
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW active_users CASCADE";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users CASCADE
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._drop_behavior = Fragment::from("CASCADE");
+    self
+  }
+
+  /// Gets the params of the [DropView] that have content. The raw SQL added by the methods
+  /// [DropView::raw], [DropView::raw_before] and [DropView::raw_after] is not considered
+  pub fn clauses(&self) -> Vec<DropViewParams> {
+    let mut params = vec![];
+
+    if self._drop_view.is_empty() == false {
+      params.push(DropViewParams::DropView);
+    }
+
+    params
+  }
+
+  /// Prints the current state of the [DropView] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// DROP VIEW active_users
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Defines a drop view parameter, this method overrides the previous value
+  ///
+  /// ### Example 1
+  ///
+  ///```
+  /// # #[cfg(not(any(feature = "postgresql", feature = "mysql")))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view("paid_orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW paid_orders";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DROP VIEW paid_orders
+  /// ```
+  ///
+  /// ### Example 2 `crate features postgresql and mysql only`
+  ///
+  /// Multiples call will concatenates all values
+  ///
+  ///```
+  /// # #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view("paid_orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW active_users, paid_orders";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DROP VIEW active_users, paid_orders
+  /// ```
+  pub fn drop_view(mut self, view_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._drop_view, view_name.into().trim());
+    self
+  }
+
+  /// Defines a drop view parameter with the `if exists` modifier, this method overrides the previous value
+  ///
+  /// ### Example 1
+  ///
+  /// ```
+  /// # #[cfg(not(any(feature = "postgresql", feature = "mysql")))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view_if_exists("paid_orders")
+  ///   .to_string();
+  ///
+  /// # let expected = "DROP VIEW IF EXISTS paid_orders";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DROP VIEW IF EXISTS paid_orders
+  /// ```
+  ///
+  /// ### Example 2 `crate features postgresql and mysql only`
+  ///
+  /// Multiples call will concatenates all values
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view_if_exists("paid_orders")
+  ///   .to_string();
+  ///
+  /// # let expected = "DROP VIEW IF EXISTS active_users, paid_orders";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Outputs
+  ///
+  /// ```sql
+  /// DROP VIEW IF EXISTS active_users, paid_orders
+  /// ```
+  pub fn drop_view_if_exists(mut self, view_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._drop_view, view_name.into().trim());
+    self._if_exists = true;
+    self
+  }
+
+  /// Gets the views of the `drop view` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users");
+  ///
+  /// # assert_eq!(["active_users"], drop_view.get_drop_view());
+  /// # assert_eq!(false, drop_view.get_if_exists());
+  /// ```
+  pub fn get_drop_view(&self) -> &[Fragment] {
+    &self._drop_view
+  }
+
+  /// Gets whether the `if exists` modifier is defined
+  pub fn get_if_exists(&self) -> bool {
+    self._if_exists
+  }
+
+  /// Creates instance of the [DropView] command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [DropView] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query. Is useful to create a more complex drop view command.
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .raw("/* drop command */")
+  ///   .drop_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* drop command */ DROP VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* drop command */ DROP VIEW active_users
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified parameter.
+  ///
+  /// The `DropViewParams::DropView` works both to `.drop_view` and `.drop_view_if_exists` methods
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .raw_after(sql::DropViewParams::DropView, "/* drop view */")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW active_users /* drop view */";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users /* drop view */
+  /// ```
+  pub fn raw_after(mut self, param: DropViewParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((param, raw_sql.into().trim()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified parameter.
+  ///
+  /// The `DropViewParams::DropView` works both to `.drop_view` and `.drop_view_if_exists` methods
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw = "CREATE VIEW active_users AS SELECT * FROM users;";
+  ///
+  /// let query = sql::DropView::new()
+  ///   .raw_before(sql::DropViewParams::DropView, raw)
+  ///   .drop_view("active_users")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE VIEW active_users AS SELECT * FROM users; DROP VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT * FROM users; DROP VIEW active_users
+  /// ```
+  pub fn raw_before(mut self, param: DropViewParams, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((param, raw_sql.into().trim()));
+    self
+  }
+
+  /// Defines the `restrict` drop behavior, the view is not dropped when other objects depend on it.
+  /// This method overrides the [cascade](DropView::cascade) method and it's ignored by SQLite
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .restrict()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP VIEW active_users RESTRICT";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users RESTRICT
+  /// ```
+  pub fn restrict(mut self) -> Self {
+    self._drop_behavior = Fragment::from("RESTRICT");
+    self
+  }
+
+  /// Gets the current state of the [DropView] and returns it as string rendered in the specified SQL [Dialect],
+  /// the params not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view("paid_orders");
+  ///
+  /// let standard_query = drop_view.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = drop_view.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("DROP VIEW paid_orders", standard_query);
+  /// # assert_eq!("DROP VIEW active_users, paid_orders", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// DROP VIEW paid_orders
+  ///
+  /// -- Postgres
+  /// DROP VIEW active_users, paid_orders
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [DropView] and returns it as string, returns a [BuildError] when
+  /// the [DropView::validate] method fails
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DROP VIEW active_users", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [DropView] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [DropView::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("DROP VIEW active_users", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [DropView], returns a [BuildError] when there are no views and no raw SQL
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_view = sql::DropView::new();
+  ///
+  /// let error = drop_view.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "DROP VIEW".to_string(),
+  /// #   clause: "DROP VIEW".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [DropView] like the [DropView::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .cascade();
+  ///
+  /// let error = drop_view.validate_for(sql::Dialect::Sqlite).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Sqlite,
+  /// #   feature: "CASCADE, RESTRICT".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// Writes the current state of the [DropView] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// drop_view.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nDROP VIEW active_users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [DropView] into the [std::io::Write], like a file or a socket,
+  /// the same way the [DropView::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users");
+  ///
+  /// let mut output = Vec::new();
+  /// drop_view.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "DROP VIEW active_users";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
+}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl DropView {
+  /// Defines the `materialized` modifier to drop a materialized view
+  ///
+  /// ### Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::DropView::new()
+  ///   .drop_view_if_exists("monthly_sales")
+  ///   .materialized()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP MATERIALIZED VIEW IF EXISTS monthly_sales";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP MATERIALIZED VIEW IF EXISTS monthly_sales
+  /// ```
+  #[cfg(feature = "postgresql")]
+  pub fn materialized(mut self) -> Self {
+    self._materialized = true;
+    self
+  }
+}
+
+impl std::fmt::Display for DropView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropView {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self, &fmts))
+  }
+}
@@ -0,0 +1,94 @@
+use crate::{
+  behavior::Validate,
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  structure::{BuildError, Dialect, DropView, DropViewParams},
+  utils,
+};
+use std::fmt::Write;
+
+impl Concat for DropView {
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
+
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_drop_view(w, &fmts)?;
+
+    w.trim_end_since(start);
+    Ok(())
+  }
+}
+
+impl DropView {
+  fn concat_drop_view(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+
+    let multiple_views = match fmts.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => true,
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => true,
+      _ => false,
+    };
+
+    // SQLite doesn't support the drop behavior
+    let drop_behavior = match fmts.dialect {
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => false,
+      _ => self._drop_behavior.is_empty() == false,
+    };
+
+    let materialized = match fmts.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => self._materialized,
+      _ => false,
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      w,
+      fmts,
+      DropViewParams::DropView,
+      |w| {
+        if let Some(last_view) = self._drop_view.last() {
+          write!(w, "DROP{space}")?;
+          if materialized {
+            write!(w, "MATERIALIZED{space}")?;
+          }
+          write!(w, "VIEW{space}")?;
+          if self._if_exists {
+            write!(w, "IF EXISTS{space}")?;
+          }
+          if multiple_views {
+            w.write_joined(&self._drop_view, comma)?;
+          } else {
+            w.write_fragment(last_view)?;
+          }
+          if drop_behavior {
+            w.write_str(space)?;
+            w.write_fragment(&self._drop_behavior)?;
+          }
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+}
+
+impl Validate for DropView {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    utils::require_support(
+      dialect,
+      "CASCADE, RESTRICT",
+      self._drop_behavior.is_empty() == false,
+      utils::is_sqlite(dialect) == false,
+    )?;
+    #[cfg(feature = "postgresql")]
+    utils::require_support(dialect, "MATERIALIZED", self._materialized, utils::is_postgres(dialect))?;
+
+    let has_raw = utils::has_raw(&self._raw, &self._raw_before, &self._raw_after);
+    utils::require_clause("DROP VIEW", "DROP VIEW", has_raw == false && self._drop_view.is_empty())
+  }
+}
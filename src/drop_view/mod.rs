@@ -0,0 +1,2 @@
+mod drop_view;
+mod drop_view_internal;
@@ -0,0 +1,56 @@
+use crate::structure::{BuildError, Dialect, ParseError};
+
+impl std::fmt::Display for BuildError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Self::UnsupportedFeature { dialect, feature } => {
+        write!(f, "{feature} is not supported by the {} dialect", dialect_name(dialect))
+      }
+      Self::IncompatibleActions { dialect, actions } => write!(
+        f,
+        "the actions {} cannot be executed in a single statement by the {} dialect",
+        actions.join(", "),
+        dialect_name(dialect)
+      ),
+      Self::DuplicateMigration { version } => write!(f, "the migration version {version} is duplicated"),
+      Self::MissingMigration { version } => write!(f, "the migration version {version} is missing"),
+      Self::MissingClause { command, clause } => write!(f, "the {command} command requires the {clause} clause"),
+      Self::ConflictingClauses { command, clauses } => write!(
+        f,
+        "the clauses {} cannot be used together in the {command} command",
+        clauses.join(", ")
+      ),
+      Self::ParamCountMismatch { expected, found } => {
+        write!(f, "the query has {expected} parameters but {found} values were bound")
+      }
+    }
+  }
+}
+
+impl std::error::Error for BuildError {}
+
+impl std::fmt::Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match self {
+      Self::UnexpectedStatement { expected } => write!(f, "the statement is not a {expected} command"),
+      Self::UnsupportedClause { clause } => write!(f, "the {clause} clause is not supported by the builder"),
+      Self::MisplacedClause { clause } => write!(f, "the {clause} clause is repeated or out of order"),
+      Self::EmptyClause { clause } => write!(f, "the {clause} clause is empty"),
+      Self::Unbalanced { position } => write!(f, "unbalanced quote, comment or parenthesis at position {position}"),
+    }
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+fn dialect_name(dialect: &Dialect) -> &'static str {
+  match dialect {
+    Dialect::Standard => "SQL Standard",
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql => "Postgres",
+    #[cfg(feature = "sqlite")]
+    Dialect::Sqlite => "SQLite",
+    #[cfg(feature = "mysql")]
+    Dialect::Mysql => "MySQL",
+  }
+}
@@ -0,0 +1,716 @@
+use crate::{
+  structure::{DataType, Dialect, Expr, Fragment, Ident, Literal, Part, Precedence, Select, Value},
+  utils,
+};
+use std::sync::Arc;
+
+/// Creates an operand with a column name or any other SQL expression, the text is used as is
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::col;
+///
+/// let condition = col("users.login").eq(col("orders.login"));
+///
+/// # assert_eq!("users.login = orders.login", condition.as_string());
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// users.login = orders.login
+/// ```
+pub fn col(name: impl Into<Fragment>) -> Expr {
+  Expr::new(name.into().trim(), Precedence::Operand)
+}
+
+/// Creates an operand with a value bound to the query, the value is rendered as the placeholder of the dialect
+/// and returned by the `as_string_with_binds` method of the builders. The methods of the [Expr] bind its arguments
+/// implicitly, this function is useful when the value is the left side of the comparison
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::{col, val};
+///
+/// let (query, values) = sql::Select::new()
+///   .where_clause(&val(18).lte(col("age")))
+///   .to_sql_with_binds(sql::Dialect::Standard);
+///
+/// # assert_eq!("WHERE ? <= age", query);
+/// # assert_eq!(vec![sql::Value::Int(18)], values);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// WHERE ? <= age
+/// ```
+pub fn val(value: impl Into<Value>) -> Expr {
+  Expr::new(Fragment::from(Part::Value(value.into())), Precedence::Operand)
+}
+
+/// Creates the `EXISTS` condition with a sub-query, the sub-query is rendered in the dialect
+/// and the format of the outer query
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::exists;
+///
+/// let orders = sql::Select::new()
+///   .select("1")
+///   .from("orders")
+///   .where_clause("orders.user_id = users.id");
+///
+/// let select_query = sql::Select::new()
+///   .select("login")
+///   .from("users")
+///   .where_clause(&!exists(orders))
+///   .as_string();
+///
+/// # let expected = "SELECT login FROM users WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+/// # assert_eq!(expected, select_query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT login FROM users WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)
+/// ```
+pub fn exists(select: impl Into<Arc<Select>>) -> Expr {
+  Expr::new(
+    Fragment::from("EXISTS ").push(Fragment::sub_query(select)),
+    Precedence::Operand,
+  )
+}
+
+impl Expr {
+  /// Gets the expression in the syntax of the default dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("deleted_at").is_null().and(col("active").is_not_null());
+  ///
+  /// # assert_eq!("deleted_at IS NULL AND active IS NOT NULL", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// deleted_at IS NULL AND active IS NOT NULL
+  /// ```
+  pub fn as_string(&self) -> String {
+    self._sql.as_string()
+  }
+
+  /// Gets the expression in the syntax of the specified SQL [Dialect]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("login").eq("foo").or(col("login").eq("bar"));
+  ///
+  /// let standard_condition = condition.to_sql(sql::Dialect::Standard);
+  /// let postgres_condition = condition.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("login = ? OR login = ?", standard_condition);
+  /// # assert_eq!("login = $1 OR login = $2", postgres_condition);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// login = ? OR login = ?
+  ///
+  /// -- Postgres
+  /// login = $1 OR login = $2
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    self._sql.to_sql(dialect)
+  }
+
+  /// The `AND` operator, the operands are grouped by parentheses when needed
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("a").eq(col("b")).or(col("c").eq(col("d"))).and(col("e").eq(col("f")));
+  ///
+  /// # assert_eq!("(a = b OR c = d) AND e = f", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (a = b OR c = d) AND e = f
+  /// ```
+  pub fn and(self, other: Expr) -> Self {
+    let sql = self
+      .grouped(Precedence::And)
+      .push(" AND ")
+      .push(other.grouped(Precedence::And));
+    Self::new(sql, Precedence::And)
+  }
+
+  /// The `OR` operator. When the expression is used in a builder method the `OR` operations are
+  /// enclosed in parentheses, this way the expression is not mixed up with the other conditions of the clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .where_clause(&col("login").eq(col("'foo'")).or(col("login").eq(col("'bar'"))))
+  ///   .where_clause("active = true")
+  ///   .as_string();
+  ///
+  /// # assert_eq!("WHERE (login = 'foo' OR login = 'bar') AND active = true", select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE (login = 'foo' OR login = 'bar') AND active = true
+  /// ```
+  pub fn or(self, other: Expr) -> Self {
+    let sql = self._sql.push(" OR ").push(other._sql);
+    Self::new(sql, Precedence::Or)
+  }
+
+  /// The `=` operator, the value is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("login").eq("foo");
+  ///
+  /// # assert_eq!("login = ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// login = ?
+  /// ```
+  pub fn eq(self, other: impl Into<Expr>) -> Self {
+    self.compare("=", other.into())
+  }
+
+  /// The `<>` operator, the value is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("status").ne("closed");
+  ///
+  /// # assert_eq!("status <> ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// status <> ?
+  /// ```
+  pub fn ne(self, other: impl Into<Expr>) -> Self {
+    self.compare("<>", other.into())
+  }
+
+  /// The `>` operator, the value is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("age").gt(18);
+  ///
+  /// # assert_eq!("age > ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// age > ?
+  /// ```
+  pub fn gt(self, other: impl Into<Expr>) -> Self {
+    self.compare(">", other.into())
+  }
+
+  /// The `>=` operator, the value is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("age").gte(18);
+  ///
+  /// # assert_eq!("age >= ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// age >= ?
+  /// ```
+  pub fn gte(self, other: impl Into<Expr>) -> Self {
+    self.compare(">=", other.into())
+  }
+
+  /// The `<` operator, the value is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("price").lt(9.9);
+  ///
+  /// # assert_eq!("price < ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// price < ?
+  /// ```
+  pub fn lt(self, other: impl Into<Expr>) -> Self {
+    self.compare("<", other.into())
+  }
+
+  /// The `<=` operator, the value is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("price").lte(9.9);
+  ///
+  /// # assert_eq!("price <= ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// price <= ?
+  /// ```
+  pub fn lte(self, other: impl Into<Expr>) -> Self {
+    self.compare("<=", other.into())
+  }
+
+  /// The `LIKE` operator, the pattern is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("name").like("a%");
+  ///
+  /// # assert_eq!("name LIKE ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// name LIKE ?
+  /// ```
+  pub fn like(self, pattern: impl Into<Expr>) -> Self {
+    self.compare("LIKE", pattern.into())
+  }
+
+  /// The `NOT LIKE` operator, the pattern is bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("name").not_like("a%");
+  ///
+  /// # assert_eq!("name NOT LIKE ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// name NOT LIKE ?
+  /// ```
+  pub fn not_like(self, pattern: impl Into<Expr>) -> Self {
+    self.compare("NOT LIKE", pattern.into())
+  }
+
+  /// The `IS NULL` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("deleted_at").is_null();
+  ///
+  /// # assert_eq!("deleted_at IS NULL", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// deleted_at IS NULL
+  /// ```
+  pub fn is_null(self) -> Self {
+    let sql = self.grouped(Precedence::Operand).push(" IS NULL");
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  /// The `IS NOT NULL` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("deleted_at").is_not_null();
+  ///
+  /// # assert_eq!("deleted_at IS NOT NULL", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// deleted_at IS NOT NULL
+  /// ```
+  pub fn is_not_null(self) -> Self {
+    let sql = self.grouped(Precedence::Operand).push(" IS NOT NULL");
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  /// The `IN` operator, each value of the list is bound to the query.
+  /// An empty list renders a condition that is always false
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("status").is_in(["open", "pending"]);
+  ///
+  /// # assert_eq!("status IN (?, ?)", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// status IN (?, ?)
+  /// ```
+  pub fn is_in<T: Into<Expr>>(self, list: impl IntoIterator<Item = T>) -> Self {
+    self.in_list("IN", "1 = 0", list)
+  }
+
+  /// The `NOT IN` operator, each value of the list is bound to the query.
+  /// An empty list renders a condition that is always true
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("id").not_in([1, 2, 3]);
+  ///
+  /// # assert_eq!("id NOT IN (?, ?, ?)", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// id NOT IN (?, ?, ?)
+  /// ```
+  pub fn not_in<T: Into<Expr>>(self, list: impl IntoIterator<Item = T>) -> Self {
+    self.in_list("NOT IN", "1 = 1", list)
+  }
+
+  /// The `IN` operator with a sub-query, the sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let admins = sql::Select::new().select("user_id").from("admins");
+  /// let condition = col("id").in_query(admins);
+  ///
+  /// # assert_eq!("id IN (SELECT user_id FROM admins)", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// id IN (SELECT user_id FROM admins)
+  /// ```
+  pub fn in_query(self, select: impl Into<Arc<Select>>) -> Self {
+    let sql = self
+      .grouped(Precedence::Operand)
+      .push(" IN ")
+      .push(Fragment::sub_query(select));
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  /// The `NOT IN` operator with a sub-query, the sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let banned = sql::Select::new().select("user_id").from("banned_users");
+  /// let condition = col("id").not_in_query(banned);
+  ///
+  /// # assert_eq!("id NOT IN (SELECT user_id FROM banned_users)", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// id NOT IN (SELECT user_id FROM banned_users)
+  /// ```
+  pub fn not_in_query(self, select: impl Into<Arc<Select>>) -> Self {
+    let sql = self
+      .grouped(Precedence::Operand)
+      .push(" NOT IN ")
+      .push(Fragment::sub_query(select));
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  /// The `BETWEEN` operator, the limits are bound to the query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = col("age").between(18, 65);
+  ///
+  /// # assert_eq!("age BETWEEN ? AND ?", condition.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// age BETWEEN ? AND ?
+  /// ```
+  pub fn between(self, low: impl Into<Expr>, high: impl Into<Expr>) -> Self {
+    let sql = self
+      .grouped(Precedence::Operand)
+      .push(" BETWEEN ")
+      .push(low.into().grouped(Precedence::Operand))
+      .push(" AND ")
+      .push(high.into().grouped(Precedence::Operand));
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  /// The `CAST` expression, the type is rendered as the native type of the dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let price = col("price").cast(sql::DataType::Integer);
+  ///
+  /// # assert_eq!("CAST(price AS INTEGER)", price.to_sql(sql::Dialect::Standard));
+  /// # assert_eq!("CAST(price AS SIGNED)", price.to_sql(sql::Dialect::Mysql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// CAST(price AS INTEGER)
+  ///
+  /// -- MySQL
+  /// CAST(price AS SIGNED)
+  /// ```
+  pub fn cast(self, data_type: DataType) -> Self {
+    let data_type = Fragment::variants(|dialect| Fragment::from(data_type.cast_sql(dialect)));
+    let sql = Fragment::from("CAST(")
+      .push(self._sql)
+      .push(" AS ")
+      .push(data_type)
+      .push(")");
+    Self::new(sql, Precedence::Operand)
+  }
+
+  pub(crate) fn new(sql: Fragment, precedence: Precedence) -> Self {
+    Self {
+      _sql: sql,
+      _precedence: precedence,
+    }
+  }
+
+  fn compare(self, operator: &str, other: Expr) -> Self {
+    let sql = self
+      .grouped(Precedence::Operand)
+      .push(format!(" {operator} "))
+      .push(other.grouped(Precedence::Operand));
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  fn in_list<T: Into<Expr>>(self, operator: &str, empty: &str, list: impl IntoIterator<Item = T>) -> Self {
+    let items = list
+      .into_iter()
+      .map(|item| item.into().grouped(Precedence::Operand))
+      .collect::<Vec<_>>();
+
+    if items.is_empty() {
+      return Self::new(Fragment::from(empty), Precedence::Predicate);
+    }
+
+    let sql = self
+      .grouped(Precedence::Operand)
+      .push(format!(" {operator} ("))
+      .push(utils::join(&items, ", "))
+      .push(")");
+    Self::new(sql, Precedence::Predicate)
+  }
+
+  /// The expression enclosed in parentheses when its operator binds weaker than the operator of the context,
+  /// the `OR` operations are always enclosed
+  pub(crate) fn grouped(&self, context: Precedence) -> Fragment {
+    if self._precedence == Precedence::Or || self._precedence < context {
+      return Fragment::from("(").push(&self._sql).push(")");
+    }
+    self._sql.clone()
+  }
+}
+
+impl std::ops::Not for Expr {
+  type Output = Expr;
+
+  /// The `NOT` operator, the operand is enclosed in parentheses unless it's a single column or value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let condition = !col("login").eq(col("'foo'")).and(!col("active"));
+  ///
+  /// # assert_eq!("NOT (login = 'foo' AND NOT active)", condition.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// NOT (login = 'foo' AND NOT active)
+  /// ```
+  fn not(self) -> Self::Output {
+    let sql = Fragment::from("NOT ").push(self.grouped(Precedence::Operand));
+    Self::new(sql, Precedence::Not)
+  }
+}
+
+impl<T: Into<Value>> From<T> for Expr {
+  fn from(value: T) -> Self {
+    val(value)
+  }
+}
+
+impl From<Ident> for Expr {
+  fn from(ident: Ident) -> Self {
+    Self::new(Fragment::from(ident), Precedence::Operand)
+  }
+}
+
+impl From<&Ident> for Expr {
+  fn from(ident: &Ident) -> Self {
+    Self::new(Fragment::from(ident), Precedence::Operand)
+  }
+}
+
+impl From<Literal> for Expr {
+  fn from(literal: Literal) -> Self {
+    Self::new(Fragment::from(literal), Precedence::Operand)
+  }
+}
+
+impl From<&Literal> for Expr {
+  fn from(literal: &Literal) -> Self {
+    Self::new(Fragment::from(literal), Precedence::Operand)
+  }
+}
+
+/// A scalar sub-query used as an operand, the sub-query is enclosed in parentheses
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::col;
+///
+/// let average = sql::Select::new().select("avg(price)").from("products");
+/// let condition = col("price").gt(average);
+///
+/// # assert_eq!("price > (SELECT avg(price) FROM products)", condition.as_string());
+/// ```
+impl From<Select> for Expr {
+  fn from(select: Select) -> Self {
+    Self::new(Fragment::sub_query(select), Precedence::Operand)
+  }
+}
+
+impl std::fmt::Display for Expr {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Expr {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
@@ -1,5 +1,19 @@
+use crate::{
+  concat::Concat,
+  highlight::highlight,
+  pretty,
+  structure::{
+    AnsiBackend, BuildError, DataType, Dialect, FormatOptions, Fragment, LogicalOperator, Part, Select, Value,
+  },
+  utils,
+};
+use std::{fmt::Write, io};
+
+pub type Result = std::fmt::Result;
+
 pub struct Formatter<'a> {
   pub comma: &'a str,
+  pub dialect: Dialect,
   pub hr: &'a str, // horizontal rule
   pub indent: &'a str,
   pub lb: &'a str, // line break
@@ -9,6 +23,7 @@ pub struct Formatter<'a> {
 pub fn one_line<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
+    dialect: Dialect::default(),
     hr: "",
     indent: "",
     lb: "",
@@ -19,7 +34,8 @@ pub fn one_line<'a>() -> Formatter<'a> {
 pub fn multiline<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
-    hr: "-- ------------------------------------------------------------------------------\x1b[0m",
+    dialect: Dialect::default(),
+    hr: "-- ------------------------------------------------------------------------------",
     indent: "  ",
     lb: "\n",
     space: " ",
@@ -27,132 +43,696 @@ pub fn multiline<'a>() -> Formatter<'a> {
 }
 
 pub fn colorize(query: String) -> String {
-  let sql_syntax: Vec<(fn(&str) -> String, &str, &str)> = vec![
-    (blue, "ADD", "add"),
-    (blue, "ALTER", "alter"),
-    (blue, "COLUMN", "column"),
-    (blue, "COMMIT", "commit"),
-    (blue, "COMMITTED", "committed"),
-    (blue, "CONCURRENTLY", "concurrently"),
-    (blue, "CONFLICT", "conflict"),
-    (blue, "CONSTRAINT", "constraint"),
-    (blue, "CREATE ", "create "),
-    (blue, "DEFAULT", "default"),
-    (blue, "DEFERRABLE", "deferrable"),
-    (blue, "DELETE ", "delete "),
-    (blue, "DISTINCT", "distinct"),
-    (blue, "DROP", "drop"),
-    (blue, "EXCEPT", "except"),
-    (blue, "EXISTS", "exists"),
-    (blue, "FROM ", "from "),
-    (blue, "GROUP BY", "group by"),
-    (blue, "HAVING", "having"),
-    (blue, "INCLUDE", "include"),
-    (blue, "INDEX", "index"),
-    (blue, "INNER", "inner"),
-    (blue, "INSERT", "insert"),
-    (blue, "INTERSECT", "intersect"),
-    (blue, "ISOLATION", "isolation"),
-    (blue, "JOIN ", "join "),
-    (blue, "LEFT ", "left "),
-    (blue, "LIMIT ", "limit "),
-    (blue, "NOTHING", "nothing"),
-    (blue, "OFFSET", "offset"),
-    (blue, "ON ", "on "),
-    (blue, "ONLY ", "only "),
-    (blue, "ORDER BY", "order by"),
-    (blue, "OVERRIDING", "overriding"),
-    (blue, "PARTITION", "partition"),
-    (blue, "PRIMARY", "primary"),
-    (blue, "READ ONLY", "read only"),
-    (blue, "READ WRITE", "read write"),
-    (blue, "RELEASE", "release"),
-    (blue, "REPEATABLE", "repeatable"),
-    (blue, "REPLACE", "REPLACE"),
-    (blue, "RETURNING", "returning"),
-    (blue, "RIGHT", "right"),
-    (blue, "ROLLBACK", "rollback"),
-    (blue, "ROW", "row"),
-    (blue, "SAVEPOINT", "savepoint"),
-    (blue, "SELECT ", "select "),
-    (blue, "SERIALIZABLE", "serializable"),
-    (blue, "SET ", "set "),
-    (blue, "START ", "start "),
-    (blue, "TABLE", "table"),
-    (blue, "TRANSACTION", "transaction"),
-    (blue, "UNCOMMITTED", "uncommitted"),
-    (blue, "UNION ", "union "),
-    (blue, "UNIQUE ", "unique "),
-    (blue, "UPDATE ", "update "),
-    (blue, "USING ", "using "),
-    (blue, "VALUES ", "values "),
-    (blue, "WHERE ", "where "),
-    (blue, "WITH ", "with "),
-    (blue, " ALL", " all"),
-    (blue, " AND", " and"),
-    (blue, " AS ", " as "),
-    (blue, " ASC", " asc"),
-    (blue, " CROSS", " cross"),
-    (blue, " DESC", " desc"),
-    (blue, " DO", " do"),
-    (blue, " END", " end"),
-    (blue, " FIRST", " first"),
-    (blue, " FULL", " full"),
-    (blue, " IF", " if"),
-    (blue, " IN ", " in "),
-    (blue, " INTO", " into"),
-    (blue, " KEY", " key"),
-    (blue, " LAST", " last"),
-    (blue, " LEVEL", " level"),
-    (blue, " NOT", " not"),
-    (blue, " OR ", " or "),
-    (blue, " OUTER", " OUTER"),
-    (blue, " UNIQUE", " unique"),
-    (blue, " USING", " using"),
-    (blue, " VARCHAR", " varchar"),
-    (red, " NULL", " null"),
-    (comment_start, "--", "--"),
-    (comment_start, "/*", "/*"),
-    (comment_end, "*/", "*/"),
-  ];
-
-  let mut query = sql_syntax.iter().fold(query, |acc, item| {
-    let (color_fn, text_upper, text_lower) = item;
-    acc
-      .replace(text_upper, &color_fn(text_upper))
-      .replace(text_lower, &color_fn(text_lower))
-  });
-
-  for index in 1..=10 {
-    let arg_number = format!("${index}");
-    query = query.replace(&arg_number, &bold(&arg_number))
-  }
-
-  query
-}
-
-pub fn format(query: String, fmts: &Formatter) -> String {
+  highlight(&query, &AnsiBackend)
+}
+
+pub fn format(query: &impl Concat, fmts: &Formatter) -> String {
+  let query = pretty::format(query, fmts.dialect, &FormatOptions::default());
   let template = format!("{0}{1}{0}{query}{0}{1}{0}", fmts.lb, fmts.hr);
   let template = colorize(template);
   template
 }
 
-fn blue(text: &str) -> String {
-  format!("\x1b[34;1m{text}\x1b[0m")
+/// Renders the query and returns the bound values in the same order of its placeholders
+pub(crate) fn render(query: &impl Concat, fmts: &Formatter) -> (String, Vec<Value>) {
+  let mut w = Writer::new(fmts);
+  query.concat(&mut w, fmts).unwrap();
+  let (query, values, _) = w.finish(false);
+  (query, values)
+}
+
+/// Same as [render] but writes the query to the output, the bound values are discarded. The query can't be
+/// streamed to the output because the placeholders are renumbered when the rendering finishes.
+/// Returns an error when the number of values bound to a clause doesn't match its placeholders
+pub(crate) fn write(query: &impl Concat, fmts: &Formatter, output: &mut impl Write) -> Result {
+  let mut w = Writer::new(fmts);
+  query.concat(&mut w, fmts).unwrap();
+  if w.bind_mismatch.is_some() {
+    return Err(std::fmt::Error);
+  }
+  let (query, _, _) = w.finish(false);
+  output.write_str(&query)
+}
+
+/// Same as [render] but also returns the number of parameters of the query
+pub(crate) fn render_prepared(query: &impl Concat, fmts: &Formatter) -> (String, Vec<Value>, usize) {
+  let mut w = Writer::new(fmts);
+  query.concat(&mut w, fmts).unwrap();
+  w.finish(true)
+}
+
+/// Same as [render] but returns the query split in the segments of the outline, together with the outline
+/// of the clauses written by the query
+pub(crate) fn outline(query: &impl Concat, fmts: &Formatter) -> (Vec<String>, Vec<pretty::Statement>) {
+  let mut w = Writer::new(fmts);
+  w.outline = Some(pretty::Outline::new());
+  query.concat(&mut w, fmts).unwrap();
+  let statements = w.outline.take().map(pretty::Outline::into_statements);
+  let (query, _, _, starts) = w.finish_segments(false);
+
+  let ends = starts.iter().copied().chain([query.len()]);
+  let segments = [0].into_iter().chain(starts.iter().copied()).zip(ends);
+  let segments = segments.map(|(start, end)| query[start..end].to_string()).collect();
+  (segments, statements.unwrap_or_default())
+}
+
+/// Returns the number of parameters of the query
+pub(crate) fn param_count(query: &impl Concat, fmts: &Formatter) -> usize {
+  let (_, _, count) = render_prepared(query, fmts);
+  count
+}
+
+/// Returns the error of the first clause whose number of bound values doesn't match its placeholders
+pub(crate) fn check_binds(query: &impl Concat, dialect: Dialect) -> std::result::Result<(), BuildError> {
+  let fmts = Formatter { dialect, ..one_line() };
+  let mut w = Writer::new(&fmts);
+  query.concat(&mut w, &fmts).unwrap();
+  match w.bind_mismatch {
+    Some((expected, found)) => Err(BuildError::ParamCountMismatch { expected, found }),
+    None => Ok(()),
+  }
+}
+
+/// Returns the error of the first data type of the definitions that has no equivalent in the dialect
+pub(crate) fn check_data_types<'a>(
+  definitions: impl IntoIterator<Item = &'a Fragment>,
+  dialect: Dialect,
+) -> std::result::Result<(), BuildError> {
+  let fmts = Formatter { dialect, ..one_line() };
+  let mut w = Writer::new(&fmts);
+  for definition in definitions {
+    w.write_fragment(definition).unwrap();
+  }
+  match w.unsupported_type {
+    Some(data_type) => Err(BuildError::UnsupportedFeature {
+      dialect,
+      feature: data_type.to_sql(dialect),
+    }),
+    None => Ok(()),
+  }
+}
+
+/// Returns true when the query renders no text, the rendering stops at the first text written
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+pub(crate) fn renders_empty(query: &(impl Concat + ?Sized), fmts: &Formatter) -> bool {
+  let mut w = Writer::new(fmts);
+  w.probe = true;
+  query.concat(&mut w, fmts).is_ok()
+}
+
+/// The output of the rendering, each clause writes its text and the parts of its fragments to the writer.
+///
+/// The whitespaces at the end of the text are held until some text follows them, this way the query is never
+/// rendered with trailing whitespaces and the clauses don't need to trim the text written before them.
+///
+/// The placeholders `$1, $2, ...` written in a sub-query continue the numbering of the outer query, and the
+/// placeholders of the bound values come after all of them, so the writer keeps the positions of the sub-queries,
+/// the bound values, the identifiers and the literals, and the placeholders are renumbered when the rendering
+/// finishes. The queries without sub-queries and bound values are never scanned.
+///
+/// The pretty printer renders the query with an [pretty::Outline], the clauses of the builders tell the writer
+/// where each clause and each of its items start, this way the layout follows the structure of the builder
+pub struct Writer {
+  bind_mismatch: Option<(usize, usize)>,
+  dialect: Dialect,
+  events: Vec<Event>,
+  has_sub_query: bool,
+  indent: String,
+  multiline: bool,
+  outline: Option<pretty::Outline>,
+  output: String,
+  pending: String,
+  probe: bool,
+  /// The positions of the output where the segments of the outline start, after the first one, and the number
+  /// of values written before them. The values of Postgres have no text until the placeholders are numbered,
+  /// so the number of values tells if the value at the start of the segment belongs to it
+  segments: Vec<(usize, usize)>,
+  unsupported_type: Option<DataType>,
+  values: Vec<Value>,
+}
+
+/// A position of the [Writer], used to know if some text was written after it
+#[derive(Clone, Copy)]
+pub struct Mark {
+  flushed: usize,
+  pending: usize,
+}
+
+/// The positions of the output kept to renumber the placeholders
+enum Event {
+  SubQueryStart(usize),
+  SubQueryEnd(usize),
+  /// The text of an identifier or a literal, the text is never scanned for placeholders and quotes
+  Verbatim(usize, usize),
+  Value(usize),
+}
+
+/// A query, the outer query or a sub-query, with the highest placeholder written in its own clauses
+/// and its sub-queries in the order they were written
+#[derive(Default)]
+struct Frame {
+  max_placeholder: usize,
+  sub_queries: Vec<usize>,
+}
+
+/// The position in the output of the placeholder written in a sub-query or of a bound value
+struct Placeholder {
+  end: usize,
+  frame: usize,
+  number: Option<usize>,
+  start: usize,
+}
+
+impl Writer {
+  pub fn new(fmts: &Formatter) -> Self {
+    Self {
+      bind_mismatch: None,
+      dialect: fmts.dialect,
+      events: vec![],
+      has_sub_query: false,
+      indent: String::new(),
+      multiline: fmts.lb.is_empty() == false,
+      outline: None,
+      output: String::new(),
+      pending: String::new(),
+      probe: false,
+      segments: vec![],
+      unsupported_type: None,
+      values: vec![],
+    }
+  }
+
+  pub fn mark(&self) -> Mark {
+    Mark {
+      flushed: self.output.len(),
+      pending: self.pending.len(),
+    }
+  }
+
+  /// Returns true when some text other than whitespaces was written after the mark
+  pub fn has_written_since(&self, mark: Mark) -> bool {
+    self.output.len() > mark.flushed
+  }
+
+  /// Discards the whitespaces and the lazy text held after the mark
+  pub fn trim_end_since(&mut self, mark: Mark) {
+    if self.has_written_since(mark) {
+      self.pending.clear();
+    } else {
+      self.pending.truncate(mark.pending);
+    }
+  }
+
+  /// Holds the text like the trailing whitespaces, it's written only when some text follows it
+  pub fn write_lazy(&mut self, text: &str) {
+    self.pending.push_str(text);
+  }
+
+  pub fn write_fragment(&mut self, fragment: &Fragment) -> Result {
+    for part in &fragment._parts {
+      match part {
+        Part::Text(text) => self.write_str(text)?,
+        Part::Value(value) => self.write_value(value)?,
+        Part::Ident(ident) => self.write_verbatim(&ident.to_sql(self.dialect))?,
+        Part::Literal(literal) => self.write_verbatim(literal.variant(self.dialect))?,
+        Part::SubQuery(select) => self.write_select(select)?,
+        Part::Variants(variants) => {
+          let position = utils::dialects()
+            .into_iter()
+            .position(|dialect| dialect == self.dialect)
+            .unwrap_or_default();
+          if let Some(variant) = variants.get(position) {
+            self.write_fragment(variant)?;
+          }
+        }
+        Part::BindMismatch { expected, found } => {
+          self.bind_mismatch.get_or_insert((*expected, *found));
+        }
+        Part::UnsupportedType(data_type) => {
+          self.unsupported_type.get_or_insert_with(|| data_type.clone());
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Writes the items that are not empty separated by the separator
+  pub fn write_joined<'b>(&mut self, items: impl IntoIterator<Item = &'b Fragment>, sep: &str) -> Result {
+    let mut first = true;
+    for item in items {
+      if item.is_empty() {
+        continue;
+      }
+      if first == false {
+        self.write_str(sep)?;
+      }
+      self.write_fragment(item)?;
+      first = false;
+    }
+    Ok(())
+  }
+
+  /// Writes the query composed into another one, the placeholders written in the query continue
+  /// the numbering of the outer query
+  pub fn write_sub_query(&mut self, write: impl FnOnce(&mut Self) -> Result) -> Result {
+    self.open_operand();
+    let result = self.write_nested(write);
+    self.close_operand();
+    result
+  }
+
+  /// Same as [Writer::write_sub_query] for the query that follows the clauses of the outer query, like the select
+  /// of an insert, in the outline of the pretty printer its clauses are clauses of the outer query
+  pub(crate) fn write_query(&mut self, write: impl FnOnce(&mut Self) -> Result) -> Result {
+    if let Some(outline) = &mut self.outline {
+      outline.boundary();
+    }
+    self.write_nested(write)
+  }
+
+  fn write_nested(&mut self, write: impl FnOnce(&mut Self) -> Result) -> Result {
+    let mark = self.mark();
+    self.has_sub_query = true;
+    self.events.push(Event::SubQueryStart(self.output.len()));
+    write(self)?;
+    self.events.push(Event::SubQueryEnd(self.output.len()));
+    self.trim_end_since(mark);
+    Ok(())
+  }
+
+  /// Writes the keyword of a clause, in the outline of the pretty printer the text written next
+  /// is the item of the clause
+  pub(crate) fn write_clause(&mut self, keyword: &str, body: pretty::Body) -> Result {
+    match &mut self.outline {
+      Some(outline) => {
+        outline.clause(keyword, body);
+        Ok(())
+      }
+      None => self.write_str(keyword),
+    }
+  }
+
+  /// Writes the items of a clause that are not empty separated by the separator, in the outline
+  /// of the pretty printer each fragment is one item of the clause
+  pub(crate) fn write_items<'b>(&mut self, items: impl IntoIterator<Item = &'b Fragment>, sep: &str) -> Result {
+    let mut first = true;
+    for item in items {
+      if item.is_empty() {
+        continue;
+      }
+      if first == false {
+        self.write_separator(sep)?;
+      }
+      self.write_fragment(item)?;
+      first = false;
+    }
+    Ok(())
+  }
+
+  /// Writes the separator of the items of a clause, in the outline of the pretty printer the separator
+  /// starts the next item instead
+  pub(crate) fn write_separator(&mut self, sep: &str) -> Result {
+    match &mut self.outline {
+      Some(outline) => {
+        outline.item("");
+        Ok(())
+      }
+      None => self.write_str(sep),
+    }
+  }
+
+  /// Writes the logical operator of the next condition of a clause, in the outline of the pretty printer
+  /// the operator starts the next condition instead
+  pub(crate) fn write_operator(
+    &mut self,
+    operator: &LogicalOperator,
+    write: impl FnOnce(&mut Self) -> Result,
+  ) -> Result {
+    match &mut self.outline {
+      Some(outline) => {
+        outline.item(&operator.to_string());
+        Ok(())
+      }
+      None => write(self),
+    }
+  }
+
+  /// Ends the clause in the outline of the pretty printer, the text written next is printed in its own lines
+  pub(crate) fn end_clause(&mut self) {
+    if let Some(outline) = &mut self.outline {
+      outline.end_clause();
+    }
+  }
+
+  /// Starts the raw SQL in the outline of the pretty printer, the raw SQL is laid out from its tokens
+  pub(crate) fn begin_raw(&mut self) {
+    if let Some(outline) = &mut self.outline {
+      outline.raw();
+    }
+  }
+
+  /// Starts the operand of a combinator in the outline of the pretty printer, the clauses written
+  /// until the [Writer::close_operand] are nested in the text written before it
+  pub(crate) fn open_operand(&mut self) {
+    if let Some(outline) = &mut self.outline {
+      outline.open();
+    }
+  }
+
+  pub(crate) fn close_operand(&mut self) {
+    if let Some(outline) = &mut self.outline {
+      outline.close();
+    }
+  }
+
+  /// Starts the next statement of a transaction in the outline of the pretty printer
+  pub(crate) fn begin_statement(&mut self) {
+    if let Some(outline) = &mut self.outline {
+      outline.next_statement();
+    }
+  }
+
+  /// Writes the select composed into another query enclosed in parentheses
+  pub(crate) fn write_select(&mut self, select: &Select) -> Result {
+    let fmts = Formatter {
+      dialect: self.dialect,
+      ..if self.multiline { multiline() } else { one_line() }
+    };
+    let indent_len = self.indent.len();
+
+    self.write_str("(")?;
+    self.indent.push_str(fmts.indent);
+    self.write_lazy(fmts.lb);
+    let result = self.write_sub_query(|w| select.concat(w, &fmts));
+    self.indent.truncate(indent_len);
+    result?;
+    self.write_lazy(fmts.lb);
+    self.write_str(")")
+  }
+
+  /// Returns the query, the bound values and, when asked, the number of parameters of the query.
+  /// In the dialects where the bound values are rendered as `?` the placeholders `?` written
+  /// in the clauses are counted too
+  pub(crate) fn finish(self, count: bool) -> (String, Vec<Value>, usize) {
+    let (output, values, count, _) = self.finish_segments(count);
+    (output, values, count)
+  }
+
+  /// Same as [Writer::finish] but also returns the positions where the segments of the outline start,
+  /// the positions are moved by the renumbering of the placeholders
+  fn finish_segments(self, count: bool) -> (String, Vec<Value>, usize, Vec<usize>) {
+    let is_postgres = match self.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => true,
+      _ => false,
+    };
+    let numbered_values = is_postgres && self.values.is_empty() == false;
+    let starts = |segments: Vec<(usize, usize)>| segments.into_iter().map(|(start, _)| start).collect();
+    if count == false && self.has_sub_query == false && numbered_values == false {
+      return (self.output, self.values, 0, starts(self.segments));
+    }
+
+    let (frames, placeholders, question_marks) = self.scan(is_postgres);
+    let mut offsets = vec![0; frames.len()];
+    let total = offsets_of(&frames, 0, 0, &mut offsets);
+    let count = match is_postgres {
+      true => total + self.values.len(),
+      false => total + question_marks + self.values.len(),
+    };
+
+    if placeholders.is_empty() {
+      return (self.output, self.values, count, starts(self.segments));
+    }
+
+    let mut output = String::with_capacity(self.output.len() + placeholders.len() * 3);
+    let mut segments = Vec::with_capacity(self.segments.len());
+    let mut next_segments = self.segments.iter().peekable();
+    let mut value_number = total;
+    let mut values_written = 0;
+    let mut start = 0;
+    for placeholder in &placeholders {
+      let is_value = placeholder.number.is_none();
+      let is_before = |(segment, values_before): &&(usize, usize)| {
+        *segment < placeholder.start
+          || (*segment == placeholder.start && (is_value == false || *values_before <= values_written))
+      };
+      while let Some((segment, _)) = next_segments.next_if(is_before) {
+        segments.push(output.len() + segment - start);
+      }
+      if is_value {
+        values_written += 1;
+      }
+      output.push_str(&self.output[start..placeholder.start]);
+      let number = match placeholder.number {
+        Some(number) => number + offsets[placeholder.frame],
+        None => {
+          value_number += 1;
+          value_number
+        }
+      };
+      output.push('$');
+      output.push_str(&number.to_string());
+      start = placeholder.end;
+    }
+    for (segment, _) in next_segments {
+      segments.push(output.len() + segment - start);
+    }
+    output.push_str(&self.output[start..]);
+
+    (output, self.values, count, segments)
+  }
+
+  /// Scans the text written for the placeholders `$1, $2, ...` and `?` outside the quoted strings and
+  /// the identifiers. Returns the sub-queries, the placeholders to be renumbered in the order they were written
+  /// and the number of placeholders `?`
+  fn scan(&self, is_postgres: bool) -> (Vec<Frame>, Vec<Placeholder>, usize) {
+    let mut frames = vec![Frame::default()];
+    let mut placeholders = vec![];
+    let mut question_marks = 0;
+    let mut stack = vec![(0, None)];
+    let mut quote = None;
+    let mut start = 0;
+
+    let end = Event::Verbatim(self.output.len(), self.output.len());
+    for event in self.events.iter().chain([&end]) {
+      let (position, next_start) = match event {
+        Event::SubQueryStart(position) | Event::SubQueryEnd(position) => (*position, *position),
+        Event::Verbatim(position, end) => (*position, *end),
+        Event::Value(position) if is_postgres => (*position, *position),
+        Event::Value(position) => (*position, position + 1),
+      };
+      let frame = stack.last().map_or(0, |(frame, _)| *frame);
+      let mut scanner = Scanner {
+        frame,
+        frames: &mut frames,
+        placeholders: &mut placeholders,
+        question_marks: &mut question_marks,
+        quote: &mut quote,
+      };
+      scanner.scan(&self.output, start, position);
+      start = next_start;
+
+      match event {
+        Event::SubQueryStart(_) => {
+          frames.push(Frame::default());
+          let sub_query = frames.len() - 1;
+          frames[frame].sub_queries.push(sub_query);
+          stack.push((sub_query, quote.take()));
+        }
+        Event::SubQueryEnd(_) => {
+          if let Some((_, outer_quote)) = stack.pop() {
+            quote = outer_quote;
+          }
+        }
+        Event::Value(position) if is_postgres => placeholders.push(Placeholder {
+          end: *position,
+          frame,
+          number: None,
+          start: *position,
+        }),
+        _ => {}
+      }
+    }
+
+    (frames, placeholders, question_marks)
+  }
+
+  fn write_value(&mut self, value: &Value) -> Result {
+    self.flush()?;
+    self.events.push(Event::Value(self.output.len()));
+    match self.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {}
+      _ => self.output.push('?'),
+    }
+    self.values.push(value.clone());
+    Ok(())
+  }
+
+  fn write_verbatim(&mut self, text: &str) -> Result {
+    if text.is_empty() {
+      return Ok(());
+    }
+    self.flush()?;
+    let start = self.output.len();
+    self.push_output(text);
+    self.events.push(Event::Verbatim(start, self.output.len()));
+    Ok(())
+  }
+
+  fn flush(&mut self) -> Result {
+    if self.probe {
+      return Err(std::fmt::Error);
+    }
+    if let Some(outline) = &mut self.outline {
+      if outline.take_boundary() {
+        self.pending.clear();
+        self.segments.push((self.output.len(), self.values.len()));
+      }
+      outline.text();
+    }
+    if self.pending.is_empty() == false {
+      let pending = std::mem::take(&mut self.pending);
+      self.push_output(&pending);
+      self.pending = pending;
+      self.pending.clear();
+    }
+    Ok(())
+  }
+
+  /// Pushes the text to the output, the lines of the sub-queries are indented in the multiline format
+  fn push_output(&mut self, text: &str) {
+    if self.indent.is_empty() || text.contains('\n') == false {
+      self.output.push_str(text);
+      return;
+    }
+    for (index, line) in text.split('\n').enumerate() {
+      if index > 0 {
+        self.output.push('\n');
+        self.output.push_str(&self.indent);
+      }
+      self.output.push_str(line);
+    }
+  }
+}
+
+impl Write for Writer {
+  fn write_str(&mut self, text: &str) -> Result {
+    let content_len = text.trim_end().len();
+    if content_len == 0 {
+      self.pending.push_str(text);
+      return Ok(());
+    }
+    self.flush()?;
+    self.push_output(&text[..content_len]);
+    self.pending.push_str(&text[content_len..]);
+    Ok(())
+  }
 }
 
-fn red(text: &str) -> String {
-  format!("\x1b[91;2m{text}\x1b[0m")
+/// Sets the offset of the placeholders of the frame and of its sub-queries, the placeholders of a sub-query
+/// come after the ones of the outer query and of the sub-queries before it. Returns the number of placeholders
+/// of the frame and its sub-queries
+fn offsets_of(frames: &[Frame], frame: usize, offset: usize, offsets: &mut Vec<usize>) -> usize {
+  offsets[frame] = offset;
+  let mut next_offset = offset + frames[frame].max_placeholder;
+  for sub_query in &frames[frame].sub_queries {
+    next_offset += offsets_of(frames, *sub_query, next_offset, offsets);
+  }
+  next_offset - offset
 }
 
-fn bold(text: &str) -> String {
-  format!("\x1b[0;1m{text}\x1b[0m")
+/// The state of the scan of the text written in a frame
+struct Scanner<'a> {
+  frame: usize,
+  frames: &'a mut Vec<Frame>,
+  placeholders: &'a mut Vec<Placeholder>,
+  question_marks: &'a mut usize,
+  quote: &'a mut Option<u8>,
 }
 
-fn comment_start(text: &str) -> String {
-  format!("\x1b[32;2m{text}")
+impl Scanner<'_> {
+  fn scan(&mut self, output: &str, start: usize, end: usize) {
+    let bytes = output.as_bytes();
+    let mut index = start;
+
+    while index < end {
+      // the quotes and the placeholders are ASCII characters, so the text is scanned byte by byte
+      if let Some(quote) = *self.quote {
+        match bytes[index..end].iter().position(|byte| *byte == quote) {
+          Some(position) => {
+            index += position + 1;
+            *self.quote = None;
+            continue;
+          }
+          None => return,
+        }
+      }
+
+      match bytes[index] {
+        quote @ (b'\'' | b'"' | b'`') => *self.quote = Some(quote),
+        b'?' => *self.question_marks += 1,
+        b'$' => {
+          let digits = bytes[index + 1..end]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+          let number_end = index + 1 + digits;
+          let prev = output[..index].chars().last();
+          let is_placeholder = prev.map_or(true, |prev| {
+            (prev.is_alphanumeric() || prev == '_' || prev == '$') == false
+          });
+
+          if let (true, Ok(number)) = (is_placeholder, output[index + 1..number_end].parse::<usize>()) {
+            let frame = &mut self.frames[self.frame];
+            frame.max_placeholder = frame.max_placeholder.max(number);
+            if self.frame > 0 {
+              self.placeholders.push(Placeholder {
+                end: number_end,
+                frame: self.frame,
+                number: Some(number),
+                start: index,
+              });
+            }
+          }
+          index = number_end;
+          continue;
+        }
+        _ => {}
+      }
+      index += 1;
+    }
+  }
 }
 
-fn comment_end(text: &str) -> String {
-  format!("\x1b[32;2m{text}\x1b[0m")
+/// Runs the write function with a [std::fmt::Write] that forwards the text to the [io::Write],
+/// the error of the [io::Write] is returned instead of the opaque [std::fmt::Error]
+pub(crate) fn write_io<W: io::Write>(
+  output: &mut W,
+  write: impl FnOnce(&mut IoWriter<W>) -> std::fmt::Result,
+) -> io::Result<()> {
+  let mut writer = IoWriter { output, error: None };
+  match write(&mut writer) {
+    Ok(()) => Ok(()),
+    Err(_) => Err(writer.error.unwrap_or_else(|| {
+      io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "the bound values don't match the placeholders",
+      )
+    })),
+  }
+}
+
+pub(crate) struct IoWriter<'a, W: io::Write> {
+  output: &'a mut W,
+  error: Option<io::Error>,
+}
+
+impl<W: io::Write> std::fmt::Write for IoWriter<'_, W> {
+  fn write_str(&mut self, text: &str) -> std::fmt::Result {
+    self.output.write_all(text.as_bytes()).map_err(|error| {
+      self.error = Some(error);
+      std::fmt::Error
+    })
+  }
 }
@@ -0,0 +1,318 @@
+use crate::{
+  fmt,
+  structure::{
+    Check, ColumnDef, Dialect, Expr, ForeignKey, Fragment, Ident, Literal, Part, Precedence, PrimaryKey, Select, Unique,
+  },
+  utils,
+};
+use std::sync::Arc;
+
+impl Fragment {
+  /// Creates an empty fragment, use the [Fragment::push] method to compose it
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let fragment = sql::Fragment::new().push("login = ").push(sql::Literal::new("foo"));
+  ///
+  /// # assert_eq!("login = 'foo'", fragment.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// login = 'foo'
+  /// ```
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Gets the fragment in the syntax of the default dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let fragment = sql::Fragment::from("id = 1");
+  ///
+  /// # assert_eq!("id = 1", fragment.as_string());
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts)
+  }
+
+  /// Appends the text, the identifier, the literal or any other fragment to the end of the fragment,
+  /// nothing is added between them
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let column = sql::Fragment::from("COLUMN ").push(sql::Ident::new("key"));
+  ///
+  /// # assert_eq!("COLUMN `key`", column.to_sql(sql::Dialect::Mysql));
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// COLUMN `key`
+  /// ```
+  pub fn push(mut self, fragment: impl Into<Fragment>) -> Self {
+    for part in fragment.into()._parts {
+      self.push_part(part);
+    }
+    self
+  }
+
+  /// Gets the fragment in the syntax of the specified SQL [Dialect]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let fragment = sql::Fragment::from("SUM(").push(sql::Ident::new("total")).push(")");
+  ///
+  /// let standard_fragment = fragment.to_sql(sql::Dialect::Standard);
+  /// let mysql_fragment = fragment.to_sql(sql::Dialect::Mysql);
+  ///
+  /// # assert_eq!(r#"SUM("total")"#, standard_fragment);
+  /// # assert_eq!("SUM(`total`)", mysql_fragment);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// SUM("total")
+  ///
+  /// -- MySQL
+  /// SUM(`total`)
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    self.render(&fmts)
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self._parts.is_empty()
+  }
+
+  /// Returns true when the fragment is a definition rendered differently in each dialect,
+  /// like the fragments of the [ColumnDef] and the constraints
+  pub(crate) fn is_variants(&self) -> bool {
+    matches!(self._parts.as_slice(), [Part::Variants(_)])
+  }
+
+  /// Removes the whitespaces at the edges of the fragment, the builders trim the fragments they receive
+  pub(crate) fn trim(mut self) -> Self {
+    while let Some(Part::Text(text)) = self._parts.first_mut() {
+      if text.trim_start().len() == text.len() {
+        break;
+      }
+      *text = text.trim_start().to_string();
+      if text.is_empty() {
+        self._parts.remove(0);
+      }
+    }
+    while let Some(Part::Text(text)) = self._parts.last_mut() {
+      if text.trim_end().len() == text.len() {
+        break;
+      }
+      text.truncate(text.trim_end().len());
+      if text.is_empty() {
+        self._parts.pop();
+      }
+    }
+    self
+  }
+
+  /// Creates the fragment of the query composed into another one, the query is rendered with the outer query
+  pub(crate) fn sub_query(select: impl Into<Arc<Select>>) -> Self {
+    Self::from(Part::SubQuery(select.into()))
+  }
+
+  /// Creates the fragment rendered in the syntax of each dialect by the render function
+  pub(crate) fn variants(render: impl Fn(Dialect) -> Fragment) -> Self {
+    let variants = utils::dialects().into_iter().map(render).collect();
+    Self::from(Part::Variants(variants))
+  }
+
+  pub(crate) fn push_part(&mut self, part: Part) {
+    match (self._parts.last_mut(), part) {
+      (_, Part::Text(text)) if text.is_empty() => {}
+      (Some(Part::Text(last)), Part::Text(text)) => last.push_str(&text),
+      (_, part) => self._parts.push(part),
+    }
+  }
+
+  fn render(&self, fmts: &fmt::Formatter) -> String {
+    let mut w = fmt::Writer::new(fmts);
+    w.write_fragment(self).unwrap();
+    let (sql, _, _) = w.finish(false);
+    sql
+  }
+}
+
+impl From<Part> for Fragment {
+  fn from(part: Part) -> Self {
+    let mut fragment = Self::default();
+    fragment.push_part(part);
+    fragment
+  }
+}
+
+impl From<&str> for Fragment {
+  fn from(text: &str) -> Self {
+    Self::from(Part::Text(text.to_string()))
+  }
+}
+
+impl From<String> for Fragment {
+  fn from(text: String) -> Self {
+    Self::from(Part::Text(text))
+  }
+}
+
+impl From<&String> for Fragment {
+  fn from(text: &String) -> Self {
+    Self::from(Part::Text(text.clone()))
+  }
+}
+
+impl From<&Fragment> for Fragment {
+  fn from(fragment: &Fragment) -> Self {
+    fragment.clone()
+  }
+}
+
+impl From<Ident> for Fragment {
+  fn from(ident: Ident) -> Self {
+    Self::from(Part::Ident(ident))
+  }
+}
+
+impl From<&Ident> for Fragment {
+  fn from(ident: &Ident) -> Self {
+    Self::from(Part::Ident(ident.clone()))
+  }
+}
+
+impl From<Literal> for Fragment {
+  fn from(literal: Literal) -> Self {
+    Self::from(Part::Literal(literal))
+  }
+}
+
+impl From<&Literal> for Fragment {
+  fn from(literal: &Literal) -> Self {
+    Self::from(Part::Literal(literal.clone()))
+  }
+}
+
+/// Implements the conversion of the definitions rendered differently in each dialect, by value and by reference
+macro_rules! fragment_from_definition {
+  ($($source:ty),+) => {
+    $(
+      impl From<$source> for Fragment {
+        fn from(value: $source) -> Self {
+          Self::from(&value)
+        }
+      }
+
+      impl From<&$source> for Fragment {
+        fn from(value: &$source) -> Self {
+          Self::variants(|dialect| value.definition(dialect))
+        }
+      }
+    )+
+  };
+}
+
+fragment_from_definition!(Check, ColumnDef, ForeignKey, PrimaryKey, Unique);
+
+#[cfg(feature = "postgresql")]
+fragment_from_definition!(crate::structure::Exclude);
+
+impl From<Expr> for Fragment {
+  fn from(expr: Expr) -> Self {
+    Self::from(&expr)
+  }
+}
+
+impl From<&Expr> for Fragment {
+  fn from(expr: &Expr) -> Self {
+    expr.grouped(Precedence::Or)
+  }
+}
+
+impl PartialEq for Part {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Part::Text(text), Part::Text(other)) => text == other,
+      (Part::Value(value), Part::Value(other)) => value == other,
+      (Part::Ident(ident), Part::Ident(other)) => ident == other,
+      (Part::Literal(literal), Part::Literal(other)) => literal == other,
+      (Part::SubQuery(select), Part::SubQuery(other)) => {
+        Arc::ptr_eq(select, other) || select.as_string_with_binds() == other.as_string_with_binds()
+      }
+      (Part::Variants(variants), Part::Variants(other)) => variants == other,
+      (
+        Part::BindMismatch { expected, found },
+        Part::BindMismatch {
+          expected: other_expected,
+          found: other_found,
+        },
+      ) => expected == other_expected && found == other_found,
+      (Part::UnsupportedType(data_type), Part::UnsupportedType(other)) => data_type == other,
+      _ => false,
+    }
+  }
+}
+
+impl PartialEq<str> for Fragment {
+  fn eq(&self, other: &str) -> bool {
+    self.as_string() == other
+  }
+}
+
+impl PartialEq<&str> for Fragment {
+  fn eq(&self, other: &&str) -> bool {
+    self.as_string() == *other
+  }
+}
+
+impl PartialEq<Fragment> for str {
+  fn eq(&self, other: &Fragment) -> bool {
+    other == self
+  }
+}
+
+impl PartialEq<Fragment> for &str {
+  fn eq(&self, other: &Fragment) -> bool {
+    other == *self
+  }
+}
+
+impl std::fmt::Display for Fragment {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Fragment {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
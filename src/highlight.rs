@@ -0,0 +1,291 @@
+use crate::{
+  behavior::HighlightBackend,
+  structure::{AnsiBackend, HtmlBackend, PlainBackend, TokenKind},
+};
+
+pub(crate) const KEYWORDS: [&str; 94] = [
+  "ADD",
+  "ALL",
+  "ALTER",
+  "AND",
+  "AS",
+  "ASC",
+  "BEGIN",
+  "BETWEEN",
+  "BY",
+  "CASCADE",
+  "CASE",
+  "CHECK",
+  "COLUMN",
+  "COMMIT",
+  "COMMITTED",
+  "CONCURRENTLY",
+  "CONFLICT",
+  "CONSTRAINT",
+  "CREATE",
+  "CROSS",
+  "DEFAULT",
+  "DEFERRABLE",
+  "DELETE",
+  "DESC",
+  "DISTINCT",
+  "DO",
+  "DROP",
+  "DUPLICATE",
+  "ELSE",
+  "END",
+  "EXCEPT",
+  "EXISTS",
+  "FOREIGN",
+  "FROM",
+  "FULL",
+  "GROUP",
+  "HAVING",
+  "IF",
+  "IN",
+  "INCLUDE",
+  "INDEX",
+  "INNER",
+  "INSERT",
+  "INTERSECT",
+  "INTO",
+  "IS",
+  "ISOLATION",
+  "JOIN",
+  "KEY",
+  "LEFT",
+  "LIKE",
+  "LIMIT",
+  "MATCHED",
+  "MERGE",
+  "NATURAL",
+  "NOT",
+  "NOTHING",
+  "OFFSET",
+  "ON",
+  "ONLY",
+  "OR",
+  "ORDER",
+  "OUTER",
+  "OVERRIDING",
+  "PARTITION",
+  "PRIMARY",
+  "RECURSIVE",
+  "REFERENCES",
+  "RELEASE",
+  "REPEATABLE",
+  "REPLACE",
+  "RESTRICT",
+  "RETURNING",
+  "RIGHT",
+  "ROLLBACK",
+  "SAVEPOINT",
+  "SELECT",
+  "SERIALIZABLE",
+  "SET",
+  "START",
+  "TABLE",
+  "THEN",
+  "TRANSACTION",
+  "UNCOMMITTED",
+  "UNION",
+  "UNIQUE",
+  "UPDATE",
+  "USING",
+  "VALUES",
+  "VIEW",
+  "WHEN",
+  "WHERE",
+  "WITH",
+  "WORK",
+];
+
+const LITERAL_WORDS: [&str; 3] = ["FALSE", "NULL", "TRUE"];
+
+/// Highlights the tokens of a SQL query with the given backend.
+///
+/// The query is split into keywords, identifiers, literals, comments, placeholders, operators and whitespace,
+/// text inside string literals, quoted identifiers and comments is never mistaken for a keyword.
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let query = sql::Select::new()
+///   .select("addition")
+///   .from("orders")
+///   .where_clause("note = 'select me'")
+///   .as_string();
+///
+/// let html = sql::highlight(&query, &sql::HtmlBackend);
+///
+/// # let expected = "\
+/// #   <span class=\"sql-keyword\">SELECT</span> \
+/// #   <span class=\"sql-identifier\">addition</span> \
+/// #   <span class=\"sql-keyword\">FROM</span> \
+/// #   <span class=\"sql-identifier\">orders</span> \
+/// #   <span class=\"sql-keyword\">WHERE</span> \
+/// #   <span class=\"sql-identifier\">note</span> \
+/// #   <span class=\"sql-operator\">=</span> \
+/// #   <span class=\"sql-literal\">&#39;select me&#39;</span>\
+/// # ";
+/// # assert_eq!(expected, html);
+/// ```
+///
+/// Custom outputs are made by implementing the [HighlightBackend] trait
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// use sql::{HighlightBackend, TokenKind};
+///
+/// struct Brackets;
+///
+/// impl HighlightBackend for Brackets {
+///   fn paint(&self, kind: TokenKind, text: &str) -> String {
+///     match kind {
+///       TokenKind::Keyword => format!("[{text}]"),
+///       _ => text.to_string(),
+///     }
+///   }
+/// }
+///
+/// let query = sql::highlight("select id from users", &Brackets);
+///
+/// # assert_eq!("[select] id [from] users", query);
+/// ```
+pub fn highlight(sql: &str, backend: &impl HighlightBackend) -> String {
+  tokenize(sql)
+    .into_iter()
+    .map(|(kind, text)| backend.paint(kind, text))
+    .collect()
+}
+
+/// Splits the query into tokens, concatenating the text of the tokens gives back the original query
+pub(crate) fn tokenize(sql: &str) -> Vec<(TokenKind, &str)> {
+  let bytes = sql.as_bytes();
+  let mut tokens = vec![];
+  let mut start = 0;
+
+  while start < sql.len() {
+    let rest = &sql[start..];
+    let c = rest.chars().next().unwrap();
+
+    let (kind, len) = if c.is_whitespace() {
+      (TokenKind::Whitespace, span(rest, |c| c.is_whitespace()))
+    } else if rest.starts_with("--") {
+      (TokenKind::Comment, rest.find('\n').unwrap_or(rest.len()))
+    } else if let Some(body) = rest.strip_prefix("/*") {
+      (TokenKind::Comment, body.find("*/").map_or(rest.len(), |end| end + 4))
+    } else if c == '\'' {
+      (TokenKind::Literal, quoted(bytes, start, b'\''))
+    } else if c == '"' || c == '`' {
+      (TokenKind::Identifier, quoted(bytes, start, c as u8))
+    } else if c.is_ascii_digit() {
+      (TokenKind::Literal, span(rest, |c| c.is_alphanumeric() || c == '.'))
+    } else if c.is_alphabetic() || c == '_' {
+      let len = span(rest, |c| c.is_alphanumeric() || c == '_');
+      let after_dot = tokens.last().map_or(false, |(_, text): &(_, &str)| text.ends_with('.'));
+      (word_kind(&rest[..len], after_dot), len)
+    } else if (c == '$' || c == '?') && rest[1..].starts_with(|c: char| c.is_ascii_digit()) {
+      (TokenKind::Placeholder, 1 + span(&rest[1..], |c| c.is_ascii_digit()))
+    } else if c == '?' {
+      (TokenKind::Placeholder, 1)
+    } else if "(),;.".contains(c) {
+      (TokenKind::Operator, c.len_utf8())
+    } else {
+      (TokenKind::Operator, operator(rest))
+    };
+
+    tokens.push((kind, &sql[start..start + len]));
+    start += len;
+  }
+
+  tokens
+}
+
+fn word_kind(word: &str, after_dot: bool) -> TokenKind {
+  let upper = word.to_uppercase();
+  if after_dot {
+    TokenKind::Identifier
+  } else if KEYWORDS.contains(&upper.as_str()) {
+    TokenKind::Keyword
+  } else if LITERAL_WORDS.contains(&upper.as_str()) {
+    TokenKind::Literal
+  } else {
+    TokenKind::Identifier
+  }
+}
+
+/// Returns the length of a quoted text starting at `start`, a doubled quote is an escaped quote
+fn quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+  let mut index = start + 1;
+  while index < bytes.len() {
+    if bytes[index] == quote {
+      if bytes.get(index + 1) == Some(&quote) {
+        index += 2;
+        continue;
+      }
+      return index + 1 - start;
+    }
+    index += 1;
+  }
+  bytes.len() - start
+}
+
+/// Returns the length of a run of operator characters, a comment start ends the run
+fn operator(text: &str) -> usize {
+  let mut len = 0;
+  for c in text.chars() {
+    let rest = &text[len..];
+    let is_operator = len == 0 || !(c.is_alphanumeric() || c.is_whitespace() || "_'\"`$?(),;.".contains(c));
+    if is_operator == false || (len > 0 && (rest.starts_with("--") || rest.starts_with("/*"))) {
+      break;
+    }
+    len += c.len_utf8();
+  }
+  len
+}
+
+fn span(text: &str, predicate: impl Fn(char) -> bool) -> usize {
+  text.find(|c| predicate(c) == false).unwrap_or(text.len())
+}
+
+impl HighlightBackend for AnsiBackend {
+  fn paint(&self, kind: TokenKind, text: &str) -> String {
+    let color = match kind {
+      TokenKind::Keyword => "\x1b[34;1m",
+      TokenKind::Literal => "\x1b[33m",
+      TokenKind::Comment => "\x1b[32;2m",
+      TokenKind::Placeholder => "\x1b[0;1m",
+      TokenKind::Identifier | TokenKind::Operator | TokenKind::Whitespace => return text.to_string(),
+    };
+    format!("{color}{text}\x1b[0m")
+  }
+}
+
+impl HighlightBackend for HtmlBackend {
+  fn paint(&self, kind: TokenKind, text: &str) -> String {
+    let class = match kind {
+      TokenKind::Keyword => "sql-keyword",
+      TokenKind::Identifier => "sql-identifier",
+      TokenKind::Literal => "sql-literal",
+      TokenKind::Comment => "sql-comment",
+      TokenKind::Placeholder => "sql-placeholder",
+      TokenKind::Operator => "sql-operator",
+      TokenKind::Whitespace => return text.to_string(),
+    };
+    let text = text
+      .replace('&', "&amp;")
+      .replace('<', "&lt;")
+      .replace('>', "&gt;")
+      .replace('"', "&quot;")
+      .replace('\'', "&#39;");
+    format!("<span class=\"{class}\">{text}</span>")
+  }
+}
+
+impl HighlightBackend for PlainBackend {
+  fn paint(&self, _kind: TokenKind, text: &str) -> String {
+    text.to_string()
+  }
+}
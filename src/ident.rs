@@ -0,0 +1,128 @@
+use crate::structure::{Dialect, Ident};
+
+impl Ident {
+  /// Creates an identifier with a single name, the name is used as is, dots included
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let ident = sql::Ident::new("order");
+  ///
+  /// # assert_eq!(r#""order""#, ident.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// "order"
+  /// ```
+  pub fn new(name: &str) -> Self {
+    Self::qualified(&[name])
+  }
+
+  /// Creates a qualified identifier like `schema.table` or `table.column`, each part is quoted separately
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let ident = sql::Ident::qualified(&["public", "users", "login"]);
+  ///
+  /// # assert_eq!(r#""public"."users"."login""#, ident.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// "public"."users"."login"
+  /// ```
+  pub fn qualified(parts: &[&str]) -> Self {
+    Self {
+      _parts: parts.iter().map(|part| part.to_string()).collect(),
+    }
+  }
+
+  /// Gets the identifier quoted in the syntax of the default dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let ident = sql::Ident::new("user \"name\"");
+  ///
+  /// # assert_eq!(r#""user ""name""""#, ident.as_string());
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// "user ""name"""
+  /// ```
+  pub fn as_string(&self) -> String {
+    quote(&self._parts, Dialect::default())
+  }
+
+  /// Gets the identifier quoted in the syntax of the specified SQL [Dialect],
+  /// the double quotes in Postgres, SQLite and SQL Standard and the backticks in MySQL
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let ident = sql::Ident::qualified(&["shop", "order"]);
+  ///
+  /// let standard_ident = ident.to_sql(sql::Dialect::Standard);
+  /// let mysql_ident = ident.to_sql(sql::Dialect::Mysql);
+  ///
+  /// # assert_eq!(r#""shop"."order""#, standard_ident);
+  /// # assert_eq!("`shop`.`order`", mysql_ident);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// "shop"."order"
+  ///
+  /// -- MySQL
+  /// `shop`.`order`
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    quote(&self._parts, dialect)
+  }
+}
+
+impl std::fmt::Display for Ident {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Ident {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+/// Renders the parts of the identifier quoted in the syntax of the dialect
+fn quote(parts: &[String], dialect: Dialect) -> String {
+  let quote = match dialect {
+    #[cfg(feature = "mysql")]
+    Dialect::Mysql => '`',
+    _ => '"',
+  };
+  let escaped_quote = format!("{quote}{quote}");
+
+  parts
+    .iter()
+    .map(|part| format!("{quote}{}{quote}", part.replace(quote, &escaped_quote)))
+    .collect::<Vec<_>>()
+    .join(".")
+}
@@ -1,14 +1,59 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{Insert, InsertClause, InsertVariance, Select, ValuesVariance},
-  utils::push_unique,
+  behavior::{BindValues, TransactionQuery, Validate},
+  bind, fmt, introspection, parser, pretty,
+  structure::{
+    BuildError, Dialect, FormatOptions, Fragment, Insert, InsertClause, InsertVariance, ParseError, PreparedQuery,
+    Select, Value, ValuesVariance,
+  },
+  utils::{self, push_unique},
 };
 
 impl TransactionQuery for Insert {}
 
 impl Insert {
+  /// Gets the current state of the [Insert] and returns it as string formatted by the [FormatOptions], the column
+  /// list and the rows are wrapped one item per line when they don't fit in the `max_line_length`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   max_line_length: 20,
+  ///   ..Default::default()
+  /// };
+  /// let insert_query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .values("('bar', 'Bar')")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # INSERT INTO users (\n  \
+  /// # login,\n  \
+  /// # name\n\
+  /// # )\n\
+  /// # VALUES\n  \
+  /// # ('foo', 'Foo'),\n  \
+  /// # ('bar', 'Bar')";
+  /// # assert_eq!(expected, insert_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (
+  ///   login,
+  ///   name
+  /// )
+  /// VALUES
+  ///   ('foo', 'Foo'),
+  ///   ('bar', 'Bar')
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [Insert] and returns it as string
   ///
   /// # Example
@@ -30,8 +75,145 @@ impl Insert {
   /// INSERT INTO users (login) VALUES ('foo')
   /// ```
   pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string together with the values bound
+  /// by the methods with the `_bind` suffix, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, values) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("(?, ?)", ("foo", "Foo"))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ($1, $2)";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("Foo")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ($1, $2)
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the clauses of the [Insert] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [Insert::raw], [Insert::raw_before] and [Insert::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .values("('foo')")
+  ///   .insert_into("users (login)");
+  ///
+  /// let clauses = insert.clauses();
+  ///
+  /// # assert_eq!(vec![sql::InsertClause::InsertInto, sql::InsertClause::Values], clauses);
+  /// ```
+  pub fn clauses(&self) -> Vec<InsertClause> {
+    let mut clauses = vec![];
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if self._with.is_empty() == false {
+      clauses.push(InsertClause::With);
+    }
+    match self._insert_variance {
+      InsertVariance::InsertInto => {
+        if self._insert_into.is_empty() == false {
+          clauses.push(InsertClause::InsertInto);
+        }
+      }
+      #[cfg(feature = "sqlite")]
+      InsertVariance::InsertOr => {
+        if self._insert_or.is_empty() == false {
+          clauses.push(InsertClause::InsertOr);
+        }
+      }
+      #[cfg(feature = "sqlite")]
+      InsertVariance::ReplaceInto => {
+        if self._replace_into.is_empty() == false {
+          clauses.push(InsertClause::ReplaceInto);
+        }
+      }
+      #[cfg(feature = "mysql")]
+      InsertVariance::InsertSplitted => {
+        if self._insert.is_empty() == false {
+          clauses.push(InsertClause::Insert);
+        }
+        if self._into.is_empty() == false {
+          clauses.push(InsertClause::Into);
+        }
+      }
+    }
+    #[cfg(feature = "mysql")]
+    {
+      if self._partition.is_empty() == false {
+        clauses.push(InsertClause::Partition);
+      }
+      if self._column.is_empty() == false {
+        clauses.push(InsertClause::Column);
+      }
+    }
+    #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+    if self._overriding.is_empty() == false {
+      clauses.push(InsertClause::Overriding);
+    }
+    match self._values_variance {
+      ValuesVariance::InsertValues => {
+        if self._values.is_empty() == false {
+          clauses.push(InsertClause::Values);
+        }
+      }
+      ValuesVariance::InsertSelect => {
+        if self._select.is_some() {
+          clauses.push(InsertClause::Select);
+        }
+      }
+      #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+      ValuesVariance::InsertDefaultValues => clauses.push(InsertClause::DefaultValues),
+      #[cfg(feature = "mysql")]
+      ValuesVariance::InsertSet => {
+        if self._set.is_empty() == false {
+          clauses.push(InsertClause::Set);
+        }
+      }
+      #[cfg(feature = "mysql")]
+      ValuesVariance::InsertValuesRow => {
+        if self._values.is_empty() == false {
+          clauses.push(InsertClause::Values);
+        }
+      }
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      if self._on_conflict.is_empty() == false {
+        clauses.push(InsertClause::OnConflict);
+      }
+      if self._returning.is_empty() == false {
+        clauses.push(InsertClause::Returning);
+      }
+    }
+    #[cfg(feature = "mysql")]
+    if self._on_duplicate_key_update.is_empty() == false {
+      clauses.push(InsertClause::OnDuplicateKeyUpdate);
+    }
+
+    clauses
   }
 
   /// Prints the current state of the [Insert] to the standard output in a more ease to read version.
@@ -59,7 +241,7 @@ impl Insert {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -86,12 +268,51 @@ impl Insert {
   /// ```sql
   /// INSERT INTO users DEFAULT VALUES
   /// ```
-  #[cfg(not(feature = "mysql"))]
+  #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
   pub fn default_values(mut self) -> Self {
     self._values_variance = ValuesVariance::InsertDefaultValues;
     self
   }
 
+  /// Gets the table and the columns of the `insert into` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("users (login, name)")), insert.get_insert_into());
+  /// ```
+  pub fn get_insert_into(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._insert_into)
+  }
+
+  /// Gets the [Select] of the `select` clause, `None` when the clause is not defined
+  pub fn get_select(&self) -> Option<&Select> {
+    self._select.as_ref()
+  }
+
+  /// Gets the rows of the `values` clause as they were added, the values bound by the methods
+  /// with the `_bind` suffix are rendered as placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .values("('bar', 'Bar')");
+  ///
+  /// # assert_eq!(["('foo', 'Foo')", "('bar', 'Bar')"], insert.get_values());
+  /// ```
+  pub fn get_values(&self) -> &[Fragment] {
+    &self._values
+  }
+
   /// The `insert into` clause. This method overrides the previous value
   ///
   /// # Example
@@ -127,7 +348,7 @@ impl Insert {
   ///   .replace_into("users (login, name)");
   /// #
   /// # let expected = "REPLACE INTO users (login, name)";
-  /// # assert_eq!(expected, insert.to_string());
+  /// # assert_eq!(expected, insert.to_sql(sql::Dialect::Sqlite));
   /// # }
   /// ```
   /// Output
@@ -151,7 +372,7 @@ impl Insert {
   ///   .column("login");
   /// #
   /// # let expected = "INSERT low_priority INTO users (login)";
-  /// # assert_eq!(expected, insert.to_string());
+  /// # assert_eq!(expected, insert.to_sql(sql::Dialect::Mysql));
   /// # }
   /// ```
   /// Output
@@ -159,8 +380,8 @@ impl Insert {
   /// ```sql
   /// INSERT low_priority INTO users (login)
   /// ```
-  pub fn insert_into(mut self, table_name: &str) -> Self {
-    self._insert_into = table_name.trim().to_string();
+  pub fn insert_into(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._insert_into = table_name.into().trim();
     self._insert_variance = InsertVariance::InsertInto;
     self
   }
@@ -190,17 +411,98 @@ impl Insert {
   /// ```sql
   /// INSERT INTO users (login) OVERRIDING user value
   /// ```
-  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
-  pub fn overriding(mut self, option: &str) -> Self {
-    self._overriding = option.trim().to_string();
+  #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+  pub fn overriding(mut self, option: impl Into<Fragment>) -> Self {
+    self._overriding = option.into().trim();
     self
   }
 
+  /// Returns the number of parameters of the [Insert], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound by the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .select(sql::Select::new().select("login, $2").from("users_bkp").where_clause("id = $1"));
+  ///
+  /// # let expected = "INSERT INTO users (login, name) SELECT login, $2 FROM users_bkp WHERE id = $1";
+  /// # assert_eq!(expected, insert.as_string());
+  /// # assert_eq!(2, insert.param_count());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) SELECT login, $2 FROM users_bkp WHERE id = $1
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
+  /// Parses the SQL text of an insert command into a builder, this way an existing query can be changed with
+  /// the builder methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::parse("INSERT INTO users (login, name) VALUES ('foo', 'Foo')")
+  ///   .unwrap()
+  ///   .values("('bar', 'Bar')");
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # assert_eq!(expected, insert.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// ```
+  pub fn parse(sql: &str) -> Result<Self, ParseError> {
+    parser::insert(sql)
+  }
+  /// Renders the [Insert] once in the syntax of the [Dialect] and returns a [PreparedQuery], the SQL and the number
+  /// of parameters are kept so the same query can be executed many times binding new values with the
+  /// [PreparedQuery::bind] method, without rendering the builder again
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("(?, ?)", ("foo", "Foo"));
+  ///
+  /// let prepared = insert.prepare(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("INSERT INTO users (login, name) VALUES (?, ?)", prepared.sql());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES (?, ?)
+  /// ```
+  pub fn prepare(&self, dialect: Dialect) -> PreparedQuery {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (sql, values, param_count) = fmt::render_prepared(self, &fmts);
+    PreparedQuery::new(sql, values, param_count, dialect)
+  }
+
   /// Prints the current state of the [Insert] to the standard output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -265,8 +567,8 @@ impl Insert {
   /// ```sql
   /// insert into users (login, name) VALUES ('foo', 'Foo')
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -292,8 +594,8 @@ impl Insert {
   /// ```sql
   /// INSERT INTO users (login, name) values ('foo', 'Foo')
   /// ```
-  pub fn raw_after(mut self, clause: InsertClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, clause: InsertClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim()));
     self
   }
 
@@ -319,11 +621,213 @@ impl Insert {
   /// ```sql
   /// insert into users (login, name) VALUES ('bar', 'Bar')
   /// ```
-  pub fn raw_before(mut self, clause: InsertClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, clause: InsertClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim()));
     self
   }
 
+  /// Gets the names of the tables written and read by the [Insert], the table of the `insert into` clause
+  /// and the tables of the sub-queries in the order they appear. The names of the common table expressions
+  /// are not included and the quoted names are returned without the quotes
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users_archive (id, login)")
+  ///   .select(sql::Select::new().select("id, login").from("users"));
+  ///
+  /// let tables = insert.tables();
+  ///
+  /// # assert_eq!(vec!["users_archive", "users"], tables);
+  /// ```
+  pub fn tables(&self) -> Vec<String> {
+    introspection::tables(self)
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .returning("id");
+  ///
+  /// let standard_query = insert.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = insert.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("INSERT INTO users (login) VALUES ('foo')", standard_query);
+  /// # assert_eq!("INSERT INTO users (login) VALUES ('foo') RETURNING id", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// INSERT INTO users (login) VALUES ('foo')
+  ///
+  /// -- Postgres
+  /// INSERT INTO users (login) VALUES ('foo') RETURNING id
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound by the methods with the `_bind` suffix,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values_bind("(?)", "foo");
+  ///
+  /// let (standard_query, values) = insert.to_sql_with_binds(sql::Dialect::Standard);
+  /// let (postgresql_query, _) = insert.to_sql_with_binds(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("INSERT INTO users (login) VALUES (?)", standard_query);
+  /// # assert_eq!("INSERT INTO users (login) VALUES ($1)", postgresql_query);
+  /// # assert_eq!(vec![sql::Value::from("foo")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// INSERT INTO users (login) VALUES (?)
+  ///
+  /// -- Postgres
+  /// INSERT INTO users (login) VALUES ($1)
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string, returns a [BuildError] when
+  /// the [Insert::validate] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("INSERT INTO users (login) VALUES ('foo')", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [Insert] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Insert::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("INSERT INTO users (login) VALUES ('foo')", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [Insert], returns a [BuildError] when the table or the rows to insert are
+  /// missing, or when more than one source of rows is set, like the `values` and the `select` clauses.
+  /// The required clauses are not checked when the raw SQL is set. The rows of the `values_bind` method
+  /// must have one value for each placeholder
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .select(sql::Select::new().select("login").from("users_bk"));
+  ///
+  /// let error = insert.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::ConflictingClauses {
+  /// #   command: "INSERT".to_string(),
+  /// #   clauses: vec!["VALUES".to_string(), "SELECT".to_string()],
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [Insert] like the [Insert::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .returning("id");
+  ///
+  /// let error = insert.validate_for(sql::Dialect::Mysql).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Mysql,
+  /// #   feature: "RETURNING".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
   /// The `values` clause
   ///
   /// # Example
@@ -357,7 +861,7 @@ impl Insert {
   /// let query = sql::Insert::new()
   ///   .values("('foo', 'Foo')")
   ///   .row("('bar', 'Bar')")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   /// # let expected = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
   /// # assert_eq!(expected, query);
   /// # }
@@ -383,7 +887,7 @@ impl Insert {
   ///   .row("('bar', 'Bar')")
   ///   .set("login = 'foo'")
   ///   .set("name = 'Foo'")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   /// # let expected = "SET login = 'foo', name = 'Foo'";
   /// # assert_eq!(expected, query);
   /// # }
@@ -394,12 +898,89 @@ impl Insert {
   /// ```sql
   /// SET login = 'foo', name = 'Foo'
   /// ```
-  pub fn values(mut self, expression: &str) -> Self {
-    push_unique(&mut self._values, expression.trim().to_string());
+  pub fn values(mut self, expression: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._values, expression.into().trim());
+    self._values_variance = ValuesVariance::InsertValues;
+
+    self
+  }
+
+  /// The `values` clause with values bound to its placeholders, each `?` of the expression is replaced
+  /// by a value in the same order. The values are returned by the [Insert::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (insert_query, values) = sql::Insert::new()
+  ///   .values_bind("(?, ?)", ("foo", "Foo"))
+  ///   .values_bind("(?, ?)", ("bar", None::<&str>))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "VALUES ($1, $2), ($3, $4)";
+  /// # assert_eq!(expected, insert_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("Foo"), sql::Value::from("bar"), sql::Value::Null], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES ($1, $2), ($3, $4)
+  /// ```
+  pub fn values_bind(mut self, expression: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._values, bind::bind(expression, values));
     self._values_variance = ValuesVariance::InsertValues;
 
     self
   }
+
+  /// Writes the current state of the [Insert] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// insert.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nINSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Insert] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Insert::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')");
+  ///
+  /// let mut output = Vec::new();
+  /// insert.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -412,6 +993,33 @@ impl WithQuery for Insert {}
 #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl Insert {
+  /// Gets the action of the `on conflict` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .on_conflict("do nothing")
+  ///   .returning("id");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("do nothing")), insert.get_on_conflict());
+  /// # assert_eq!(["id"], insert.get_returning());
+  /// # }
+  /// ```
+  pub fn get_on_conflict(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._on_conflict)
+  }
+
+  /// Gets the output names of the `returning` clause
+  pub fn get_returning(&self) -> &[Fragment] {
+    &self._returning
+  }
+
   /// The `on conflict` clause. This method overrides the previous value
   ///
   /// # Example
@@ -435,8 +1043,8 @@ impl Insert {
   /// ```sql
   /// INSERT INTO users (login) ON CONFLICT do nothing
   /// ```
-  pub fn on_conflict(mut self, conflict: &str) -> Self {
-    self._on_conflict = conflict.trim().to_string();
+  pub fn on_conflict(mut self, conflict: impl Into<Fragment>) -> Self {
+    self._on_conflict = conflict.into().trim();
     self
   }
 
@@ -464,8 +1072,8 @@ impl Insert {
   /// ```sql
   /// INSERT INTO users RETURNING id, login
   /// ```
-  pub fn returning(mut self, output_name: &str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_string());
+  pub fn returning(mut self, output_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._returning, output_name.into().trim());
     self
   }
 
@@ -515,8 +1123,8 @@ impl Insert {
   /// FROM active_users
   /// ```
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub fn with(mut self, name: &str, query: impl WithQuery + 'static + Send + Sync) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Fragment>, query: impl WithQuery + 'static + Send + Sync) -> Self {
+    self._with.push((name.into().trim(), std::sync::Arc::new(query)));
     self
   }
 }
@@ -536,14 +1144,14 @@ impl Insert {
   ///   .insert_or("abort into users (login, name)");
   /// #
   /// # let expected = "INSERT OR abort into users (login, name)";
-  /// # assert_eq!(expected, insert.to_string());
+  /// # assert_eq!(expected, insert.to_sql(sql::Dialect::Sqlite));
   ///
   /// let insert = sql::Insert::new()
   ///   .insert_or("fail into addresses (state, country)")
   ///   .insert_or("abort into users (login, name)");
   ///
   /// # let expected = "INSERT OR abort into users (login, name)";
-  /// # assert_eq!(expected, insert.to_string());
+  /// # assert_eq!(expected, insert.to_sql(sql::Dialect::Sqlite));
   /// # }
   /// ```
   ///
@@ -552,8 +1160,8 @@ impl Insert {
   /// ```sql
   /// INSERT OR abort into users (login, name)
   /// ```
-  pub fn insert_or(mut self, expression: &str) -> Self {
-    self._insert_or = expression.trim().to_string();
+  pub fn insert_or(mut self, expression: impl Into<Fragment>) -> Self {
+    self._insert_or = expression.into().trim();
     self._insert_variance = InsertVariance::InsertOr;
     self
   }
@@ -570,7 +1178,7 @@ impl Insert {
   ///   .replace_into("users (login, name)");
   /// #
   /// # let expected = "REPLACE INTO users (login, name)";
-  /// # assert_eq!(expected, insert.to_string());
+  /// # assert_eq!(expected, insert.to_sql(sql::Dialect::Sqlite));
   /// # }
   /// ```
   ///
@@ -579,8 +1187,8 @@ impl Insert {
   /// ```sql
   /// REPLACE INTO users (login, name)
   /// ```
-  pub fn replace_into(mut self, table_name: &str) -> Self {
-    self._replace_into = table_name.trim().to_string();
+  pub fn replace_into(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._replace_into = table_name.into().trim();
     self._insert_variance = InsertVariance::ReplaceInto;
     self
   }
@@ -601,7 +1209,7 @@ impl Insert {
   ///   .into("users")
   ///   .column("login")
   ///   .column("name")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "INTO users (login, name)";
   /// # assert_eq!(expected, query);
@@ -613,12 +1221,17 @@ impl Insert {
   /// ```sql
   /// INTO users (login, name)
   /// ```
-  pub fn column(mut self, column_name: &str) -> Self {
-    push_unique(&mut self._column, column_name.trim().to_string());
+  pub fn column(mut self, column_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._column, column_name.into().trim());
     self._insert_variance = InsertVariance::InsertSplitted;
     self
   }
 
+  /// Gets the columns of the `column` clause
+  pub fn get_column(&self) -> &[Fragment] {
+    &self._column
+  }
+
   /// The `insert` clause, used to defined modifiers to change de insert execution
   ///
   /// ### Example
@@ -631,7 +1244,7 @@ impl Insert {
   ///   .insert("LOW_PRIORITY")
   ///   .into("users")
   ///   .column("login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "INSERT LOW_PRIORITY INTO users (login)";
   /// # assert_eq!(expected, query);
@@ -643,8 +1256,8 @@ impl Insert {
   /// ```sql
   /// INSERT LOW_PRIORITY INTO users (login)
   /// ```
-  pub fn insert(mut self, modifier: &str) -> Self {
-    self._insert = modifier.trim().to_string();
+  pub fn insert(mut self, modifier: impl Into<Fragment>) -> Self {
+    self._insert = modifier.into().trim();
     self._insert_variance = InsertVariance::InsertSplitted;
     self
   }
@@ -660,7 +1273,7 @@ impl Insert {
   /// let query = sql::Insert::new()
   ///   .into("users")
   ///   .column("login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "INTO users (login)";
   /// # assert_eq!(expected, query);
@@ -672,8 +1285,8 @@ impl Insert {
   /// ```sql
   /// INTO users (login)
   /// ```
-  pub fn into(mut self, table: &str) -> Self {
-    self._into = table.trim().to_string();
+  pub fn into(mut self, table: impl Into<Fragment>) -> Self {
+    self._into = table.into().trim();
     self._insert_variance = InsertVariance::InsertSplitted;
     self
   }
@@ -689,7 +1302,7 @@ impl Insert {
   /// let query = sql::Insert::new()
   ///   .into("employees")
   ///   .partition("p1")
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "INTO employees PARTITION (p1)";
   /// # assert_eq!(expected, query);
@@ -701,9 +1314,9 @@ impl Insert {
   /// ```sql
   /// INTO employees PARTITION (p1)
   /// ```
-  pub fn partition(mut self, name: &str) -> Self {
-    push_unique(&mut self._partition, name.trim().to_string());
-    self._insert_into = "".to_string();
+  pub fn partition(mut self, name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._partition, name.into().trim());
+    self._insert_into = Fragment::new();
     self
   }
 
@@ -719,7 +1332,7 @@ impl Insert {
   ///   .insert_into("t1 (a, b, c)")
   ///   .values("(1, 2, 3)")
   ///   .on_duplicate_key_update("c = c+1")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "\
   /// #   INSERT INTO t1 (a, b, c) \
@@ -737,8 +1350,8 @@ impl Insert {
   /// VALUES (1, 2, 3)
   /// ON DUPLICATE KEY UPDATE c = c+1
   /// ```
-  pub fn on_duplicate_key_update(mut self, assignment: &str) -> Self {
-    push_unique(&mut self._on_duplicate_key_update, assignment.trim().to_string());
+  pub fn on_duplicate_key_update(mut self, assignment: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._on_duplicate_key_update, assignment.into().trim());
     self
   }
 
@@ -754,7 +1367,7 @@ impl Insert {
   ///   .insert_into("users (login, name)")
   ///   .row("('foo', 'Foo')")
   ///   .row("('bar', 'Bar')")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "INSERT INTO users (login, name) VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
   /// # assert_eq!(expected, query);
@@ -766,12 +1379,17 @@ impl Insert {
   /// ```sql
   /// INSERT INTO users (login, name) VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')
   /// ```
-  pub fn row(mut self, expression: &str) -> Self {
-    push_unique(&mut self._values, expression.trim().to_string());
+  pub fn row(mut self, expression: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._values, expression.into().trim());
     self._values_variance = ValuesVariance::InsertValuesRow;
     self
   }
 
+  /// Gets the assignments of the `set` clause
+  pub fn get_set(&self) -> &[Fragment] {
+    &self._set
+  }
+
   /// The `set` clause
   ///
   /// # Example
@@ -782,7 +1400,7 @@ impl Insert {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Insert::new()
   ///   .set("name = 'Bar'")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "SET name = 'Bar'";
   /// # assert_eq!(expected, update_query);
@@ -794,8 +1412,8 @@ impl Insert {
   /// ```sql
   /// SET name = 'Bar'
   /// ```
-  pub fn set(mut self, assignment: &str) -> Self {
-    push_unique(&mut self._set, assignment.trim().to_string());
+  pub fn set(mut self, assignment: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._set, assignment.into().trim());
     self._values_variance = ValuesVariance::InsertSet;
     self
   }
@@ -810,6 +1428,6 @@ impl std::fmt::Display for Insert {
 impl std::fmt::Debug for Insert {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,297 +1,298 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{Insert, InsertClause, ValuesVariance},
+  introspection::{CollectTables, Tables},
+  pretty::Body,
+  structure::{BuildError, Dialect, Insert, InsertClause, InsertVariance, ValuesVariance},
+  utils,
 };
-
-#[cfg(any(feature = "sqlite", feature = "mysql"))]
-use crate::structure::InsertVariance;
+use std::fmt::Write;
 
 impl Concat for Insert {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
-
-    #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_insert_into(query, &fmts);
-      query = self.concat_overriding(query, &fmts);
-      match self._values_variance {
-        ValuesVariance::InsertDefaultValues => {
-          query = self.concat_default_values(query, &fmts);
-        }
-        ValuesVariance::InsertSelect => {
-          query = self.concat_select(query, &fmts);
-        }
-        ValuesVariance::InsertValues => {
-          query = self.concat_values(query, &fmts);
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
+
+    match fmts.dialect {
+      Dialect::Standard => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_insert_into(w, &fmts)?;
+        #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+        {
+          self.concat_overriding(w, &fmts)?;
         }
+        self.concat_values_variance(w, &fmts)?;
       }
-    }
 
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        InsertClause::With,
-        &self._with,
-      );
-      query = self.concat_insert_into(query, &fmts);
-      query = self.concat_overriding(query, &fmts);
-      match self._values_variance {
-        ValuesVariance::InsertDefaultValues => {
-          query = self.concat_default_values(query, &fmts);
-        }
-        ValuesVariance::InsertSelect => {
-          query = self.concat_select(query, &fmts);
-        }
-        ValuesVariance::InsertValues => {
-          query = self.concat_values(query, &fmts);
-        }
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          InsertClause::With,
+          &self._with,
+        )?;
+        self.concat_insert_into(w, &fmts)?;
+        self.concat_overriding(w, &fmts)?;
+        self.concat_values_variance(w, &fmts)?;
+        self.concat_on_conflict(w, &fmts)?;
+        self.concat_returning(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          InsertClause::Returning,
+          &self._returning,
+        )?;
       }
-      query = self.concat_on_conflict(query, &fmts);
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        InsertClause::Returning,
-        &self._returning,
-      );
-    }
 
-    #[cfg(feature = "sqlite")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        InsertClause::With,
-        &self._with,
-      );
-      match self._insert_variance {
-        InsertVariance::InsertInto => {
-          query = self.concat_insert_into(query, &fmts);
-        }
-        InsertVariance::InsertOr => {
-          query = self.concat_insert_or(query, &fmts);
-        }
-        InsertVariance::ReplaceInto => {
-          query = self.concat_replace_into(query, &fmts);
-        }
-      }
-      match self._values_variance {
-        ValuesVariance::InsertDefaultValues => {
-          query = self.concat_default_values(query, &fmts);
-        }
-        ValuesVariance::InsertSelect => {
-          query = self.concat_select(query, &fmts);
-        }
-        ValuesVariance::InsertValues => {
-          query = self.concat_values(query, &fmts);
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          InsertClause::With,
+          &self._with,
+        )?;
+        match self._insert_variance {
+          InsertVariance::InsertInto => {
+            self.concat_insert_into(w, &fmts)?;
+          }
+          InsertVariance::InsertOr => {
+            self.concat_insert_or(w, &fmts)?;
+          }
+          InsertVariance::ReplaceInto => {
+            self.concat_replace_into(w, &fmts)?;
+          }
+          #[cfg(feature = "mysql")]
+          InsertVariance::InsertSplitted => {
+            self.concat_insert(w, &fmts)?;
+            self.concat_into(w, &fmts)?;
+          }
         }
+        self.concat_values_variance(w, &fmts)?;
+        self.concat_on_conflict(w, &fmts)?;
+        self.concat_returning(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          InsertClause::Returning,
+          &self._returning,
+        )?;
       }
-      query = self.concat_on_conflict(query, &fmts);
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        InsertClause::Returning,
-        &self._returning,
-      );
-    }
 
-    #[cfg(feature = "mysql")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      match self._insert_variance {
-        InsertVariance::InsertInto => {
-          query = self.concat_insert_into(query, &fmts);
-        }
-        InsertVariance::InsertSplitted => {
-          query = self.concat_insert(query, &fmts);
-          query = self.concat_into(query, &fmts);
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        match self._insert_variance {
+          InsertVariance::InsertInto => {
+            self.concat_insert_into(w, &fmts)?;
+          }
+          InsertVariance::InsertSplitted => {
+            self.concat_insert(w, &fmts)?;
+            self.concat_into(w, &fmts)?;
+          }
+          #[cfg(feature = "sqlite")]
+          InsertVariance::InsertOr => {
+            self.concat_insert_into(w, &fmts)?;
+          }
+          #[cfg(feature = "sqlite")]
+          InsertVariance::ReplaceInto => {
+            self.concat_replace_into(w, &fmts)?;
+          }
         }
-      }
 
-      query = self.concat_partition(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        InsertClause::Partition,
-        &self._partition,
-      );
-
-      match self._values_variance {
-        ValuesVariance::InsertSelect => {
-          if self._insert_variance == InsertVariance::InsertSplitted {
-            query = self.concat_column(
-              &self._raw_before,
-              &self._raw_after,
-              query,
-              &fmts,
-              InsertClause::Column,
-              &self._column,
-            );
+        self.concat_partition(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          InsertClause::Partition,
+          &self._partition,
+        )?;
+
+        match self._values_variance {
+          ValuesVariance::InsertSelect => {
+            if self._insert_variance == InsertVariance::InsertSplitted {
+              self.concat_column(
+                &self._raw_before,
+                &self._raw_after,
+                w,
+                &fmts,
+                InsertClause::Column,
+                &self._column,
+              )?;
+            }
+            self.concat_select(w, &fmts)?;
           }
-          query = self.concat_select(query, &fmts);
-        }
-        ValuesVariance::InsertSet => {
-          query = self.concat_set(
-            &self._raw_before,
-            &self._raw_after,
-            query,
-            &fmts,
-            InsertClause::Set,
-            &self._set,
-          );
-        }
-        ValuesVariance::InsertValues | ValuesVariance::InsertValuesRow => {
-          if self._insert_variance == InsertVariance::InsertSplitted {
-            query = self.concat_column(
+          ValuesVariance::InsertSet => {
+            self.concat_set(
               &self._raw_before,
               &self._raw_after,
-              query,
+              w,
               &fmts,
-              InsertClause::Column,
-              &self._column,
-            );
+              InsertClause::Set,
+              &self._set,
+            )?;
+          }
+          ValuesVariance::InsertValues | ValuesVariance::InsertValuesRow => {
+            if self._insert_variance == InsertVariance::InsertSplitted {
+              self.concat_column(
+                &self._raw_before,
+                &self._raw_after,
+                w,
+                &fmts,
+                InsertClause::Column,
+                &self._column,
+              )?;
+            }
+            self.concat_values(w, &fmts)?;
           }
-          query = self.concat_values(query, &fmts);
+          // MySQL doesn't have the DEFAULT VALUES clause
+          #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+          ValuesVariance::InsertDefaultValues => {}
         }
-      }
 
-      query = self.concat_on_duplicate_key_update(query, &fmts);
+        self.concat_on_duplicate_key_update(w, &fmts)?;
+      }
     }
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl Insert {
-  fn concat_insert_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_insert_into(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._insert_into.is_empty() == false {
-      let expression = &self._insert_into;
-      format!("INSERT INTO{space}{expression}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::InsertInto,
-      sql,
+      |w| {
+        if self._insert_into.is_empty() == false {
+          w.write_clause("INSERT INTO", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._insert_into)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
-  fn concat_overriding(&self, query: String, fmts: &fmt::Formatter) -> String {
+  #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+  fn concat_overriding(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._overriding.is_empty() == false {
-      let overriding = &self._overriding;
-      format!("OVERRIDING{space}{overriding}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::Overriding,
-      sql,
+      |w| {
+        if self._overriding.is_empty() == false {
+          w.write_clause("OVERRIDING", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._overriding)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_select(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = self._select.as_ref().map_or("".to_string(), |select| {
-      let select_string = select.concat(fmts);
-      format!("{select_string}{space}{lb}")
-    });
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::Select,
-      sql,
+      |w| {
+        if let Some(select) = &self._select {
+          w.write_query(|w| select.concat(w, fmts))?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  #[cfg(not(feature = "mysql"))]
-  fn concat_default_values(&self, query: String, fmts: &fmt::Formatter) -> String {
+  #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+  fn concat_default_values(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = format!("DEFAULT VALUES{space}{lb}");
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::DefaultValues,
-      sql,
+      |w| {
+        w.write_clause("DEFAULT VALUES", Body::Text)?;
+        write!(w, "{space}{lb}")
+      },
     )
   }
 
-  fn concat_values(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_values(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._values.is_empty() == false {
-      let sep = format!("{comma}{lb}");
-      let rows = self
-        ._values
-        .iter()
-        .filter(|item| item.is_empty() == false)
-        .map(|item| {
-          #[cfg(not(feature = "mysql"))]
-          {
-            item.clone()
-          }
-
-          #[cfg(feature = "mysql")]
-          {
-            if self._values_variance == ValuesVariance::InsertValuesRow {
-              format!("ROW{item}")
-            } else {
-              item.clone()
-            }
-          }
-        })
-        .collect::<Vec<_>>()
-        .join(&sep);
-
-      if rows.is_empty() == true {
-        return "".to_string();
-      }
-
-      format!("VALUES{space}{lb}{rows}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::Values,
-      sql,
+      |w| {
+        let start = w.mark();
+        let rows = self._values.iter().filter(|item| item.is_empty() == false);
+        for (index, item) in rows.enumerate() {
+          if index == 0 {
+            w.write_clause("VALUES", Body::List)?;
+            write!(w, "{space}{lb}")?;
+          } else {
+            w.write_separator(comma)?;
+            w.write_str(lb)?;
+          }
+          #[cfg(feature = "mysql")]
+          if fmts.dialect == Dialect::Mysql && self._values_variance == ValuesVariance::InsertValuesRow {
+            w.write_str("ROW")?;
+          }
+          w.write_fragment(item)?;
+        }
+        if w.has_written_since(start) {
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
+
+  fn concat_values_variance(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    match self._values_variance {
+      #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+      ValuesVariance::InsertDefaultValues => self.concat_default_values(w, fmts),
+      ValuesVariance::InsertSelect => self.concat_select(w, fmts),
+      ValuesVariance::InsertValues => self.concat_values(w, fmts),
+      #[cfg(feature = "mysql")]
+      ValuesVariance::InsertSet | ValuesVariance::InsertValuesRow => self.concat_values(w, fmts),
+    }
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -304,72 +305,75 @@ impl ConcatWith<InsertClause> for Insert {}
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 impl Insert {
-  fn concat_on_conflict(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_on_conflict(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._on_conflict.is_empty() == false {
-      let overriding = &self._on_conflict;
-      format!("ON CONFLICT{space}{overriding}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::OnConflict,
-      sql,
+      |w| {
+        if self._on_conflict.is_empty() == false {
+          w.write_clause("ON CONFLICT", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._on_conflict)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
 
 #[cfg(feature = "sqlite")]
 impl Insert {
-  fn concat_insert_or(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_insert_or(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._insert_or.is_empty() == false {
-      let expression = &self._insert_or;
-      format!("INSERT OR{space}{expression}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::InsertOr,
-      sql,
+      |w| {
+        if self._insert_or.is_empty() == false {
+          w.write_clause("INSERT OR", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._insert_or)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_replace_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_replace_into(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._replace_into.is_empty() == false {
-      let table_name = &self._replace_into;
-      format!("REPLACE INTO{space}{table_name}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::ReplaceInto,
-      sql,
+      |w| {
+        if self._replace_into.is_empty() == false {
+          w.write_clause("REPLACE INTO", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._replace_into)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
 
 #[cfg(feature = "mysql")]
-use crate::{
-  concat::{mysql::ConcatPartition, non_standard::ConcatColumn, sql_standard::ConcatSet},
-  utils,
-};
+use crate::concat::{mysql::ConcatPartition, non_standard::ConcatColumn, sql_standard::ConcatSet};
 
 #[cfg(feature = "mysql")]
 impl ConcatColumn<InsertClause> for Insert {}
@@ -380,60 +384,223 @@ impl ConcatSet<InsertClause> for Insert {}
 
 #[cfg(feature = "mysql")]
 impl Insert {
-  fn concat_insert(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_insert(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
-    let sql = if self._insert.is_empty() == false {
-      let modifiers = &self._insert;
-      format!("INSERT{space}{modifiers}{space}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::Insert,
-      sql,
+      |w| {
+        if self._insert.is_empty() == false {
+          w.write_clause("INSERT", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._insert)?;
+          write!(w, "{space}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_into(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
-    let sql = if self._into.is_empty() == false {
-      let table_name = &self._into;
-      format!("INTO{space}{table_name}{space}")
-    } else {
-      "".to_string()
-    };
 
-    concat_raw_before_after(
-      &self._raw_before,
-      &self._raw_after,
-      query,
-      fmts,
-      InsertClause::Into,
-      sql,
-    )
+    concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, InsertClause::Into, |w| {
+      if self._into.is_empty() == false {
+        w.write_clause("INTO", Body::Text)?;
+        w.write_str(space)?;
+        w.write_fragment(&self._into)?;
+        write!(w, "{space}")?;
+      }
+      Ok(())
+    })
   }
 
-  fn concat_on_duplicate_key_update(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_on_duplicate_key_update(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._on_duplicate_key_update.is_empty() == false {
-      let values = utils::join(&self._on_duplicate_key_update, comma);
-      format!("ON DUPLICATE KEY UPDATE{space}{values}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       InsertClause::OnDuplicateKeyUpdate,
-      sql,
+      |w| {
+        if self._on_duplicate_key_update.is_empty() == false {
+          w.write_clause("ON DUPLICATE KEY UPDATE", Body::List)?;
+          w.write_str(space)?;
+          w.write_items(&self._on_duplicate_key_update, comma)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
+
+impl Validate for Insert {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    #[allow(unused_mut)]
+    let mut values_clauses = vec![
+      ("VALUES", self._values.is_empty() == false),
+      ("SELECT", self._select.is_some()),
+    ];
+    #[cfg(feature = "mysql")]
+    values_clauses.push(("SET", self._set.is_empty() == false));
+
+    utils::exclusive_clauses("INSERT", &values_clauses)?;
+    self.check_support(dialect)?;
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) == false {
+      let (clause, is_missing) = match self._insert_variance {
+        InsertVariance::InsertInto => ("INSERT INTO", self._insert_into.is_empty()),
+        #[cfg(feature = "sqlite")]
+        InsertVariance::InsertOr => ("INSERT OR", self._insert_or.is_empty()),
+        #[cfg(feature = "sqlite")]
+        InsertVariance::ReplaceInto => ("REPLACE INTO", self._replace_into.is_empty()),
+        #[cfg(feature = "mysql")]
+        InsertVariance::InsertSplitted => ("INTO", self._into.is_empty()),
+      };
+      utils::require_clause("INSERT", clause, is_missing)?;
+
+      let (clause, is_missing) = match self._values_variance {
+        ValuesVariance::InsertValues => ("VALUES", self._values.is_empty()),
+        ValuesVariance::InsertSelect => ("SELECT", self._select.is_none()),
+        #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+        ValuesVariance::InsertDefaultValues => ("DEFAULT VALUES", false),
+        #[cfg(feature = "mysql")]
+        ValuesVariance::InsertSet => ("SET", self._set.is_empty()),
+        #[cfg(feature = "mysql")]
+        ValuesVariance::InsertValuesRow => ("VALUES", self._values.is_empty()),
+      };
+      utils::require_clause("INSERT", clause, is_missing)?;
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    for (_, query) in &self._with {
+      query.check_clauses(dialect)?;
+    }
+
+    match &self._select {
+      Some(select) => select.check_clauses(dialect),
+      None => Ok(()),
+    }
+  }
+}
+
+impl Insert {
+  /// Checks if the dialect supports the clauses specific to some dialects
+  fn check_support(&self, dialect: Dialect) -> Result<(), BuildError> {
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      let is_supported = utils::is_postgres(dialect) || utils::is_sqlite(dialect);
+      utils::require_support(
+        dialect,
+        "ON CONFLICT",
+        self._on_conflict.is_empty() == false,
+        is_supported,
+      )?;
+      utils::require_support(dialect, "RETURNING", self._returning.is_empty() == false, is_supported)?;
+    }
+
+    #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+    utils::require_support(
+      dialect,
+      "OVERRIDING",
+      self._overriding.is_empty() == false,
+      dialect == Dialect::Standard || utils::is_postgres(dialect),
+    )?;
+
+    #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+    utils::require_support(
+      dialect,
+      "DEFAULT VALUES",
+      self._values_variance == ValuesVariance::InsertDefaultValues,
+      utils::is_mysql(dialect) == false,
+    )?;
+
+    #[cfg(feature = "sqlite")]
+    {
+      utils::require_support(
+        dialect,
+        "INSERT OR",
+        self._insert_variance == InsertVariance::InsertOr,
+        utils::is_sqlite(dialect),
+      )?;
+      utils::require_support(
+        dialect,
+        "REPLACE INTO",
+        self._insert_variance == InsertVariance::ReplaceInto,
+        utils::is_sqlite(dialect) || utils::is_mysql(dialect),
+      )?;
+    }
+
+    #[cfg(feature = "mysql")]
+    {
+      let is_supported = utils::is_mysql(dialect);
+      utils::require_support(
+        dialect,
+        "ON DUPLICATE KEY UPDATE",
+        self._on_duplicate_key_update.is_empty() == false,
+        is_supported,
+      )?;
+      utils::require_support(dialect, "PARTITION", self._partition.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "SET", self._set.is_empty() == false, is_supported)?;
+      utils::require_support(
+        dialect,
+        "VALUES ROW",
+        self._values_variance == ValuesVariance::InsertValuesRow,
+        is_supported,
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+impl CollectTables for Insert {
+  fn collect_tables(&self, tables: &mut Tables) {
+    self._raw.iter().for_each(|raw| tables.raw(raw));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    for (name, query) in self._with.iter() {
+      tables.cte(name);
+      query.collect_tables(tables);
+    }
+
+    tables.clause("INTO", &self._insert_into);
+
+    #[cfg(feature = "sqlite")]
+    {
+      tables.clause("INSERT OR", &self._insert_or);
+      tables.clause("INTO", &self._replace_into);
+    }
+
+    #[cfg(feature = "mysql")]
+    {
+      tables.clause("INTO", &self._into);
+      self._set.iter().for_each(|assignment| tables.expr(assignment));
+      self
+        ._on_duplicate_key_update
+        .iter()
+        .for_each(|assignment| tables.expr(assignment));
+    }
+
+    self._values.iter().for_each(|row| tables.expr(row));
+    if let Some(select) = &self._select {
+      select.collect_tables(tables);
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      tables.expr(&self._on_conflict);
+      self._returning.iter().for_each(|column| tables.expr(column));
+    }
+
+    let raw_before_after = self._raw_before.iter().chain(self._raw_after.iter());
+    raw_before_after.for_each(|(_, raw)| tables.raw(raw));
+  }
+}
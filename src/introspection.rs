@@ -0,0 +1,264 @@
+use crate::{
+  highlight,
+  structure::{Dialect, Fragment, Part, TokenKind},
+};
+
+// The keywords that introduce the name of a table, the `update` keyword also starts the assignments
+// of an upsert, so it's not considered when preceded by one of the ignored keywords. The `from` of
+// the `is distinct from` operator is not a table either
+const TABLE_KEYWORDS: [&str; 5] = ["FROM", "INTO", "JOIN", "UPDATE", "USING"];
+const NOT_UPDATE_TABLE_KEYWORDS: [&str; 5] = ["DO", "FOR", "KEY", "ON", "THEN"];
+
+struct Token<'a> {
+  kind: TokenKind,
+  text: &'a str,
+  spaced: bool,
+}
+
+struct Frame {
+  from_list: bool,
+  is_query: bool,
+}
+
+/// Gets the names of the tables referenced by the command, including the tables of the sub-queries and excluding
+/// the names of the common table expressions. The names are unquoted and listed once in the order they are found
+pub(crate) fn tables(command: &impl CollectTables) -> Vec<String> {
+  let mut tables = Tables::default();
+  command.collect_tables(&mut tables);
+  tables.into_names()
+}
+
+/// Represents the commands whose tables are collected walking the clauses of the builder
+pub trait CollectTables {
+  fn collect_tables(&self, tables: &mut Tables);
+}
+
+/// The names of the tables referenced by a command, each clause of the builder is added with its role,
+/// the names of the tables are read only from the clauses that reference tables and from the sub-queries
+#[derive(Default)]
+pub struct Tables {
+  ctes: Vec<String>,
+  names: Vec<String>,
+}
+
+impl Tables {
+  /// Adds a clause that references tables, the keyword is the one that introduces the table in the clause,
+  /// like the `FROM` of the `from` clause or the `INTO` of the `insert into` clause
+  pub(crate) fn clause(&mut self, keyword: &str, fragment: &Fragment) {
+    if fragment.is_empty() == false {
+      let text = self.text(fragment);
+      self.scan(&format!("{keyword} {text}"), true);
+    }
+  }
+
+  /// Adds a common table expression, its name is not a table of the command
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  pub(crate) fn cte(&mut self, name: &Fragment) {
+    self.ctes.push(unquote(name.to_sql(Dialect::default()).trim()));
+  }
+
+  /// Adds an expression, only the tables of the sub-queries inside the expression are read
+  pub(crate) fn expr(&mut self, fragment: &Fragment) {
+    let text = self.text(fragment);
+    self.scan(&text, false);
+  }
+
+  /// Adds the raw SQL, its role is unknown so the tables are read like in a whole query
+  pub(crate) fn raw(&mut self, fragment: &Fragment) {
+    let text = self.text(fragment);
+    self.scan(&text, true);
+  }
+
+  /// The tables found, each one listed once and without the common table expressions
+  fn into_names(self) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for table in self.names {
+      if self.ctes.contains(&table) == false && names.contains(&table) == false {
+        names.push(table);
+      }
+    }
+
+    names
+  }
+
+  /// The text of the fragment, the tables of the sub-queries are collected and the sub-queries are
+  /// replaced by empty parentheses
+  fn text(&mut self, fragment: &Fragment) -> String {
+    let mut text = String::new();
+
+    for part in fragment._parts.iter() {
+      match part {
+        Part::SubQuery(select) => {
+          select.collect_tables(self);
+          text.push_str("()");
+        }
+        Part::Variants(variants) => {
+          if let Some(variant) = variants.first() {
+            text.push_str(&self.text(variant));
+          }
+        }
+        part => text.push_str(&Fragment::from(part.clone()).to_sql(Dialect::default())),
+      }
+    }
+
+    text
+  }
+
+  /// Reads the tables of the SQL text, when the text is not a query only the tables of the sub-queries are read
+  fn scan(&mut self, sql: &str, is_query: bool) {
+    let tokens = significant_tokens(sql);
+    let mut frames = vec![Frame {
+      from_list: false,
+      is_query,
+    }];
+    let mut index = 0;
+
+    while index < tokens.len() {
+      let token = &tokens[index];
+
+      if token.text == "(" {
+        let is_query = tokens
+          .get(index + 1)
+          .map_or(false, |next| is_word(next, "SELECT") || is_word(next, "WITH"));
+        frames.push(Frame {
+          from_list: false,
+          is_query,
+        });
+        index += 1;
+        continue;
+      }
+
+      if token.text == ")" {
+        if frames.len() > 1 {
+          frames.pop();
+        }
+        index += 1;
+        continue;
+      }
+
+      let frame = frames.last_mut().unwrap();
+      if frame.is_query == false {
+        index += 1;
+        continue;
+      }
+
+      let is_cte = is_name(token)
+        && tokens.get(index + 1).map_or(false, |next| is_word(next, "AS"))
+        && tokens.get(index + 2).map_or(false, |next| next.text == "(");
+      if is_cte {
+        self.ctes.push(unquote(token.text));
+      } else if token.kind == TokenKind::Keyword {
+        let keyword = token.text.to_uppercase();
+        if keyword != "AS" {
+          frame.from_list = keyword == "FROM";
+        }
+
+        let previous = index.checked_sub(1).map(|previous| &tokens[previous]);
+        let is_table = match keyword.as_str() {
+          "UPDATE" => previous.map_or(true, |previous| {
+            NOT_UPDATE_TABLE_KEYWORDS
+              .iter()
+              .any(|ignored| is_word(previous, ignored))
+              == false
+          }),
+          "FROM" => previous.map_or(true, |previous| is_word(previous, "DISTINCT") == false),
+          keyword => TABLE_KEYWORDS.contains(&keyword),
+        };
+
+        if is_table {
+          let has_functions = keyword == "FROM" || keyword == "JOIN" || keyword == "USING";
+          index = read_table(&tokens, index + 1, has_functions, &mut self.names);
+          continue;
+        }
+      } else if token.text == "," && frame.from_list {
+        index = read_table(&tokens, index + 1, true, &mut self.names);
+        continue;
+      }
+
+      index += 1;
+    }
+  }
+}
+
+/// Reads the name of the table starting at the index, where the functions are allowed a name followed
+/// by parentheses is a function and is skipped. The name is read whatever its token kind, the tables can have
+/// the name of a keyword like `work` or `key`. Returns the index of the token after the name and its alias
+fn read_table(tokens: &[Token], mut index: usize, has_functions: bool, tables: &mut Vec<String>) -> usize {
+  while let Some(token) = tokens.get(index) {
+    if is_word(token, "ONLY") || is_word(token, "LATERAL") {
+      index += 1;
+    } else if is_word(token, "OR") {
+      // the conflict resolution of the SQLite `update or replace`
+      index += 2;
+    } else {
+      break;
+    }
+  }
+
+  let mut parts = vec![];
+  while let Some(token) = tokens.get(index) {
+    if is_name(token) == false {
+      break;
+    }
+    parts.push(unquote(token.text));
+    index += 1;
+
+    let is_qualified = tokens.get(index).map_or(false, |next| next.text == ".");
+    if is_qualified == false {
+      break;
+    }
+    index += 1;
+  }
+
+  let is_function = has_functions
+    && tokens
+      .get(index)
+      .map_or(false, |next| next.text == "(" && next.spaced == false);
+  if parts.is_empty() == false && is_function == false {
+    tables.push(parts.join("."));
+
+    let has_alias =
+      tokens.get(index).map_or(false, |next| is_word(next, "AS")) && tokens.get(index + 1).map_or(false, is_name);
+    if has_alias {
+      index += 2;
+    }
+  }
+
+  index
+}
+
+fn significant_tokens(sql: &str) -> Vec<Token<'_>> {
+  let mut tokens = vec![];
+  let mut spaced = false;
+
+  for (kind, text) in highlight::tokenize(sql) {
+    match kind {
+      TokenKind::Whitespace | TokenKind::Comment => spaced = true,
+      _ => {
+        tokens.push(Token { kind, text, spaced });
+        spaced = false;
+      }
+    }
+  }
+
+  tokens
+}
+
+fn is_name(token: &Token) -> bool {
+  token.kind == TokenKind::Identifier || token.kind == TokenKind::Keyword
+}
+
+fn is_word(token: &Token, word: &str) -> bool {
+  token.kind != TokenKind::Literal && token.text.eq_ignore_ascii_case(word)
+}
+
+/// Removes the quotes of a quoted identifier, the doubled quotes inside the identifier are unescaped
+fn unquote(text: &str) -> String {
+  match text.chars().next() {
+    Some(quote) if (quote == '"' || quote == '`') && text.len() > 1 && text.ends_with(quote) => {
+      let escaped_quote = format!("{quote}{quote}");
+      text[1..text.len() - 1].replace(&escaped_quote, &quote.to_string())
+    }
+    _ => text.to_string(),
+  }
+}
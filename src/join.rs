@@ -0,0 +1,467 @@
+use crate::{
+  behavior::Validate,
+  concat::Concat,
+  fmt,
+  introspection::{CollectTables, Tables},
+  pretty::Body,
+  structure::{BuildError, Dialect, Fragment, Join, JoinKind, LogicalOperator, Select},
+  utils::{self, push_unique},
+};
+use std::{fmt::Write, sync::Arc};
+
+impl Join {
+  /// Creates a `cross join` with the table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::cross("colors");
+  ///
+  /// # assert_eq!("CROSS JOIN colors", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CROSS JOIN colors
+  /// ```
+  pub fn cross(table: impl Into<Fragment>) -> Self {
+    Self::new(JoinKind::Cross, table)
+  }
+
+  /// Creates a `full join` with the table, MySQL doesn't support it and the validation of the command reports it
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::full("addresses").on("addresses.user_id = users.id");
+  ///
+  /// # let expected = "FULL JOIN addresses ON addresses.user_id = users.id";
+  /// # assert_eq!(expected, join.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FULL JOIN addresses ON addresses.user_id = users.id
+  /// ```
+  pub fn full(table: impl Into<Fragment>) -> Self {
+    Self::new(JoinKind::Full, table)
+  }
+
+  /// Creates an `inner join` with the table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::inner("addresses").on("addresses.user_id = users.id");
+  ///
+  /// # assert_eq!("INNER JOIN addresses ON addresses.user_id = users.id", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INNER JOIN addresses ON addresses.user_id = users.id
+  /// ```
+  pub fn inner(table: impl Into<Fragment>) -> Self {
+    Self::new(JoinKind::Inner, table)
+  }
+
+  /// Creates a `left join` with the table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::left("addresses").on("addresses.user_id = users.id");
+  ///
+  /// # assert_eq!("LEFT JOIN addresses ON addresses.user_id = users.id", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LEFT JOIN addresses ON addresses.user_id = users.id
+  /// ```
+  pub fn left(table: impl Into<Fragment>) -> Self {
+    Self::new(JoinKind::Left, table)
+  }
+
+  /// Creates a `right join` with the table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::right("addresses").on("addresses.user_id = users.id");
+  ///
+  /// # assert_eq!("RIGHT JOIN addresses ON addresses.user_id = users.id", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// RIGHT JOIN addresses ON addresses.user_id = users.id
+  /// ```
+  pub fn right(table: impl Into<Fragment>) -> Self {
+    Self::new(JoinKind::Right, table)
+  }
+
+  /// Gets the current state of the [Join] and returns it as string
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::inner("roles").using("role_id");
+  ///
+  /// # assert_eq!("INNER JOIN roles USING (role_id)", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INNER JOIN roles USING (role_id)
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Gets the current state of the [Join] and returns it as string in the syntax of the specified SQL [Dialect]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::left("addresses").natural();
+  ///
+  /// # assert_eq!("NATURAL LEFT JOIN addresses", join.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// NATURAL LEFT JOIN addresses
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (query, _) = fmt::render(self, &fmts);
+    query
+  }
+
+  /// Adds the `lateral` modifier, the sub-query of the join can reference the columns of the tables
+  /// that came before it, SQLite doesn't support it and the validation of the command reports it
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let last_order = sql::Select::new()
+  ///   .select("created_at")
+  ///   .from("orders")
+  ///   .where_clause("orders.user_id = users.id");
+  ///
+  /// let join = sql::Join::left("last_order")
+  ///   .sub_query(last_order)
+  ///   .lateral()
+  ///   .on("true");
+  ///
+  /// # let expected = "\
+  /// #   LEFT JOIN LATERAL (\
+  /// #     SELECT created_at \
+  /// #     FROM orders \
+  /// #     WHERE orders.user_id = users.id\
+  /// #   ) AS last_order ON true\
+  /// # ";
+  /// # assert_eq!(expected, join.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LEFT JOIN LATERAL (
+  ///   SELECT created_at
+  ///   FROM orders
+  ///   WHERE orders.user_id = users.id
+  /// ) AS last_order ON true
+  /// ```
+  pub fn lateral(mut self) -> Self {
+    self._lateral = true;
+    self
+  }
+
+  /// Turns the join into a `natural join`, the tables are joined by the columns with the same name
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::inner("addresses").natural();
+  ///
+  /// # assert_eq!("NATURAL INNER JOIN addresses", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// NATURAL INNER JOIN addresses
+  /// ```
+  pub fn natural(mut self) -> Self {
+    self._natural = true;
+    self
+  }
+
+  /// The `on` condition of the join, this method will concatenate multiples calls using the `and` operator.
+  /// The method accepts an [Expr](crate::Expr) through its dereferenced value `&expr`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::left("orders")
+  ///   .on("orders.user_id = users.id")
+  ///   .on("orders.status = 'paid'");
+  ///
+  /// # let expected = "LEFT JOIN orders ON orders.user_id = users.id AND orders.status = 'paid'";
+  /// # assert_eq!(expected, join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LEFT JOIN orders ON orders.user_id = users.id AND orders.status = 'paid'
+  /// ```
+  pub fn on(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._on, (LogicalOperator::And, condition.into().trim()));
+    self
+  }
+
+  /// The `on` condition of the join, this method will concatenate multiples calls using the `or` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::left("orders")
+  ///   .on("orders.user_id = users.id")
+  ///   .on_or("orders.guest_id = users.id");
+  ///
+  /// # let expected = "LEFT JOIN orders ON orders.user_id = users.id OR orders.guest_id = users.id";
+  /// # assert_eq!(expected, join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// LEFT JOIN orders ON orders.user_id = users.id OR orders.guest_id = users.id
+  /// ```
+  pub fn on_or(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._on, (LogicalOperator::Or, condition.into().trim()));
+    self
+  }
+
+  /// Joins the result of a sub-query instead of a table, the table name of the join is used as the alias
+  /// of the sub-query. The sub-query can be shared with other queries behind an [Arc], it's rendered
+  /// only when the query is rendered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let last_orders = sql::Select::new()
+  ///   .select("user_id, max(created_at) as created_at")
+  ///   .from("orders")
+  ///   .group_by("user_id");
+  ///
+  /// let join = sql::Join::inner("last_orders")
+  ///   .sub_query(last_orders)
+  ///   .using("user_id");
+  ///
+  /// # let expected = "\
+  /// #   INNER JOIN (\
+  /// #     SELECT user_id, max(created_at) as created_at \
+  /// #     FROM orders \
+  /// #     GROUP BY user_id\
+  /// #   ) AS last_orders USING (user_id)\
+  /// # ";
+  /// # assert_eq!(expected, join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INNER JOIN (
+  ///   SELECT user_id, max(created_at) as created_at
+  ///   FROM orders
+  ///   GROUP BY user_id
+  /// ) AS last_orders USING (user_id)
+  /// ```
+  pub fn sub_query(mut self, select: impl Into<Arc<Select>>) -> Self {
+    self._sub_query = Some(select.into());
+    self
+  }
+
+  /// The `using` columns of the join, this method will accumulate values on consecutive calls
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let join = sql::Join::inner("orders")
+  ///   .using("user_id")
+  ///   .using("account_id");
+  ///
+  /// # assert_eq!("INNER JOIN orders USING (user_id, account_id)", join.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INNER JOIN orders USING (user_id, account_id)
+  /// ```
+  pub fn using(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._using, column.into().trim());
+    self
+  }
+
+  fn new(kind: JoinKind, table: impl Into<Fragment>) -> Self {
+    Self {
+      _kind: kind,
+      _lateral: false,
+      _natural: false,
+      _on: vec![],
+      _sub_query: None,
+      _table: table.into().trim(),
+      _using: vec![],
+    }
+  }
+}
+
+impl Concat for Join {
+  fn concat(&self, w: &mut fmt::Writer, _fmts: &fmt::Formatter) -> fmt::Result {
+    let kind = match self._kind {
+      JoinKind::Cross => "CROSS JOIN",
+      JoinKind::Full => "FULL JOIN",
+      JoinKind::Inner => "INNER JOIN",
+      JoinKind::Left => "LEFT JOIN",
+      JoinKind::Right => "RIGHT JOIN",
+    };
+    match self._natural {
+      true => w.write_clause(&format!("NATURAL {kind}"), Body::Text)?,
+      false => w.write_clause(kind, Body::Text)?,
+    }
+    w.write_str(" ")?;
+    if self._lateral {
+      w.write_str("LATERAL ")?;
+    }
+
+    match &self._sub_query {
+      Some(select) => {
+        w.write_select(select)?;
+        if self._table.is_empty() == false {
+          w.write_str(" AS ")?;
+          w.write_fragment(&self._table)?;
+        }
+      }
+      None => w.write_fragment(&self._table)?,
+    }
+
+    let conditions = self._on.iter().filter(|(_, condition)| condition.is_empty() == false);
+    for (index, (log_op, condition)) in conditions.enumerate() {
+      if index == 0 {
+        w.write_str(" ON ")?;
+      } else {
+        write!(w, " {log_op} ")?;
+      }
+      w.write_fragment(condition)?;
+    }
+
+    if self._using.is_empty() == false {
+      w.write_str(" USING (")?;
+      w.write_joined(&self._using, ", ")?;
+      w.write_str(")")?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Validate for Join {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    utils::exclusive_clauses(
+      "JOIN",
+      &[("NATURAL", self._natural), ("ON", self._on.is_empty() == false)],
+    )?;
+    utils::exclusive_clauses(
+      "JOIN",
+      &[("NATURAL", self._natural), ("USING", self._using.is_empty() == false)],
+    )?;
+    utils::exclusive_clauses(
+      "JOIN",
+      &[
+        ("ON", self._on.is_empty() == false),
+        ("USING", self._using.is_empty() == false),
+      ],
+    )?;
+    utils::exclusive_clauses(
+      "JOIN",
+      &[("NATURAL", self._natural), ("CROSS", self._kind == JoinKind::Cross)],
+    )?;
+    utils::require_support(
+      dialect,
+      "FULL JOIN",
+      self._kind == JoinKind::Full,
+      utils::is_mysql(dialect) == false,
+    )?;
+    utils::require_support(dialect, "LATERAL", self._lateral, utils::is_sqlite(dialect) == false)?;
+
+    match &self._sub_query {
+      Some(select) => select.check_clauses(dialect),
+      None => Ok(()),
+    }
+  }
+}
+
+impl CollectTables for Join {
+  fn collect_tables(&self, tables: &mut Tables) {
+    match &self._sub_query {
+      Some(select) => select.collect_tables(tables),
+      None => tables.clause("JOIN", &self._table),
+    }
+    self._on.iter().for_each(|(_, condition)| tables.expr(condition));
+  }
+}
+
+// Two joins are the same when its SQL is the same, this way the builders don't accumulate repeated joins.
+// The SQL Standard renders all kinds of join
+impl PartialEq for Join {
+  fn eq(&self, other: &Self) -> bool {
+    let fmts = fmt::Formatter {
+      dialect: Dialect::Standard,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts) == fmt::render(other, &fmts)
+  }
+}
+
+impl std::fmt::Display for Join {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Join {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self, &fmts))
+  }
+}
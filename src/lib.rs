@@ -3,22 +3,50 @@
 
 mod alter_table;
 mod behavior;
+mod bind;
+mod column_def;
 mod concat;
+mod constraint;
 mod create_table;
+mod create_view;
+mod data_type;
 mod delete;
 mod drop_table;
+mod drop_view;
+mod error;
+mod expr;
 mod fmt;
+mod fragment;
+mod highlight;
+mod ident;
 mod insert;
+mod introspection;
+mod join;
+mod literal;
+mod merge;
+mod migration;
+mod parser;
+mod prepared_query;
+mod pretty;
+mod schema;
 mod select;
 mod structure;
 mod transaction;
 mod update;
 mod utils;
 mod values;
+mod where_group;
 
+pub use crate::behavior::{BindValues, HighlightBackend, ToSqlLiteral};
+pub use crate::expr::{col, exists, val};
+pub use crate::highlight::highlight;
 pub use crate::structure::{
-  AlterTable, AlterTableAction, CreateTable, CreateTableParams, Delete, DeleteClause, DropTable, DropTableParams,
-  Insert, InsertClause, Select, SelectClause, Transaction, Update, UpdateClause, Values, ValuesClause,
+  AlterTable, AlterTableAction, AnsiBackend, BuildError, Check, Column, ColumnDef, CommaStyle, CreateTable,
+  CreateTableParams, CreateView, CreateViewParams, DataType, Delete, DeleteClause, Dialect, DropTable, DropTableParams,
+  DropView, DropViewParams, Expr, ForeignKey, FormatOptions, Fragment, HtmlBackend, Ident, Insert, InsertClause, Join,
+  KeywordCase, Literal, LogicalOperator, MatchType, Merge, MergeClause, Migration, MigrationSet, ParseError,
+  PlainBackend, PreparedQuery, PrimaryKey, ReferentialAction, Schema, Select, SelectClause, Table, TokenKind,
+  Transaction, Unique, Update, UpdateClause, Value, Values, ValuesClause, WhereGroup,
 };
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
@@ -26,5 +54,8 @@ mod create_index;
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 mod drop_index;
 
+#[cfg(feature = "postgresql")]
+pub use crate::structure::Exclude;
+
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-pub use crate::structure::{CreateIndex, CreateIndexParams, DropIndex, DropIndexParams};
+pub use crate::structure::{CreateIndex, CreateIndexParams, DropIndex, DropIndexParams, Index};
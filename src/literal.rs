@@ -0,0 +1,267 @@
+use crate::{
+  behavior::ToSqlLiteral,
+  structure::{Dialect, Literal, Value},
+  utils,
+};
+
+impl Literal {
+  /// Creates a literal from any value that implements [ToSqlLiteral](crate::ToSqlLiteral)
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let text = sql::Literal::new("it's");
+  /// let number = sql::Literal::new(42);
+  /// let nothing = sql::Literal::new(None::<i32>);
+  ///
+  /// # assert_eq!("'it''s'", text.to_sql(sql::Dialect::Standard));
+  /// # assert_eq!("42", number.to_sql(sql::Dialect::Standard));
+  /// # assert_eq!("NULL", nothing.to_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// 'it''s'
+  /// 42
+  /// NULL
+  /// ```
+  pub fn new(value: impl ToSqlLiteral) -> Self {
+    Self {
+      _variants: utils::dialects()
+        .into_iter()
+        .map(|dialect| value.to_sql_literal(dialect))
+        .collect(),
+    }
+  }
+
+  /// Gets the literal escaped in the syntax of the default dialect
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let literal = sql::Literal::new(true);
+  ///
+  /// # assert_eq!(literal.to_sql(sql::Dialect::default()), literal.as_string());
+  /// ```
+  pub fn as_string(&self) -> String {
+    self.variant(Dialect::default()).to_string()
+  }
+
+  /// Gets the literal escaped in the syntax of the specified SQL [Dialect]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let literal = sql::Literal::new(vec![0xde_u8, 0xad, 0xbe, 0xef]);
+  ///
+  /// let standard_literal = literal.to_sql(sql::Dialect::Standard);
+  /// let postgres_literal = literal.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("X'deadbeef'", standard_literal);
+  /// # assert_eq!("'\\xdeadbeef'", postgres_literal);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// X'deadbeef'
+  ///
+  /// -- Postgres
+  /// '\xdeadbeef'
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    self.variant(dialect).to_string()
+  }
+
+  /// The literal escaped in the syntax of the dialect
+  pub(crate) fn variant(&self, dialect: Dialect) -> &str {
+    let position = utils::dialects()
+      .into_iter()
+      .position(|enabled| enabled == dialect)
+      .unwrap_or_default();
+    self._variants.get(position).map_or("", |variant| variant.as_str())
+  }
+}
+
+impl std::fmt::Display for Literal {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Literal {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+fn text_literal(text: &str, dialect: Dialect) -> String {
+  let text = text.replace('\'', "''");
+
+  match dialect {
+    // the escape string is immune to the standard_conforming_strings setting
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql if text.contains('\\') => format!("E'{}'", text.replace('\\', "\\\\")),
+    // the backslash is an escape character in the MySQL strings
+    #[cfg(feature = "mysql")]
+    Dialect::Mysql => format!("'{}'", text.replace('\\', "\\\\")),
+    _ => format!("'{text}'"),
+  }
+}
+
+fn bytes_literal(bytes: &[u8], dialect: Dialect) -> String {
+  let hex = utils::to_hex(bytes);
+
+  match dialect {
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql => format!("'\\x{hex}'"),
+    _ => format!("X'{hex}'"),
+  }
+}
+
+fn float_literal(value: f64, dialect: Dialect) -> String {
+  if value.is_finite() {
+    return format!("{value:?}");
+  }
+
+  match dialect {
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql if value.is_nan() => "'NaN'".to_string(),
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql if value > 0.0 => "'Infinity'".to_string(),
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql => "'-Infinity'".to_string(),
+    _ => "NULL".to_string(),
+  }
+}
+
+#[cfg(feature = "chrono")]
+fn typed_literal(type_name: &str, text: &str, dialect: Dialect) -> String {
+  match dialect {
+    // SQLite has no date and time types, the values are stored as text
+    #[cfg(feature = "sqlite")]
+    Dialect::Sqlite => format!("'{text}'"),
+    _ => format!("{type_name} '{text}'"),
+  }
+}
+
+impl<T: ToSqlLiteral + ?Sized> ToSqlLiteral for &T {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    (**self).to_sql_literal(dialect)
+  }
+}
+
+impl<T: ToSqlLiteral> ToSqlLiteral for Option<T> {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    match self {
+      Some(value) => value.to_sql_literal(dialect),
+      None => "NULL".to_string(),
+    }
+  }
+}
+
+impl ToSqlLiteral for str {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    text_literal(self, dialect)
+  }
+}
+
+impl ToSqlLiteral for String {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    text_literal(self, dialect)
+  }
+}
+
+impl ToSqlLiteral for [u8] {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    bytes_literal(self, dialect)
+  }
+}
+
+impl ToSqlLiteral for Vec<u8> {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    bytes_literal(self, dialect)
+  }
+}
+
+impl ToSqlLiteral for bool {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    match dialect {
+      // SQLite has no boolean type, the values are stored as integers
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => (*self as u8).to_string(),
+      _ => if *self { "TRUE" } else { "FALSE" }.to_string(),
+    }
+  }
+}
+
+impl ToSqlLiteral for f32 {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    float_literal(*self as f64, dialect)
+  }
+}
+
+impl ToSqlLiteral for f64 {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    float_literal(*self, dialect)
+  }
+}
+
+macro_rules! integer_literal {
+  ($($type:ty),+) => {
+    $(
+      impl ToSqlLiteral for $type {
+        fn to_sql_literal(&self, _dialect: Dialect) -> String {
+          self.to_string()
+        }
+      }
+    )+
+  };
+}
+
+integer_literal!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl ToSqlLiteral for Value {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    match self {
+      Value::Null => "NULL".to_string(),
+      Value::Bool(value) => value.to_sql_literal(dialect),
+      Value::Int(value) => value.to_sql_literal(dialect),
+      Value::Float(value) => value.to_sql_literal(dialect),
+      Value::Text(value) => value.to_sql_literal(dialect),
+      Value::Bytes(value) => value.to_sql_literal(dialect),
+    }
+  }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl ToSqlLiteral for chrono::NaiveDate {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    typed_literal("DATE", &self.to_string(), dialect)
+  }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl ToSqlLiteral for chrono::NaiveTime {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    typed_literal("TIME", &self.to_string(), dialect)
+  }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl ToSqlLiteral for chrono::NaiveDateTime {
+  fn to_sql_literal(&self, dialect: Dialect) -> String {
+    typed_literal("TIMESTAMP", &self.to_string(), dialect)
+  }
+}
@@ -0,0 +1,853 @@
+use crate::{
+  behavior::{TransactionQuery, Validate},
+  fmt, introspection, pretty,
+  structure::{BuildError, Dialect, FormatOptions, Fragment, LogicalOperator, Merge, MergeClause, Value},
+  utils::{self, push_unique},
+};
+
+impl TransactionQuery for Merge {}
+
+impl Merge {
+  /// Gets the current state of the [Merge] and returns it as string formatted by the [FormatOptions], each
+  /// `WHEN` clause starts in its own line
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge_query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("imports i")
+  ///   .on("c.id = i.id")
+  ///   .when_matched_then_update("name = i.name")
+  ///   .as_pretty_string(&sql::FormatOptions::default());
+  ///
+  /// # let expected = "\
+  /// # MERGE INTO customers c\n\
+  /// # USING imports i\n\
+  /// # ON c.id = i.id\n\
+  /// # WHEN MATCHED THEN UPDATE SET name = i.name";
+  /// # assert_eq!(expected, merge_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c
+  /// USING imports i
+  /// ON c.id = i.id
+  /// WHEN MATCHED THEN UPDATE SET name = i.name
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_delete()
+  ///   .as_string();
+  ///
+  /// # let expected = "MERGE INTO customers c USING staging_customers s ON c.id = s.id WHEN MATCHED THEN DELETE";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING staging_customers s ON c.id = s.id WHEN MATCHED THEN DELETE
+  /// ```
+  pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string together with the values bound
+  /// to the conditions, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let (query, values) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on(&col("c.id").eq(col("s.id")).and(col("s.region").eq("eu")))
+  ///   .when_matched_then_delete()
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "MERGE INTO customers c USING staging_customers s ON c.id = s.id AND s.region = $1 WHEN MATCHED THEN DELETE";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from("eu")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING staging_customers s ON c.id = s.id AND s.region = $1 WHEN MATCHED THEN DELETE
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
+    let fmts = fmt::one_line();
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the clauses of the [Merge] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [Merge::raw], [Merge::raw_before] and [Merge::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_delete();
+  ///
+  /// let clauses = merge.clauses();
+  ///
+  /// # let expected = vec![
+  /// #   sql::MergeClause::MergeInto,
+  /// #   sql::MergeClause::Using,
+  /// #   sql::MergeClause::On,
+  /// #   sql::MergeClause::When,
+  /// # ];
+  /// # assert_eq!(expected, clauses);
+  /// ```
+  pub fn clauses(&self) -> Vec<MergeClause> {
+    let mut clauses = vec![];
+
+    #[cfg(feature = "postgresql")]
+    if self._with.is_empty() == false {
+      clauses.push(MergeClause::With);
+    }
+    if self._merge_into.is_empty() == false {
+      clauses.push(MergeClause::MergeInto);
+    }
+    if self._using.is_empty() == false {
+      clauses.push(MergeClause::Using);
+    }
+    if self._on.is_empty() == false {
+      clauses.push(MergeClause::On);
+    }
+    if self._when.is_empty() == false {
+      clauses.push(MergeClause::When);
+    }
+
+    clauses
+  }
+
+  /// Prints the current state of the [Merge] to the standard output in a more ease to read version.
+  /// This method is useful to debug complex queries or just print the generated SQL while you type
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_update("name = s.name")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Prints to the standard output
+  ///
+  /// ```sql
+  /// -- ------------------------------------------------------------------------------
+  /// MERGE INTO customers c
+  /// USING staging_customers s
+  /// ON c.id = s.id
+  /// WHEN MATCHED THEN UPDATE SET name = s.name
+  /// -- ------------------------------------------------------------------------------
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Gets the target table of the `merge into` clause, `None` when the clause is not defined
+  pub fn get_merge_into(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._merge_into)
+  }
+
+  /// Gets the conditions of the `on` clause together with the [LogicalOperator] that joins each one
+  /// to the previous conditions
+  pub fn get_on(&self) -> &[(LogicalOperator, Fragment)] {
+    &self._on
+  }
+
+  /// Gets the source of the `using` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("customers c")), merge.get_merge_into());
+  /// # assert_eq!(Some(&sql::Fragment::from("staging_customers s")), merge.get_using());
+  /// ```
+  pub fn get_using(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._using)
+  }
+
+  /// Gets the `when` clauses rendered with their actions
+  pub fn get_when(&self) -> &[Fragment] {
+    &self._when
+  }
+
+  /// The `merge into` clause, the target table of the command. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers");
+  /// #
+  /// # let expected = "MERGE INTO customers";
+  /// # assert_eq!(expected, merge.to_string());
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("orders")
+  ///   .merge_into("customers");
+  ///
+  /// # let expected = "MERGE INTO customers";
+  /// # assert_eq!(expected, merge.to_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers
+  /// ```
+  pub fn merge_into(mut self, table: impl Into<Fragment>) -> Self {
+    self._merge_into = table.into().trim();
+    self
+  }
+
+  /// Creates instance of the Merge command
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The `on` clause, the condition that matches the rows of the source with the rows of the target table.
+  /// This method will concatenate multiples calls using the `and` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .on("c.id = s.id")
+  ///   .on("c.region = s.region");
+  ///
+  /// # let expected = "ON c.id = s.id AND c.region = s.region";
+  /// # assert_eq!(expected, merge.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ON c.id = s.id AND c.region = s.region
+  /// ```
+  pub fn on(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._on, (LogicalOperator::And, condition.into().trim()));
+    self
+  }
+
+  /// Returns the number of parameters of the [Merge], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound to the conditions
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_update("name = $1, updated_at = $2");
+  ///
+  /// # let expected = "MERGE INTO customers c USING staging_customers s ON c.id = s.id WHEN MATCHED THEN UPDATE SET name = $1, updated_at = $2";
+  /// # assert_eq!(expected, merge.as_string());
+  /// # assert_eq!(2, merge.param_count());
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING staging_customers s ON c.id = s.id WHEN MATCHED THEN UPDATE SET name = $1, updated_at = $2
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
+  /// Prints the current state of the [Merge] to the standard output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_query = "merge into customers c using staging_customers s";
+  ///
+  /// let query = sql::Merge::new()
+  ///   .raw(raw_query)
+  ///   .on("c.id = s.id")
+  ///   .as_string();
+  ///
+  /// # let expected = "merge into customers c using staging_customers s ON c.id = s.id";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// merge into customers c using staging_customers s ON c.id = s.id
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw = "when matched and s.deleted = true then delete";
+  ///
+  /// let query = sql::Merge::new()
+  ///   .on("c.id = s.id")
+  ///   .raw_after(sql::MergeClause::On, raw)
+  ///   .as_string();
+  ///
+  /// # let expected = "ON c.id = s.id when matched and s.deleted = true then delete";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ON c.id = s.id when matched and s.deleted = true then delete
+  /// ```
+  pub fn raw_after(mut self, clause: MergeClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw = "merge into customers c";
+  ///
+  /// let query = sql::Merge::new()
+  ///   .raw_before(sql::MergeClause::Using, raw)
+  ///   .using("staging_customers s")
+  ///   .as_string();
+  ///
+  /// # let expected = "merge into customers c USING staging_customers s";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// merge into customers c USING staging_customers s
+  /// ```
+  pub fn raw_before(mut self, clause: MergeClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim()));
+    self
+  }
+
+  /// Gets the names of the tables written and read by the [Merge], the target table, the source table
+  /// and the tables of the sub-queries in the order they appear. The names of the common table expressions
+  /// are not included and the quoted names are returned without the quotes
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_update("name = s.name");
+  ///
+  /// let tables = merge.tables();
+  ///
+  /// # assert_eq!(vec!["customers", "staging_customers"], tables);
+  /// ```
+  pub fn tables(&self) -> Vec<String> {
+    introspection::tables(self)
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let staging_customers = sql::Select::new()
+  ///   .select("id, name")
+  ///   .from("imports")
+  ///   .where_clause("valid = true");
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .with("s", staging_customers)
+  ///   .merge_into("customers c")
+  ///   .using("s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_delete();
+  ///
+  /// let standard_query = merge.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = merge.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("MERGE INTO customers c USING s ON c.id = s.id WHEN MATCHED THEN DELETE", standard_query);
+  /// # assert_eq!("WITH s AS (SELECT id, name FROM imports WHERE valid = true) MERGE INTO customers c USING s ON c.id = s.id WHEN MATCHED THEN DELETE", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// MERGE INTO customers c USING s ON c.id = s.id WHEN MATCHED THEN DELETE
+  ///
+  /// -- Postgres
+  /// WITH s AS (SELECT id, name FROM imports WHERE valid = true) MERGE INTO customers c USING s ON c.id = s.id WHEN MATCHED THEN DELETE
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound to the conditions,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let (query, values) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on(&col("c.id").eq(col("s.id")).and(col("s.region").eq("eu")))
+  ///   .when_matched_then_delete()
+  ///   .to_sql_with_binds(sql::Dialect::Standard);
+  ///
+  /// # let expected = "MERGE INTO customers c USING staging_customers s ON c.id = s.id AND s.region = ? WHEN MATCHED THEN DELETE";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from("eu")], values);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING staging_customers s ON c.id = s.id AND s.region = ? WHEN MATCHED THEN DELETE
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string, returns a [BuildError] when
+  /// the [Merge::validate] method fails
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [Merge] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Merge::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Merge::new()
+  ///   .merge_into("users")
+  ///   .using("new_users")
+  ///   .on("users.id = new_users.id")
+  ///   .when_matched_then_delete()
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("MERGE INTO users USING new_users ON users.id = new_users.id WHEN MATCHED THEN DELETE", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO users USING new_users ON users.id = new_users.id WHEN MATCHED THEN DELETE
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [Merge], returns a [BuildError] when the target table, the source,
+  /// the join condition or the `when` actions are missing. Nothing is checked when the raw SQL is set
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", not(any(feature = "sqlite", feature = "mysql"))))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("users")
+  ///   .using("users_bk")
+  ///   .on("users.id = users_bk.id");
+  ///
+  /// let error = merge.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "MERGE".to_string(),
+  /// #   clause: "WHEN".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [Merge] like the [Merge::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("users")
+  ///   .using("new_users")
+  ///   .on("users.id = new_users.id")
+  ///   .when_not_matched_then_insert("(id)", "(new_users.id)");
+  ///
+  /// let error = merge.validate_for(sql::Dialect::Mysql).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Mysql,
+  /// #   feature: "MERGE".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
+  /// The `using` clause, the source of the rows merged into the target table, a table or a sub-query
+  /// enclosed in parentheses with an alias. This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("(select * from imports where valid = true) s");
+  ///
+  /// # let expected = "MERGE INTO customers c USING (select * from imports where valid = true) s";
+  /// # assert_eq!(expected, merge.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING (select * from imports where valid = true) s
+  /// ```
+  pub fn using(mut self, source: impl Into<Fragment>) -> Self {
+    self._using = source.into().trim();
+    self
+  }
+
+  /// The `when matched then delete` clause, deletes the rows of the target table that match a row of the source.
+  /// The `when` clauses are rendered in the same order they were added
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("removed_customers r")
+  ///   .on("c.id = r.id")
+  ///   .when_matched_then_delete();
+  ///
+  /// # let expected = "MERGE INTO customers c USING removed_customers r ON c.id = r.id WHEN MATCHED THEN DELETE";
+  /// # assert_eq!(expected, merge.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING removed_customers r ON c.id = r.id WHEN MATCHED THEN DELETE
+  /// ```
+  pub fn when_matched_then_delete(mut self) -> Self {
+    push_unique(&mut self._when, Fragment::from("WHEN MATCHED THEN DELETE"));
+    self
+  }
+
+  /// The `when matched then update` clause, updates the rows of the target table that match a row of the source
+  /// with the assignments of the `set` argument. The `when` clauses are rendered in the same order they were added
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_update("name = s.name, email = s.email");
+  ///
+  /// # let expected = "\
+  /// #   MERGE INTO customers c \
+  /// #   USING staging_customers s \
+  /// #   ON c.id = s.id \
+  /// #   WHEN MATCHED THEN UPDATE SET name = s.name, email = s.email\
+  /// # ";
+  /// # assert_eq!(expected, merge.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c
+  /// USING staging_customers s
+  /// ON c.id = s.id
+  /// WHEN MATCHED THEN UPDATE SET name = s.name, email = s.email
+  /// ```
+  pub fn when_matched_then_update(mut self, set: impl Into<Fragment>) -> Self {
+    let set = set.into().trim();
+    push_unique(
+      &mut self._when,
+      Fragment::from("WHEN MATCHED THEN UPDATE SET ").push(set),
+    );
+    self
+  }
+
+  /// The `when not matched then insert` clause, inserts the rows of the source that don't match a row
+  /// of the target table. The `columns` argument is optional and can be an empty string.
+  /// The `when` clauses are rendered in the same order they were added
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_not_matched_then_insert("(id, name)", "(s.id, s.name)");
+  ///
+  /// # let expected = "\
+  /// #   MERGE INTO customers c \
+  /// #   USING staging_customers s \
+  /// #   ON c.id = s.id \
+  /// #   WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)\
+  /// # ";
+  /// # assert_eq!(expected, merge.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c
+  /// USING staging_customers s
+  /// ON c.id = s.id
+  /// WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)
+  /// ```
+  pub fn when_not_matched_then_insert(mut self, columns: impl Into<Fragment>, values: impl Into<Fragment>) -> Self {
+    let columns = columns.into().trim();
+    let values = values.into().trim();
+    let insert = if columns.is_empty() {
+      Fragment::from("WHEN NOT MATCHED THEN INSERT VALUES ").push(values)
+    } else {
+      Fragment::from("WHEN NOT MATCHED THEN INSERT ")
+        .push(columns)
+        .push(" VALUES ")
+        .push(values)
+    };
+    push_unique(&mut self._when, insert);
+    self
+  }
+
+  /// Writes the current state of the [Merge] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_delete();
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// merge.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nMERGE INTO customers c USING staging_customers s ON c.id = s.id WHEN MATCHED THEN DELETE";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Merge] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Merge::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_delete();
+  ///
+  /// let mut output = Vec::new();
+  /// merge.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "MERGE INTO customers c USING staging_customers s ON c.id = s.id WHEN MATCHED THEN DELETE";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+use crate::behavior::WithQuery;
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+impl WithQuery for Merge {}
+
+#[cfg(any(doc, feature = "postgresql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+impl Merge {
+  /// The `with` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let valid_imports = sql::Select::new()
+  ///   .select("id, name")
+  ///   .from("imports")
+  ///   .where_clause("valid = true");
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .with("valid_imports", valid_imports)
+  ///   .merge_into("customers c")
+  ///   .using("valid_imports s")
+  ///   .on("c.id = s.id")
+  ///   .when_not_matched_then_insert("(id, name)", "(s.id, s.name)")
+  ///   .debug();
+  ///
+  /// # let expected = "\
+  /// #   WITH valid_imports AS (\
+  /// #     SELECT id, name \
+  /// #     FROM imports \
+  /// #     WHERE valid = true\
+  /// #   ) \
+  /// #   MERGE INTO customers c \
+  /// #   USING valid_imports s \
+  /// #   ON c.id = s.id \
+  /// #   WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)\
+  /// # ";
+  /// # assert_eq!(expected, merge.to_string());
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WITH valid_imports AS (
+  ///   SELECT id, name
+  ///   FROM imports
+  ///   WHERE valid = true
+  /// )
+  /// MERGE INTO customers c
+  /// USING valid_imports s
+  /// ON c.id = s.id
+  /// WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)
+  /// ```
+  #[cfg(feature = "postgresql")]
+  pub fn with(mut self, name: impl Into<Fragment>, query: impl WithQuery + 'static + Send + Sync) -> Self {
+    self._with.push((name.into().trim(), std::sync::Arc::new(query)));
+    self
+  }
+}
+
+impl std::fmt::Display for Merge {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Merge {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self, &fmts))
+  }
+}
@@ -0,0 +1,168 @@
+use crate::{
+  behavior::Validate,
+  concat::{concat_raw_before_after, Concat},
+  fmt,
+  introspection::{CollectTables, Tables},
+  pretty::Body,
+  structure::{BuildError, Dialect, Merge, MergeClause},
+  utils,
+};
+use std::fmt::Write;
+
+impl Concat for Merge {
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
+
+    match fmts.dialect {
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          MergeClause::With,
+          &self._with,
+        )?;
+        self.concat_merge_into(w, &fmts)?;
+        self.concat_using(w, &fmts)?;
+        self.concat_on(w, &fmts)?;
+        self.concat_when(w, &fmts)?;
+      }
+
+      _ => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_merge_into(w, &fmts)?;
+        self.concat_using(w, &fmts)?;
+        self.concat_on(w, &fmts)?;
+        self.concat_when(w, &fmts)?;
+      }
+    }
+
+    w.trim_end_since(start);
+    Ok(())
+  }
+}
+
+impl Merge {
+  fn concat_merge_into(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      w,
+      fmts,
+      MergeClause::MergeInto,
+      |w| {
+        if self._merge_into.is_empty() == false {
+          w.write_clause("MERGE INTO", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._merge_into)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
+    )
+  }
+
+  fn concat_on(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, MergeClause::On, |w| {
+      let start = w.mark();
+      let conditions = self._on.iter().filter(|(_, condition)| condition.is_empty() == false);
+      for (index, (log_op, condition)) in conditions.enumerate() {
+        if index == 0 {
+          w.write_clause("ON", Body::Conditions)?;
+          w.write_str(space)?;
+        } else {
+          w.write_operator(log_op, |w| write!(w, "{space}{log_op}{space}"))?;
+        }
+        w.write_fragment(condition)?;
+      }
+      if w.has_written_since(start) {
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
+  }
+
+  fn concat_using(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, MergeClause::Using, |w| {
+      if self._using.is_empty() == false {
+        w.write_clause("USING", Body::Text)?;
+        w.write_str(space)?;
+        w.write_fragment(&self._using)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
+  }
+
+  fn concat_when(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, MergeClause::When, |w| {
+      for when in &self._when {
+        w.end_clause();
+        w.write_fragment(when)?;
+        write!(w, "{space}{lb}")?;
+      }
+      Ok(())
+    })
+  }
+}
+
+#[cfg(feature = "postgresql")]
+use crate::concat::non_standard::ConcatWith;
+#[cfg(feature = "postgresql")]
+impl ConcatWith<MergeClause> for Merge {}
+
+impl Validate for Merge {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    utils::require_support(
+      dialect,
+      "MERGE",
+      true,
+      utils::is_sqlite(dialect) == false && utils::is_mysql(dialect) == false,
+    )?;
+
+    #[cfg(feature = "postgresql")]
+    for (_, query) in &self._with {
+      query.check_clauses(dialect)?;
+    }
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) {
+      return Ok(());
+    }
+
+    utils::require_clause("MERGE", "MERGE INTO", self._merge_into.is_empty())?;
+    utils::require_clause("MERGE", "USING", self._using.is_empty())?;
+    utils::require_clause("MERGE", "ON", self._on.is_empty())?;
+    utils::require_clause("MERGE", "WHEN", self._when.is_empty())
+  }
+}
+
+impl CollectTables for Merge {
+  fn collect_tables(&self, tables: &mut Tables) {
+    self._raw.iter().for_each(|raw| tables.raw(raw));
+
+    #[cfg(feature = "postgresql")]
+    for (name, query) in self._with.iter() {
+      tables.cte(name);
+      query.collect_tables(tables);
+    }
+
+    tables.clause("INTO", &self._merge_into);
+    tables.clause("USING", &self._using);
+    self._on.iter().for_each(|(_, condition)| tables.expr(condition));
+    self._when.iter().for_each(|when| tables.expr(when));
+
+    let raw_before_after = self._raw_before.iter().chain(self._raw_after.iter());
+    raw_before_after.for_each(|(_, raw)| tables.raw(raw));
+  }
+}
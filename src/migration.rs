@@ -0,0 +1,385 @@
+use crate::{
+  fmt,
+  structure::{
+    BuildError, ColumnDef, CreateTable, DataType, Delete, Dialect, Fragment, Insert, Literal, Migration, MigrationSet,
+    Transaction,
+  },
+};
+
+const MIGRATIONS_TABLE: &str = "schema_migrations";
+
+impl Migration {
+  /// Creates a migration with its version, name and the transactions to apply and revert it.
+  /// The transactions should contain only the commands of the migration, the [Migration::up_sql]
+  /// and [Migration::down_sql] methods wrap the commands in a transaction block. When the transaction opens
+  /// or closes its own block, like with the `begin` and `commit` methods, its commands are used instead
+  pub fn new(version: u64, name: &str, up: Transaction, down: Transaction) -> Self {
+    Self {
+      _down: down,
+      _name: name.trim().to_string(),
+      _up: up,
+      _version: version,
+    }
+  }
+
+  /// Gets the SQL to revert the migration in the syntax of the specified [Dialect], the commands of the `down`
+  /// transaction followed by the delete of the version from the `schema_migrations` table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let migration = sql::Migration::new(
+  ///   1,
+  ///   "create_users",
+  ///   sql::Transaction::new().create_table(sql::CreateTable::new().create_table("users").column("login text")),
+  ///   sql::Transaction::new().drop_table(sql::DropTable::new().drop_table("users")),
+  /// );
+  ///
+  /// let query = migration.down_sql(sql::Dialect::Sqlite);
+  ///
+  /// # let expected = "\
+  /// #   BEGIN; \
+  /// #   DROP TABLE users; \
+  /// #   DELETE FROM schema_migrations WHERE version = 1; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// BEGIN;
+  /// DROP TABLE users;
+  /// DELETE FROM schema_migrations WHERE version = 1;
+  /// COMMIT;
+  /// ```
+  pub fn down_sql(&self, dialect: Dialect) -> String {
+    transaction_block(dialect, &self._down, self.delete_version_sql(dialect))
+  }
+
+  /// Gets the SQL that removes the version of the migration from the `schema_migrations` table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let migration = sql::Migration::new(3, "add_users_bio", sql::Transaction::new(), sql::Transaction::new());
+  ///
+  /// # let expected = "DELETE FROM schema_migrations WHERE version = 3;";
+  /// # assert_eq!(expected, migration.delete_version_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM schema_migrations WHERE version = 3;
+  /// ```
+  pub fn delete_version_sql(&self, dialect: Dialect) -> String {
+    let delete = Delete::new()
+      .delete_from(MIGRATIONS_TABLE)
+      .where_clause(format!("version = {}", self._version));
+
+    format!("{};", delete.to_sql(dialect))
+  }
+
+  /// Gets the SQL that registers the version of the migration in the `schema_migrations` table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let migration = sql::Migration::new(3, "add_users_bio", sql::Transaction::new(), sql::Transaction::new());
+  ///
+  /// # let expected = "INSERT INTO schema_migrations (version, name) VALUES (3, 'add_users_bio');";
+  /// # assert_eq!(expected, migration.insert_version_sql(sql::Dialect::Standard));
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO schema_migrations (version, name) VALUES (3, 'add_users_bio');
+  /// ```
+  pub fn insert_version_sql(&self, dialect: Dialect) -> String {
+    let insert = Insert::new()
+      .insert_into(format!("{MIGRATIONS_TABLE} (version, name)"))
+      .values(
+        Fragment::from(format!("({}, ", self._version))
+          .push(Literal::new(self._name.as_str()))
+          .push(")"),
+      );
+
+    format!("{};", insert.to_sql(dialect))
+  }
+
+  /// Gets the SQL to apply the migration in the syntax of the specified [Dialect], the commands of the `up`
+  /// transaction followed by the insert of the version in the `schema_migrations` table
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let migration = sql::Migration::new(
+  ///   1,
+  ///   "create_users",
+  ///   sql::Transaction::new().create_table(sql::CreateTable::new().create_table("users").column("login text")),
+  ///   sql::Transaction::new().drop_table(sql::DropTable::new().drop_table("users")),
+  /// );
+  ///
+  /// let query = migration.up_sql(sql::Dialect::Postgresql);
+  ///
+  /// # let expected = "\
+  /// #   BEGIN; \
+  /// #   CREATE TABLE users (login text); \
+  /// #   INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users'); \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// BEGIN;
+  /// CREATE TABLE users (login text);
+  /// INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users');
+  /// COMMIT;
+  /// ```
+  pub fn up_sql(&self, dialect: Dialect) -> String {
+    transaction_block(dialect, &self._up, self.insert_version_sql(dialect))
+  }
+}
+
+impl MigrationSet {
+  /// Gets the SQL that creates the `schema_migrations` table in the syntax of the specified [Dialect],
+  /// the table registers the versions already applied
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::MigrationSet::new().create_table_sql(sql::Dialect::Mysql);
+  ///
+  /// # let expected = "\
+  /// #   CREATE TABLE IF NOT EXISTS schema_migrations (\
+  /// #     version BIGINT NOT NULL PRIMARY KEY, \
+  /// #     name VARCHAR(255) NOT NULL, \
+  /// #     applied_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP\
+  /// #   );\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// CREATE TABLE IF NOT EXISTS schema_migrations (
+  ///   version BIGINT NOT NULL PRIMARY KEY,
+  ///   name VARCHAR(255) NOT NULL,
+  ///   applied_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+  /// );
+  /// ```
+  pub fn create_table_sql(&self, dialect: Dialect) -> String {
+    let version = ColumnDef::new("version", DataType::BigInt).not_null().primary_key();
+    let name = ColumnDef::new("name", DataType::Varchar(255)).not_null();
+    let applied_at = ColumnDef::new("applied_at", DataType::Timestamp)
+      .not_null()
+      .default("CURRENT_TIMESTAMP");
+
+    let create_table = CreateTable::new()
+      .create_table_if_not_exists(MIGRATIONS_TABLE)
+      .column(&version)
+      .column(&name)
+      .column(&applied_at);
+
+    format!("{};", create_table.to_sql(dialect))
+  }
+
+  /// Gets the SQL to revert the migrations applied after the target version, from the current version
+  /// down to the target version in descending order. Returns a [BuildError] when the versions are not valid
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let migrations = sql::MigrationSet::new()
+  ///   .migration(sql::Migration::new(
+  ///     1,
+  ///     "create_users",
+  ///     sql::Transaction::new().create_table(sql::CreateTable::new().create_table("users").column("login text")),
+  ///     sql::Transaction::new().drop_table(sql::DropTable::new().drop_table("users")),
+  ///   ));
+  ///
+  /// let query = migrations.down_sql(sql::Dialect::Postgresql, 1, 0).unwrap();
+  ///
+  /// # let expected = "\
+  /// #   BEGIN; \
+  /// #   DROP TABLE users; \
+  /// #   DELETE FROM schema_migrations WHERE version = 1; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// BEGIN;
+  /// DROP TABLE users;
+  /// DELETE FROM schema_migrations WHERE version = 1;
+  /// COMMIT;
+  /// ```
+  pub fn down_sql(&self, dialect: Dialect, current_version: u64, target_version: u64) -> Result<String, BuildError> {
+    self.validate()?;
+
+    let sql = self
+      ._migration
+      .iter()
+      .rev()
+      .filter(|migration| migration._version <= current_version && migration._version > target_version)
+      .map(|migration| migration.down_sql(dialect))
+      .collect::<Vec<_>>()
+      .join(" ");
+
+    Ok(sql)
+  }
+
+  /// Adds a migration to the set, the migrations are kept sorted by version
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let migrations = sql::MigrationSet::new()
+  ///   .migration(sql::Migration::new(2, "add_users_bio", sql::Transaction::new(), sql::Transaction::new()))
+  ///   .migration(sql::Migration::new(1, "create_users", sql::Transaction::new(), sql::Transaction::new()));
+  /// ```
+  pub fn migration(mut self, migration: Migration) -> Self {
+    let position = self
+      ._migration
+      .partition_point(|item| item._version <= migration._version);
+    self._migration.insert(position, migration);
+    self
+  }
+
+  /// Creates an empty set of migrations
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Gets the SQL to apply the migrations pending after the current version in ascending order,
+  /// each migration is applied in its own transaction block. Use the version `0` when no migration was applied.
+  /// Returns a [BuildError] when the versions are not valid
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let migrations = sql::MigrationSet::new()
+  ///   .migration(sql::Migration::new(1, "create_users", sql::Transaction::new(), sql::Transaction::new()))
+  ///   .migration(sql::Migration::new(1, "create_orders", sql::Transaction::new(), sql::Transaction::new()));
+  ///
+  /// let error = migrations.up_sql(sql::Dialect::Standard, 0).unwrap_err();
+  ///
+  /// # assert_eq!(sql::BuildError::DuplicateMigration { version: 1 }, error);
+  /// ```
+  pub fn up_sql(&self, dialect: Dialect, current_version: u64) -> Result<String, BuildError> {
+    self.validate()?;
+
+    let sql = self
+      ._migration
+      .iter()
+      .filter(|migration| migration._version > current_version)
+      .map(|migration| migration.up_sql(dialect))
+      .collect::<Vec<_>>()
+      .join(" ");
+
+    Ok(sql)
+  }
+
+  /// Checks that the versions are unique and consecutive
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let migrations = sql::MigrationSet::new()
+  ///   .migration(sql::Migration::new(1, "create_users", sql::Transaction::new(), sql::Transaction::new()))
+  ///   .migration(sql::Migration::new(3, "add_users_bio", sql::Transaction::new(), sql::Transaction::new()));
+  ///
+  /// # assert_eq!(Err(sql::BuildError::MissingMigration { version: 2 }), migrations.validate());
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    for pair in self._migration.windows(2) {
+      let (previous, next) = (pair[0]._version, pair[1]._version);
+
+      if previous == next {
+        return Err(BuildError::DuplicateMigration { version: next });
+      }
+      if next > previous + 1 {
+        return Err(BuildError::MissingMigration { version: previous + 1 });
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Wraps the commands of the transaction and the statement of the version in a transaction block using the syntax
+/// of the dialect. The block is not opened again when the transaction opens its own, and when the transaction closes
+/// its own block the statement of the version is written before the closing command
+fn transaction_block(dialect: Dialect, transaction: &Transaction, version_sql: String) -> String {
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  let has_begin = transaction._begin.is_some() || transaction._start_transaction.is_some();
+  #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
+  let has_begin = transaction._start_transaction.is_some();
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  let closing = transaction._end.as_ref().or(transaction._commit.as_ref());
+  #[cfg(not(any(feature = "postgresql", feature = "sqlite")))]
+  let closing = transaction._commit.as_ref();
+
+  let begin = match dialect {
+    _ if has_begin => "",
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql => "BEGIN;",
+    #[cfg(feature = "sqlite")]
+    Dialect::Sqlite => "BEGIN;",
+    _ => "START TRANSACTION;",
+  };
+
+  let sql = transaction.to_sql(dialect);
+  let (commands, commit) = match closing {
+    Some(closing) => {
+      let fmts = fmt::Formatter {
+        dialect,
+        ..fmt::one_line()
+      };
+      let (closing, _) = fmt::render(closing, &fmts);
+      let closing = format!("{closing};");
+      let commands = sql.strip_suffix(&closing).unwrap_or(&sql).trim_end().to_string();
+      (commands, closing)
+    }
+    None => (sql, "COMMIT;".to_string()),
+  };
+
+  [begin, &commands, &version_sql, &commit]
+    .iter()
+    .filter(|statement| statement.is_empty() == false)
+    .copied()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
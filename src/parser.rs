@@ -0,0 +1,653 @@
+use crate::structure::{Delete, Insert, Join, ParseError, Select, Update};
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+use crate::{behavior::WithQuery, structure::Fragment};
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+use std::sync::Arc;
+
+const SELECT_KEYWORDS: [&str; 10] = [
+  "WITH", "SELECT", "FROM", "WHERE", "GROUP BY", "HAVING", "WINDOW", "ORDER BY", "LIMIT", "OFFSET",
+];
+
+pub(crate) const COMBINATOR_KEYWORDS: [&str; 6] = [
+  "UNION",
+  "UNION ALL",
+  "EXCEPT",
+  "EXCEPT ALL",
+  "INTERSECT",
+  "INTERSECT ALL",
+];
+
+pub(crate) const JOIN_KEYWORDS: [&str; 18] = [
+  "JOIN",
+  "CROSS JOIN",
+  "INNER JOIN",
+  "LEFT JOIN",
+  "LEFT OUTER JOIN",
+  "RIGHT JOIN",
+  "RIGHT OUTER JOIN",
+  "FULL JOIN",
+  "FULL OUTER JOIN",
+  "NATURAL JOIN",
+  "NATURAL CROSS JOIN",
+  "NATURAL INNER JOIN",
+  "NATURAL LEFT JOIN",
+  "NATURAL LEFT OUTER JOIN",
+  "NATURAL RIGHT JOIN",
+  "NATURAL RIGHT OUTER JOIN",
+  "NATURAL FULL JOIN",
+  "NATURAL FULL OUTER JOIN",
+];
+
+const INSERT_KEYWORDS: [&str; 9] = [
+  "WITH",
+  "INSERT INTO",
+  "OVERRIDING",
+  "VALUES",
+  "DEFAULT VALUES",
+  "SELECT",
+  "ON CONFLICT",
+  "ON DUPLICATE KEY UPDATE",
+  "RETURNING",
+];
+
+const UPDATE_KEYWORDS: [&str; 8] = [
+  "WITH",
+  "UPDATE",
+  "SET",
+  "FROM",
+  "WHERE",
+  "RETURNING",
+  "ORDER BY",
+  "LIMIT",
+];
+
+const DELETE_KEYWORDS: [&str; 6] = ["WITH", "DELETE FROM", "WHERE", "RETURNING", "ORDER BY", "LIMIT"];
+
+// clauses without builder method, without them the text would be appended to the clause before it
+const UNSUPPORTED_SELECT_KEYWORDS: [&str; 8] = [
+  "INTO",
+  "FOR UPDATE",
+  "FOR NO KEY UPDATE",
+  "FOR SHARE",
+  "FOR KEY SHARE",
+  "FETCH FIRST",
+  "FETCH NEXT",
+  "LOCK IN SHARE MODE",
+];
+
+const UNSUPPORTED_DELETE_KEYWORDS: [&str; 1] = ["USING"];
+
+pub(crate) fn select(sql: &str) -> Result<Select, ParseError> {
+  parse_select(&normalize(sql)?)
+}
+
+pub(crate) fn insert(sql: &str) -> Result<Insert, ParseError> {
+  parse_insert(&normalize(sql)?)
+}
+
+pub(crate) fn update(sql: &str) -> Result<Update, ParseError> {
+  parse_update(&normalize(sql)?)
+}
+
+pub(crate) fn delete(sql: &str) -> Result<Delete, ParseError> {
+  parse_delete(&normalize(sql)?)
+}
+
+fn parse_select(sql: &str) -> Result<Select, ParseError> {
+  let mut operands = split(sql, &COMBINATOR_KEYWORDS).into_iter();
+  let (_, first) = operands.next().unwrap();
+  let mut select = parse_select_clauses(unwrap_parens(first))?;
+
+  // the builder renders the combinators grouped by kind, so the operands can be combined by only one kind
+  let mut kind = None;
+  for (combinator, operand) in operands {
+    if *kind.get_or_insert(combinator) != combinator {
+      return Err(unsupported(combinator));
+    }
+    let operand = parse_select_clauses(unwrap_parens(operand))?;
+    select = combine(select, combinator, operand)?;
+  }
+
+  Ok(select)
+}
+
+fn parse_select_clauses(sql: &str) -> Result<Select, ParseError> {
+  let mut select = Select::new();
+
+  for (keyword, body) in clauses(sql, "SELECT", &SELECT_KEYWORDS)? {
+    select = match keyword {
+      "SELECT" => list(body).into_iter().fold(select, Select::select),
+      "FROM" => {
+        let mut parts = split(body, &JOIN_KEYWORDS).into_iter();
+        let (_, tables) = parts.next().unwrap();
+        select = list(tables).into_iter().fold(select, Select::from);
+        for (keyword, join) in parts {
+          select = select.join(parse_join(keyword, join)?);
+        }
+        select
+      }
+      "WHERE" => conditions(body)
+        .iter()
+        .fold(select, |select, condition| select.where_clause(condition)),
+      "GROUP BY" => list(body).into_iter().fold(select, Select::group_by),
+      "HAVING" => conditions(body)
+        .iter()
+        .fold(select, |select, condition| select.having(condition)),
+      "WINDOW" => list(body).into_iter().fold(select, Select::window),
+      "ORDER BY" => list(body).into_iter().fold(select, Select::order_by),
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+      "WITH" => {
+        select._with.extend(with_queries(body)?);
+        select
+      }
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+      "LIMIT" => select.limit(body),
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+      "OFFSET" => select.offset(body),
+      clause => return Err(unsupported(clause)),
+    };
+  }
+
+  Ok(select)
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+fn combine(select: Select, combinator: &str, operand: Select) -> Result<Select, ParseError> {
+  match combinator {
+    "UNION" => Ok(select.union(operand)),
+    "EXCEPT" => Ok(select.except(operand)),
+    "INTERSECT" => Ok(select.intersect(operand)),
+    clause => Err(unsupported(clause)),
+  }
+}
+
+#[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
+fn combine(_select: Select, combinator: &str, _operand: Select) -> Result<Select, ParseError> {
+  Err(unsupported(combinator))
+}
+
+fn parse_join(keyword: &str, body: &str) -> Result<Join, ParseError> {
+  let mut parts = split(body, &["ON", "USING"]).into_iter();
+  let (_, table) = parts.next().unwrap();
+
+  let kind = keyword.trim_start_matches("NATURAL ").replace(" OUTER", "");
+  let mut join = match kind.as_str() {
+    "JOIN" | "INNER JOIN" => Join::inner(table),
+    "CROSS JOIN" => Join::cross(table),
+    "LEFT JOIN" => Join::left(table),
+    "RIGHT JOIN" => Join::right(table),
+    #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+    "FULL JOIN" => Join::full(table),
+    _ => return Err(unsupported(keyword)),
+  };
+
+  if keyword.starts_with("NATURAL") {
+    join = join.natural();
+  }
+
+  for (keyword, constraint) in parts {
+    join = match keyword {
+      "ON" => conditions(constraint)
+        .iter()
+        .fold(join, |join, condition| join.on(condition)),
+      _ => list(unwrap_parens(constraint)).into_iter().fold(join, Join::using),
+    };
+  }
+
+  Ok(join)
+}
+
+fn parse_insert(sql: &str) -> Result<Insert, ParseError> {
+  let mut insert = Insert::new();
+
+  for (keyword, body) in clauses(sql, "INSERT INTO", &INSERT_KEYWORDS)? {
+    insert = match keyword {
+      "INSERT INTO" => insert.insert_into(body),
+      "VALUES" => list(body).into_iter().fold(insert, Insert::values),
+      "SELECT" => insert.select(parse_select(&format!("SELECT {body}"))?),
+      #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+      "DEFAULT VALUES" => insert.default_values(),
+      #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+      "OVERRIDING" => insert.overriding(body),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "WITH" => {
+        insert._with.extend(with_queries(body)?);
+        insert
+      }
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "ON CONFLICT" => insert.on_conflict(body),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "RETURNING" => list(body).into_iter().fold(insert, Insert::returning),
+      #[cfg(feature = "mysql")]
+      "ON DUPLICATE KEY UPDATE" => list(body).into_iter().fold(insert, Insert::on_duplicate_key_update),
+      clause => return Err(unsupported(clause)),
+    };
+  }
+
+  Ok(insert)
+}
+
+fn parse_update(sql: &str) -> Result<Update, ParseError> {
+  let mut update = Update::new();
+
+  for (keyword, body) in clauses(sql, "UPDATE", &UPDATE_KEYWORDS)? {
+    update = match keyword {
+      "UPDATE" => update.update(body),
+      "SET" => list(body).into_iter().fold(update, Update::set),
+      "WHERE" => conditions(body)
+        .iter()
+        .fold(update, |update, condition| update.where_clause(condition)),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "WITH" => {
+        update._with.extend(with_queries(body)?);
+        update
+      }
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "FROM" => list(body).into_iter().fold(update, Update::from),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "RETURNING" => list(body).into_iter().fold(update, Update::returning),
+      #[cfg(any(feature = "sqlite", feature = "mysql"))]
+      "ORDER BY" => list(body).into_iter().fold(update, Update::order_by),
+      #[cfg(feature = "mysql")]
+      "LIMIT" => update.limit(body),
+      clause => return Err(unsupported(clause)),
+    };
+  }
+
+  Ok(update)
+}
+
+fn parse_delete(sql: &str) -> Result<Delete, ParseError> {
+  let mut delete = Delete::new();
+
+  for (keyword, body) in clauses(sql, "DELETE FROM", &DELETE_KEYWORDS)? {
+    delete = match keyword {
+      "DELETE FROM" => delete.delete_from(body),
+      "WHERE" => conditions(body)
+        .iter()
+        .fold(delete, |delete, condition| delete.where_clause(condition)),
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+      "WITH" => {
+        delete._with.extend(with_queries(body)?);
+        delete
+      }
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      "RETURNING" => list(body).into_iter().fold(delete, Delete::returning),
+      #[cfg(any(feature = "sqlite", feature = "mysql"))]
+      "ORDER BY" => list(body).into_iter().fold(delete, Delete::order_by),
+      #[cfg(feature = "mysql")]
+      "LIMIT" => delete.limit(body),
+      clause => return Err(unsupported(clause)),
+    };
+  }
+
+  Ok(delete)
+}
+
+/// Parses the list of the `with` clause, each item is a name followed by the keyword `AS` and the query in parentheses
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+fn with_queries(body: &str) -> Result<Vec<(Fragment, Arc<dyn WithQuery + Send + Sync>)>, ParseError> {
+  if starts_with_word(body, "RECURSIVE") {
+    return Err(unsupported("WITH RECURSIVE"));
+  }
+
+  list(body)
+    .into_iter()
+    .map(|item| {
+      let parts = split(item, &["AS"]);
+      let (name, query) = match parts.as_slice() {
+        [(_, name), (_, query)] => (Fragment::from(*name), unwrap_parens(query)),
+        _ => return Err(unsupported("WITH")),
+      };
+
+      let query: Arc<dyn WithQuery + Send + Sync> = match query {
+        #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+        query if starts_with_word(query, "INSERT") => Arc::new(parse_insert(query)?),
+        #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+        query if starts_with_word(query, "UPDATE") => Arc::new(parse_update(query)?),
+        query if starts_with_word(query, "DELETE") => Arc::new(parse_delete(query)?),
+        query => Arc::new(parse_select(query)?),
+      };
+
+      Ok((name, query))
+    })
+    .collect()
+}
+
+fn unsupported(clause: &str) -> ParseError {
+  ParseError::UnsupportedClause {
+    clause: clause.to_string(),
+  }
+}
+
+/// Splits the statement into its clauses, the keywords must be in the order of the command, each one can appear
+/// only once and all of them, except the `DEFAULT VALUES`, must have a body. The select of an insert command is
+/// a whole statement, so the keywords of the insert found before its end are part of the select
+fn clauses<'a>(
+  sql: &'a str,
+  command: &'static str,
+  keywords: &[&'static str],
+) -> Result<Vec<(&'static str, &'a str)>, ParseError> {
+  let mut marks: Vec<(usize, usize, usize)> = vec![];
+
+  for (keyword_start, keyword_end, keyword) in marks_of(sql, keywords) {
+    let position = keywords.iter().position(|item| *item == keyword).unwrap();
+    if let Some((last_position, ..)) = marks.last() {
+      let last_keyword = keywords[*last_position];
+      if position <= *last_position && last_keyword == "SELECT" && command != "SELECT" {
+        continue;
+      }
+      if position <= *last_position {
+        return Err(ParseError::MisplacedClause {
+          clause: keyword.to_string(),
+        });
+      }
+    }
+    marks.push((position, keyword_start, keyword_end));
+  }
+
+  let has_command = marks.iter().any(|(position, ..)| keywords[*position] == command);
+  let starts_with_keyword = marks.first().map_or(false, |(_, keyword_start, _)| *keyword_start == 0);
+  if has_command == false || starts_with_keyword == false {
+    return Err(ParseError::UnexpectedStatement {
+      expected: command.to_string(),
+    });
+  }
+
+  let unsupported_keywords: &[&str] = match command {
+    "SELECT" => &UNSUPPORTED_SELECT_KEYWORDS,
+    "DELETE FROM" => &UNSUPPORTED_DELETE_KEYWORDS,
+    _ => &[],
+  };
+  if let Some((_, _, keyword)) = marks_of(sql, unsupported_keywords).first() {
+    return Err(unsupported(keyword));
+  }
+
+  let clauses = marks
+    .iter()
+    .enumerate()
+    .map(|(index, (position, _, keyword_end))| {
+      let body_end = marks.get(index + 1).map_or(sql.len(), |(_, start, _)| *start);
+      (keywords[*position], sql[*keyword_end..body_end].trim())
+    })
+    .collect::<Vec<_>>();
+
+  let empty_clause = clauses
+    .iter()
+    .find(|(keyword, body)| body.is_empty() && *keyword != "DEFAULT VALUES");
+  if let Some((keyword, _)) = empty_clause {
+    return Err(ParseError::EmptyClause {
+      clause: keyword.to_string(),
+    });
+  }
+
+  Ok(clauses)
+}
+
+/// Splits the text at each keyword, the first item has an empty keyword and contains the text before the first keyword
+pub(crate) fn split<'a>(sql: &'a str, keywords: &[&'static str]) -> Vec<(&'static str, &'a str)> {
+  let mut parts = vec![];
+  let mut keyword = "";
+  let mut body_start = 0;
+
+  for (keyword_start, keyword_end, next_keyword) in marks_of(sql, keywords) {
+    parts.push((keyword, sql[body_start..keyword_start].trim()));
+    keyword = next_keyword;
+    body_start = keyword_end;
+  }
+  parts.push((keyword, sql[body_start..].trim()));
+
+  parts
+}
+
+/// Splits the text at the commas outside of quotes and parentheses
+pub(crate) fn list(sql: &str) -> Vec<&str> {
+  let mut items = vec![];
+  let mut start = 0;
+
+  for (index, token) in tokens(sql) {
+    if token == "," {
+      items.push(sql[start..index].trim());
+      start = index + 1;
+    }
+  }
+  items.push(sql[start..].trim());
+
+  items.into_iter().filter(|item| item.is_empty() == false).collect()
+}
+
+/// Splits the conditions at the `AND` operator, the `AND` of a `BETWEEN` predicate is kept. When the conditions use
+/// the `OR` operator they are kept as a single condition wrapped in parentheses, this way the conditions added by the
+/// builder methods don't change the precedence of the original ones
+fn conditions(sql: &str) -> Vec<String> {
+  let tokens = tokens(sql);
+  if tokens.iter().any(|(_, token)| token.eq_ignore_ascii_case("OR")) {
+    return vec![format!("({sql})")];
+  }
+
+  let mut conditions = vec![];
+  let mut start = 0;
+  let mut between = false;
+
+  for (index, token) in tokens {
+    if token.eq_ignore_ascii_case("BETWEEN") {
+      between = true;
+    } else if token.eq_ignore_ascii_case("AND") && between {
+      between = false;
+    } else if token.eq_ignore_ascii_case("AND") {
+      conditions.push(sql[start..index].trim());
+      start = index + token.len();
+    }
+  }
+  conditions.push(sql[start..].trim());
+
+  conditions
+    .into_iter()
+    .filter(|condition| condition.is_empty() == false)
+    .map(|condition| condition.to_string())
+    .collect()
+}
+
+/// Returns the byte range of each keyword found outside of quotes, parentheses and `CASE` expressions, when more than
+/// one keyword matches at the same word the longest one is used
+pub(crate) fn marks_of(sql: &str, keywords: &[&'static str]) -> Vec<(usize, usize, &'static str)> {
+  let tokens = tokens(sql);
+  let mut marks = vec![];
+  let mut index = 0;
+
+  while index < tokens.len() {
+    let found = keywords
+      .iter()
+      .filter(|keyword| is_keyword_position(&tokens, index, keyword))
+      .filter_map(|keyword| keyword_len(sql, &tokens[index..], keyword).map(|len| (*keyword, len)))
+      .max_by_key(|(_, len)| *len);
+
+    match found {
+      Some((keyword, len)) => {
+        let (keyword_start, _) = tokens[index];
+        let (last_start, last_token) = tokens[index + len - 1];
+        marks.push((keyword_start, last_start + last_token.len(), keyword));
+        index += len;
+      }
+      None => index += 1,
+    }
+  }
+
+  marks
+}
+
+/// The `WITH` keyword is a clause only at the start of the statement and the `FROM` of the
+/// `IS DISTINCT FROM` predicate is not a clause
+fn is_keyword_position(tokens: &[(usize, &str)], index: usize, keyword: &str) -> bool {
+  match keyword {
+    "WITH" => index == 0,
+    "FROM" if index >= 2 => {
+      let (_, distinct) = tokens[index - 1];
+      let (_, operator) = tokens[index - 2];
+      let is_distinct_from = distinct.eq_ignore_ascii_case("DISTINCT")
+        && (operator.eq_ignore_ascii_case("IS") || operator.eq_ignore_ascii_case("NOT"));
+      is_distinct_from == false
+    }
+    _ => true,
+  }
+}
+
+/// Returns the number of tokens of the keyword when the tokens start with its words separated only by whitespaces
+fn keyword_len(sql: &str, tokens: &[(usize, &str)], keyword: &str) -> Option<usize> {
+  let mut prev_end = None;
+
+  for (len, word) in keyword.split(' ').enumerate() {
+    let (start, token) = *tokens.get(len)?;
+    let is_adjacent = prev_end.map_or(true, |end| sql[end..start].trim().is_empty());
+    if is_adjacent == false || token.eq_ignore_ascii_case(word) == false {
+      return None;
+    }
+    prev_end = Some(start + token.len());
+  }
+
+  Some(keyword.split(' ').count())
+}
+
+/// Returns the words and commas outside of quotes, parentheses and `CASE` expressions with its byte position.
+/// The words after a dot are names qualified by a table or a schema, like `t.limit`, they are never keywords
+pub(crate) fn tokens(sql: &str) -> Vec<(usize, &str)> {
+  let mut tokens = vec![];
+  let mut depth = 0;
+  let mut case_depth = 0;
+  let mut quote = None;
+  let mut word_start = None;
+
+  for (index, c) in sql.char_indices().chain([(sql.len(), ' ')]) {
+    if let Some(quote_char) = quote {
+      if c == quote_char {
+        quote = None;
+      }
+      continue;
+    }
+
+    if c.is_alphanumeric() || c == '_' {
+      word_start.get_or_insert(index);
+      continue;
+    }
+
+    let is_qualified = |start: &usize| sql[..*start].ends_with('.');
+    if let Some(start) = word_start.take().filter(|start| is_qualified(start) == false) {
+      let word = &sql[start..index];
+      if depth == 0 && word.eq_ignore_ascii_case("CASE") {
+        case_depth += 1;
+      } else if depth == 0 && case_depth > 0 && word.eq_ignore_ascii_case("END") {
+        case_depth -= 1;
+      } else if depth == 0 && case_depth == 0 {
+        tokens.push((start, word));
+      }
+    }
+
+    match c {
+      '\'' | '"' | '`' => quote = Some(c),
+      '(' => depth += 1,
+      ')' => depth -= 1,
+      ',' if depth == 0 && case_depth == 0 => tokens.push((index, &sql[index..index + 1])),
+      _ => {}
+    }
+  }
+
+  tokens
+}
+
+/// Removes the comments, collapses the whitespaces outside of quotes and removes the trailing semicolon
+fn normalize(sql: &str) -> Result<String, ParseError> {
+  let mut result = String::with_capacity(sql.len());
+  let mut chars = sql.char_indices().peekable();
+  let mut parens = vec![];
+
+  let push_space = |result: &mut String| {
+    if result.is_empty() == false && result.ends_with(' ') == false {
+      result.push(' ');
+    }
+  };
+
+  while let Some((index, c)) = chars.next() {
+    match c {
+      '\'' | '"' | '`' => {
+        result.push(c);
+        loop {
+          match chars.next() {
+            Some((_, next)) => result.push(next),
+            None => return Err(ParseError::Unbalanced { position: index }),
+          }
+          if result.ends_with(c) {
+            break;
+          }
+        }
+      }
+      '-' if matches!(chars.peek(), Some((_, '-'))) => {
+        chars.find(|(_, next)| *next == '\n');
+        push_space(&mut result);
+      }
+      '/' if matches!(chars.peek(), Some((_, '*'))) => {
+        chars.next();
+        let mut prev = ' ';
+        loop {
+          match chars.next() {
+            Some((_, '/')) if prev == '*' => break,
+            Some((_, next)) => prev = next,
+            None => return Err(ParseError::Unbalanced { position: index }),
+          }
+        }
+        push_space(&mut result);
+      }
+      '(' => {
+        parens.push(index);
+        result.push(c);
+      }
+      ')' => {
+        if parens.pop().is_none() {
+          return Err(ParseError::Unbalanced { position: index });
+        }
+        result.push(c);
+      }
+      c if c.is_whitespace() => push_space(&mut result),
+      c => result.push(c),
+    }
+  }
+
+  if let Some(position) = parens.pop() {
+    return Err(ParseError::Unbalanced { position });
+  }
+
+  let sql = result.trim();
+  let sql = sql.strip_suffix(';').unwrap_or(sql).trim_end();
+
+  Ok(sql.to_string())
+}
+
+/// Removes the parentheses wrapping the whole text
+pub(crate) fn unwrap_parens(sql: &str) -> &str {
+  let inner = match sql.strip_prefix('(').and_then(|sql| sql.strip_suffix(')')) {
+    Some(inner) => inner,
+    None => return sql,
+  };
+
+  let mut depth = 0;
+  let mut quote = None;
+  for c in inner.chars() {
+    match (quote, c) {
+      (Some(quote_char), c) if c == quote_char => quote = None,
+      (Some(_), _) => {}
+      (None, '\'' | '"' | '`') => quote = Some(c),
+      (None, '(') => depth += 1,
+      (None, ')') if depth == 0 => return sql,
+      (None, ')') => depth -= 1,
+      _ => {}
+    }
+  }
+
+  inner.trim()
+}
+
+pub(crate) fn starts_with_word(sql: &str, word: &str) -> bool {
+  tokens(sql)
+    .first()
+    .map_or(false, |(start, token)| *start == 0 && token.eq_ignore_ascii_case(word))
+}
@@ -0,0 +1,138 @@
+use crate::{
+  behavior::BindValues,
+  structure::{BuildError, Dialect, PreparedQuery, Value},
+};
+use std::hash::{Hash, Hasher};
+
+// Parameters of the 64 bits FNV-1a hash, the hash only depends on the bytes of the SQL,
+// so the same query has the same hash in every run, platform and version of the crate
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+impl PreparedQuery {
+  pub(crate) fn new(sql: String, values: Vec<Value>, param_count: usize, dialect: Dialect) -> Self {
+    Self {
+      _dialect: dialect,
+      _param_count: param_count,
+      _sql_hash: fnv1a(sql.as_bytes()),
+      _sql: sql.into(),
+      _values: values.into(),
+    }
+  }
+
+  /// Checks the values to execute the query, the number of values must match the number of parameters.
+  /// The values are returned in the order of the parameters, ready to be passed to the driver
+  /// together with the [PreparedQuery::sql]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let prepared = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = ?")
+  ///   .where_clause("id = ?")
+  ///   .prepare(sql::Dialect::Standard);
+  ///
+  /// let values = prepared.bind(("foo", 42)).unwrap();
+  /// let error = prepared.bind("foo").unwrap_err();
+  ///
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from(42)], values);
+  /// # assert_eq!(sql::BuildError::ParamCountMismatch { expected: 2, found: 1 }, error);
+  /// ```
+  pub fn bind(&self, values: impl BindValues) -> Result<Vec<Value>, BuildError> {
+    let values = values.into_values();
+    if values.len() != self._param_count {
+      return Err(BuildError::ParamCountMismatch {
+        expected: self._param_count,
+        found: values.len(),
+      });
+    }
+
+    Ok(values)
+  }
+
+  /// Gets the [Dialect] used to render the query
+  pub fn dialect(&self) -> Dialect {
+    self._dialect
+  }
+
+  /// Gets the number of parameters of the query, the placeholders written in the clauses
+  /// plus the ones of the values bound with the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let prepared = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 1)
+  ///   .where_clause("login = ?")
+  ///   .prepare(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!(2, prepared.param_count());
+  /// ```
+  pub fn param_count(&self) -> usize {
+    self._param_count
+  }
+
+  /// Gets the rendered SQL of the query
+  pub fn sql(&self) -> &str {
+    &self._sql
+  }
+
+  /// Gets the 64 bits FNV-1a hash of the SQL. Unlike the [std::hash::Hash] implementation this value doesn't
+  /// change between the runs of the program nor the versions of Rust, so it can be stored or shared between
+  /// processes to identify the statements
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let first = sql::Select::new().select("id").from("users").where_clause_bind("id = ?", 1);
+  /// let second = sql::Select::new().select("id").from("users").where_clause_bind("id = ?", 2);
+  ///
+  /// let first = first.prepare(sql::Dialect::Standard);
+  /// let second = second.prepare(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!(first.sql_hash(), second.sql_hash());
+  /// ```
+  pub fn sql_hash(&self) -> u64 {
+    self._sql_hash
+  }
+
+  /// Gets the values bound by the methods with the `_bind` suffix when the query was prepared,
+  /// useful to execute the query with the same values it was built
+  pub fn values(&self) -> &[Value] {
+    &self._values
+  }
+}
+
+impl std::fmt::Display for PreparedQuery {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    f.write_str(&self._sql)
+  }
+}
+
+// Two prepared queries are the same statement when the SQL and the dialect are the same,
+// the values bound when the query was prepared are not part of the statement
+impl PartialEq for PreparedQuery {
+  fn eq(&self, other: &Self) -> bool {
+    self._dialect == other._dialect && self._sql == other._sql
+  }
+}
+
+impl Eq for PreparedQuery {}
+
+impl Hash for PreparedQuery {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self._dialect.hash(state);
+    self._sql.hash(state);
+  }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+    (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+  })
+}
@@ -0,0 +1,958 @@
+use crate::{
+  concat::Concat,
+  fmt, highlight, parser,
+  structure::{CommaStyle, Dialect, FormatOptions, KeywordCase, TokenKind},
+};
+
+const SELECT_CLAUSES: [&str; 9] = [
+  "SELECT", "FROM", "WHERE", "GROUP BY", "HAVING", "WINDOW", "ORDER BY", "LIMIT", "OFFSET",
+];
+
+const INSERT_CLAUSES: [&str; 20] = [
+  "INSERT",
+  "INSERT INTO",
+  "INSERT OR",
+  "INSERT OR ABORT INTO",
+  "INSERT OR FAIL INTO",
+  "INSERT OR IGNORE INTO",
+  "INSERT OR REPLACE INTO",
+  "INSERT OR ROLLBACK INTO",
+  "REPLACE INTO",
+  "INTO",
+  "PARTITION",
+  "OVERRIDING",
+  "VALUES",
+  "DEFAULT VALUES",
+  "SET",
+  "SELECT",
+  "ON CONFLICT",
+  "DO UPDATE SET",
+  "ON DUPLICATE KEY UPDATE",
+  "RETURNING",
+];
+
+const UPDATE_CLAUSES: [&str; 7] = ["UPDATE", "SET", "FROM", "WHERE", "RETURNING", "ORDER BY", "LIMIT"];
+
+const DELETE_CLAUSES: [&str; 8] = [
+  "DELETE",
+  "DELETE FROM",
+  "FROM",
+  "USING",
+  "WHERE",
+  "RETURNING",
+  "ORDER BY",
+  "LIMIT",
+];
+
+const VALUES_CLAUSES: [&str; 3] = ["VALUES", "ORDER BY", "LIMIT"];
+
+const MERGE_CLAUSES: [&str; 7] = [
+  "MERGE INTO",
+  "USING",
+  "ON",
+  "WHEN MATCHED",
+  "WHEN NOT MATCHED",
+  "WHEN NOT MATCHED BY SOURCE",
+  "WHEN NOT MATCHED BY TARGET",
+];
+
+// The first of these words found in the statement tells which clauses it has, the statements of the other
+// commands are printed as a single expression
+const COMMANDS: [&str; 18] = [
+  "SELECT",
+  "INSERT",
+  "REPLACE",
+  "UPDATE",
+  "DELETE",
+  "VALUES",
+  "MERGE",
+  "ALTER",
+  "BEGIN",
+  "COMMIT",
+  "CREATE",
+  "DROP",
+  "END",
+  "RELEASE",
+  "ROLLBACK",
+  "SAVEPOINT",
+  "SET",
+  "START",
+];
+
+/// How the items of a clause are wrapped when the clause doesn't fit in the line
+#[derive(Clone, Copy)]
+pub(crate) enum Body {
+  List,
+  Conditions,
+  Text,
+}
+
+/// Formats the query of a builder, the layout follows the clauses written by the builder and the nesting
+/// of its sub-queries, the statements of a transaction are printed one after the other. Only the raw SQL,
+/// written without the structure of a builder, is laid out from its tokens
+pub(crate) fn format(query: &impl Concat, dialect: Dialect, options: &FormatOptions) -> String {
+  let fmts = fmt::Formatter {
+    dialect,
+    ..fmt::one_line()
+  };
+  let (segments, statements) = fmt::outline(query, &fmts);
+  let segments = segments
+    .iter()
+    .map(|segment| apply_case(&collapse(segment), options.keyword_case))
+    .collect::<Vec<_>>();
+  let printer = Printer { options };
+
+  statements
+    .into_iter()
+    .map(|statement| statement.resolve(&segments))
+    .filter(|statement| statement.clauses.is_empty() == false)
+    .map(|statement| printer.statement(&statement, 0).join("\n"))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// The clauses of the statements written by a builder, the [fmt::Writer] records them as the builder writes
+/// the items of its clauses. The text stays in the output of the writer, the outline refers to the segments
+/// of the output, the writer keeps the positions where they start
+pub(crate) struct Outline {
+  boundary: bool,
+  segment: usize,
+  stack: Vec<Statement>,
+  statements: Vec<Statement>,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Statement {
+  clauses: Vec<Clause>,
+}
+
+#[derive(Clone)]
+enum Clause {
+  /// The keyword followed by the items of the clause
+  Keyword {
+    keyword: String,
+    body: Body,
+    items: Vec<Item>,
+  },
+  /// The raw SQL, laid out from its tokens
+  Raw(Expr),
+  /// The text written outside of the clauses, printed in its own lines
+  Text(Expr),
+}
+
+#[derive(Clone, Default)]
+struct Item {
+  operator: String,
+  expr: Expr,
+}
+
+/// The text of an item with its sub-queries
+type Expr = Vec<Piece>;
+
+#[derive(Clone)]
+enum Piece {
+  Query(Statement),
+  Segment(usize),
+  Text(String),
+}
+
+impl Outline {
+  pub(crate) fn new() -> Self {
+    Self {
+      boundary: false,
+      segment: 0,
+      stack: vec![Statement::default()],
+      statements: vec![],
+    }
+  }
+
+  /// Returns true when the next text starts a new segment, the writer keeps the position of the text
+  pub(crate) fn take_boundary(&mut self) -> bool {
+    let boundary = self.boundary;
+    if boundary {
+      self.boundary = false;
+      self.segment += 1;
+    }
+    boundary
+  }
+
+  /// Records the segment of the text written to the output in the last clause
+  pub(crate) fn text(&mut self) {
+    let segment = self.segment;
+    let expr = self.statement().expr();
+    if let Some(Piece::Segment(last)) = expr.last() {
+      if *last == segment {
+        return;
+      }
+    }
+    expr.push(Piece::Segment(segment));
+  }
+
+  /// Ends the segment, the text written next is a new segment
+  pub(crate) fn boundary(&mut self) {
+    self.boundary = true;
+  }
+
+  pub(crate) fn clause(&mut self, keyword: &str, body: Body) {
+    self.boundary();
+    self.statement().clauses.push(Clause::Keyword {
+      keyword: keyword.to_string(),
+      body,
+      items: vec![],
+    });
+  }
+
+  /// Starts the next item of the last clause
+  pub(crate) fn item(&mut self, operator: &str) {
+    self.boundary();
+    if let Some(Clause::Keyword { items, .. }) = self.statement().clauses.last_mut() {
+      items.push(Item {
+        operator: operator.to_string(),
+        expr: vec![],
+      });
+    }
+  }
+
+  /// Ends the last clause, the text written next is printed in its own lines
+  pub(crate) fn end_clause(&mut self) {
+    self.boundary();
+    self.statement().clauses.push(Clause::Text(vec![]));
+  }
+
+  pub(crate) fn raw(&mut self) {
+    self.boundary();
+    self.statement().clauses.push(Clause::Raw(vec![]));
+  }
+
+  /// Starts a query nested in the last clause
+  pub(crate) fn open(&mut self) {
+    self.boundary();
+    self.stack.push(Statement::default());
+  }
+
+  pub(crate) fn close(&mut self) {
+    self.boundary();
+    if self.stack.len() > 1 {
+      if let Some(query) = self.stack.pop() {
+        self.statement().expr().push(Piece::Query(query));
+      }
+    }
+  }
+
+  /// Starts the next statement of a transaction
+  pub(crate) fn next_statement(&mut self) {
+    self.boundary();
+    if self.stack.len() == 1 {
+      let statement = std::mem::take(&mut self.stack[0]);
+      self.statements.push(statement);
+    }
+  }
+
+  pub(crate) fn into_statements(mut self) -> Vec<Statement> {
+    self.statements.extend(self.stack.drain(..1));
+    self.statements
+  }
+
+  fn statement(&mut self) -> &mut Statement {
+    let last = self.stack.len() - 1;
+    &mut self.stack[last]
+  }
+}
+
+impl Statement {
+  /// Returns the expression that receives the text written next, the last item of the last clause
+  fn expr(&mut self) -> &mut Expr {
+    if self.clauses.is_empty() {
+      self.clauses.push(Clause::Text(vec![]));
+    }
+    let last = self.clauses.len() - 1;
+    match &mut self.clauses[last] {
+      Clause::Keyword { items, .. } => {
+        if items.is_empty() {
+          items.push(Item::default());
+        }
+        let last = items.len() - 1;
+        &mut items[last].expr
+      }
+      Clause::Raw(expr) | Clause::Text(expr) => expr,
+    }
+  }
+
+  /// Replaces the segments by their text, the raw SQL is replaced by the clauses of its tokens
+  /// and the empty clauses and items are removed
+  fn resolve(self, segments: &[String]) -> Self {
+    let mut clauses = vec![];
+
+    for clause in self.clauses {
+      match clause {
+        Clause::Keyword { keyword, body, items } => {
+          let items = items
+            .into_iter()
+            .map(|item| Item {
+              operator: item.operator,
+              expr: resolve(item.expr, segments),
+            })
+            .filter(|item| is_blank(&item.expr) == false)
+            .collect();
+          clauses.push(Clause::Keyword { keyword, body, items });
+        }
+        Clause::Raw(expr) => {
+          let expr = resolve(expr, segments);
+          match text_of(&expr) {
+            Some(sql) => clauses.extend(parse(&sql).clauses),
+            None => clauses.push(Clause::Text(expr)),
+          }
+        }
+        Clause::Text(expr) => {
+          let expr = resolve(expr, segments);
+          if is_blank(&expr) == false {
+            clauses.push(Clause::Text(expr));
+          }
+        }
+      }
+    }
+
+    Self { clauses }
+  }
+}
+
+impl Item {
+  fn text(operator: &str, text: &str) -> Self {
+    Self {
+      operator: operator.to_string(),
+      expr: vec![Piece::Text(text.to_string())],
+    }
+  }
+}
+
+struct Printer<'a> {
+  options: &'a FormatOptions,
+}
+
+impl Printer<'_> {
+  /// Returns the lines of the statement, all lines are indented at the column
+  fn statement(&self, statement: &Statement, column: usize) -> Vec<String> {
+    let river = statement
+      .clauses
+      .iter()
+      .filter_map(|clause| match clause {
+        Clause::Keyword { keyword, .. } => Some(width(&self.keyword(keyword))),
+        _ => None,
+      })
+      .max()
+      .filter(|_| self.options.align);
+
+    let mut lines = vec![];
+    for clause in &statement.clauses {
+      match clause {
+        Clause::Keyword { keyword, body, items } => lines.extend(self.clause(keyword, *body, items, column, river)),
+        Clause::Raw(expr) | Clause::Text(expr) => lines.extend(self.text(expr, column)),
+      }
+    }
+
+    lines
+  }
+
+  /// Returns the lines of the clause, all lines are indented at the column. When the river is defined the keyword
+  /// is right-aligned to it and the wrapped items start in the column after the river
+  fn clause(&self, keyword: &str, body: Body, items: &[Item], column: usize, river: Option<usize>) -> Vec<String> {
+    let indent = pad(column);
+    let keyword = self.keyword(keyword);
+    let head = match river {
+      Some(river) => format!("{}{keyword}", pad(river - width(&keyword))),
+      None => keyword.clone(),
+    };
+
+    let mut items = split_items(body, items);
+    for item in &mut items {
+      item.operator = self.keyword(&item.operator);
+    }
+    if items.is_empty() {
+      return vec![format!("{indent}{head}")];
+    }
+
+    if let Some(text) = one_line(body, &items) {
+      let line = format!("{head} {text}");
+      if self.fits(column, &line) && has_query(&text) == false {
+        return vec![format!("{indent}{line}")];
+      }
+    }
+
+    let body_column = column + width(&head) + 1;
+    let body = match body {
+      Body::List | Body::Conditions if items.len() == 1 => Body::Text,
+      body => body,
+    };
+
+    match (body, river) {
+      (Body::List, None) => {
+        let mut lines = vec![format!("{indent}{head}")];
+        lines.extend(self.list(&items, column + self.options.indent_width));
+        lines
+      }
+      (Body::Conditions, None) => {
+        let mut lines = vec![format!("{indent}{head}")];
+        lines.extend(self.conditions(&items, column + self.options.indent_width, None));
+        lines
+      }
+      (Body::List, Some(_)) => {
+        let mut lines = self.list(&items, body_column);
+        lines[0] = format!("{indent}{head} {}", lines[0].trim_start());
+        lines
+      }
+      (Body::Conditions, Some(river)) => {
+        let mut lines = self.conditions(&items, body_column, Some(column + river));
+        lines[0] = format!("{indent}{head} {}", lines[0].trim_start());
+        lines
+      }
+      (Body::Text, _) => {
+        let expr_indent = if river.is_some() { body_column } else { column };
+        let mut lines = self.expr(&joined(&items), expr_indent, body_column);
+        lines[0] = format!("{indent}{head} {}", lines[0]);
+        lines
+      }
+    }
+  }
+
+  /// Returns one item per line, all lines are indented at the column. With the comma first style the items
+  /// are shifted by the width of the comma and the comma of the first item is replaced by spaces
+  fn list(&self, items: &[Item], column: usize) -> Vec<String> {
+    let last_index = items.len().saturating_sub(1);
+    let mut lines = vec![];
+
+    for (index, item) in items.iter().enumerate() {
+      match self.options.comma_style {
+        CommaStyle::Last => {
+          let mut item_lines = self.expr(&item.expr, column, column);
+          item_lines[0] = format!("{}{}", pad(column), item_lines[0]);
+          if index < last_index {
+            item_lines.last_mut().unwrap().push(',');
+          }
+          lines.extend(item_lines);
+        }
+        CommaStyle::First => {
+          let comma = if index == 0 { "  " } else { ", " };
+          let column = column.saturating_sub(comma.len());
+          let mut item_lines = self.expr(&item.expr, column + comma.len(), column + comma.len());
+          item_lines[0] = format!("{}{comma}{}", pad(column), item_lines[0]);
+          lines.extend(item_lines);
+        }
+      }
+    }
+
+    lines
+  }
+
+  /// Returns one condition per line starting with its logical operator, all lines are indented at the column.
+  /// When the river is defined the operators are right-aligned to it
+  fn conditions(&self, items: &[Item], column: usize, river: Option<usize>) -> Vec<String> {
+    let mut lines = vec![];
+
+    for Item { operator, expr } in items {
+      let prefix = match (operator.is_empty(), river) {
+        (true, _) => pad(column),
+        (false, Some(river)) => format!("{}{operator} ", pad(river.saturating_sub(width(operator)))),
+        (false, None) => format!("{}{operator} ", pad(column)),
+      };
+      let mut condition_lines = self.expr(expr, column, width(&prefix));
+      condition_lines[0] = format!("{prefix}{}", condition_lines[0]);
+      lines.extend(condition_lines);
+    }
+
+    lines
+  }
+
+  /// Returns the lines of the text, all lines are indented at the column
+  fn text(&self, expr: &[Piece], column: usize) -> Vec<String> {
+    let mut lines = self.expr(expr, column, column);
+    lines[0] = format!("{}{}", pad(column), lines[0]);
+    lines
+  }
+
+  /// Returns the lines of an expression that starts at the `start` column, the first line is returned without
+  /// indentation and the next ones are indented at the `indent` column. The sub-queries are always printed in their
+  /// own lines one level deeper than the expression
+  fn expr(&self, expr: &[Piece], indent: usize, start: usize) -> Vec<String> {
+    let query = expr.iter().position(|piece| matches!(piece, Piece::Query(_)));
+    let (index, statement) = match query.map(|index| (index, &expr[index])) {
+      Some((index, Piece::Query(statement))) => (index, statement),
+      _ => return self.sql(&text_of(expr).unwrap_or_default(), indent, start),
+    };
+
+    let mut lines = vec![text_of(&expr[..index]).unwrap_or_default()];
+    lines.extend(self.statement(statement, indent + self.options.indent_width));
+
+    let rest = &expr[index + 1..];
+    if is_blank(rest) == false {
+      let mut rest_lines = self.expr(rest, indent, indent);
+      rest_lines[0] = format!("{}{}", pad(indent), rest_lines[0]);
+      lines.extend(rest_lines);
+    }
+
+    lines
+  }
+
+  /// Same as [Printer::expr] for the text of the builders, the sub-queries written in the text are printed in
+  /// their own lines, the lists enclosed in parentheses and the lists of the text are wrapped only when they don't
+  /// fit in the line
+  fn sql(&self, text: &str, indent: usize, start: usize) -> Vec<String> {
+    let too_long = self.fits(start, text) == false;
+    let groups = groups(text);
+    let query_group = groups.iter().find(|(open, close)| is_query(&text[open + 1..*close]));
+    let list_group = groups
+      .iter()
+      .find(|(open, close)| parser::list(&text[open + 1..*close]).len() > 1);
+    let group = query_group.or(list_group.filter(|_| too_long));
+
+    if let Some((open, close)) = group {
+      let content = text[open + 1..*close].trim();
+      let inner_indent = indent + self.options.indent_width;
+      let mut lines = vec![text[..=*open].to_string()];
+
+      if is_query(content) {
+        lines.extend(self.statement(&parse(content), inner_indent));
+      } else {
+        lines.extend(self.list(&items_of(&parser::list(content)), inner_indent));
+      }
+
+      let mut rest = self.sql(&text[*close..], indent, indent);
+      rest[0] = format!("{}{}", pad(indent), rest[0]);
+      lines.extend(rest);
+
+      return lines;
+    }
+
+    let items = parser::list(text);
+    if too_long && items.len() > 1 {
+      let mut lines = self.list(&items_of(&items), indent + self.options.indent_width);
+      lines[0] = lines[0].trim_start().to_string();
+      return lines;
+    }
+
+    vec![text.to_string()]
+  }
+
+  fn fits(&self, column: usize, text: &str) -> bool {
+    column + width(text) <= self.options.max_line_length
+  }
+
+  fn keyword(&self, keyword: &str) -> String {
+    match self.options.keyword_case {
+      KeywordCase::Upper => keyword.to_uppercase(),
+      KeywordCase::Lower => keyword.to_lowercase(),
+    }
+  }
+}
+
+/// Lays out the raw SQL from its tokens, the clauses are found by the keywords of its command
+fn parse(sql: &str) -> Statement {
+  let mut clauses = vec![];
+  parse_statement(sql, &mut clauses);
+  Statement { clauses }
+}
+
+fn parse_statement(sql: &str, clauses: &mut Vec<Clause>) {
+  if let Some(with_end) = with_end(sql) {
+    let (with, command) = sql.split_at(with_end);
+    clauses.push(keyword_clause("WITH", with["WITH".len()..].trim()));
+    parse_statement(command.trim(), clauses);
+    return;
+  }
+
+  let combinators = parser::marks_of(sql, &parser::COMBINATOR_KEYWORDS);
+  if combinators.is_empty() {
+    parse_clauses(sql, clauses);
+    return;
+  }
+
+  let mut operand_start = 0;
+  for (keyword_start, keyword_end, keyword) in combinators {
+    parse_operand(sql[operand_start..keyword_start].trim(), clauses);
+    clauses.push(keyword_clause(keyword, ""));
+    operand_start = keyword_end;
+  }
+  parse_operand(sql[operand_start..].trim(), clauses);
+}
+
+/// The operands of the `UNION`, `EXCEPT` and `INTERSECT` are enclosed in parentheses in their own lines
+fn parse_operand(sql: &str, clauses: &mut Vec<Clause>) {
+  let inner = parser::unwrap_parens(sql);
+  if inner.len() == sql.len() {
+    parse_clauses(sql, clauses);
+    return;
+  }
+
+  clauses.push(Clause::Text(vec![
+    Piece::Text("(".to_string()),
+    Piece::Query(parse(inner)),
+    Piece::Text(")".to_string()),
+  ]));
+}
+
+fn parse_clauses(sql: &str, clauses: &mut Vec<Clause>) {
+  let keywords = clauses_of(sql);
+  if keywords.is_empty() {
+    parse_command(sql, clauses);
+    return;
+  }
+
+  for (keyword, body) in parser::split(sql, &keywords) {
+    match keyword {
+      "" if body.is_empty() => {}
+      "" => clauses.push(Clause::Text(vec![Piece::Text(body.to_string())])),
+      "SELECT" if keywords.contains(&"INSERT") => parse_statement(&format!("{keyword} {body}"), clauses),
+      _ => clauses.push(keyword_clause(keyword, body)),
+    }
+  }
+}
+
+/// The commands without clauses are printed in one expression, the query of a `CREATE VIEW` or
+/// `CREATE TABLE ... AS` starts in its own line
+fn parse_command(sql: &str, clauses: &mut Vec<Clause>) {
+  let select = parser::marks_of(sql, &["SELECT"])
+    .into_iter()
+    .map(|(start, _, _)| start)
+    .find(|start| *start > 0);
+
+  match select {
+    Some(start) => {
+      clauses.push(Clause::Text(vec![Piece::Text(sql[..start].trim().to_string())]));
+      parse_statement(&sql[start..], clauses);
+    }
+    None => clauses.push(Clause::Text(vec![Piece::Text(sql.to_string())])),
+  }
+}
+
+fn keyword_clause(keyword: &str, body: &str) -> Clause {
+  let items = match body.is_empty() {
+    true => vec![],
+    false => vec![Item::text("", body)],
+  };
+  Clause::Keyword {
+    keyword: keyword.to_string(),
+    body: body_of(keyword),
+    items,
+  }
+}
+
+fn body_of(keyword: &str) -> Body {
+  match keyword.to_uppercase().as_str() {
+    "SELECT"
+    | "FROM"
+    | "GROUP BY"
+    | "ORDER BY"
+    | "SET"
+    | "RETURNING"
+    | "VALUES"
+    | "WINDOW"
+    | "WITH"
+    | "DO UPDATE SET"
+    | "ON DUPLICATE KEY UPDATE" => Body::List,
+    "WHERE" | "HAVING" | "ON" => Body::Conditions,
+    _ => Body::Text,
+  }
+}
+
+/// Returns the keywords of the clauses of the command, the statements of the commands without clauses
+/// return no keyword
+fn clauses_of(sql: &str) -> Vec<&'static str> {
+  let command = parser::tokens(sql)
+    .into_iter()
+    .map(|(_, token)| token.to_uppercase())
+    .find(|token| COMMANDS.contains(&token.as_str()));
+
+  match command.as_deref() {
+    Some("INSERT" | "REPLACE") => INSERT_CLAUSES.to_vec(),
+    Some("UPDATE") => [&UPDATE_CLAUSES[..], &parser::JOIN_KEYWORDS[..]].concat(),
+    Some("DELETE") => [&DELETE_CLAUSES[..], &parser::JOIN_KEYWORDS[..]].concat(),
+    Some("VALUES") => VALUES_CLAUSES.to_vec(),
+    Some("MERGE") => MERGE_CLAUSES.to_vec(),
+    Some("SELECT") | None => [&SELECT_CLAUSES[..], &parser::JOIN_KEYWORDS[..]].concat(),
+    Some(_) => vec![],
+  }
+}
+
+/// Splits the text of the items at the commas of the lists and at the logical operators of the conditions,
+/// one item of the builder can have many of them, like the `select("id, login")`
+fn split_items(body: Body, items: &[Item]) -> Vec<Item> {
+  let mut split = vec![];
+
+  for item in items {
+    let text = match (body, text_of(&item.expr)) {
+      (Body::List | Body::Conditions, Some(text)) => text,
+      _ => {
+        split.push(item.clone());
+        continue;
+      }
+    };
+
+    match body {
+      Body::List => split.extend(items_of(&parser::list(&text))),
+      _ => {
+        for (index, (operator, condition)) in conditions(&text).into_iter().enumerate() {
+          let operator = if index == 0 { item.operator.as_str() } else { operator };
+          split.push(Item::text(operator, condition));
+        }
+      }
+    }
+  }
+
+  split
+}
+
+/// Returns the clause body in one line, the items with sub-queries are never printed in one line
+fn one_line(body: Body, items: &[Item]) -> Option<String> {
+  let mut line = String::new();
+
+  for (index, item) in items.iter().enumerate() {
+    let text = text_of(&item.expr)?;
+    if index > 0 {
+      line.push_str(if let Body::List = body { ", " } else { " " });
+    }
+    if item.operator.is_empty() == false {
+      line.push_str(&item.operator);
+      line.push(' ');
+    }
+    line.push_str(&text);
+  }
+
+  Some(line)
+}
+
+/// Returns the items as one expression separated by spaces
+fn joined(items: &[Item]) -> Expr {
+  let mut expr = vec![];
+
+  for (index, item) in items.iter().enumerate() {
+    let mut prefix = String::new();
+    if index > 0 {
+      prefix.push(' ');
+    }
+    if item.operator.is_empty() == false {
+      prefix.push_str(&item.operator);
+      prefix.push(' ');
+    }
+    if prefix.is_empty() == false {
+      expr.push(Piece::Text(prefix));
+    }
+    expr.extend(item.expr.iter().cloned());
+  }
+
+  merge(expr)
+}
+
+fn items_of(items: &[&str]) -> Vec<Item> {
+  items.iter().map(|item| Item::text("", item)).collect()
+}
+
+/// Replaces the segments of the expression by their text
+fn resolve(expr: Expr, segments: &[String]) -> Expr {
+  let expr = expr
+    .into_iter()
+    .map(|piece| match piece {
+      Piece::Query(statement) => Piece::Query(statement.resolve(segments)),
+      Piece::Segment(index) => Piece::Text(segments.get(index).cloned().unwrap_or_default()),
+      text => text,
+    })
+    .collect();
+
+  merge(expr)
+}
+
+/// Merges the consecutive texts of the expression
+fn merge(expr: Expr) -> Expr {
+  let mut merged: Expr = vec![];
+
+  for piece in expr {
+    match (merged.last_mut(), piece) {
+      (Some(Piece::Text(last)), Piece::Text(text)) => {
+        let is_spaced = last.ends_with(' ') || text.starts_with(' ');
+        if last.is_empty() == false && text.is_empty() == false && is_spaced == false {
+          last.push(' ');
+        }
+        last.push_str(&text);
+      }
+      (_, piece) => merged.push(piece),
+    }
+  }
+
+  merged
+}
+
+/// Returns the text of the expression, the expressions with sub-queries have no text
+fn text_of(expr: &[Piece]) -> Option<String> {
+  let mut text = String::new();
+  for piece in expr {
+    match piece {
+      Piece::Text(piece) => text.push_str(piece),
+      _ => return None,
+    }
+  }
+  Some(text)
+}
+
+fn is_blank(expr: &[Piece]) -> bool {
+  expr.iter().all(|piece| match piece {
+    Piece::Text(text) => text.trim().is_empty(),
+    _ => false,
+  })
+}
+
+/// Splits the conditions at the `AND` and `OR` operators outside of parentheses, the `AND` of a `BETWEEN`
+/// predicate is kept. The first condition is returned with an empty operator
+fn conditions(sql: &str) -> Vec<(&str, &str)> {
+  let mut conditions = vec![];
+  let mut operator = "";
+  let mut start = 0;
+  let mut between = false;
+
+  for (index, token) in parser::tokens(sql) {
+    let is_and = token.eq_ignore_ascii_case("AND");
+    if token.eq_ignore_ascii_case("BETWEEN") {
+      between = true;
+    } else if is_and && between {
+      between = false;
+    } else if is_and || token.eq_ignore_ascii_case("OR") {
+      conditions.push((operator, sql[start..index].trim()));
+      operator = token;
+      start = index + token.len();
+    }
+  }
+  conditions.push((operator, sql[start..].trim()));
+
+  conditions
+}
+
+/// Returns the end of the `WITH` clause at the start of the statement
+fn with_end(sql: &str) -> Option<usize> {
+  if parser::starts_with_word(sql, "WITH") == false {
+    return None;
+  }
+
+  parser::tokens(sql)
+    .into_iter()
+    .skip(1)
+    .find(|(_, token)| {
+      let token = token.to_uppercase();
+      ["SELECT", "INSERT", "REPLACE", "UPDATE", "DELETE", "MERGE", "VALUES"].contains(&token.as_str())
+    })
+    .map(|(start, _)| start)
+}
+
+/// Returns the byte position of the parentheses enclosing the groups outside of quotes and other parentheses
+fn groups(sql: &str) -> Vec<(usize, usize)> {
+  let mut groups = vec![];
+  let mut depth = 0;
+  let mut open = 0;
+  let mut quote = None;
+
+  for (index, c) in sql.char_indices() {
+    match (quote, c) {
+      (Some(quote_char), c) if c == quote_char => quote = None,
+      (Some(_), _) => {}
+      (None, '\'' | '"' | '`') => quote = Some(c),
+      (None, '(') => {
+        if depth == 0 {
+          open = index;
+        }
+        depth += 1;
+      }
+      (None, ')') if depth > 0 => {
+        depth -= 1;
+        if depth == 0 {
+          groups.push((open, index));
+        }
+      }
+      _ => {}
+    }
+  }
+
+  groups
+}
+
+fn has_query(sql: &str) -> bool {
+  groups(sql)
+    .into_iter()
+    .any(|(open, close)| is_query(&sql[open + 1..close]))
+}
+
+fn is_query(sql: &str) -> bool {
+  let sql = sql.trim();
+  ["SELECT", "WITH", "VALUES"]
+    .iter()
+    .any(|word| parser::starts_with_word(sql, word))
+}
+
+/// Collapses the whitespaces outside of quotes and removes the ones inside the parentheses edges, this way
+/// the query rendered in multiple lines is formatted in the same way of the query rendered in one line.
+/// The line comments are turned into block comments, they would comment out the text that follows them
+/// once the line break is collapsed
+fn collapse(sql: &str) -> String {
+  let mut result = String::with_capacity(sql.len());
+  let mut quote = None;
+  let mut chars = sql.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    match (quote, c) {
+      (None, '-') if chars.peek() == Some(&'-') => {
+        chars.next();
+        let mut comment = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '\n') {
+          comment.push(c);
+        }
+        result.push_str(&format!("/* {} */", comment.trim().replace("*/", "* /")));
+      }
+      (Some(quote_char), c) => {
+        result.push(c);
+        if c == quote_char {
+          quote = None;
+        }
+      }
+      (None, '\'' | '"' | '`') => {
+        quote = Some(c);
+        result.push(c);
+      }
+      (None, c) if c.is_whitespace() => {
+        if result.is_empty() == false && result.ends_with(' ') == false && result.ends_with('(') == false {
+          result.push(' ');
+        }
+      }
+      (None, ')') => {
+        if result.ends_with(' ') {
+          result.pop();
+        }
+        result.push(c);
+      }
+      (None, c) => result.push(c),
+    }
+  }
+
+  result.trim_end().to_string()
+}
+
+/// Changes the case of the keywords outside of quotes and comments
+fn apply_case(sql: &str, case: KeywordCase) -> String {
+  highlight::tokenize(sql)
+    .into_iter()
+    .map(|(kind, text)| {
+      let is_keyword = match kind {
+        TokenKind::Keyword => true,
+        TokenKind::Literal => text.eq_ignore_ascii_case("NULL"),
+        _ => false,
+      };
+      match (is_keyword, case) {
+        (true, KeywordCase::Upper) => text.to_uppercase(),
+        (true, KeywordCase::Lower) => text.to_lowercase(),
+        (false, _) => text.to_string(),
+      }
+    })
+    .collect()
+}
+
+fn pad(column: usize) -> String {
+  " ".repeat(column)
+}
+
+fn width(text: &str) -> usize {
+  text.chars().count()
+}
@@ -0,0 +1,496 @@
+use crate::structure::{
+  AlterTable, Column, ColumnDef, CreateTable, DataType, Dialect, DropTable, PrimaryKey, Schema, Table, Transaction,
+};
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+use crate::structure::{CreateIndex, DropIndex, Fragment, Index};
+
+#[cfg(feature = "mysql")]
+use crate::structure::AlterTableTypedAction;
+
+impl Schema {
+  /// Creates an empty schema
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Compares two versions of the schema and returns a [Transaction] with the commands that migrate
+  /// the `old` schema to the `new` one in the syntax of the `dialect`. The commands are ordered to be executed
+  /// one after the other: the indexes and tables removed are dropped first, then the tables added are created,
+  /// the tables kept are altered and at last the indexes added are created.
+  ///
+  /// The tables, columns and indexes are matched by name, so a renamed item is dropped and created again.
+  /// Each change of a column is a separate [AlterTable] command, except in MySQL where the column is modified
+  /// with its full definition. SQLite can't alter the columns or the primary key, in that case the table
+  /// is rebuilt as described in [AlterTable::sqlite_rebuild](crate::AlterTable) and its indexes are created again.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let old_users = sql::Table::new("users")
+  ///   .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+  ///   .column(sql::Column::new("name", sql::DataType::Text));
+  ///
+  /// let new_users = sql::Table::new("users")
+  ///   .column(sql::Column::new("id", sql::DataType::BigInt).not_null())
+  ///   .column(sql::Column::new("active", sql::DataType::Boolean).default("true"));
+  ///
+  /// let old_schema = sql::Schema::new().table(old_users);
+  /// let new_schema = sql::Schema::new().table(new_users);
+  ///
+  /// let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+  ///
+  /// # let expected = "\
+  /// #   ALTER TABLE users DROP COLUMN name; \
+  /// #   ALTER TABLE users ALTER COLUMN id SET DATA TYPE BIGINT; \
+  /// #   ALTER TABLE users ADD COLUMN active BOOLEAN DEFAULT true;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN name;
+  /// ALTER TABLE users ALTER COLUMN id SET DATA TYPE BIGINT;
+  /// ALTER TABLE users ADD COLUMN active BOOLEAN DEFAULT true;
+  /// ```
+  pub fn diff(old: &Schema, new: &Schema, dialect: Dialect) -> Transaction {
+    let mut transaction = Transaction::new();
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for old_table in old._table.iter() {
+      if let Some(new_table) = new.find_table(&old_table._name) {
+        let dropped_indexes = old_table
+          ._index
+          .iter()
+          .filter(|old_index| new_table._index.contains(old_index) == false);
+
+        for index in dropped_indexes {
+          transaction = transaction.drop_index(index.to_drop_index(&old_table._name));
+        }
+      }
+    }
+
+    for old_table in old._table.iter() {
+      if new.find_table(&old_table._name).is_none() {
+        transaction = transaction.drop_table(DropTable::new().drop_table(&old_table._name));
+      }
+    }
+
+    for new_table in new._table.iter() {
+      match old.find_table(&new_table._name) {
+        #[cfg(feature = "sqlite")]
+        Some(old_table) if Table::is_rebuilt(old_table, new_table, dialect) => {
+          let rebuild = Table::sqlite_rebuild(old_table, new_table);
+          transaction._ordered_commands.extend(rebuild._ordered_commands);
+        }
+        Some(old_table) => {
+          for alter_table in Table::diff(old_table, new_table, dialect) {
+            transaction = transaction.alter_table(alter_table);
+          }
+        }
+        None => transaction = transaction.create_table(new_table.to_create_table()),
+      }
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for new_table in new._table.iter() {
+      let old_indexes = old
+        .find_table(&new_table._name)
+        .filter(|old_table| Table::is_rebuilt(old_table, new_table, dialect) == false)
+        .map(|old_table| old_table._index.clone())
+        .unwrap_or_default();
+      let created_indexes = new_table
+        ._index
+        .iter()
+        .filter(|new_index| old_indexes.contains(new_index) == false);
+
+      for index in created_indexes {
+        transaction = transaction.create_index(index.to_create_index(&new_table._name));
+      }
+    }
+
+    transaction
+  }
+
+  /// Adds a table to the schema, a previous table with the same name is replaced
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let schema = sql::Schema::new()
+  ///   .table(sql::Table::new("users"))
+  ///   .table(sql::Table::new("orders"));
+  /// ```
+  pub fn table(mut self, table: Table) -> Self {
+    match self._table.iter_mut().find(|item| item._name == table._name) {
+      Some(item) => *item = table,
+      None => self._table.push(table),
+    }
+    self
+  }
+
+  fn find_table(&self, name: &str) -> Option<&Table> {
+    self._table.iter().find(|table| table._name == name)
+  }
+}
+
+impl Table {
+  /// Creates a table with its name
+  pub fn new(name: &str) -> Self {
+    Self {
+      _column: vec![],
+      _name: name.trim().to_string(),
+      _primary_key: vec![],
+
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+      _index: vec![],
+    }
+  }
+
+  /// Adds a column to the table, a previous column with the same name is replaced
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Table::new("users")
+  ///   .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+  ///   .column(sql::Column::new("login", sql::DataType::Text));
+  /// ```
+  pub fn column(mut self, column: Column) -> Self {
+    match self._column.iter_mut().find(|item| item._name == column._name) {
+      Some(item) => *item = column,
+      None => self._column.push(column),
+    }
+    self
+  }
+
+  /// Defines the columns of the primary key, the constraint is named `{table}_pkey`.
+  /// This method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Table::new("users")
+  ///   .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+  ///   .primary_key(&["id"]);
+  ///
+  /// let query = users.to_create_table().to_sql(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("CREATE TABLE users (id INTEGER NOT NULL, CONSTRAINT users_pkey PRIMARY KEY (id))", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id INTEGER NOT NULL, CONSTRAINT users_pkey PRIMARY KEY (id))
+  /// ```
+  pub fn primary_key(mut self, columns: &[&str]) -> Self {
+    self._primary_key = columns.iter().map(|column| column.trim().to_string()).collect();
+    self
+  }
+
+  /// Creates the [CreateTable] command of the table, the indexes are created by separated commands
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Table::new("users").column(sql::Column::new("login", sql::DataType::Varchar(40)));
+  ///
+  /// let query = users.to_create_table().to_sql(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("CREATE TABLE users (login VARCHAR(40))", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (login VARCHAR(40))
+  /// ```
+  pub fn to_create_table(&self) -> CreateTable {
+    let create_table = self
+      ._column
+      .iter()
+      .fold(CreateTable::new().create_table(&self._name), |create_table, column| {
+        create_table.column(column.to_column_def())
+      });
+
+    match self.primary_key_constraint() {
+      Some(primary_key) => create_table.constraint(&primary_key),
+      None => create_table,
+    }
+  }
+
+  /// The commands to alter the old table to the new one, one command for each change
+  fn diff(old: &Table, new: &Table, dialect: Dialect) -> Vec<AlterTable> {
+    let alter_table = || AlterTable::new().alter_table(&new._name);
+    let mut commands = vec![];
+
+    if old._primary_key != new._primary_key && old._primary_key.is_empty() == false {
+      let command = match dialect {
+        #[cfg(feature = "mysql")]
+        Dialect::Mysql => alter_table().drop("PRIMARY KEY"),
+        _ => alter_table().drop_constraint(old.primary_key_name()),
+      };
+      commands.push(command);
+    }
+
+    for old_column in old._column.iter() {
+      if new.find_column(&old_column._name).is_none() {
+        commands.push(alter_table().drop_column(&old_column._name));
+      }
+    }
+
+    for new_column in new._column.iter() {
+      match old.find_column(&new_column._name) {
+        Some(old_column) => match dialect {
+          #[cfg(feature = "mysql")]
+          Dialect::Mysql => commands.extend(Column::mysql_diff(old_column, new_column, alter_table)),
+          _ => commands.extend(Column::diff(old_column, new_column, alter_table)),
+        },
+        None => commands.push(alter_table().add_column(new_column.to_column_def())),
+      }
+    }
+
+    if old._primary_key != new._primary_key {
+      if let Some(primary_key) = new.primary_key_constraint() {
+        commands.push(alter_table().add_constraint(&primary_key));
+      }
+    }
+
+    commands
+  }
+
+  /// SQLite only adds and drops columns, the table is rebuilt when a column or the primary key changes
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  fn is_rebuilt(old: &Table, new: &Table, dialect: Dialect) -> bool {
+    let is_sqlite = match dialect {
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => true,
+      _ => false,
+    };
+
+    is_sqlite
+      && (old._primary_key != new._primary_key
+        || new._column.iter().any(|new_column| {
+          old
+            .find_column(&new_column._name)
+            .map_or(false, |old_column| old_column != new_column)
+        }))
+  }
+
+  /// The commands that rebuild the old table with the definition of the new one, the columns added are not copied
+  #[cfg(feature = "sqlite")]
+  fn sqlite_rebuild(old: &Table, new: &Table) -> Transaction {
+    let added_columns = new
+      ._column
+      .iter()
+      .filter(|new_column| old.find_column(&new_column._name).is_none());
+
+    added_columns
+      .fold(AlterTable::new().alter_table(&old._name), |alter_table, column| {
+        alter_table.add_column(column.to_column_def())
+      })
+      .sqlite_rebuild(new.to_create_table())
+  }
+
+  fn find_column(&self, name: &str) -> Option<&Column> {
+    self._column.iter().find(|column| column._name == name)
+  }
+
+  fn primary_key_constraint(&self) -> Option<PrimaryKey> {
+    if self._primary_key.is_empty() {
+      return None;
+    }
+
+    Some(PrimaryKey::new(&self._primary_key).name(self.primary_key_name()))
+  }
+
+  fn primary_key_name(&self) -> String {
+    format!("{}_pkey", self._name)
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+impl Table {
+  /// Adds an index to the table, a previous index with the same name is replaced
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let users = sql::Table::new("users")
+  ///   .column(sql::Column::new("login", sql::DataType::Text))
+  ///   .index(sql::Index::new("users_login_idx", &["login"]).unique());
+  /// # }
+  /// ```
+  pub fn index(mut self, index: Index) -> Self {
+    match self._index.iter_mut().find(|item| item._name == index._name) {
+      Some(item) => *item = index,
+      None => self._index.push(index),
+    }
+    self
+  }
+}
+
+impl Column {
+  /// Creates a nullable column with its name and data type
+  pub fn new(name: &str, data_type: DataType) -> Self {
+    Self {
+      _data_type: data_type,
+      _default: "".to_string(),
+      _name: name.trim().to_string(),
+      _not_null: false,
+    }
+  }
+
+  /// Defines the default value of the column, the expression is written as is, this method overrides the previous value
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let active = sql::Column::new("active", sql::DataType::Boolean).default("true");
+  ///
+  /// # assert_eq!("active BOOLEAN DEFAULT true", active.to_column_def().to_sql(sql::Dialect::Standard));
+  /// ```
+  pub fn default(mut self, expression: &str) -> Self {
+    self._default = expression.trim().to_string();
+    self
+  }
+
+  /// Defines the column as `NOT NULL`
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let login = sql::Column::new("login", sql::DataType::Text).not_null();
+  ///
+  /// # assert_eq!("login TEXT NOT NULL", login.to_column_def().to_sql(sql::Dialect::Standard));
+  /// ```
+  pub fn not_null(mut self) -> Self {
+    self._not_null = true;
+    self
+  }
+
+  /// Creates the [ColumnDef] of the column, used to create the table and to add the column
+  pub fn to_column_def(&self) -> ColumnDef {
+    let column_def = ColumnDef::new(&self._name, self._data_type.clone());
+    let column_def = if self._not_null {
+      column_def.not_null()
+    } else {
+      column_def
+    };
+
+    if self._default.is_empty() {
+      column_def
+    } else {
+      column_def.default(&self._default)
+    }
+  }
+
+  /// The commands to alter the old column to the new one, the type is changed before the default and the nullability
+  fn diff(old: &Column, new: &Column, alter_table: impl Fn() -> AlterTable) -> Vec<AlterTable> {
+    let mut commands = vec![];
+
+    if old._data_type != new._data_type {
+      commands.push(alter_table().alter_column_type(&new._name, new._data_type.clone()));
+    }
+
+    if old._default != new._default {
+      let command = match new._default.is_empty() {
+        true => alter_table().drop_default(&new._name),
+        false => alter_table().set_default(&new._name, &new._default),
+      };
+      commands.push(command);
+    }
+
+    if old._not_null != new._not_null {
+      let command = match new._not_null {
+        true => alter_table().set_not_null(&new._name),
+        false => alter_table().drop_not_null(&new._name),
+      };
+      commands.push(command);
+    }
+
+    commands
+  }
+
+  /// MySQL modifies the column with its full definition, the nullability and the default not redefined are lost
+  #[cfg(feature = "mysql")]
+  fn mysql_diff(old: &Column, new: &Column, alter_table: impl Fn() -> AlterTable) -> Option<AlterTable> {
+    if old == new {
+      return None;
+    }
+
+    let column_def = Fragment::from(new.to_column_def());
+    Some(alter_table().push_typed_action(AlterTableTypedAction::ModifyColumn(column_def)))
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+impl Index {
+  /// Creates an index with its name and the indexed columns
+  pub fn new(name: &str, columns: &[&str]) -> Self {
+    Self {
+      _column: columns.iter().map(|column| column.trim().to_string()).collect(),
+      _name: name.trim().to_string(),
+      _unique: false,
+    }
+  }
+
+  /// Defines the index as unique
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let index = sql::Index::new("users_login_idx", &["login"]).unique();
+  /// # }
+  /// ```
+  pub fn unique(mut self) -> Self {
+    self._unique = true;
+    self
+  }
+
+  fn to_create_index(&self, table_name: &str) -> CreateIndex {
+    let create_index = self._column.iter().fold(
+      CreateIndex::new().create_index(&self._name).on(table_name),
+      |create_index, column| create_index.column(column),
+    );
+
+    match self._unique {
+      true => create_index.unique(),
+      false => create_index,
+    }
+  }
+
+  /// MySQL requires the table of the index to drop it
+  fn to_drop_index(&self, table_name: &str) -> DropIndex {
+    let is_mysql = |dialect: Dialect| match dialect {
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => true,
+      _ => false,
+    };
+    let index_name = Fragment::variants(|dialect| match is_mysql(dialect) {
+      true => Fragment::from(format!("{} ON {table_name}", self._name)),
+      false => Fragment::from(&self._name),
+    });
+
+    DropIndex::new().drop_index(&index_name)
+  }
+}
@@ -1,14 +1,60 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{LogicalOperator, Select, SelectClause},
+  behavior::{BindValues, TransactionQuery, Validate},
+  bind, fmt, introspection, parser, pretty,
+  structure::{
+    BuildError, Dialect, FormatOptions, Fragment, Join, LogicalOperator, ParseError, PreparedQuery, Select,
+    SelectClause, Value, WhereGroup,
+  },
   utils::push_unique,
+  where_group,
 };
+use std::sync::Arc;
 
 impl TransactionQuery for Select {}
 
 impl Select {
+  /// Gets the current state of the [Select] and returns it as string formatted by the [FormatOptions], each clause
+  /// starts in its own line and the sub-queries are indented one level deeper than the clause that contains them
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .where_clause("active = true")
+  ///   .where_clause("id IN (SELECT user_id FROM admins)")
+  ///   .as_pretty_string(&sql::FormatOptions::default());
+  ///
+  /// # let expected = "\
+  /// # SELECT id, login\n\
+  /// # FROM users\n\
+  /// # WHERE\n  \
+  /// # active = true\n  \
+  /// # AND id IN (\n    \
+  /// # SELECT user_id\n    \
+  /// # FROM admins\n  \
+  /// # )";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login
+  /// FROM users
+  /// WHERE
+  ///   active = true
+  ///   AND id IN (
+  ///     SELECT user_id
+  ///     FROM admins
+  ///   )
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [Select] and returns it as string
   ///
   /// # Example
@@ -31,8 +77,115 @@ impl Select {
   /// SELECT id FROM users WHERE login = 'foo'
   /// ```
   pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Select] and returns it as string together with the values bound
+  /// by the methods with the `_bind` suffix, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, values) = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_clause_bind("active = ?", true)
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "SELECT id FROM users WHERE login = $1 AND active = $2";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from(true)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM users WHERE login = $1 AND active = $2
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the clauses of the [Select] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [Select::raw], [Select::raw_before] and [Select::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .from("users")
+  ///   .select("id");
+  ///
+  /// let clauses = select.clauses();
+  ///
+  /// # let expected = vec![sql::SelectClause::Select, sql::SelectClause::From, sql::SelectClause::Where];
+  /// # assert_eq!(expected, clauses);
+  /// ```
+  pub fn clauses(&self) -> Vec<SelectClause> {
+    let mut clauses = vec![];
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    if self._with.is_empty() == false {
+      clauses.push(SelectClause::With);
+    }
+    if self._select.is_empty() == false {
+      clauses.push(SelectClause::Select);
+    }
+    if self._from.is_empty() == false {
+      clauses.push(SelectClause::From);
+    }
+    #[cfg(feature = "mysql")]
+    if self._partition.is_empty() == false {
+      clauses.push(SelectClause::Partition);
+    }
+    if self._join.is_empty() == false {
+      clauses.push(SelectClause::Join);
+    }
+    if self._where.is_empty() == false {
+      clauses.push(SelectClause::Where);
+    }
+    if self._group_by.is_empty() == false {
+      clauses.push(SelectClause::GroupBy);
+    }
+    if self._having.is_empty() == false {
+      clauses.push(SelectClause::Having);
+    }
+    if self._window.is_empty() == false {
+      clauses.push(SelectClause::Window);
+    }
+    if self._order_by.is_empty() == false {
+      clauses.push(SelectClause::OrderBy);
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    {
+      if self._limit.is_empty() == false {
+        clauses.push(SelectClause::Limit);
+      }
+      if self._offset.is_empty() == false {
+        clauses.push(SelectClause::Offset);
+      }
+      if self._except.is_empty() == false {
+        clauses.push(SelectClause::Except);
+      }
+      if self._intersect.is_empty() == false {
+        clauses.push(SelectClause::Intersect);
+      }
+      if self._union.is_empty() == false {
+        clauses.push(SelectClause::Union);
+      }
+    }
+
+    clauses
   }
 
   /// Prints the current state of the [Select] to the standard output in a more ease to read version.
@@ -85,7 +238,7 @@ impl Select {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -101,11 +254,142 @@ impl Select {
   /// # let expected = "FROM users";
   /// # assert_eq!(expected, select.as_string());
   /// ```
-  pub fn from(mut self, table: &str) -> Self {
-    push_unique(&mut self._from, table.trim().to_string());
+  pub fn from(mut self, table: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._from, table.into().trim());
     self
   }
 
+  /// The `from` clause with a sub-query as the source of the rows, the sub-query is enclosed in parentheses
+  /// and named by the alias. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .where_clause("active = true");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("u.login")
+  ///   .from_subquery("u", active_users)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT u.login FROM (SELECT id, login FROM users WHERE active = true) AS u";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT u.login FROM (SELECT id, login FROM users WHERE active = true) AS u
+  /// ```
+  pub fn from_subquery(mut self, alias: impl Into<Fragment>, select: impl Into<Arc<Select>>) -> Self {
+    let alias = alias.into().trim();
+    let sub_query = Fragment::sub_query(select);
+    let source = if alias.is_empty() {
+      sub_query
+    } else {
+      sub_query.push(" AS ").push(alias)
+    };
+    push_unique(&mut self._from, source);
+    self
+  }
+
+  /// Gets the items of the `from` clause as they were added. The identifiers and the sub-queries of the
+  /// [Select::from_subquery] are parts of the [Fragment], rendered in the syntax of the dialect by [Fragment::to_sql]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .from("users u")
+  ///   .from("orders o");
+  ///
+  /// # assert_eq!(["users u", "orders o"], select.get_from());
+  /// ```
+  pub fn get_from(&self) -> &[Fragment] {
+    &self._from
+  }
+
+  /// Gets the columns of the `group by` clause
+  pub fn get_group_by(&self) -> &[Fragment] {
+    &self._group_by
+  }
+
+  /// Gets the conditions of the `having` clause
+  pub fn get_having(&self) -> &[Fragment] {
+    &self._having
+  }
+
+  /// Gets the joins in the order they were added, the [Join] is rendered by its [Display](std::fmt::Display)
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .from("users")
+  ///   .inner_join("orders ON orders.user_id = users.id");
+  ///
+  /// # assert_eq!("INNER JOIN orders ON orders.user_id = users.id", select.get_join()[0].to_string());
+  /// ```
+  pub fn get_join(&self) -> &[Join] {
+    &self._join
+  }
+
+  /// Gets the columns of the `order by` clause
+  pub fn get_order_by(&self) -> &[Fragment] {
+    &self._order_by
+  }
+
+  /// Gets the columns of the `select` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id, login")
+  ///   .select("name");
+  ///
+  /// # assert_eq!(["id, login", "name"], select.get_select());
+  /// ```
+  pub fn get_select(&self) -> &[Fragment] {
+    &self._select
+  }
+
+  /// Gets the conditions of the `where` clause together with the [LogicalOperator] that joins each one
+  /// to the previous conditions. The values bound by the methods with the `_bind` suffix are rendered
+  /// as placeholders and the sub-queries are rendered inside the condition
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_or("admin = true");
+  ///
+  /// let conditions = select.get_where();
+  ///
+  /// # let expected = [
+  /// #   (sql::LogicalOperator::And, sql::Fragment::from("active = true")),
+  /// #   (sql::LogicalOperator::Or, sql::Fragment::from("admin = true")),
+  /// # ];
+  /// # assert_eq!(expected, conditions);
+  /// ```
+  pub fn get_where(&self) -> &[(LogicalOperator, Fragment)] {
+    &self._where
+  }
+
+  /// Gets the definitions of the `window` clause
+  pub fn get_window(&self) -> &[Fragment] {
+    &self._window
+  }
+
   /// The `group by` clause
   ///
   /// # Example
@@ -118,8 +402,8 @@ impl Select {
   /// # let expected = "GROUP BY id";
   /// # assert_eq!(expected, select.as_string());
   /// ```
-  pub fn group_by(mut self, column: &str) -> Self {
-    push_unique(&mut self._group_by, column.trim().to_string());
+  pub fn group_by(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._group_by, column.into().trim());
     self
   }
 
@@ -143,8 +427,67 @@ impl Select {
   /// ```sql
   /// GROUP BY status HAVING status != 'disabled'
   /// ```
-  pub fn having(mut self, condition: &str) -> Self {
-    push_unique(&mut self._having, condition.trim().to_string());
+  pub fn having(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._having, condition.into().trim());
+    self
+  }
+
+  /// The `having` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order. The values are returned by the [Select::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (select_query, values) = sql::Select::new()
+  ///   .group_by("status")
+  ///   .having_bind("count(*) > ?", 10)
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "GROUP BY status HAVING count(*) > $1";
+  /// # assert_eq!(expected, select_query);
+  /// # assert_eq!(vec![sql::Value::from(10)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// GROUP BY status HAVING count(*) > $1
+  /// ```
+  pub fn having_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._having, bind::bind(condition, values));
+    self
+  }
+
+  /// The `join` clause with a [Join] builder, the table or sub-query and the conditions of the join are defined
+  /// separately. This method accumulates the joins in the same order of the `*_join` methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .from("users")
+  ///   .join(sql::Join::left("addresses").on("addresses.user_id = users.id"))
+  ///   .join(sql::Join::inner("roles").using("role_id"))
+  ///   .as_string();
+  ///
+  /// # let expected = "FROM users LEFT JOIN addresses ON addresses.user_id = users.id INNER JOIN roles USING (role_id)";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users
+  /// LEFT JOIN addresses ON addresses.user_id = users.id
+  /// INNER JOIN roles USING (role_id)
+  /// ```
+  pub fn join(mut self, join: Join) -> Self {
+    push_unique(&mut self._join, join);
     self
   }
 
@@ -168,11 +511,10 @@ impl Select {
   /// ```sql
   /// FROM users CROSS JOIN addresses
   /// ```
-  pub fn cross_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn cross_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("CROSS JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::cross(table));
     }
     self
   }
@@ -197,11 +539,10 @@ impl Select {
   /// ```sql
   /// FROM users INNER JOIN addresses on addresses.user_login = users.login
   /// ```
-  pub fn inner_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn inner_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("INNER JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::inner(table));
     }
     self
   }
@@ -226,11 +567,10 @@ impl Select {
   /// ```sql
   /// FROM users LEFT JOIN addresses on addresses.user_login = users.login
   /// ```
-  pub fn left_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn left_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("LEFT JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::left(table));
     }
     self
   }
@@ -255,11 +595,10 @@ impl Select {
   /// ```sql
   /// FROM users RIGHT JOIN addresses on addresses.user_login = users.login
   /// ```
-  pub fn right_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn right_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let join = format!("RIGHT JOIN {table}");
-      push_unique(&mut self._join, join);
+      push_unique(&mut self._join, Join::right(table));
     }
     self
   }
@@ -282,126 +621,423 @@ impl Select {
   /// # let expected = "SELECT name, login ORDER BY login asc";
   /// # assert_eq!(expected, select.as_string());
   /// ```
-  pub fn order_by(mut self, column: &str) -> Self {
-    push_unique(&mut self._order_by, column.trim().to_string());
+  pub fn order_by(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._order_by, column.into().trim());
     self
   }
 
+  /// Returns the number of parameters of the [Select], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound by the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("id = $1")
+  ///   .union(sql::Select::new().select("login").from("admins").where_clause("id = $1"));
+  ///
+  /// # let expected = "(SELECT login FROM users WHERE id = $1) UNION (SELECT login FROM admins WHERE id = $2)";
+  /// # assert_eq!(expected, select.as_string());
+  /// # assert_eq!(2, select.param_count());
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// (SELECT login FROM users WHERE id = $1) UNION (SELECT login FROM admins WHERE id = $2)
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
+  /// Parses the SQL text of a select command into a builder, this way an existing query can be changed with
+  /// the builder methods. The conditions of the `where` and `having` clauses are split at the `and` operator,
+  /// the conditions with the `or` operator are kept as a single condition wrapped in parentheses
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::parse("SELECT id, login FROM users WHERE active = true")
+  ///   .unwrap()
+  ///   .where_clause("login = $1")
+  ///   .order_by("id");
+  ///
+  /// # let expected = "SELECT id, login FROM users WHERE active = true AND login = $1 ORDER BY id";
+  /// # assert_eq!(expected, select.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login
+  /// FROM users
+  /// WHERE active = true AND login = $1
+  /// ORDER BY id
+  /// ```
+  pub fn parse(sql: &str) -> Result<Self, ParseError> {
+    parser::select(sql)
+  }
+
+  /// Renders the [Select] once in the syntax of the [Dialect] and returns a [PreparedQuery], the SQL and the number
+  /// of parameters are kept so the same query can be executed many times binding new values with the
+  /// [PreparedQuery::bind] method, without rendering the builder again
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .where_clause_bind("login = ?", "foo");
+  ///
+  /// let prepared = select.prepare(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("SELECT id, login FROM users WHERE login = ?", prepared.sql());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login FROM users WHERE login = ?
+  /// ```
+  pub fn prepare(&self, dialect: Dialect) -> PreparedQuery {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (sql, values, param_count) = fmt::render_prepared(self, &fmts);
+    PreparedQuery::new(sql, values, param_count, dialect)
+  }
+
   /// Prints the current state of the [Select] to the standard output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_query = "select * from users";
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .raw(raw_query)
+  ///   .where_clause("users.login = 'foo'")
+  ///   .as_string();
+  ///
+  /// # let expected = "select * from users WHERE users.login = 'foo'";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// select * from users WHERE users.login = 'foo'
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
-  /// Adds at the beginning a raw SQL query.
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_join = "inner join addresses addr on u.login = addr.owner_login";
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .from("users u")
+  ///   .raw_after(sql::SelectClause::From, raw_join)
+  ///   .where_clause("u.login = foo")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   FROM users u \
+  /// #   inner join addresses addr on u.login = addr.owner_login \
+  /// #   WHERE u.login = foo\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// FROM users u
+  /// inner join addresses addr on u.login = addr.owner_login
+  /// WHERE u.login = foo
+  /// ```
+  pub fn raw_after(mut self, clause: SelectClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let raw_query = "from users";
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .raw_before(sql::SelectClause::Where, raw_query)
+  ///   .where_clause("users.login = 'foo'")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   from users \
+  /// #   WHERE users.login = 'foo'\
+  /// # ";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// from users
+  /// WHERE users.login = 'foo'
+  /// ```
+  pub fn raw_before(mut self, clause: SelectClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim()));
+    self
+  }
+
+  /// The `select` clause
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("count(id)");
+  ///
+  /// # let expected = "SELECT count(id)";
+  /// # assert_eq!(expected, select.as_string());
+  /// ```
+  pub fn select(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._select, column.into().trim());
+    self
+  }
+
+  /// Gets the names of the tables read by the [Select], the tables of the `from` and `join` clauses and
+  /// of the sub-queries in the order they appear. The names of the common table expressions are not included
+  /// and the quoted names are returned without the quotes
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("u.login")
+  ///   .from("users u")
+  ///   .inner_join("orders o ON o.user_id = u.id")
+  ///   .where_in("u.id", sql::Select::new().select("user_id").from("admins"));
+  ///
+  /// let tables = select.tables();
+  ///
+  /// # assert_eq!(vec!["users", "orders", "admins"], tables);
+  /// ```
+  pub fn tables(&self) -> Vec<String> {
+    introspection::tables(self)
+  }
+
+  /// Gets the current state of the [Select] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .limit("10");
+  ///
+  /// let standard_query = select.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = select.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("SELECT id FROM users", standard_query);
+  /// # assert_eq!("SELECT id FROM users LIMIT 10", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// SELECT id FROM users
+  ///
+  /// -- Postgres
+  /// SELECT id FROM users LIMIT 10
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Select] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound by the methods with the `_bind` suffix,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause_bind("login = ?", "foo");
+  ///
+  /// let (standard_query, values) = select.to_sql_with_binds(sql::Dialect::Standard);
+  /// let (postgresql_query, _) = select.to_sql_with_binds(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("SELECT id FROM users WHERE login = ?", standard_query);
+  /// # assert_eq!("SELECT id FROM users WHERE login = $1", postgresql_query);
+  /// # assert_eq!(vec![sql::Value::from("foo")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// SELECT id FROM users WHERE login = ?
+  ///
+  /// -- Postgres
+  /// SELECT id FROM users WHERE login = $1
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Select] and returns it as string, returns a [BuildError] when
+  /// the [Select::validate] method fails
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let raw_query = "select * from users";
-  ///
-  /// let select_query = sql::Select::new()
-  ///   .raw(raw_query)
-  ///   .where_clause("users.login = 'foo'")
-  ///   .as_string();
+  /// let query = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .try_as_string()
+  ///   .unwrap();
   ///
-  /// # let expected = "select * from users WHERE users.login = 'foo'";
-  /// # assert_eq!(select_query, expected);
+  /// # assert_eq!("SELECT login FROM users", query);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// select * from users WHERE users.login = 'foo'
+  /// SELECT login FROM users
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
-    self
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
   }
 
-  /// Adds a raw SQL query after a specified clause.
+  /// Gets the current state of the [Select] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Select::validate_for] method fails
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let raw_join = "inner join addresses addr on u.login = addr.owner_login";
-  ///
-  /// let select_query = sql::Select::new()
-  ///   .from("users u")
-  ///   .raw_after(sql::SelectClause::From, raw_join)
-  ///   .where_clause("u.login = foo")
-  ///   .as_string();
+  /// let query = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
   ///
-  /// # let expected = "\
-  /// #   FROM users u \
-  /// #   inner join addresses addr on u.login = addr.owner_login \
-  /// #   WHERE u.login = foo\
-  /// # ";
-  /// # assert_eq!(select_query, expected);
+  /// # assert_eq!("SELECT login FROM users", query);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// FROM users u
-  /// inner join addresses addr on u.login = addr.owner_login
-  /// WHERE u.login = foo
+  /// SELECT login FROM users
   /// ```
-  pub fn raw_after(mut self, clause: SelectClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
-    self
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
   }
 
-  /// Adds a raw SQL query before a specified clause.
+  /// Checks the clauses of the [Select], returns a [BuildError] when the `select` clause is missing, when
+  /// the `having` clause is used without `group by` or a join without `from`. The operands of the combinators are
+  /// checked too. The required clauses are not checked when the raw SQL is set, it can contain any clause.
+  /// The number of values of the methods with the `_bind` suffix must match the placeholders of the clause
   ///
   /// # Example
   ///
   /// ```
   /// # use sql_query_builder as sql;
-  /// let raw_query = "from users";
-  ///
-  /// let select_query = sql::Select::new()
-  ///   .raw_before(sql::SelectClause::Where, raw_query)
-  ///   .where_clause("users.login = 'foo'")
-  ///   .as_string();
-  ///
-  /// # let expected = "\
-  /// #   from users \
-  /// #   WHERE users.login = 'foo'\
-  /// # ";
-  /// # assert_eq!(select_query, expected);
-  /// ```
+  /// let select = sql::Select::new()
+  ///   .select("login, count(*)")
+  ///   .from("users")
+  ///   .having("count(*) > 1");
   ///
-  /// Output
+  /// let error = select.validate().unwrap_err();
   ///
-  /// ```sql
-  /// from users
-  /// WHERE users.login = 'foo'
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "SELECT".to_string(),
+  /// #   clause: "GROUP BY".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
   /// ```
-  pub fn raw_before(mut self, clause: SelectClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
-    self
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
   }
 
-  /// The `select` clause
+  /// Checks the clauses of the [Select] like the [Select::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
   ///
   /// # Example
   ///
   /// ```
+  /// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  /// # {
   /// # use sql_query_builder as sql;
   /// let select = sql::Select::new()
-  ///   .select("count(id)");
+  ///   .select("*")
+  ///   .from("users")
+  ///   .join(sql::Join::full("addresses").on("addresses.user_id = users.id"));
   ///
-  /// # let expected = "SELECT count(id)";
-  /// # assert_eq!(expected, select.as_string());
+  /// let error = select.validate_for(sql::Dialect::Mysql).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Mysql,
+  /// #   feature: "FULL JOIN".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
   /// ```
-  pub fn select(mut self, column: &str) -> Self {
-    push_unique(&mut self._select, column.trim().to_string());
-    self
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
   }
 
   /// The method will concatenate multiples calls using the `and` operator. This method is un alias of `where_clause`.
@@ -430,7 +1066,7 @@ impl Select {
   ///   AND session_id = $2
   ///   AND created_at >= current_date
   /// ```
-  pub fn where_and(self, condition: &str) -> Self {
+  pub fn where_and(self, condition: impl Into<Fragment>) -> Self {
     self.where_clause(condition)
   }
 
@@ -457,8 +1093,39 @@ impl Select {
   ///   login = $1
   ///   AND status = 'active'
   /// ```
-  pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+  pub fn where_clause(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, condition.into().trim()));
+    self
+  }
+
+  /// The `where` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `and` operator.
+  /// The values are returned by the [Select::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (select_query, values) = sql::Select::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_clause_bind("created_at between ? and ?", ("2024-01-01", "2024-12-31"))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "WHERE login = $1 AND created_at between $2 and $3";
+  /// # assert_eq!(expected, select_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("2024-01-01"), sql::Value::from("2024-12-31")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE login = $1 AND created_at between $2 and $3
+  /// ```
+  pub fn where_clause_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, bind::bind(condition, values)));
     self
   }
 
@@ -515,6 +1182,31 @@ impl Select {
   ///
   /// # Example
   ///
+  /// The conditions built with [Expr](crate::Expr) keep its grouping, the `or` operations are enclosed in parentheses
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// use sql::col;
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .where_clause(&col("login").eq(col("'foo'")).or(col("login").eq(col("'bar'"))))
+  ///   .where_clause("active = true")
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE (login = 'foo' OR login = 'bar') AND active = true";
+  /// # assert_eq!(select_query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE
+  ///   (login = 'foo' OR login = 'bar')
+  ///   AND active = true
+  /// ```
+  ///
+  /// # Example
+  ///
   /// If the `where_or` was the first clause then the operator will be ignored
   ///
   /// ```
@@ -535,8 +1227,158 @@ impl Select {
   ///   login = 'joe'
   ///   AND login = 'foo'
   /// ```
-  pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+  pub fn where_or(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, condition.into().trim()));
+    self
+  }
+
+  /// The `where` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `or` operator.
+  /// The values are returned by the [Select::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (select_query, values) = sql::Select::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_or_bind("login = ?", "bar")
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "WHERE login = $1 OR login = $2";
+  /// # assert_eq!(expected, select_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("bar")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE login = $1 OR login = $2
+  /// ```
+  pub fn where_or_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, bind::bind(condition, values)));
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `and` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("a = 1")
+  ///   .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE a = 1 AND (b = 2 OR c = 3)";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE a = 1 AND (b = 2 OR c = 3)
+  /// ```
+  pub fn where_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::And, group);
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `or` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("a = 1")
+  ///   .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE a = 1 OR (b = 2 AND c = 3)";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE a = 1 OR (b = 2 AND c = 3)
+  /// ```
+  pub fn where_or_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::Or, group);
+    self
+  }
+
+  /// The `where` clause with the `exists` operator and a sub-query, this method will concatenate multiples calls
+  /// using the `and` operator. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let orders = sql::Select::new()
+  ///   .select("1")
+  ///   .from("orders")
+  ///   .where_clause("orders.user_id = users.id");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_exists(orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users
+  /// WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)
+  /// ```
+  pub fn where_exists(mut self, select: impl Into<Arc<Select>>) -> Self {
+    let condition = Fragment::from("EXISTS ").push(Fragment::sub_query(select));
+    push_unique(&mut self._where, (LogicalOperator::And, condition));
+    self
+  }
+
+  /// The `where` clause with the `in` operator and a sub-query, this method will concatenate multiples calls
+  /// using the `and` operator. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let admins = sql::Select::new()
+  ///   .select("user_id")
+  ///   .from("admins");
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_in("id", admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM users WHERE id IN (SELECT user_id FROM admins)";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users
+  /// WHERE id IN (SELECT user_id FROM admins)
+  /// ```
+  pub fn where_in(mut self, column: impl Into<Fragment>, select: impl Into<Arc<Select>>) -> Self {
+    let condition = column.into().trim().push(" IN ").push(Fragment::sub_query(select));
+    push_unique(&mut self._where, (LogicalOperator::And, condition));
     self
   }
 
@@ -559,14 +1401,59 @@ impl Select {
   /// ```sql
   /// WINDOW win as (partition by department)
   /// ```
-  pub fn window(mut self, name: &str) -> Self {
-    push_unique(&mut self._window, name.trim().to_string());
+  pub fn window(mut self, name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._window, name.into().trim());
     self
   }
+
+  /// Writes the current state of the [Select] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// select.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nSELECT id FROM users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Select] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Select::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users");
+  ///
+  /// let mut output = Vec::new();
+  /// select.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "SELECT id FROM users";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-use crate::behavior::WithQuery;
+use crate::{behavior::WithQuery, utils};
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 impl WithQuery for Select {}
@@ -616,6 +1503,53 @@ impl Select {
     self
   }
 
+  /// Gets the value of the `limit` clause, `None` when the clause is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .limit("10");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("10")), select.get_limit());
+  /// # assert_eq!(None, select.get_offset());
+  /// # }
+  /// ```
+  pub fn get_limit(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._limit)
+  }
+
+  /// Gets the value of the `offset` clause, `None` when the clause is not defined
+  pub fn get_offset(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._offset)
+  }
+
+  /// Gets the names of the common table expressions of the `with` clause in the order they were added,
+  /// the tables read by its queries are listed by [Select::tables]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let select = sql::Select::new()
+  ///   .with("admins", sql::Select::new().select("user_id").from("roles"))
+  ///   .select("*")
+  ///   .from("admins");
+  ///
+  /// # assert_eq!(vec![&sql::Fragment::from("admins")], select.get_with());
+  /// # }
+  /// ```
+  pub fn get_with(&self) -> Vec<&Fragment> {
+    self._with.iter().map(|(name, _)| name).collect()
+  }
+
   /// The `intersect` clause
   ///
   /// # Example
@@ -675,8 +1609,8 @@ impl Select {
   /// # assert_eq!(expected, select.as_string());
   /// # }
   /// ```
-  pub fn limit(mut self, num: &str) -> Self {
-    self._limit = num.trim().to_string();
+  pub fn limit(mut self, num: impl Into<Fragment>) -> Self {
+    self._limit = num.into().trim();
     self
   }
 
@@ -699,8 +1633,8 @@ impl Select {
   /// # assert_eq!(expected, select.as_string());
   /// # }
   /// ```
-  pub fn offset(mut self, num: &str) -> Self {
-    self._offset = num.trim().to_string();
+  pub fn offset(mut self, num: impl Into<Fragment>) -> Self {
+    self._offset = num.into().trim();
     self
   }
 
@@ -794,8 +1728,8 @@ impl Select {
   /// -- ------------------------------------------------------------------------------
   /// ```
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-  pub fn with(mut self, name: &str, query: impl WithQuery + Send + Sync + 'static) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Fragment>, query: impl WithQuery + Send + Sync + 'static) -> Self {
+    self._with.push((name.into().trim(), std::sync::Arc::new(query)));
     self
   }
 }
@@ -803,6 +1737,11 @@ impl Select {
 #[cfg(any(doc, feature = "mysql"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
 impl Select {
+  /// Gets the partitions of the `partition` clause
+  pub fn get_partition(&self) -> &[Fragment] {
+    &self._partition
+  }
+
   /// The `partition` clause
   ///
   /// # Example
@@ -815,7 +1754,7 @@ impl Select {
   ///   .select("*")
   ///   .from("employees")
   ///   .partition("p1")
-  ///   .to_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected_query = "SELECT * FROM employees PARTITION (p1)";
   /// # assert_eq!(expected_query, query);
@@ -827,8 +1766,8 @@ impl Select {
   /// ```sql
   /// SELECT * FROM employees PARTITION (p1)
   /// ```
-  pub fn partition(mut self, name: &str) -> Self {
-    push_unique(&mut self._partition, name.trim().to_string());
+  pub fn partition(mut self, name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._partition, name.into().trim());
     self
   }
 }
@@ -842,6 +1781,6 @@ impl std::fmt::Display for Select {
 impl std::fmt::Debug for Select {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,13 +1,17 @@
 use crate::{
+  behavior::Validate,
   concat::{
     concat_raw_before_after,
     sql_standard::{ConcatFrom, ConcatJoin, ConcatOrderBy, ConcatWhere},
     Concat,
   },
   fmt,
-  structure::{Select, SelectClause},
+  introspection::{CollectTables, Tables},
+  pretty::Body,
+  structure::{BuildError, Dialect, LogicalOperator, Select, SelectClause},
   utils,
 };
+use std::fmt::Write;
 
 impl ConcatFrom<SelectClause> for Select {}
 impl ConcatWhere<SelectClause> for Select {}
@@ -15,325 +19,344 @@ impl ConcatJoin<SelectClause> for Select {}
 impl ConcatOrderBy<SelectClause> for Select {}
 
 impl Concat for Select {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
-
-    #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_select(query, &fmts);
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::From,
-        &self._from,
-      );
-      query = self.concat_join(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Join,
-        &self._join,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Where,
-        &self._where,
-      );
-      query = self.concat_group_by(query, &fmts);
-      query = self.concat_having(query, &fmts);
-      query = self.concat_window(query, &fmts);
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::OrderBy,
-        &self._order_by,
-      );
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
+
+    match fmts.dialect {
+      Dialect::Standard => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_select(w, &fmts)?;
+        self.concat_from(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::From,
+          &self._from,
+        )?;
+        self.concat_join(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Join,
+          &self._join,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Where,
+          &self._where,
+        )?;
+        self.concat_group_by(w, &fmts)?;
+        self.concat_having(w, &fmts)?;
+        self.concat_window(w, &fmts)?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::OrderBy,
+          &self._order_by,
+        )?;
+      }
+
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        use crate::structure::Combinator;
+
+        self.concat_combinator_start(w)?;
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::With,
+          &self._with,
+        )?;
+        self.concat_select(w, &fmts)?;
+        self.concat_from(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::From,
+          &self._from,
+        )?;
+        self.concat_join(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Join,
+          &self._join,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Where,
+          &self._where,
+        )?;
+        self.concat_group_by(w, &fmts)?;
+        self.concat_having(w, &fmts)?;
+        self.concat_window(w, &fmts)?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::OrderBy,
+          &self._order_by,
+        )?;
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Limit,
+          &self._limit,
+        )?;
+        self.concat_offset(w, &fmts)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Except)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Intersect)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Union)?;
+      }
+
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => {
+        use crate::structure::Combinator;
+
+        self.concat_combinator_start(w)?;
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::With,
+          &self._with,
+        )?;
+        self.concat_select(w, &fmts)?;
+        self.concat_from(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::From,
+          &self._from,
+        )?;
+        self.concat_join(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Join,
+          &self._join,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Where,
+          &self._where,
+        )?;
+        self.concat_group_by(w, &fmts)?;
+        self.concat_having(w, &fmts)?;
+        self.concat_window(w, &fmts)?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::OrderBy,
+          &self._order_by,
+        )?;
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Limit,
+          &self._limit,
+        )?;
+        self.concat_offset(w, &fmts)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Except)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Intersect)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Union)?;
+      }
+
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => {
+        use crate::structure::Combinator;
+
+        self.concat_combinator_start(w)?;
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::With,
+          &self._with,
+        )?;
+        self.concat_select(w, &fmts)?;
+        self.concat_from(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::From,
+          &self._from,
+        )?;
+        self.concat_join(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Join,
+          &self._join,
+        )?;
+        self.concat_partition(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Partition,
+          &self._partition,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Where,
+          &self._where,
+        )?;
+        self.concat_group_by(w, &fmts)?;
+        self.concat_having(w, &fmts)?;
+        self.concat_window(w, &fmts)?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::OrderBy,
+          &self._order_by,
+        )?;
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          SelectClause::Limit,
+          &self._limit,
+        )?;
+        self.concat_offset(w, &fmts)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Except)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Intersect)?;
+        self.concat_combinator(w, &fmts, start, Combinator::Union)?;
+      }
     }
 
-    #[cfg(feature = "postgresql")]
-    {
-      use crate::structure::Combinator;
-
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::With,
-        &self._with,
-      );
-      query = self.concat_select(query, &fmts);
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::From,
-        &self._from,
-      );
-      query = self.concat_join(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Join,
-        &self._join,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Where,
-        &self._where,
-      );
-      query = self.concat_group_by(query, &fmts);
-      query = self.concat_having(query, &fmts);
-      query = self.concat_window(query, &fmts);
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::OrderBy,
-        &self._order_by,
-      );
-      query = self.concat_limit(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Limit,
-        &self._limit,
-      );
-      query = self.concat_offset(query, &fmts);
-      query = self.concat_combinator(query, &fmts, Combinator::Except);
-      query = self.concat_combinator(query, &fmts, Combinator::Intersect);
-      query = self.concat_combinator(query, &fmts, Combinator::Union);
-    }
-
-    #[cfg(feature = "sqlite")]
-    {
-      use crate::structure::Combinator;
-
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::With,
-        &self._with,
-      );
-      query = self.concat_select(query, &fmts);
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::From,
-        &self._from,
-      );
-      query = self.concat_join(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Join,
-        &self._join,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Where,
-        &self._where,
-      );
-      query = self.concat_group_by(query, &fmts);
-      query = self.concat_having(query, &fmts);
-      query = self.concat_window(query, &fmts);
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::OrderBy,
-        &self._order_by,
-      );
-      query = self.concat_limit(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Limit,
-        &self._limit,
-      );
-      query = self.concat_offset(query, &fmts);
-      query = self.concat_combinator(query, &fmts, Combinator::Except);
-      query = self.concat_combinator(query, &fmts, Combinator::Intersect);
-      query = self.concat_combinator(query, &fmts, Combinator::Union);
-    }
-
-    #[cfg(feature = "mysql")]
-    {
-      use crate::structure::Combinator;
-
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::With,
-        &self._with,
-      );
-      query = self.concat_select(query, &fmts);
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::From,
-        &self._from,
-      );
-      query = self.concat_join(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Join,
-        &self._join,
-      );
-      query = self.concat_partition(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Partition,
-        &self._partition,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Where,
-        &self._where,
-      );
-      query = self.concat_group_by(query, &fmts);
-      query = self.concat_having(query, &fmts);
-      query = self.concat_window(query, &fmts);
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::OrderBy,
-        &self._order_by,
-      );
-      query = self.concat_limit(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::Limit,
-        &self._limit,
-      );
-      query = self.concat_offset(query, &fmts);
-      query = self.concat_combinator(query, &fmts, Combinator::Except);
-      query = self.concat_combinator(query, &fmts, Combinator::Intersect);
-      query = self.concat_combinator(query, &fmts, Combinator::Union);
-    }
-
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl Select {
-  fn concat_group_by(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_group_by(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._group_by.is_empty() == false {
-      let columns = utils::join(&self._group_by, comma);
-      format!("GROUP BY{space}{columns}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       SelectClause::GroupBy,
-      sql,
+      |w| {
+        if self._group_by.is_empty() == false {
+          w.write_clause("GROUP BY", Body::List)?;
+          w.write_str(space)?;
+          w.write_items(&self._group_by, comma)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_having(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_having(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._having.is_empty() == false {
-      let conditions = utils::join(&self._having, " AND ");
-      format!("HAVING{space}{conditions}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       SelectClause::Having,
-      sql,
+      |w| {
+        if self._having.is_empty() == false {
+          w.write_clause("HAVING", Body::Conditions)?;
+          w.write_str(space)?;
+          let conditions = self._having.iter().filter(|condition| condition.is_empty() == false);
+          for (index, condition) in conditions.enumerate() {
+            if index > 0 {
+              w.write_operator(&LogicalOperator::And, |w| w.write_str(" AND "))?;
+            }
+            w.write_fragment(condition)?;
+          }
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_select(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._select.is_empty() == false {
-      let columns = utils::join(&self._select, comma);
-      format!("SELECT{space}{columns}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       SelectClause::Select,
-      sql,
+      |w| {
+        if self._select.is_empty() == false {
+          w.write_clause("SELECT", Body::List)?;
+          w.write_str(space)?;
+          w.write_items(&self._select, comma)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 
-  fn concat_window(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_window(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._window.is_empty() == false {
-      let columns = utils::join(&self._window, comma);
-      format!("WINDOW{space}{columns}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       SelectClause::Window,
-      sql,
+      |w| {
+        if self._window.is_empty() == false {
+          w.write_clause("WINDOW", Body::List)?;
+          w.write_str(space)?;
+          w.write_items(&self._window, comma)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
@@ -348,13 +371,26 @@ impl ConcatLimit<SelectClause> for Select {}
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 impl Select {
+  /// Opens one parenthesis for each combinator used, the query written so far is enclosed
+  /// before each combinator is written
+  fn concat_combinator_start(&self, w: &mut fmt::Writer) -> fmt::Result {
+    for clause_list in [&self._except, &self._intersect, &self._union] {
+      if clause_list.is_empty() == false {
+        w.write_char('(')?;
+        w.open_operand();
+      }
+    }
+    Ok(())
+  }
+
   fn concat_combinator(
     &self,
-    query: String,
+    w: &mut fmt::Writer,
     fmts: &fmt::Formatter,
+    start: fmt::Mark,
     combinator: crate::structure::Combinator,
-  ) -> String {
-    use crate::{concat::raw_queries, structure::Combinator};
+  ) -> fmt::Result {
+    use crate::{concat::push_joined, structure::Combinator};
 
     let fmt::Formatter { lb, space, .. } = fmts;
     let (clause, clause_name, clause_list) = match combinator {
@@ -363,56 +399,61 @@ impl Select {
       Combinator::Union => (SelectClause::Union, "UNION", &self._union),
     };
 
-    let raw_before = raw_queries(&self._raw_before, &clause).join(space).trim().to_string();
-    let raw_after = raw_queries(&self._raw_after, &clause).join(space).trim().to_string();
-
-    let space_before = if raw_before.is_empty() {
-      "".to_string()
-    } else {
-      space.to_string()
-    };
-    let space_after = if raw_after.is_empty() {
-      "".to_string()
-    } else {
-      space.to_string()
-    };
-
     if clause_list.is_empty() {
-      let sql = "".to_string();
-      return format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}");
+      return concat_raw_before_after(&self._raw_before, &self._raw_after, w, fmts, clause, |_| Ok(()));
     }
 
-    let right_stmt = clause_list.iter().fold("".to_string(), |acc, select| {
-      let query = select.concat(&fmts);
-      format!("{acc}{clause_name}{space}({lb}{query}){space}{lb}")
-    });
+    w.trim_end_since(start);
+    let raw_before = self
+      ._raw_before
+      .iter()
+      .filter(|item| item.0 == clause)
+      .map(|item| &item.1);
+    push_joined(w, raw_before, space)?;
+    w.close_operand();
+    write!(w, "){space}")?;
+
+    for select in clause_list {
+      w.write_clause(clause_name, Body::Text)?;
+      w.end_clause();
+      write!(w, "{space}({lb}")?;
+      w.write_sub_query(|w| select.concat(w, fmts))?;
+      write!(w, "){space}{lb}")?;
+    }
 
-    let query = query.trim_end();
-    let space_before = space;
-    let left_stmt = format!("({query}{raw_before}){space_before}");
+    let raw_after = self
+      ._raw_after
+      .iter()
+      .filter(|item| item.0 == clause)
+      .map(|item| &item.1);
+    if push_joined(w, raw_after, space)? {
+      w.write_str(space)?;
+    }
 
-    format!("{left_stmt}{right_stmt}{raw_after}{space_after}")
+    Ok(())
   }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 impl Select {
-  fn concat_offset(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_offset(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._offset.is_empty() == false {
-      let start = &self._offset;
-      format!("OFFSET{space}{start}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       SelectClause::Offset,
-      sql,
+      |w| {
+        if self._offset.is_empty() == false {
+          w.write_clause("OFFSET", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._offset)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
@@ -422,3 +463,89 @@ use crate::concat::mysql::ConcatPartition;
 
 #[cfg(feature = "mysql")]
 impl ConcatPartition<SelectClause> for Select {}
+
+impl Validate for Select {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) == false {
+      utils::require_clause("SELECT", "SELECT", self._select.is_empty())?;
+      utils::require_clause(
+        "SELECT",
+        "FROM",
+        self._from.is_empty() && self._join.is_empty() == false,
+      )?;
+      utils::require_clause(
+        "SELECT",
+        "GROUP BY",
+        self._group_by.is_empty() && self._having.is_empty() == false,
+      )?;
+    }
+
+    #[cfg(feature = "mysql")]
+    utils::require_support(
+      dialect,
+      "PARTITION",
+      self._partition.is_empty() == false,
+      utils::is_mysql(dialect),
+    )?;
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    {
+      let is_supported = dialect != Dialect::Standard;
+      utils::require_support(dialect, "WITH", self._with.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "LIMIT", self._limit.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "OFFSET", self._offset.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "UNION", self._union.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "EXCEPT", self._except.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "INTERSECT", self._intersect.is_empty() == false, is_supported)?;
+    }
+
+    for join in &self._join {
+      join.check_clauses(dialect)?;
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for (_, query) in &self._with {
+      query.check_clauses(dialect)?;
+    }
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for select in self._union.iter().chain(&self._except).chain(&self._intersect) {
+      select.check_clauses(dialect)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl CollectTables for Select {
+  fn collect_tables(&self, tables: &mut Tables) {
+    self._raw.iter().for_each(|raw| tables.raw(raw));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    for (name, query) in self._with.iter() {
+      tables.cte(name);
+      query.collect_tables(tables);
+    }
+
+    self._select.iter().for_each(|column| tables.expr(column));
+    self._from.iter().for_each(|table| tables.clause("FROM", table));
+    self._join.iter().for_each(|join| join.collect_tables(tables));
+    self._where.iter().for_each(|(_, condition)| tables.expr(condition));
+    self._group_by.iter().for_each(|column| tables.expr(column));
+    self._having.iter().for_each(|condition| tables.expr(condition));
+    self._window.iter().for_each(|window| tables.expr(window));
+    self._order_by.iter().for_each(|column| tables.expr(column));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    {
+      tables.expr(&self._limit);
+      tables.expr(&self._offset);
+      self._union.iter().for_each(|query| query.collect_tables(tables));
+      self._except.iter().for_each(|query| query.collect_tables(tables));
+      self._intersect.iter().for_each(|query| query.collect_tables(tables));
+    }
+
+    let raw_before_after = self._raw_before.iter().chain(self._raw_after.iter());
+    raw_before_after.for_each(|(_, raw)| tables.raw(raw));
+  }
+}
@@ -3,7 +3,6 @@ use crate::behavior::TransactionQuery;
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 use crate::behavior::WithQuery;
 
-#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 use std::sync::Arc;
 
 /// Builder of [AlterTable] command.
@@ -29,21 +28,21 @@ use std::sync::Arc;
 /// ```
 #[derive(Default, Clone)]
 pub struct AlterTable {
-  pub(crate) _alter_table: String,
+  pub(crate) _alter_table: Fragment,
   pub(crate) _ordered_actions: Vec<AlterTableActionItem>,
-  pub(crate) _raw_after: Vec<(AlterTableAction, String)>,
-  pub(crate) _raw_before: Vec<(AlterTableAction, String)>,
-  pub(crate) _raw: Vec<String>,
+  pub(crate) _raw_after: Vec<(AlterTableAction, Fragment)>,
+  pub(crate) _raw_before: Vec<(AlterTableAction, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _rename: String,
+  pub(crate) _rename: Fragment,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _rename_to: String,
+  pub(crate) _rename_to: Fragment,
 }
 
 #[derive(PartialEq, Clone)]
-pub(crate) struct AlterTableActionItem(pub(crate) AlterTableOrderedAction, pub(crate) String);
+pub(crate) struct AlterTableActionItem(pub(crate) AlterTableOrderedAction, pub(crate) Fragment);
 
 /// Actions used to build the sequencial part of [AlterTable]
 #[derive(PartialEq, Clone)]
@@ -56,10 +55,43 @@ pub(crate) enum AlterTableOrderedAction {
 
   #[cfg(feature = "mysql")]
   Rename,
+
+  Typed(AlterTableTypedAction),
 }
 
-/// All available params to be used in [AlterTable::raw_before] and [AlterTable::raw_after] methods on [AlterTable] builder
+/// Typed actions of [AlterTable], rendered in the syntax of the dialect
 #[derive(PartialEq, Clone)]
+pub(crate) enum AlterTableTypedAction {
+  AddColumn(Fragment),
+  AddConstraint(Fragment),
+  AlterColumnType(Fragment, DataType, Fragment),
+  DropColumn(Fragment),
+  DropConstraint(Fragment),
+  DropDefault(Fragment),
+  DropNotNull(Fragment),
+  RenameColumn(Fragment, Fragment),
+  SetDefault(Fragment, Fragment),
+  SetNotNull(Fragment),
+
+  #[cfg(feature = "postgresql")]
+  OwnerTo(Fragment),
+
+  #[cfg(feature = "postgresql")]
+  SetSchema(Fragment),
+
+  #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  SetTablespace(Fragment),
+
+  #[cfg(feature = "postgresql")]
+  ValidateConstraint(Fragment),
+
+  /// The full definition of the column, used by the [Schema::diff] because MySQL redefines the whole column
+  #[cfg(feature = "mysql")]
+  ModifyColumn(Fragment),
+}
+
+/// All available params to be used in [AlterTable::raw_before] and [AlterTable::raw_after] methods on [AlterTable] builder
+#[derive(Debug, PartialEq, Clone)]
 pub enum AlterTableAction {
   AlterTable,
 
@@ -73,10 +105,7 @@ pub enum AlterTableAction {
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   RenameTo,
 
-  #[cfg(not(any(feature = "postgresql")))]
   Add,
-
-  #[cfg(not(any(feature = "postgresql")))]
   Drop,
 }
 
@@ -87,6 +116,372 @@ pub(crate) enum Combinator {
   Union,
 }
 
+/// Builder of a column definition, renders the data type and the constraints of the column in the syntax
+/// of the dialect the query is rendered, like the auto-increment that is written differently by each database.
+/// The definition can be passed to [CreateTable::column] and to [AlterTable::add],
+/// to compose it inside a clause use a [Fragment]
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let id = sql::ColumnDef::new("id", sql::DataType::Integer).identity().primary_key();
+/// let login = sql::ColumnDef::new("login", sql::DataType::Varchar(40)).not_null().unique();
+///
+/// let query = sql::CreateTable::new()
+///   .create_table("users")
+///   .column(&id)
+///   .column(&login)
+///   .to_sql(sql::Dialect::Standard);
+///
+/// # let expected = "\
+/// #   CREATE TABLE users (\
+/// #     id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY, \
+/// #     login VARCHAR(40) NOT NULL UNIQUE\
+/// #   )\
+/// # ";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// CREATE TABLE users (
+///   id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+///   login VARCHAR(40) NOT NULL UNIQUE
+/// )
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ColumnDef {
+  pub(crate) _check: Fragment,
+  pub(crate) _data_type: DataType,
+  pub(crate) _default: Fragment,
+  pub(crate) _generated: Fragment,
+  pub(crate) _generated_stored: bool,
+  pub(crate) _identity: bool,
+  pub(crate) _name: Fragment,
+  pub(crate) _nullable: Option<bool>,
+  pub(crate) _primary_key: bool,
+  pub(crate) _references: Fragment,
+  pub(crate) _unique: bool,
+}
+
+/// Errors returned when a builder cannot be rendered in the syntax of a dialect
+///
+/// ```
+/// # #[cfg(feature = "sqlite")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let alter_table = sql::AlterTable::new()
+///   .alter_table("users")
+///   .set_not_null("login");
+///
+/// let error = alter_table.try_to_sql(sql::Dialect::Sqlite).unwrap_err();
+///
+/// # let expected = sql::BuildError::UnsupportedFeature {
+/// #   dialect: sql::Dialect::Sqlite,
+/// #   feature: "ALTER COLUMN SET NOT NULL".to_string(),
+/// # };
+/// # assert_eq!(expected, error);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+  /// The dialect has no syntax for the feature
+  UnsupportedFeature { dialect: Dialect, feature: String },
+
+  /// The dialect cannot execute the actions together in a single statement
+  IncompatibleActions { dialect: Dialect, actions: Vec<String> },
+
+  /// Two migrations of the [MigrationSet] have the same version
+  DuplicateMigration { version: u64 },
+
+  /// The versions of the [MigrationSet] are not consecutive, the migration of the version is missing
+  MissingMigration { version: u64 },
+
+  /// The command cannot be rendered as a valid statement without the clause
+  MissingClause { command: String, clause: String },
+
+  /// The clauses cannot be used together in the same command
+  ConflictingClauses { command: String, clauses: Vec<String> },
+
+  /// The number of values bound to the [PreparedQuery] is not the number of its parameters
+  ParamCountMismatch { expected: usize, found: usize },
+}
+
+/// A query rendered once in the syntax of a [Dialect] and reused as many times as needed, only the values
+/// of its parameters change between the executions. The SQL is shared between the clones, so cloning is cheap,
+/// and the [PreparedQuery::sql_hash] is stable to be used as the key of the statement cache of the drivers
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let prepared = sql::Select::new()
+///   .select("id, login")
+///   .from("users")
+///   .where_clause_bind("login = ?", "foo")
+///   .prepare(sql::Dialect::Postgresql);
+///
+/// let values = prepared.bind("bar").unwrap();
+///
+/// # assert_eq!("SELECT id, login FROM users WHERE login = $1", prepared.sql());
+/// # assert_eq!(vec![sql::Value::from("bar")], values);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT id, login FROM users WHERE login = $1
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedQuery {
+  pub(crate) _dialect: Dialect,
+  pub(crate) _param_count: usize,
+  pub(crate) _sql: Arc<str>,
+  pub(crate) _sql_hash: u64,
+  pub(crate) _values: Arc<[Value]>,
+}
+
+/// Errors returned when an SQL text cannot be parsed into a builder
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let error = sql::Select::parse("UPDATE users SET login = 'foo'").unwrap_err();
+///
+/// # let expected = sql::ParseError::UnexpectedStatement {
+/// #   expected: "SELECT".to_string(),
+/// # };
+/// # assert_eq!(expected, error);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+  /// The text is not a statement of the command of the builder
+  UnexpectedStatement { expected: String },
+
+  /// The builder has no method to represent the clause with the enabled crate features
+  UnsupportedClause { clause: String },
+
+  /// The clause appears more than once or out of the order of the command
+  MisplacedClause { clause: String },
+
+  /// The clause has no body, like a `SELECT` without the columns
+  EmptyClause { clause: String },
+
+  /// The quote, comment or parenthesis at the byte position is not closed or not opened
+  Unbalanced { position: usize },
+}
+
+/// Builder of a `CHECK` table constraint. The constraint can be passed to [CreateTable::constraint]
+/// and to [AlterTable::add], to compose it inside a clause use a [Fragment]
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let adult = sql::Check::new("age >= 18").name("users_adult_check");
+///
+/// let query = sql::CreateTable::new()
+///   .create_table("users")
+///   .column("age integer")
+///   .constraint(&adult)
+///   .as_string();
+///
+/// # let expected = "CREATE TABLE users (age integer, CONSTRAINT users_adult_check CHECK (age >= 18))";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// CREATE TABLE users (
+///   age integer,
+///   CONSTRAINT users_adult_check CHECK (age >= 18)
+/// )
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Check {
+  pub(crate) _condition: Fragment,
+  pub(crate) _name: Fragment,
+}
+
+/// Builder of an `EXCLUDE` table constraint, available only for the crate feature `postgresql`.
+/// The constraint can be passed to [CreateTable::constraint] and to [AlterTable::add],
+/// to compose it inside a clause use a [Fragment]
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let no_overlap = sql::Exclude::new()
+///   .using("gist")
+///   .element("room_id", "=")
+///   .element("during", "&&");
+///
+/// let query = sql::AlterTable::new()
+///   .alter_table("bookings")
+///   .add(&no_overlap)
+///   .as_string();
+///
+/// # let expected = "ALTER TABLE bookings ADD EXCLUDE USING gist (room_id WITH =, during WITH &&)";
+/// # assert_eq!(expected, query);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// ALTER TABLE bookings ADD EXCLUDE USING gist (room_id WITH =, during WITH &&)
+/// ```
+#[cfg(feature = "postgresql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[derive(Clone, PartialEq)]
+pub struct Exclude {
+  pub(crate) _deferrable: Deferrability,
+  pub(crate) _element: Vec<(Fragment, Fragment)>,
+  pub(crate) _name: Fragment,
+  pub(crate) _using: Fragment,
+  pub(crate) _where: Fragment,
+}
+
+/// Builder of a `FOREIGN KEY` table constraint. The constraint can be passed to [CreateTable::foreign_key]
+/// and to [AlterTable::add], to compose it inside a clause use a [Fragment]
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let user_fk = sql::ForeignKey::new(&["user_id"])
+///   .name("orders_user_fk")
+///   .references("users", &["id"])
+///   .on_delete(sql::ReferentialAction::Cascade);
+///
+/// let query = sql::CreateTable::new()
+///   .create_table("orders")
+///   .column("user_id integer")
+///   .foreign_key(&user_fk)
+///   .to_sql(sql::Dialect::Standard);
+///
+/// # let expected = "\
+/// #   CREATE TABLE orders (\
+/// #     user_id integer, \
+/// #     CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE\
+/// #   )\
+/// # ";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// CREATE TABLE orders (
+///   user_id integer,
+///   CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+/// )
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ForeignKey {
+  pub(crate) _column: Vec<Fragment>,
+  pub(crate) _deferrable: Deferrability,
+  pub(crate) _match: Option<MatchType>,
+  pub(crate) _name: Fragment,
+  pub(crate) _on_delete: Option<ReferentialAction>,
+  pub(crate) _on_update: Option<ReferentialAction>,
+  pub(crate) _references: Fragment,
+  pub(crate) _references_column: Vec<Fragment>,
+}
+
+/// Builder of a `PRIMARY KEY` table constraint. The constraint can be passed to [CreateTable::primary_key]
+/// and to [AlterTable::add], to compose it inside a clause use a [Fragment]
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let pk = sql::PrimaryKey::new(&["order_id", "product_id"]).name("order_items_pk");
+///
+/// let query = sql::AlterTable::new()
+///   .alter_table("order_items")
+///   .add(&pk)
+///   .as_string();
+///
+/// # let expected = "ALTER TABLE order_items ADD CONSTRAINT order_items_pk PRIMARY KEY (order_id, product_id)";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// ALTER TABLE order_items ADD CONSTRAINT order_items_pk PRIMARY KEY (order_id, product_id)
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct PrimaryKey {
+  pub(crate) _column: Vec<Fragment>,
+  pub(crate) _deferrable: Deferrability,
+  pub(crate) _name: Fragment,
+}
+
+/// Builder of a `UNIQUE` table constraint. The constraint can be passed to [CreateTable::constraint]
+/// and to [AlterTable::add], to compose it inside a clause use a [Fragment]
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let unique_login = sql::Unique::new(&["login"]).name("users_login_key");
+///
+/// let query = sql::CreateTable::new()
+///   .create_table("users")
+///   .column("login varchar(40)")
+///   .constraint(&unique_login)
+///   .as_string();
+///
+/// # let expected = "CREATE TABLE users (login varchar(40), CONSTRAINT users_login_key UNIQUE (login))";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// CREATE TABLE users (
+///   login varchar(40),
+///   CONSTRAINT users_login_key UNIQUE (login)
+/// )
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Unique {
+  pub(crate) _column: Vec<Fragment>,
+  pub(crate) _deferrable: Deferrability,
+  pub(crate) _name: Fragment,
+}
+
+/// The deferrability of the constraints, used internally by the constraint builders
+#[derive(Clone, Copy, PartialEq, Default)]
+pub(crate) enum Deferrability {
+  #[default]
+  None,
+  Deferrable,
+  InitiallyDeferred,
+}
+
+/// The match type of the [ForeignKey] constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchType {
+  Full,
+  Partial,
+  Simple,
+}
+
+/// The referential actions of the [ForeignKey] constraint executed when the referenced row is deleted or updated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReferentialAction {
+  Cascade,
+  NoAction,
+  Restrict,
+  SetDefault,
+  SetNull,
+}
+
 /// Builder of [CreateIndex] command. Available only for the crate features `postgresql` and `sqlite`.
 ///
 /// Basic API
@@ -115,41 +510,41 @@ pub(crate) enum Combinator {
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
 #[derive(Default, Clone)]
 pub struct CreateIndex {
-  pub(crate) _column: Vec<String>,
-  pub(crate) _index_name: String,
+  pub(crate) _column: Vec<Fragment>,
+  pub(crate) _index_name: Fragment,
   pub(crate) _create_index: bool,
-  pub(crate) _on: String,
-  pub(crate) _raw_after: Vec<(CreateIndexParams, String)>,
-  pub(crate) _raw_before: Vec<(CreateIndexParams, String)>,
-  pub(crate) _raw: Vec<String>,
+  pub(crate) _on: Fragment,
+  pub(crate) _raw_after: Vec<(CreateIndexParams, Fragment)>,
+  pub(crate) _raw_before: Vec<(CreateIndexParams, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
   pub(crate) _unique: bool,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _if_not_exists: bool,
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
+  pub(crate) _where: Vec<(LogicalOperator, Fragment)>,
 
   #[cfg(any(feature = "postgresql", feature = "mysql"))]
-  pub(crate) _using: String,
+  pub(crate) _using: Fragment,
 
   #[cfg(feature = "postgresql")]
   pub(crate) _concurrently: bool,
   #[cfg(feature = "postgresql")]
-  pub(crate) _include: Vec<String>,
+  pub(crate) _include: Vec<Fragment>,
   #[cfg(feature = "postgresql")]
   pub(crate) _only: bool,
 
   #[cfg(feature = "mysql")]
   pub(crate) _fulltext: bool,
   #[cfg(feature = "mysql")]
-  pub(crate) _lock: String,
+  pub(crate) _lock: Fragment,
   #[cfg(feature = "mysql")]
   pub(crate) _spatial: bool,
 }
 
 /// All available params to be used in [CreateIndex::raw_before] and [CreateIndex::raw_after] methods on [CreateIndex] builder
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum CreateIndexParams {
   Column,
   CreateIndex,
@@ -214,18 +609,18 @@ pub enum CreateIndexParams {
 /// ```
 #[derive(Default, Clone)]
 pub struct CreateTable {
-  pub(crate) _column: Vec<String>,
-  pub(crate) _constraint: Vec<String>,
-  pub(crate) _create_table: String,
-  pub(crate) _foreign_key: Vec<String>,
-  pub(crate) _primary_key: String,
-  pub(crate) _raw_after: Vec<(CreateTableParams, String)>,
-  pub(crate) _raw_before: Vec<(CreateTableParams, String)>,
-  pub(crate) _raw: Vec<String>,
+  pub(crate) _column: Vec<Fragment>,
+  pub(crate) _constraint: Vec<Fragment>,
+  pub(crate) _create_table: Fragment,
+  pub(crate) _foreign_key: Vec<Fragment>,
+  pub(crate) _primary_key: Fragment,
+  pub(crate) _raw_after: Vec<(CreateTableParams, Fragment)>,
+  pub(crate) _raw_before: Vec<(CreateTableParams, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
 }
 
 /// All available params to be used in [CreateTable::raw_before] and [CreateTable::raw_after] methods on [CreateTable] builder
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum CreateTableParams {
   Column,
   Constraint,
@@ -234,6 +629,113 @@ pub enum CreateTableParams {
   PrimaryKey,
 }
 
+/// Builder of [CreateView] command.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let active_users = sql::Select::new()
+///   .select("id, login")
+///   .from("users")
+///   .where_clause("active = true");
+///
+/// let query = sql::CreateView::new()
+///   .create_view("active_users")
+///   .as_select(active_users)
+///   .as_string();
+///
+/// # let expected = "CREATE VIEW active_users AS SELECT id, login FROM users WHERE active = true";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// CREATE VIEW active_users AS
+/// SELECT id, login
+/// FROM users
+/// WHERE active = true
+/// ```
+#[derive(Default, Clone)]
+pub struct CreateView {
+  pub(crate) _as_select: Option<Box<Select>>,
+  pub(crate) _column: Vec<Fragment>,
+  pub(crate) _create_view: Fragment,
+  pub(crate) _or_replace: bool,
+  pub(crate) _raw_after: Vec<(CreateViewParams, Fragment)>,
+  pub(crate) _raw_before: Vec<(CreateViewParams, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _temporary: bool,
+  pub(crate) _with_check_option: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _materialized: bool,
+}
+
+/// All available params to be used in [CreateView::raw_before] and [CreateView::raw_after] methods on [CreateView] builder
+#[derive(Debug, PartialEq, Clone)]
+pub enum CreateViewParams {
+  AsSelect,
+  Column,
+  CreateView,
+  WithCheckOption,
+}
+
+/// Portable data types of the columns and the cast expressions, each variant is rendered as the closest native type
+/// of the dialect the query is rendered. Use [DataType::try_to_sql] to know if the dialect has an equivalent type
+///
+/// ```
+/// # #[cfg(feature = "mysql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let price = sql::DataType::Numeric(10, 2);
+///
+/// # assert_eq!("NUMERIC(10, 2)", price.to_sql(sql::Dialect::Standard));
+/// # assert_eq!("DECIMAL(10, 2)", price.to_sql(sql::Dialect::Mysql));
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// -- Standard
+/// NUMERIC(10, 2)
+///
+/// -- MySQL
+/// DECIMAL(10, 2)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DataType {
+  SmallInt,
+  Integer,
+  BigInt,
+  /// The precision and the scale of the number
+  Numeric(u32, u32),
+  Boolean,
+  Text,
+  /// The maximum length of the text
+  Varchar(u32),
+  Date,
+  /// Timestamp without time zone
+  Timestamp,
+  /// Timestamp with time zone
+  TimestampTz,
+  Uuid,
+  Json,
+  /// Binary JSON, rendered as `JSON` in the dialects without a binary JSON type
+  Jsonb,
+  /// Binary data, rendered as `BYTEA` in Postgres and `BLOB` in the other dialects
+  Blob,
+  /// Array of the data type
+  Array(Box<DataType>),
+  /// Enumeration with the name of the type and its labels, Postgres references the type by its name
+  /// and MySQL writes the labels in the column definition
+  Enum(String, Vec<String>),
+}
+
 /// Builder of [Delete] command.
 ///
 /// Basic API
@@ -257,39 +759,39 @@ pub enum CreateTableParams {
 /// ```
 #[derive(Default, Clone)]
 pub struct Delete {
-  pub(crate) _delete_from: String,
-  pub(crate) _raw_after: Vec<(DeleteClause, String)>,
-  pub(crate) _raw_before: Vec<(DeleteClause, String)>,
-  pub(crate) _raw: Vec<String>,
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
+  pub(crate) _delete_from: Fragment,
+  pub(crate) _raw_after: Vec<(DeleteClause, Fragment)>,
+  pub(crate) _raw_before: Vec<(DeleteClause, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _where: Vec<(LogicalOperator, Fragment)>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-  pub(crate) _with: Vec<(String, std::sync::Arc<dyn crate::behavior::WithQuery + Send + Sync>)>,
+  pub(crate) _with: Vec<(Fragment, std::sync::Arc<dyn crate::behavior::WithQuery + Send + Sync>)>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _returning: Vec<String>,
+  pub(crate) _returning: Vec<Fragment>,
 
   #[cfg(any(feature = "sqlite", feature = "mysql"))]
-  pub(crate) _order_by: Vec<String>,
+  pub(crate) _order_by: Vec<Fragment>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _delete: Vec<String>,
+  pub(crate) _delete: Vec<Fragment>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _from: Vec<String>,
+  pub(crate) _from: Vec<Fragment>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _join: Vec<String>,
+  pub(crate) _join: Vec<Join>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _limit: String,
+  pub(crate) _limit: Fragment,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _partition: Vec<String>,
+  pub(crate) _partition: Vec<Fragment>,
 }
 
 /// All available clauses to be used in [Delete::raw_before] and [Delete::raw_after] methods on [Delete] builder
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum DeleteClause {
   DeleteFrom,
   Where,
@@ -326,86 +828,432 @@ pub enum DeleteClause {
   #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
   Join,
 
-  #[cfg(feature = "mysql")]
-  #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
-  Partition,
+  #[cfg(feature = "mysql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+  Partition,
+}
+
+/// The SQL dialects supported by the library, used to render a builder with the [Select::to_sql] method
+/// and its equivalents on the other builders.
+///
+/// The `Standard` dialect is always available, the other variants are enabled by its respective crate features.
+/// When more than one feature is enabled the `as_string` method renders the dialect with the highest
+/// priority in the order `postgresql`, `sqlite`, `mysql`.
+///
+/// ```
+/// # #[cfg(feature = "sqlite")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("login")
+///   .from("users")
+///   .limit("10");
+///
+/// let standard_query = select.to_sql(sql::Dialect::Standard);
+/// let sqlite_query = select.to_sql(sql::Dialect::Sqlite);
+///
+/// # assert_eq!("SELECT login FROM users", standard_query);
+/// # assert_eq!("SELECT login FROM users LIMIT 10", sqlite_query);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+  Standard,
+
+  #[cfg(feature = "postgresql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  Postgresql,
+
+  #[cfg(feature = "sqlite")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+  Sqlite,
+
+  #[cfg(feature = "mysql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+  Mysql,
+}
+
+impl Default for Dialect {
+  fn default() -> Self {
+    #[cfg(feature = "postgresql")]
+    let dialect = Dialect::Postgresql;
+
+    #[cfg(all(feature = "sqlite", not(feature = "postgresql")))]
+    let dialect = Dialect::Sqlite;
+
+    #[cfg(all(feature = "mysql", not(any(feature = "postgresql", feature = "sqlite"))))]
+    let dialect = Dialect::Mysql;
+
+    #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
+    let dialect = Dialect::Standard;
+
+    dialect
+  }
+}
+
+/// Backend of the [highlight](crate::highlight) function that colors the tokens with ANSI escape codes,
+/// the same colors used by the `debug` and `print` methods of the builders
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let query = sql::highlight("SELECT id FROM users", &sql::AnsiBackend);
+///
+/// # let expected = "\x1b[34;1mSELECT\x1b[0m id \x1b[34;1mFROM\x1b[0m users";
+/// # assert_eq!(expected, query);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AnsiBackend;
+
+/// Backend of the [highlight](crate::highlight) function that wraps the tokens in `<span>` elements with the
+/// CSS class of its kind, the text is escaped to be embedded in a HTML document
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let query = sql::highlight("SELECT 'a' < 'b'", &sql::HtmlBackend);
+///
+/// # let expected = "\
+/// #   <span class=\"sql-keyword\">SELECT</span> \
+/// #   <span class=\"sql-literal\">&#39;a&#39;</span> \
+/// #   <span class=\"sql-operator\">&lt;</span> \
+/// #   <span class=\"sql-literal\">&#39;b&#39;</span>\
+/// # ";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```html
+/// <span class="sql-keyword">SELECT</span> <span class="sql-literal">&#39;a&#39;</span> <span class="sql-operator">&lt;</span> <span class="sql-literal">&#39;b&#39;</span>
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HtmlBackend;
+
+/// Backend of the [highlight](crate::highlight) function that returns the text of the tokens as is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PlainBackend;
+
+/// Kinds of the tokens of a query classified by the [highlight](crate::highlight) function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+  /// Reserved words of the SQL syntax like `SELECT`, `FROM` and `AND`
+  Keyword,
+
+  /// Names of the tables, columns and functions, quoted or not
+  Identifier,
+
+  /// Strings, numbers and the `TRUE`, `FALSE` and `NULL` values
+  Literal,
+
+  /// Comments in the `--` and `/* */` forms
+  Comment,
+
+  /// Placeholders of the bound values like `$1` and `?`
+  Placeholder,
+
+  /// Operators and punctuation like `=`, `<>`, `,` and parentheses
+  Operator,
+
+  /// Spaces, tabs and line breaks
+  Whitespace,
+}
+
+/// Options of the pretty printer used by the `as_pretty_string` method of the builders. Each clause is printed
+/// in its own line, the lists and the conditions longer than `max_line_length` are wrapped one item per line and
+/// the sub-queries are indented one level deeper than the clause that contains them.
+///
+/// # Example
+///
+/// ```
+/// # use sql_query_builder as sql;
+/// let options = sql::FormatOptions {
+///   keyword_case: sql::KeywordCase::Lower,
+///   align: true,
+///   ..Default::default()
+/// };
+///
+/// let query = sql::Select::new()
+///   .select("id, login")
+///   .from("users")
+///   .where_clause("active = true")
+///   .where_clause("login <> 'root'")
+///   .as_pretty_string(&options);
+///
+/// # let expected = "\
+/// # select id, login\n  \
+/// #   from users\n \
+/// #  where active = true and login <> 'root'";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// select id, login
+///   from users
+///  where active = true and login <> 'root'
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+  /// Number of spaces of each indentation level, the default is `2`
+  pub indent_width: usize,
+
+  /// Length from which the lists and the conditions are wrapped, the default is `80`
+  pub max_line_length: usize,
+
+  /// Case of the SQL keywords, the default is [KeywordCase::Upper]
+  pub keyword_case: KeywordCase,
+
+  /// Position of the commas when a list is wrapped, the default is [CommaStyle::Last]
+  pub comma_style: CommaStyle,
+
+  /// Right-aligns the keywords of the clauses so the expressions start in the same column, the default is `false`
+  pub align: bool,
+}
+
+impl Default for FormatOptions {
+  fn default() -> Self {
+    Self {
+      indent_width: 2,
+      max_line_length: 80,
+      keyword_case: KeywordCase::Upper,
+      comma_style: CommaStyle::Last,
+      align: false,
+    }
+  }
+}
+
+/// Case used by the pretty printer for the SQL keywords, the text inside quotes is never changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCase {
+  Upper,
+  Lower,
+}
+
+/// Position of the commas used by the pretty printer when a list is wrapped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommaStyle {
+  /// The comma starts the line of the next item
+  First,
+
+  /// The comma ends the line of the previous item
+  Last,
+}
+
+/// Builder of [DropIndex] command. Available only for the crate features `postgresql` and `sqlite` and `mysql`.
+///
+/// Basic API
+///
+/// ```
+/// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let query = sql::DropIndex::new()
+///   .drop_index("users_name_idx")
+///   .as_string();
+///
+/// # let expected = "DROP INDEX users_name_idx";
+/// # assert_eq!(expected, query);
+/// # }
+/// ```
+///
+///
+/// Output
+///
+/// ```sql
+/// DROP INDEX users_name_idx
+/// ```
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+#[derive(Default, Clone)]
+pub struct DropIndex {
+  pub(crate) _drop_index: Vec<Fragment>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _raw_after: Vec<(DropIndexParams, Fragment)>,
+  pub(crate) _raw_before: Vec<(DropIndexParams, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+}
+
+/// All available params to be used in [DropIndex::raw_before] and [DropIndex::raw_after] methods on [DropIndex] builder
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+#[derive(Debug, PartialEq, Clone)]
+pub enum DropIndexParams {
+  DropIndex,
+}
+
+/// Builder of [DropTable] command.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::DropTable::new()
+///   .drop_table("users")
+///   .as_string();
+///
+/// # let expected = "DROP TABLE users";
+/// # assert_eq!(expected, query);
+/// ```
+///
+///
+/// Output
+///
+/// ```sql
+/// DROP TABLE users
+/// ```
+#[derive(Default, Clone)]
+pub struct DropTable {
+  pub(crate) _drop_table: Vec<Fragment>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _raw_after: Vec<(DropTableParams, Fragment)>,
+  pub(crate) _raw_before: Vec<(DropTableParams, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+}
+
+/// All available params to be used in [DropTable::raw_before] and [DropTable::raw_after] methods on [DropTable] builder
+#[derive(Debug, PartialEq, Clone)]
+pub enum DropTableParams {
+  DropTable,
+}
+
+/// Builder of [DropView] command.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::DropView::new()
+///   .drop_view("active_users")
+///   .as_string();
+///
+/// # let expected = "DROP VIEW active_users";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// DROP VIEW active_users
+/// ```
+#[derive(Default, Clone)]
+pub struct DropView {
+  pub(crate) _drop_behavior: Fragment,
+  pub(crate) _drop_view: Vec<Fragment>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _raw_after: Vec<(DropViewParams, Fragment)>,
+  pub(crate) _raw_before: Vec<(DropViewParams, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _materialized: bool,
+}
+
+/// All available params to be used in [DropView::raw_before] and [DropView::raw_after] methods on [DropView] builder
+#[derive(Debug, PartialEq, Clone)]
+pub enum DropViewParams {
+  DropView,
 }
 
-/// Builder of [DropIndex] command. Available only for the crate features `postgresql` and `sqlite` and `mysql`.
-///
-/// Basic API
+/// A piece of SQL taken by the builder methods, the text written as is composed with the identifiers,
+/// the literals, the expressions and the bound values that are rendered in the syntax of the dialect of the query.
+/// Any of these types can be passed where a fragment is expected, use [Fragment::push] to compose them
 ///
 /// ```
-/// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+/// # #[cfg(feature = "mysql")]
 /// # {
 /// use sql_query_builder as sql;
 ///
-/// let query = sql::DropIndex::new()
-///   .drop_index("users_name_idx")
-///   .as_string();
+/// let condition = sql::Fragment::from(sql::Ident::new("status")).push(" = ").push(sql::Literal::new("paid"));
+/// let select = sql::Select::new()
+///   .select("*")
+///   .from("orders")
+///   .where_clause(condition);
 ///
-/// # let expected = "DROP INDEX users_name_idx";
-/// # assert_eq!(expected, query);
+/// # assert_eq!(r#"SELECT * FROM orders WHERE "status" = 'paid'"#, select.to_sql(sql::Dialect::Standard));
+/// # assert_eq!("SELECT * FROM orders WHERE `status` = 'paid'", select.to_sql(sql::Dialect::Mysql));
 /// # }
 /// ```
 ///
-///
 /// Output
 ///
 /// ```sql
-/// DROP INDEX users_name_idx
+/// -- Standard
+/// SELECT * FROM orders WHERE "status" = 'paid'
+///
+/// -- MySQL
+/// SELECT * FROM orders WHERE `status` = 'paid'
 /// ```
-#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-#[derive(Default, Clone)]
-pub struct DropIndex {
-  pub(crate) _drop_index: Vec<String>,
-  pub(crate) _if_exists: bool,
-  pub(crate) _raw_after: Vec<(DropIndexParams, String)>,
-  pub(crate) _raw_before: Vec<(DropIndexParams, String)>,
-  pub(crate) _raw: Vec<String>,
+#[derive(Default, Clone, PartialEq)]
+pub struct Fragment {
+  pub(crate) _parts: Vec<Part>,
 }
 
-/// All available params to be used in [DropIndex::raw_before] and [DropIndex::raw_after] methods on [DropIndex] builder
-#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-#[derive(PartialEq, Clone)]
-pub enum DropIndexParams {
-  DropIndex,
+/// The parts of a [Fragment], only the text is written as is, the other parts are rendered in the syntax
+/// of the dialect when the query is rendered
+#[derive(Clone)]
+pub(crate) enum Part {
+  Text(String),
+  Value(Value),
+  Ident(Ident),
+  Literal(Literal),
+  SubQuery(Arc<Select>),
+  /// The text whose syntax changes between the dialects, like the column definitions,
+  /// one variant for each dialect in the order of the enabled dialects
+  Variants(Vec<Fragment>),
+  /// The number of values bound to the placeholders of the text doesn't match, nothing is written,
+  /// the validation of the command reports it
+  BindMismatch {
+    expected: usize,
+    found: usize,
+  },
+  /// The data type written before it has no equivalent in the dialect of the variant, nothing is written,
+  /// the validation of the command reports it
+  UnsupportedType(DataType),
 }
 
-/// Builder of [DropTable] command.
-///
-/// Basic API
+/// An identifier of table, column, index or any other object of the database, quoted and escaped
+/// in the syntax of the dialect the query is rendered.
+/// The identifier can be used in any builder method that takes a name, the quotes are added when the query is rendered.
+/// To compose the identifier inside a clause use a [Fragment], the [Display](std::fmt::Display) renders
+/// the identifier quoted in the default dialect
 ///
 /// ```
+/// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+/// # {
 /// use sql_query_builder as sql;
 ///
-/// let query = sql::DropTable::new()
-///   .drop_table("users")
-///   .as_string();
+/// let status = sql::Ident::new("status");
+/// let select = sql::Select::new()
+///   .select("*")
+///   .from(&sql::Ident::qualified(&["public", "order"]))
+///   .where_clause(sql::Fragment::from(&status).push(" = 'paid'"));
 ///
-/// # let expected = "DROP TABLE users";
-/// # assert_eq!(expected, query);
-/// ```
+/// let postgres_query = select.to_sql(sql::Dialect::Postgresql);
+/// let mysql_query = select.to_sql(sql::Dialect::Mysql);
 ///
+/// # assert_eq!(r#"SELECT * FROM "public"."order" WHERE "status" = 'paid'"#, postgres_query);
+/// # assert_eq!("SELECT * FROM `public`.`order` WHERE `status` = 'paid'", mysql_query);
+/// # }
+/// ```
 ///
 /// Output
 ///
 /// ```sql
-/// DROP TABLE users
+/// -- Postgres
+/// SELECT * FROM "public"."order" WHERE "status" = 'paid'
+///
+/// -- MySQL
+/// SELECT * FROM `public`.`order` WHERE `status` = 'paid'
 /// ```
-#[derive(Default, Clone)]
-pub struct DropTable {
-  pub(crate) _drop_table: Vec<String>,
-  pub(crate) _if_exists: bool,
-  pub(crate) _raw_after: Vec<(DropTableParams, String)>,
-  pub(crate) _raw_before: Vec<(DropTableParams, String)>,
-  pub(crate) _raw: Vec<String>,
-}
-
-/// All available params to be used in [DropTable::raw_before] and [DropTable::raw_after] methods on [DropTable] builder
-#[derive(PartialEq, Clone)]
-pub enum DropTableParams {
-  DropTable,
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Ident {
+  pub(crate) _parts: Vec<String>,
 }
 
 /// Builder of [Insert] command.
@@ -432,54 +1280,54 @@ pub enum DropTableParams {
 /// ```
 #[derive(Default, Clone)]
 pub struct Insert {
-  pub(crate) _insert_into: String,
+  pub(crate) _insert_into: Fragment,
   pub(crate) _insert_variance: InsertVariance,
-  pub(crate) _raw: Vec<String>,
-  pub(crate) _raw_after: Vec<(InsertClause, String)>,
-  pub(crate) _raw_before: Vec<(InsertClause, String)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _raw_after: Vec<(InsertClause, Fragment)>,
+  pub(crate) _raw_before: Vec<(InsertClause, Fragment)>,
   pub(crate) _select: Option<Select>,
-  pub(crate) _values: Vec<String>,
+  pub(crate) _values: Vec<Fragment>,
   pub(crate) _values_variance: ValuesVariance,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _on_conflict: String,
+  pub(crate) _on_conflict: Fragment,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _returning: Vec<String>,
+  pub(crate) _returning: Vec<Fragment>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _with: Vec<(String, std::sync::Arc<dyn crate::behavior::WithQuery + Send + Sync>)>,
+  pub(crate) _with: Vec<(Fragment, std::sync::Arc<dyn crate::behavior::WithQuery + Send + Sync>)>,
 
-  #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
-  pub(crate) _overriding: String,
+  #[cfg(any(not(any(feature = "sqlite", feature = "mysql")), feature = "postgresql"))]
+  pub(crate) _overriding: Fragment,
 
   #[cfg(feature = "sqlite")]
-  pub(crate) _insert_or: String,
+  pub(crate) _insert_or: Fragment,
 
   #[cfg(feature = "sqlite")]
-  pub(crate) _replace_into: String,
+  pub(crate) _replace_into: Fragment,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _column: Vec<String>,
+  pub(crate) _column: Vec<Fragment>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _insert: String,
+  pub(crate) _insert: Fragment,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _into: String,
+  pub(crate) _into: Fragment,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _on_duplicate_key_update: Vec<String>,
+  pub(crate) _on_duplicate_key_update: Vec<Fragment>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _partition: Vec<String>,
+  pub(crate) _partition: Vec<Fragment>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _set: Vec<String>,
+  pub(crate) _set: Vec<Fragment>,
 }
 
 /// All available clauses to be used in [Insert::raw_before] and [Insert::raw_after] methods on [Insert] builder
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum InsertClause {
   DefaultValues,
   InsertInto,
@@ -552,7 +1400,7 @@ pub enum ValuesVariance {
   InsertValues,
   InsertSelect,
 
-  #[cfg(not(feature = "mysql"))]
+  #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
   InsertDefaultValues,
 
   #[cfg(feature = "mysql")]
@@ -562,8 +1410,180 @@ pub enum ValuesVariance {
   InsertValuesRow,
 }
 
+/// A literal value escaped in the syntax of the dialect the query is rendered, can be created from any type
+/// that implements [ToSqlLiteral](crate::ToSqlLiteral) and used in any builder method that takes an expression,
+/// composed inside a clause with a [Fragment]. The escapes are applied when the query is rendered
+///
+/// ```
+/// # #[cfg(feature = "mysql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let login = sql::Literal::new("o'neil\\");
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .where_clause(sql::Fragment::from("login = ").push(&login));
+///
+/// let standard_query = select.to_sql(sql::Dialect::Standard);
+/// let mysql_query = select.to_sql(sql::Dialect::Mysql);
+///
+/// # assert_eq!(r#"SELECT id FROM users WHERE login = 'o''neil\'"#, standard_query);
+/// # assert_eq!(r#"SELECT id FROM users WHERE login = 'o''neil\\'"#, mysql_query);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// -- Standard
+/// SELECT id FROM users WHERE login = 'o''neil\'
+///
+/// -- MySQL
+/// SELECT id FROM users WHERE login = 'o''neil\\'
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Literal {
+  pub(crate) _variants: Vec<String>,
+}
+
+/// A condition or operand built as an expression tree, the parentheses are added when the expressions are combined
+/// following the precedence of the operators. The values compared are bound to the query, see [col](crate::col)
+/// and [val](crate::val). The expression can be used in any builder method that takes a condition
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+/// use sql::col;
+///
+/// let condition = col("age").gt(18).and(col("name").like("a%").or(col("vip").eq(true)));
+///
+/// let (query, values) = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause(&condition)
+///   .as_string_with_binds();
+///
+/// # assert_eq!("SELECT * FROM users WHERE age > $1 AND (name LIKE $2 OR vip = $3)", query);
+/// # assert_eq!(vec![sql::Value::from(18), sql::Value::from("a%"), sql::Value::from(true)], values);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT * FROM users WHERE age > $1 AND (name LIKE $2 OR vip = $3)
+/// ```
 #[derive(Clone, PartialEq)]
-pub(crate) enum LogicalOperator {
+pub struct Expr {
+  pub(crate) _sql: Fragment,
+  pub(crate) _precedence: Precedence,
+}
+
+/// A group of conditions enclosed in parentheses, created by the `where_group` and `where_or_group` methods
+/// of the builders. The groups can be nested to any depth
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause("active = true")
+///   .where_group(|group| {
+///     group
+///       .where_clause("role = 'admin'")
+///       .where_or_group(|group| group.where_clause("role = 'user'").where_clause("verified = true"))
+///   });
+///
+/// # let expected = "\
+/// #   SELECT * FROM users \
+/// #   WHERE active = true \
+/// #   AND (role = 'admin' OR (role = 'user' AND verified = true))\
+/// # ";
+/// # assert_eq!(expected, select.as_string());
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT * FROM users
+/// WHERE active = true
+/// AND (role = 'admin' OR (role = 'user' AND verified = true))
+/// ```
+#[derive(Default, Clone, PartialEq)]
+pub struct WhereGroup {
+  pub(crate) _where: Vec<(LogicalOperator, Fragment)>,
+}
+
+/// Builder of a join, the table or the sub-query and the `on` conditions or the `using` columns
+/// are defined separately. The join can be added to the [Select], to the [Update] in SQLite
+/// and to the [Delete] in MySQL using the `join` method
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("users.login, addresses.city")
+///   .from("users")
+///   .join(
+///     sql::Join::left("addresses")
+///       .on("addresses.user_id = users.id")
+///       .on("addresses.main = true"),
+///   )
+///   .join(sql::Join::inner("roles").using("role_id"));
+///
+/// # let expected = "\
+/// #   SELECT users.login, addresses.city \
+/// #   FROM users \
+/// #   LEFT JOIN addresses ON addresses.user_id = users.id AND addresses.main = true \
+/// #   INNER JOIN roles USING (role_id)\
+/// # ";
+/// # assert_eq!(expected, select.as_string());
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT users.login, addresses.city
+/// FROM users
+/// LEFT JOIN addresses ON addresses.user_id = users.id AND addresses.main = true
+/// INNER JOIN roles USING (role_id)
+/// ```
+#[derive(Clone)]
+pub struct Join {
+  pub(crate) _kind: JoinKind,
+  pub(crate) _lateral: bool,
+  pub(crate) _natural: bool,
+  pub(crate) _on: Vec<(LogicalOperator, Fragment)>,
+  pub(crate) _sub_query: Option<Arc<Select>>,
+  pub(crate) _table: Fragment,
+  pub(crate) _using: Vec<Fragment>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum JoinKind {
+  Cross,
+  Full,
+  Inner,
+  Left,
+  Right,
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub(crate) enum Precedence {
+  Or,
+  And,
+  Not,
+  Predicate,
+  Operand,
+}
+
+/// The logical operator that joins a condition to the previous ones, returned with the conditions
+/// by the introspection methods like [Select::get_where]. The operator of the first condition is not rendered
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogicalOperator {
   And,
   Or,
 }
@@ -578,6 +1598,158 @@ impl std::fmt::Display for LogicalOperator {
   }
 }
 
+/// Builder of [Merge] command.
+///
+/// Basic API
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Merge::new()
+///   .merge_into("customers c")
+///   .using("staging_customers s")
+///   .on("c.id = s.id")
+///   .when_matched_then_update("name = s.name")
+///   .when_not_matched_then_insert("(id, name)", "(s.id, s.name)")
+///   .as_string();
+///
+/// # let expected = "\
+/// #   MERGE INTO customers c \
+/// #   USING staging_customers s \
+/// #   ON c.id = s.id \
+/// #   WHEN MATCHED THEN UPDATE SET name = s.name \
+/// #   WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)\
+/// # ";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// MERGE INTO customers c
+/// USING staging_customers s
+/// ON c.id = s.id
+/// WHEN MATCHED THEN UPDATE SET name = s.name
+/// WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)
+/// ```
+#[derive(Default, Clone)]
+pub struct Merge {
+  pub(crate) _merge_into: Fragment,
+  pub(crate) _on: Vec<(LogicalOperator, Fragment)>,
+  pub(crate) _raw_after: Vec<(MergeClause, Fragment)>,
+  pub(crate) _raw_before: Vec<(MergeClause, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _using: Fragment,
+  pub(crate) _when: Vec<Fragment>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _with: Vec<(Fragment, Arc<dyn WithQuery + Send + Sync>)>,
+}
+
+/// All available clauses to be used in [Merge::raw_before] and [Merge::raw_after] methods on [Merge] builder
+#[derive(Debug, PartialEq, Clone)]
+pub enum MergeClause {
+  MergeInto,
+  On,
+  Using,
+  When,
+
+  #[cfg(feature = "postgresql")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+  With,
+}
+
+/// A versioned migration of the database, the `up` transaction applies the migration and the `down` transaction
+/// reverts it. The migrations are grouped and validated by the [MigrationSet]
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let users = sql::CreateTable::new()
+///   .create_table("users")
+///   .column("login varchar(40) not null");
+///
+/// let migration = sql::Migration::new(
+///   1,
+///   "create_users",
+///   sql::Transaction::new().create_table(users),
+///   sql::Transaction::new().drop_table(sql::DropTable::new().drop_table("users")),
+/// );
+///
+/// let query = migration.up_sql(sql::Dialect::Standard);
+///
+/// # let expected = "\
+/// #   START TRANSACTION; \
+/// #   CREATE TABLE users (login varchar(40) not null); \
+/// #   INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users'); \
+/// #   COMMIT;\
+/// # ";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// START TRANSACTION;
+/// CREATE TABLE users (login varchar(40) not null);
+/// INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users');
+/// COMMIT;
+/// ```
+pub struct Migration {
+  pub(crate) _down: Transaction,
+  pub(crate) _name: String,
+  pub(crate) _up: Transaction,
+  pub(crate) _version: u64,
+}
+
+/// A set of [Migration] sorted by version, validates the versions and renders the SQL to apply or revert the
+/// migrations together with the bookkeeping of the `schema_migrations` table. Only the SQL is rendered,
+/// the output can be executed by any database driver
+///
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let migrations = sql::MigrationSet::new()
+///   .migration(sql::Migration::new(
+///     2,
+///     "add_users_bio",
+///     sql::Transaction::new().alter_table(sql::AlterTable::new().alter_table("users").add_column("bio text")),
+///     sql::Transaction::new().alter_table(sql::AlterTable::new().alter_table("users").drop_column("bio")),
+///   ))
+///   .migration(sql::Migration::new(
+///     1,
+///     "create_users",
+///     sql::Transaction::new().create_table(sql::CreateTable::new().create_table("users").column("login text")),
+///     sql::Transaction::new().drop_table(sql::DropTable::new().drop_table("users")),
+///   ));
+///
+/// let query = migrations.up_sql(sql::Dialect::Postgresql, 1).unwrap();
+///
+/// # let expected = "\
+/// #   BEGIN; \
+/// #   ALTER TABLE users ADD COLUMN bio text; \
+/// #   INSERT INTO schema_migrations (version, name) VALUES (2, 'add_users_bio'); \
+/// #   COMMIT;\
+/// # ";
+/// # assert_eq!(expected, query);
+/// # }
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// BEGIN;
+/// ALTER TABLE users ADD COLUMN bio text;
+/// INSERT INTO schema_migrations (version, name) VALUES (2, 'add_users_bio');
+/// COMMIT;
+/// ```
+#[derive(Default)]
+pub struct MigrationSet {
+  pub(crate) _migration: Vec<Migration>,
+}
+
 /// Builder of [Select] command.
 ///
 /// Basic API
@@ -614,17 +1786,17 @@ impl std::fmt::Display for LogicalOperator {
 /// ```
 #[derive(Default, Clone)]
 pub struct Select {
-  pub(crate) _from: Vec<String>,
-  pub(crate) _group_by: Vec<String>,
-  pub(crate) _having: Vec<String>,
-  pub(crate) _join: Vec<String>,
-  pub(crate) _order_by: Vec<String>,
-  pub(crate) _raw_after: Vec<(SelectClause, String)>,
-  pub(crate) _raw_before: Vec<(SelectClause, String)>,
-  pub(crate) _raw: Vec<String>,
-  pub(crate) _select: Vec<String>,
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
-  pub(crate) _window: Vec<String>,
+  pub(crate) _from: Vec<Fragment>,
+  pub(crate) _group_by: Vec<Fragment>,
+  pub(crate) _having: Vec<Fragment>,
+  pub(crate) _join: Vec<Join>,
+  pub(crate) _order_by: Vec<Fragment>,
+  pub(crate) _raw_after: Vec<(SelectClause, Fragment)>,
+  pub(crate) _raw_before: Vec<(SelectClause, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _select: Vec<Fragment>,
+  pub(crate) _where: Vec<(LogicalOperator, Fragment)>,
+  pub(crate) _window: Vec<Fragment>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
   pub(crate) _except: Vec<Self>,
@@ -633,23 +1805,23 @@ pub struct Select {
   pub(crate) _intersect: Vec<Self>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-  pub(crate) _limit: String,
+  pub(crate) _limit: Fragment,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-  pub(crate) _offset: String,
+  pub(crate) _offset: Fragment,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
   pub(crate) _union: Vec<Self>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-  pub(crate) _with: Vec<(String, Arc<dyn WithQuery + Send + Sync>)>,
+  pub(crate) _with: Vec<(Fragment, Arc<dyn WithQuery + Send + Sync>)>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _partition: Vec<String>,
+  pub(crate) _partition: Vec<Fragment>,
 }
 
 /// All available clauses to be used in [Select::raw_before] and [Select::raw_after] methods on [Select] builder
-#[derive(Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SelectClause {
   From,
   GroupBy,
@@ -691,6 +1863,78 @@ pub enum SelectClause {
   Partition,
 }
 
+/// Model of a database schema, the tables with its columns and indexes. Two versions of the schema can be compared
+/// with [Schema::diff] to get the commands that migrate the database from one version to the other
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let users = sql::Table::new("users")
+///   .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+///   .column(sql::Column::new("login", sql::DataType::Varchar(40)).not_null())
+///   .primary_key(&["id"]);
+///
+/// let old_schema = sql::Schema::new();
+/// let new_schema = sql::Schema::new().table(users);
+///
+/// let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+///
+/// # let expected = "\
+/// #   CREATE TABLE users (\
+/// #     id INTEGER NOT NULL, \
+/// #     login VARCHAR(40) NOT NULL, \
+/// #     CONSTRAINT users_pkey PRIMARY KEY (id)\
+/// #   );\
+/// # ";
+/// # assert_eq!(expected, query);
+/// ```
+///
+/// Output (indented for readability)
+///
+/// ```sql
+/// CREATE TABLE users (
+///   id INTEGER NOT NULL,
+///   login VARCHAR(40) NOT NULL,
+///   CONSTRAINT users_pkey PRIMARY KEY (id)
+/// );
+/// ```
+#[derive(Default, Clone, PartialEq)]
+pub struct Schema {
+  pub(crate) _table: Vec<Table>,
+}
+
+/// Model of a table of the [Schema]
+#[derive(Clone, PartialEq)]
+pub struct Table {
+  pub(crate) _column: Vec<Column>,
+  pub(crate) _name: String,
+  pub(crate) _primary_key: Vec<String>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  pub(crate) _index: Vec<Index>,
+}
+
+/// Model of a column of the [Table]
+#[derive(Clone, PartialEq)]
+pub struct Column {
+  pub(crate) _data_type: DataType,
+  pub(crate) _default: String,
+  pub(crate) _name: String,
+  pub(crate) _not_null: bool,
+}
+
+/// Model of an index of the [Table]
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
+#[derive(Clone, PartialEq)]
+pub struct Index {
+  pub(crate) _column: Vec<String>,
+  pub(crate) _name: String,
+  pub(crate) _unique: bool,
+}
+
 /// Builder of [Transaction] block.
 ///
 /// Basic API
@@ -738,7 +1982,7 @@ pub enum SelectClause {
 pub struct Transaction {
   pub(crate) _commit: Option<TransactionCommand>,
   pub(crate) _ordered_commands: Vec<Box<dyn TransactionQuery>>,
-  pub(crate) _raw: Vec<String>,
+  pub(crate) _raw: Vec<Fragment>,
   pub(crate) _set_transaction: Option<TransactionCommand>,
   pub(crate) _start_transaction: Option<TransactionCommand>,
 
@@ -768,19 +2012,19 @@ pub(crate) enum TrCmd {
   #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
   End,
 
-  #[cfg(not(feature = "sqlite"))]
+  #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
   #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
   #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
   SetTransaction,
 
-  #[cfg(not(feature = "sqlite"))]
+  #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
   #[cfg_attr(docsrs, doc(cfg(feature = "postgresql")))]
   #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
   StartTransaction,
 }
 
 #[derive(PartialEq)]
-pub(crate) struct TransactionCommand(pub(crate) TrCmd, pub(crate) String);
+pub(crate) struct TransactionCommand(pub(crate) TrCmd, pub(crate) Fragment);
 
 /// Builder of [Update] command.
 ///
@@ -806,35 +2050,35 @@ pub(crate) struct TransactionCommand(pub(crate) TrCmd, pub(crate) String);
 /// ```
 #[derive(Default, Clone)]
 pub struct Update {
-  pub(crate) _raw_after: Vec<(UpdateClause, String)>,
-  pub(crate) _raw_before: Vec<(UpdateClause, String)>,
-  pub(crate) _raw: Vec<String>,
-  pub(crate) _set: Vec<String>,
-  pub(crate) _where: Vec<(LogicalOperator, String)>,
+  pub(crate) _raw_after: Vec<(UpdateClause, Fragment)>,
+  pub(crate) _raw_before: Vec<(UpdateClause, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _set: Vec<Fragment>,
+  pub(crate) _where: Vec<(LogicalOperator, Fragment)>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _from: Vec<String>,
+  pub(crate) _from: Vec<Fragment>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _returning: Vec<String>,
+  pub(crate) _returning: Vec<Fragment>,
 
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub(crate) _with: Vec<(String, std::sync::Arc<dyn crate::behavior::WithQuery + Send + Sync>)>,
+  pub(crate) _with: Vec<(Fragment, std::sync::Arc<dyn crate::behavior::WithQuery + Send + Sync>)>,
 
   #[cfg(any(feature = "sqlite", feature = "mysql"))]
-  pub(crate) _order_by: Vec<String>,
+  pub(crate) _order_by: Vec<Fragment>,
 
   #[cfg(not(feature = "sqlite"))]
-  pub(crate) _update: String,
+  pub(crate) _update: Fragment,
 
   #[cfg(feature = "sqlite")]
-  pub(crate) _update: (UpdateVars, String),
+  pub(crate) _update: (UpdateVars, Fragment),
 
   #[cfg(feature = "sqlite")]
-  pub(crate) _join: Vec<String>,
+  pub(crate) _join: Vec<Join>,
 
   #[cfg(feature = "mysql")]
-  pub(crate) _limit: String,
+  pub(crate) _limit: Fragment,
 }
 
 #[cfg(feature = "sqlite")]
@@ -846,7 +2090,7 @@ pub(crate) enum UpdateVars {
 }
 
 /// All available clauses to be used in [Update::raw_before] and [Update::raw_after] methods on [Update] builder
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum UpdateClause {
   Set,
   Update,
@@ -885,6 +2129,47 @@ pub enum UpdateClause {
   Limit,
 }
 
+/// A typed value bound to a placeholder of the query, used by the methods with the `_bind` suffix
+/// and returned by [Select::as_string_with_binds] and its equivalents on the other builders
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let (query, values) = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .where_clause_bind("login = ?", "foo")
+///   .to_sql_with_binds(sql::Dialect::Standard);
+///
+/// # assert_eq!("SELECT id FROM users WHERE login = ?", query);
+/// # assert_eq!(vec![sql::Value::Text("foo".to_string())], values);
+/// ```
+///
+/// The `?` inside quotes is not a placeholder. The Postgres operators `?|` and `?&` of the jsonb type are kept as
+/// written, and the operator `?` is written escaped as `??`
+///
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Select::new()
+///   .select("id")
+///   .from("posts")
+///   .where_clause_bind("tags ?? 'rust' AND tags ?| array['sql'] AND author = ?", "foo")
+///   .to_sql(sql::Dialect::Standard);
+///
+/// # let expected = "SELECT id FROM posts WHERE tags ? 'rust' AND tags ?| array['sql'] AND author = ?";
+/// # assert_eq!(expected, query);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  Text(String),
+  Bytes(Vec<u8>),
+}
+
 /// Builder of [Values] command.
 ///
 /// Basic API
@@ -911,14 +2196,14 @@ pub enum UpdateClause {
 /// ```
 #[derive(Default, Clone)]
 pub struct Values {
-  pub(crate) _raw_after: Vec<(ValuesClause, String)>,
-  pub(crate) _raw_before: Vec<(ValuesClause, String)>,
-  pub(crate) _raw: Vec<String>,
-  pub(crate) _values: Vec<String>,
+  pub(crate) _raw_after: Vec<(ValuesClause, Fragment)>,
+  pub(crate) _raw_before: Vec<(ValuesClause, Fragment)>,
+  pub(crate) _raw: Vec<Fragment>,
+  pub(crate) _values: Vec<Fragment>,
 }
 
 /// All available clauses to be used in [Values::raw_before] and [Values::raw_after] methods on [Values] builder
-#[derive(PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ValuesClause {
   Values,
 }
@@ -1,8 +1,9 @@
 use crate::{
-  concat::Concat,
-  fmt,
+  behavior::Validate,
+  fmt, pretty,
   structure::{
-    AlterTable, CreateTable, Delete, DropTable, Insert, Select, TrCmd::*, Transaction, TransactionCommand, Update,
+    AlterTable, BuildError, CreateTable, CreateView, Delete, Dialect, DropTable, DropView, FormatOptions, Fragment,
+    Insert, Merge, Select, TrCmd::*, Transaction, TransactionCommand, Update, Value,
   },
   utils::push_unique,
 };
@@ -11,6 +12,49 @@ use crate::{
 use crate::structure::{CreateIndex, DropIndex};
 
 impl Transaction {
+  /// Gets the current state of the [Transaction] and returns it as string formatted by the [FormatOptions], each
+  /// command starts in its own line and is formatted with the same options
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .update(
+  ///     sql::Update::new()
+  ///       .update("users")
+  ///       .set("active = false")
+  ///       .where_clause("id = $1")
+  ///   )
+  ///   .commit("")
+  ///   .as_pretty_string(&sql::FormatOptions::default());
+  ///
+  /// # let expected = "\
+  /// # START TRANSACTION;\n\
+  /// # UPDATE users\n\
+  /// # SET active = false\n\
+  /// # WHERE id = $1;\n\
+  /// # COMMIT;";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// UPDATE users
+  /// SET active = false
+  /// WHERE id = $1;
+  /// COMMIT;
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [Transaction] and returns it as string
   ///
   /// # Example
@@ -36,8 +80,45 @@ impl Transaction {
   /// COMMIT;
   /// ```
   pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Transaction] and returns it as string together with the values bound
+  /// by the methods with the `_bind` suffix, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, values) = sql::Transaction::new()
+  ///   .delete(sql::Delete::new().delete_from("users").where_clause_bind("id = ?", 42))
+  ///   .update(
+  ///     sql::Update::new()
+  ///       .update("orders")
+  ///       .set_bind("status = ?", "canceled")
+  ///       .where_clause_bind("user_id = ?", 42),
+  ///   )
+  ///   .commit("")
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "DELETE FROM users WHERE id = $1; UPDATE orders SET status = $2 WHERE user_id = $3; COMMIT;";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from(42), sql::Value::from("canceled"), sql::Value::from(42)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE id = $1; UPDATE orders SET status = $2 WHERE user_id = $3; COMMIT;
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    fmt::render(self, &fmts)
   }
 
   /// The `commit` command, this method will be always added at the end of the transaction and
@@ -61,8 +142,8 @@ impl Transaction {
   /// ```sql
   /// COMMIT TRANSACTION;
   /// ```
-  pub fn commit(mut self, arg: &str) -> Self {
-    let cmd = TransactionCommand::new(Commit, arg.trim().to_string());
+  pub fn commit(mut self, arg: impl Into<Fragment>) -> Self {
+    let cmd = TransactionCommand::new(Commit, arg.into().trim());
     self._commit = Some(cmd);
     self
   }
@@ -98,10 +179,26 @@ impl Transaction {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
+  /// Gets the argument of the `commit` command, `None` when the command is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .select(sql::Select::new().select("1"))
+  ///   .commit("WORK");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("WORK")), transaction.get_commit());
+  /// ```
+  pub fn get_commit(&self) -> Option<&Fragment> {
+    self._commit.as_ref().map(|cmd| &cmd.1)
+  }
+
   /// The `alter table` command, access the [AlterTable] for more info
   ///
   /// # Example
@@ -186,6 +283,53 @@ impl Transaction {
     self
   }
 
+  /// The `create view` command, access the [CreateView] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let active_users = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("active = true");
+  ///
+  /// let active_users_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .as_select(active_users);
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .create_view(active_users_view)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   CREATE VIEW active_users AS \
+  /// #   SELECT * FROM users WHERE active = true; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// CREATE VIEW active_users AS
+  /// SELECT * FROM users WHERE active = true;
+  /// COMMIT;
+  /// ```
+  pub fn create_view(mut self, create_view: CreateView) -> Self {
+    let cmd = Box::new(create_view);
+    self._ordered_commands.push(cmd);
+    self
+  }
+
   /// The `delete` command, access the [Delete] for more info
   ///
   /// # Example
@@ -267,6 +411,45 @@ impl Transaction {
     self
   }
 
+  /// The `drop view` command, access the [DropView] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let drop_active_users = sql::DropView::new()
+  ///   .drop_view("active_users");
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .drop_view(drop_active_users)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   DROP VIEW active_users; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// DROP VIEW active_users;
+  /// COMMIT;
+  /// ```
+  pub fn drop_view(mut self, drop_view: DropView) -> Self {
+    let cmd = Box::new(drop_view);
+    self._ordered_commands.push(cmd);
+    self
+  }
+
   /// The `insert` command, access the [Insert] for more info
   ///
   /// # Example
@@ -309,16 +492,91 @@ impl Transaction {
     self
   }
 
+  /// The `merge` command, access the [Merge] for more info
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let merge_customers = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("staging_customers s")
+  ///   .on("c.id = s.id")
+  ///   .when_matched_then_update("name = s.name");
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .merge(merge_customers)
+  ///   .commit("")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   START TRANSACTION; \
+  /// #   MERGE INTO customers c \
+  /// #   USING staging_customers s \
+  /// #   ON c.id = s.id \
+  /// #   WHEN MATCHED THEN UPDATE SET name = s.name; \
+  /// #   COMMIT;\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  ///
+  /// Output (indented for readability)
+  ///
+  /// ```sql
+  /// START TRANSACTION;
+  /// MERGE INTO customers c
+  /// USING staging_customers s
+  /// ON c.id = s.id
+  /// WHEN MATCHED THEN UPDATE SET name = s.name;
+  /// COMMIT;
+  /// ```
+  pub fn merge(mut self, merge: Merge) -> Self {
+    let cmd = Box::new(merge);
+    self._ordered_commands.push(cmd);
+    self
+  }
+
   /// Creates instance to be used with Transaction commands
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// Returns the number of parameters of the [Transaction], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound by the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .delete(sql::Delete::new().delete_from("users").where_clause("id = $1"))
+  ///   .delete(sql::Delete::new().delete_from("orders").where_clause("user_id = $1"))
+  ///   .commit("");
+  ///
+  /// # let expected = "DELETE FROM users WHERE id = $1; DELETE FROM orders WHERE user_id = $2; COMMIT;";
+  /// # assert_eq!(expected, transaction.as_string());
+  /// # assert_eq!(2, transaction.param_count());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE id = $1; DELETE FROM orders WHERE user_id = $2; COMMIT;
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
   /// Prints the current state of the [Transaction] to the standard output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -352,8 +610,8 @@ impl Transaction {
   /// set transaction isolation level read committed;
   /// COMMIT;
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -376,8 +634,8 @@ impl Transaction {
   /// ```sql
   /// RELEASE_SAVEPOINT saved_foo;
   /// ```
-  pub fn release_savepoint(mut self, name: &str) -> Self {
-    let cmd = Box::new(TransactionCommand::new(ReleaseSavepoint, name.trim().to_string()));
+  pub fn release_savepoint(mut self, name: impl Into<Fragment>) -> Self {
+    let cmd = Box::new(TransactionCommand::new(ReleaseSavepoint, name.into().trim()));
     self._ordered_commands.push(cmd);
     self
   }
@@ -419,8 +677,8 @@ impl Transaction {
   /// ```sql
   /// ROLLBACK TO SAVEPOINT my_savepoint;
   /// ```
-  pub fn rollback(mut self, arg: &str) -> Self {
-    let cmd = Box::new(TransactionCommand::new(Rollback, arg.trim().to_string()));
+  pub fn rollback(mut self, arg: impl Into<Fragment>) -> Self {
+    let cmd = Box::new(TransactionCommand::new(Rollback, arg.into().trim()));
     self._ordered_commands.push(cmd);
     self
   }
@@ -444,8 +702,8 @@ impl Transaction {
   /// ```sql
   /// SAVEPOINT my_savepoint;
   /// ```
-  pub fn savepoint(mut self, name: &str) -> Self {
-    let cmd = Box::new(TransactionCommand::new(Savepoint, name.trim().to_string()));
+  pub fn savepoint(mut self, name: impl Into<Fragment>) -> Self {
+    let cmd = Box::new(TransactionCommand::new(Savepoint, name.into().trim()));
     self._ordered_commands.push(cmd);
     self
   }
@@ -514,9 +772,9 @@ impl Transaction {
   /// START TRANSACTION;
   /// SET TRANSACTION read only;
   /// ```
-  #[cfg(not(feature = "sqlite"))]
-  pub fn set_transaction(mut self, mode: &str) -> Self {
-    let cmd = TransactionCommand::new(SetTransaction, mode.trim().to_string());
+  #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+  pub fn set_transaction(mut self, mode: impl Into<Fragment>) -> Self {
+    let cmd = TransactionCommand::new(SetTransaction, mode.into().trim());
     self._set_transaction = Some(cmd);
     self
   }
@@ -544,13 +802,238 @@ impl Transaction {
   /// START TRANSACTION isolation level serializable;
   /// COMMIT;
   /// ```
-  #[cfg(not(feature = "sqlite"))]
-  pub fn start_transaction(mut self, mode: &str) -> Self {
-    let cmd = TransactionCommand::new(StartTransaction, mode.trim().to_string());
+  #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+  pub fn start_transaction(mut self, mode: impl Into<Fragment>) -> Self {
+    let cmd = TransactionCommand::new(StartTransaction, mode.into().trim());
     self._start_transaction = Some(cmd);
     self
   }
 
+  /// Gets the mode of the `set transaction` command, `None` when the command is not defined
+  #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+  pub fn get_set_transaction(&self) -> Option<&Fragment> {
+    self._set_transaction.as_ref().map(|cmd| &cmd.1)
+  }
+
+  /// Gets the mode of the `start transaction` command, `None` when the command is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .start_transaction("read only")
+  ///   .commit("");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("read only")), transaction.get_start_transaction());
+  /// # assert_eq!(None, transaction.get_set_transaction());
+  /// # }
+  /// ```
+  #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+  pub fn get_start_transaction(&self) -> Option<&Fragment> {
+    self._start_transaction.as_ref().map(|cmd| &cmd.1)
+  }
+
+  /// Gets the current state of the [Transaction] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .begin("")
+  ///   .select(sql::Select::new().select("login").from("users").limit("1"))
+  ///   .commit("");
+  ///
+  /// let standard_query = transaction.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = transaction.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("BEGIN; SELECT login FROM users; COMMIT;", standard_query);
+  /// # assert_eq!("BEGIN; SELECT login FROM users LIMIT 1; COMMIT;", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// BEGIN;
+  /// SELECT login FROM users;
+  /// COMMIT;
+  ///
+  /// -- Postgres
+  /// BEGIN;
+  /// SELECT login FROM users LIMIT 1;
+  /// COMMIT;
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Transaction] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound by the methods with the `_bind` suffix,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .delete(sql::Delete::new().delete_from("users").where_clause_bind("id = ?", 42));
+  ///
+  /// let (standard_query, values) = transaction.to_sql_with_binds(sql::Dialect::Standard);
+  /// let (postgresql_query, _) = transaction.to_sql_with_binds(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("DELETE FROM users WHERE id = ?;", standard_query);
+  /// # assert_eq!("DELETE FROM users WHERE id = $1;", postgresql_query);
+  /// # assert_eq!(vec![sql::Value::from(42)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// DELETE FROM users WHERE id = ?;
+  ///
+  /// -- Postgres
+  /// DELETE FROM users WHERE id = $1;
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Transaction] and returns it as string, returns a [BuildError] when
+  /// the [Transaction::validate] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .insert(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+  ///   .commit("")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("START TRANSACTION; INSERT INTO users (login) VALUES ('foo'); COMMIT;", query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// START TRANSACTION; INSERT INTO users (login) VALUES ('foo'); COMMIT;
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [Transaction] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Transaction::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .select(sql::Select::new().select("login").from("users"))
+  ///   .commit("")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("START TRANSACTION; SELECT login FROM users; COMMIT;", query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// START TRANSACTION; SELECT login FROM users; COMMIT;
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of each command of the [Transaction], returns the [BuildError] of the first command
+  /// with a required clause missing, with clauses that cannot be used together or with a number of bound values
+  /// that doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .insert(sql::Insert::new().insert_into("users (login)"));
+  ///
+  /// let error = transaction.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "INSERT".to_string(),
+  /// #   clause: "VALUES".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [Transaction] like the [Transaction::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age integer")
+  ///   .drop_column("login");
+  ///
+  /// let transaction = sql::Transaction::new()
+  ///   .begin("")
+  ///   .alter_table(alter_table)
+  ///   .commit("");
+  ///
+  /// let error = transaction.validate_for(sql::Dialect::Sqlite).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::IncompatibleActions {
+  /// #   dialect: sql::Dialect::Sqlite,
+  /// #   actions: vec!["ADD COLUMN".to_string(), "DROP COLUMN".to_string()],
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
   /// The `update` command, access the [Update] for more info
   ///
   /// # Example
@@ -591,6 +1074,57 @@ impl Transaction {
     self._ordered_commands.push(cmd);
     self
   }
+
+  /// Writes the current state of the [Transaction] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .commit("");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// transaction.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nSTART TRANSACTION; COMMIT;";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Transaction] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Transaction::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "sqlite"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .start_transaction("")
+  ///   .commit("");
+  ///
+  /// let mut output = Vec::new();
+  /// transaction.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "START TRANSACTION; COMMIT;";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// # }
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
@@ -623,12 +1157,31 @@ impl Transaction {
   /// BEGIN transaction;
   /// COMMIT;
   /// ```
-  pub fn begin(mut self, mode: &str) -> Self {
-    let cmd = TransactionCommand::new(Begin, mode.trim().to_string());
+  pub fn begin(mut self, mode: impl Into<Fragment>) -> Self {
+    let cmd = TransactionCommand::new(Begin, mode.into().trim());
     self._begin = Some(cmd);
     self
   }
 
+  /// Gets the mode of the `begin` command, `None` when the command is not defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let transaction = sql::Transaction::new()
+  ///   .begin("")
+  ///   .commit("");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::new()), transaction.get_begin());
+  /// # }
+  /// ```
+  pub fn get_begin(&self) -> Option<&Fragment> {
+    self._begin.as_ref().map(|cmd| &cmd.1)
+  }
+
   /// The `create index` command, access the [CreateIndex] for more info
   ///
   /// # Example
@@ -739,11 +1292,16 @@ impl Transaction {
   /// BEGIN;
   /// END;
   /// ```
-  pub fn end(mut self, mode: &str) -> Self {
-    let cmd = TransactionCommand::new(End, mode.trim().to_string());
+  pub fn end(mut self, mode: impl Into<Fragment>) -> Self {
+    let cmd = TransactionCommand::new(End, mode.into().trim());
     self._end = Some(cmd);
     self
   }
+
+  /// Gets the mode of the `end` command, `None` when the command is not defined
+  pub fn get_end(&self) -> Option<&Fragment> {
+    self._end.as_ref().map(|cmd| &cmd.1)
+  }
 }
 
 impl std::fmt::Display for Transaction {
@@ -755,6 +1313,6 @@ impl std::fmt::Display for Transaction {
 impl std::fmt::Debug for Transaction {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
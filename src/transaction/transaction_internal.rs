@@ -1,144 +1,147 @@
 use crate::{
-  behavior::TransactionQuery,
+  behavior::{TransactionQuery, Validate},
   concat::Concat,
   fmt,
   structure::{
+    BuildError, Dialect, Fragment,
     TrCmd::{self, *},
     Transaction, TransactionCommand,
   },
+  utils,
 };
+use std::fmt::Write;
 
 impl TransactionQuery for TransactionCommand {}
 
 impl Concat for Transaction {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
+    self.concat_raw(w, &fmts, &self._raw)?;
 
     #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
     {
-      query = self.concat_begin(query, &fmts);
+      self.concat_command(w, &fmts, &self._begin)?;
     }
 
-    query = self.concat_start_transaction(query, &fmts);
+    self.concat_command(w, &fmts, &self._start_transaction)?;
 
-    query = self.concat_set_transaction(query, &fmts);
+    self.concat_command(w, &fmts, &self._set_transaction)?;
 
-    query = self.concat_ordered_commands(query, &fmts);
+    self.concat_ordered_commands(w, &fmts)?;
 
-    query = self.concat_commit(query, &fmts);
+    self.concat_command(w, &fmts, &self._commit)?;
 
     #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
-      query = self.concat_end(query, &fmts);
+      self.concat_command(w, &fmts, &self._end)?;
     }
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl Concat for TransactionCommand {
-  fn concat(&self, fmts: &crate::fmt::Formatter) -> String {
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { space, .. } = fmts;
-    let arg = if self.1.is_empty() {
-      "".to_string()
-    } else {
-      format!("{space}{0}", self.1)
-    };
-    match self.0 {
-      Commit => format!("COMMIT{arg}"),
-      ReleaseSavepoint => format!("RELEASE SAVEPOINT{arg}"),
-      Rollback => format!("ROLLBACK{arg}"),
-      Savepoint => format!("SAVEPOINT{arg}"),
+    let command = match self.0 {
+      Commit => "COMMIT",
+      ReleaseSavepoint => "RELEASE SAVEPOINT",
+      Rollback => "ROLLBACK",
+      Savepoint => "SAVEPOINT",
 
       #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-      Begin => format!("BEGIN{arg}"),
+      Begin => "BEGIN",
       #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-      End => format!("END{arg}"),
+      End => "END",
 
-      #[cfg(not(feature = "sqlite"))]
-      SetTransaction => format!("SET TRANSACTION{arg}"),
-      #[cfg(not(feature = "sqlite"))]
-      StartTransaction => format!("START TRANSACTION{arg}"),
+      #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+      SetTransaction => "SET TRANSACTION",
+      #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+      StartTransaction => "START TRANSACTION",
+    };
+    w.write_str(command)?;
+    if self.1.is_empty() == false {
+      w.write_str(space)?;
+      w.write_fragment(&self.1)?;
     }
+    Ok(())
   }
 }
 
 impl Transaction {
-  fn concat_commit(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = match &self._commit {
-      Some(cmd) => format!("{0};{space}{lb}", cmd.concat(fmts)),
-      None => "".to_string(),
-    };
-
-    format!("{query}{sql}")
-  }
-
-  fn concat_set_transaction(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_command(
+    &self,
+    w: &mut fmt::Writer,
+    fmts: &fmt::Formatter,
+    cmd: &Option<TransactionCommand>,
+  ) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = match &self._set_transaction {
-      Some(cmd) => format!("{0};{space}{lb}", cmd.concat(fmts)),
-      None => "".to_string(),
-    };
-
-    format!("{query}{sql}")
-  }
-
-  fn concat_start_transaction(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = match &self._start_transaction {
-      Some(cmd) => format!("{0};{space}{lb}", cmd.concat(fmts)),
-      None => "".to_string(),
-    };
-
-    format!("{query}{sql}")
+    if let Some(cmd) = cmd {
+      w.begin_statement();
+      cmd.concat(w, fmts)?;
+      write!(w, ";{space}{lb}")?;
+    }
+    Ok(())
   }
 
-  fn concat_ordered_commands(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_ordered_commands(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = self._ordered_commands.iter().fold("".to_string(), |acc, cmd| {
-      let inner_cmd = cmd.concat(fmts);
-      if inner_cmd.is_empty() == false {
-        format!("{acc}{inner_cmd};{space}{lb}")
-      } else {
-        acc
+    for cmd in &self._ordered_commands {
+      let mark = w.mark();
+      w.begin_statement();
+      w.write_query(|w| cmd.concat(w, fmts))?;
+      if w.has_written_since(mark) {
+        write!(w, ";{space}{lb}")?;
       }
-    });
-
-    format!("{query}{sql}")
+    }
+    Ok(())
   }
 }
 
 impl TransactionCommand {
-  pub(crate) fn new(clause: TrCmd, arg: String) -> Self {
+  pub(crate) fn new(clause: TrCmd, arg: Fragment) -> Self {
     Self(clause, arg)
   }
 }
 
-#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
-impl Transaction {
-  fn concat_begin(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = match &self._begin {
-      Some(cmd) => format!("{0};{space}{lb}", cmd.concat(fmts)),
-      None => "".to_string(),
-    };
+impl Validate for TransactionCommand {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    let (command, is_supported) = match self.0 {
+      Commit | ReleaseSavepoint | Rollback | Savepoint => return Ok(()),
+
+      #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+      Begin => ("BEGIN", dialect != Dialect::Standard),
+      #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+      End => ("END", utils::is_postgres(dialect) || utils::is_sqlite(dialect)),
 
-    format!("{query}{sql}")
+      #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+      SetTransaction => ("SET TRANSACTION", utils::is_sqlite(dialect) == false),
+      #[cfg(any(not(feature = "sqlite"), feature = "postgresql", feature = "mysql"))]
+      StartTransaction => ("START TRANSACTION", utils::is_sqlite(dialect) == false),
+    };
+    utils::require_support(dialect, command, true, is_supported)
   }
 }
 
-#[cfg(any(feature = "postgresql", feature = "sqlite"))]
-impl Transaction {
-  fn concat_end(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = match &self._end {
-      Some(cmd) => format!("{0};{space}{lb}", cmd.concat(fmts)),
-      None => "".to_string(),
-    };
+impl Validate for Transaction {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    #[allow(unused_mut)]
+    let mut commands = vec![&self._start_transaction, &self._set_transaction, &self._commit];
+    #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+    commands.push(&self._begin);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    commands.push(&self._end);
+
+    commands
+      .into_iter()
+      .flatten()
+      .try_for_each(|command| command.check_clauses(dialect))?;
 
-    format!("{query}{sql}")
+    self
+      ._ordered_commands
+      .iter()
+      .try_for_each(|command| command.check_clauses(dialect))
   }
 }
@@ -1,14 +1,54 @@
 use crate::{
-  behavior::TransactionQuery,
-  concat::Concat,
-  fmt,
-  structure::{LogicalOperator, Update, UpdateClause},
-  utils::push_unique,
+  behavior::{BindValues, TransactionQuery, Validate},
+  bind, fmt, introspection, parser, pretty,
+  structure::{
+    BuildError, Dialect, FormatOptions, Fragment, LogicalOperator, ParseError, PreparedQuery, Select, Update,
+    UpdateClause, Value, WhereGroup,
+  },
+  utils::{self, push_unique},
+  where_group,
 };
+use std::sync::Arc;
 
 impl TransactionQuery for Update {}
 
 impl Update {
+  /// Gets the current state of the [Update] and returns it as string formatted by the [FormatOptions]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   keyword_case: sql::KeywordCase::Lower,
+  ///   align: true,
+  ///   ..Default::default()
+  /// };
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .set("name = 'Foo'")
+  ///   .where_clause("id = $1")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # update users\n   \
+  /// # set login = 'foo', name = 'Foo'\n \
+  /// # where id = $1";
+  /// # assert_eq!(expected, update_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// update users
+  ///    set login = 'foo', name = 'Foo'
+  ///  where id = $1
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [Update] and returns it as string
   ///
   /// # Example
@@ -30,8 +70,103 @@ impl Update {
   ///  UPDATE users SET login = 'foo'
   /// ```
   pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Update] and returns it as string together with the values bound
+  /// by the methods with the `_bind` suffix, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, values) = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("login = ?", "foo")
+  ///   .where_clause_bind("id = ?", 42)
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "UPDATE users SET login = $1 WHERE id = $2";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from(42)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET login = $1 WHERE id = $2
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the clauses of the [Update] that have content, in the order they are rendered. The raw SQL added by
+  /// the methods [Update::raw], [Update::raw_before] and [Update::raw_after] is not considered
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .set("login = 'foo'")
+  ///   .update("users");
+  ///
+  /// let clauses = update.clauses();
+  ///
+  /// # assert_eq!(vec![sql::UpdateClause::Update, sql::UpdateClause::Set], clauses);
+  /// ```
+  pub fn clauses(&self) -> Vec<UpdateClause> {
+    let mut clauses = vec![];
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if self._with.is_empty() == false {
+      clauses.push(UpdateClause::With);
+    }
+    #[cfg(not(feature = "sqlite"))]
+    if self._update.is_empty() == false {
+      clauses.push(UpdateClause::Update);
+    }
+    #[cfg(feature = "sqlite")]
+    if self._update.1.is_empty() == false {
+      match self._update.0 {
+        UpdateVars::Update => clauses.push(UpdateClause::Update),
+        UpdateVars::UpdateOr => clauses.push(UpdateClause::UpdateOr),
+      }
+    }
+    if self._set.is_empty() == false {
+      clauses.push(UpdateClause::Set);
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if self._from.is_empty() == false {
+      clauses.push(UpdateClause::From);
+    }
+    #[cfg(feature = "sqlite")]
+    if self._join.is_empty() == false {
+      clauses.push(UpdateClause::Join);
+    }
+    if self._where.is_empty() == false {
+      clauses.push(UpdateClause::Where);
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    if self._returning.is_empty() == false {
+      clauses.push(UpdateClause::Returning);
+    }
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    if self._order_by.is_empty() == false {
+      clauses.push(UpdateClause::OrderBy);
+    }
+    #[cfg(feature = "mysql")]
+    if self._limit.is_empty() == false {
+      clauses.push(UpdateClause::Limit);
+    }
+
+    clauses
   }
 
   /// Prints the current state of the [Update] to the standard output in a more ease to read version.
@@ -61,20 +196,163 @@ impl Update {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
+  /// Gets the assignments of the `set` clause as they were added, the values bound by the methods
+  /// with the `_bind` suffix are rendered as placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .set("name = 'Foo'");
+  ///
+  /// # assert_eq!(["login = 'foo'", "name = 'Foo'"], update.get_set());
+  /// ```
+  pub fn get_set(&self) -> &[Fragment] {
+    &self._set
+  }
+
+  /// Gets the table of the `update` clause, `None` when the clause is not defined.
+  /// In SQLite the expression of the [Update::update_or] method is returned as it was defined
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'");
+  ///
+  /// # assert_eq!(Some(&sql::Fragment::from("users")), update.get_update());
+  /// ```
+  pub fn get_update(&self) -> Option<&Fragment> {
+    #[cfg(not(feature = "sqlite"))]
+    let table_name = &self._update;
+    #[cfg(feature = "sqlite")]
+    let table_name = &self._update.1;
+
+    utils::non_empty(table_name)
+  }
+
+  /// Gets the conditions of the `where` clause together with the [LogicalOperator] that joins each one
+  /// to the previous conditions
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause("login = 'foo'");
+  ///
+  /// # assert_eq!([(sql::LogicalOperator::And, sql::Fragment::from("login = 'foo'"))], update.get_where());
+  /// ```
+  pub fn get_where(&self) -> &[(LogicalOperator, Fragment)] {
+    &self._where
+  }
+
   /// Creates instance of the Update command
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// Returns the number of parameters of the [Update], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound by the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = $1")
+  ///   .where_clause("id = $2");
+  ///
+  /// # let expected = "UPDATE users SET login = $1 WHERE id = $2";
+  /// # assert_eq!(expected, update.as_string());
+  /// # assert_eq!(2, update.param_count());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET login = $1 WHERE id = $2
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
+  /// Parses the SQL text of an update command into a builder, this way an existing query can be changed with
+  /// the builder methods. The conditions of the `where` clause are split at the `and` operator,
+  /// the conditions with the `or` operator are kept as a single condition wrapped in parentheses
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::parse("UPDATE users SET login = 'foo' WHERE id = $1 OR id = $2")
+  ///   .unwrap()
+  ///   .where_clause("active = true");
+  ///
+  /// # let expected = "UPDATE users SET login = 'foo' WHERE (id = $1 OR id = $2) AND active = true";
+  /// # assert_eq!(expected, update.as_string());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users
+  /// SET login = 'foo'
+  /// WHERE (id = $1 OR id = $2) AND active = true
+  /// ```
+  pub fn parse(sql: &str) -> Result<Self, ParseError> {
+    parser::update(sql)
+  }
+  /// Renders the [Update] once in the syntax of the [Dialect] and returns a [PreparedQuery], the SQL and the number
+  /// of parameters are kept so the same query can be executed many times binding new values with the
+  /// [PreparedQuery::bind] method, without rendering the builder again
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("login = ?", "foo")
+  ///   .where_clause_bind("id = ?", 42);
+  ///
+  /// let prepared = update.prepare(sql::Dialect::Standard);
+  ///
+  /// # assert_eq!("UPDATE users SET login = ? WHERE id = ?", prepared.sql());
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET login = ? WHERE id = ?
+  /// ```
+  pub fn prepare(&self, dialect: Dialect) -> PreparedQuery {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    let (sql, values, param_count) = fmt::render_prepared(self, &fmts);
+    PreparedQuery::new(sql, values, param_count, dialect)
+  }
+
   /// Prints the current state of the [Update] to the standard output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -101,8 +379,8 @@ impl Update {
   /// update users
   /// SET login = 'foo'
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -129,8 +407,8 @@ impl Update {
   /// UPDATE users
   /// set name = 'Foo'
   /// ```
-  pub fn raw_after(mut self, clause: UpdateClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, clause: UpdateClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim()));
     self
   }
 
@@ -157,8 +435,8 @@ impl Update {
   /// update users
   /// SET name = 'Bar'
   /// ```
-  pub fn raw_before(mut self, clause: UpdateClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, clause: UpdateClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim()));
     self
   }
 
@@ -181,11 +459,241 @@ impl Update {
   /// ```sql
   /// SET name = 'Bar'
   /// ```
-  pub fn set(mut self, value: &str) -> Self {
-    push_unique(&mut self._set, value.trim().to_string());
+  pub fn set(mut self, value: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._set, value.into().trim());
     self
   }
 
+  /// The `set` clause with values bound to its placeholders, each `?` of the value is replaced
+  /// by a value in the same order. The values are returned by the [Update::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (update_query, values) = sql::Update::new()
+  ///   .set_bind("name = ?, active = ?", ("Bar", false))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "SET name = $1, active = $2";
+  /// # assert_eq!(expected, update_query);
+  /// # assert_eq!(vec![sql::Value::from("Bar"), sql::Value::from(false)], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SET name = $1, active = $2
+  /// ```
+  pub fn set_bind(mut self, value: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._set, bind::bind(value, values));
+    self
+  }
+
+  /// Gets the names of the tables written and read by the [Update], the table of the `update` clause, the tables
+  /// of the `from` and `join` clauses and of the sub-queries in the order they appear. The names of the common table
+  /// expressions are not included and the quoted names are returned without the quotes
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_in("id", sql::Select::new().select("user_id").from("banned_users"));
+  ///
+  /// let tables = update.tables();
+  ///
+  /// # assert_eq!(vec!["users", "banned_users"], tables);
+  /// ```
+  pub fn tables(&self) -> Vec<String> {
+    introspection::tables(self)
+  }
+
+  /// Gets the current state of the [Update] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .returning("id");
+  ///
+  /// let standard_query = update.to_sql(sql::Dialect::Standard);
+  /// let postgresql_query = update.to_sql(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("UPDATE users SET login = 'foo'", standard_query);
+  /// # assert_eq!("UPDATE users SET login = 'foo' RETURNING id", postgresql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// UPDATE users SET login = 'foo'
+  ///
+  /// -- Postgres
+  /// UPDATE users SET login = 'foo' RETURNING id
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Update] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound by the methods with the `_bind` suffix,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("login = ?", "foo");
+  ///
+  /// let (standard_query, values) = update.to_sql_with_binds(sql::Dialect::Standard);
+  /// let (postgresql_query, _) = update.to_sql_with_binds(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("UPDATE users SET login = ?", standard_query);
+  /// # assert_eq!("UPDATE users SET login = $1", postgresql_query);
+  /// # assert_eq!(vec![sql::Value::from("foo")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// UPDATE users SET login = ?
+  ///
+  /// -- Postgres
+  /// UPDATE users SET login = $1
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Update] and returns it as string, returns a [BuildError] when
+  /// the [Update::validate] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("UPDATE users SET login = 'foo'", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET login = 'foo'
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [Update] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Update::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("UPDATE users SET login = 'foo'", query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET login = 'foo'
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [Update], returns a [BuildError] when the table or the `set` clause is missing,
+  /// or when the number of values of a method with the `_bind` suffix doesn't match the placeholders.
+  /// The required clauses are not checked when the raw SQL is set
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .where_clause("id = $1");
+  ///
+  /// let error = update.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "UPDATE".to_string(),
+  /// #   clause: "SET".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [Update] like the [Update::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .returning("id");
+  ///
+  /// let error = update.validate_for(sql::Dialect::Mysql).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::UnsupportedFeature {
+  /// #   dialect: sql::Dialect::Mysql,
+  /// #   feature: "RETURNING".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// # }
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
   /// The `update` clause, this method overrides the previous value
   ///
   /// # Example
@@ -200,8 +708,8 @@ impl Update {
   /// # assert_eq!(update_query, expected);
   /// ```
   #[cfg(not(feature = "sqlite"))]
-  pub fn update(mut self, table_name: &str) -> Self {
-    self._update = table_name.trim().to_string();
+  pub fn update(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._update = table_name.into().trim();
     self
   }
 
@@ -229,7 +737,7 @@ impl Update {
   ///   AND product_id = $2
   ///   AND created_at >= current_date
   /// ```
-  pub fn where_and(self, condition: &str) -> Self {
+  pub fn where_and(self, condition: impl Into<Fragment>) -> Self {
     self.where_clause(condition)
   }
 
@@ -256,8 +764,39 @@ impl Update {
   ///   login = $1
   ///   AND status = 'deactivated'
   /// ```
-  pub fn where_clause(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::And, condition.trim().to_string()));
+  pub fn where_clause(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, condition.into().trim()));
+    self
+  }
+
+  /// The `where` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `and` operator.
+  /// The values are returned by the [Update::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (update_query, values) = sql::Update::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_clause_bind("created_at between ? and ?", ("2024-01-01", "2024-12-31"))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "WHERE login = $1 AND created_at between $2 and $3";
+  /// # assert_eq!(expected, update_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("2024-01-01"), sql::Value::from("2024-12-31")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE login = $1 AND created_at between $2 and $3
+  /// ```
+  pub fn where_clause_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, bind::bind(condition, values)));
     self
   }
 
@@ -284,10 +823,205 @@ impl Update {
   ///   login = 'foo'
   ///   OR login = 'bar'
   /// ```
-  pub fn where_or(mut self, condition: &str) -> Self {
-    push_unique(&mut self._where, (LogicalOperator::Or, condition.trim().to_string()));
+  pub fn where_or(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, condition.into().trim()));
     self
   }
+
+  /// The `where` clause with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `or` operator.
+  /// The values are returned by the [Update::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (update_query, values) = sql::Update::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .where_or_bind("login = ?", "bar")
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "WHERE login = $1 OR login = $2";
+  /// # assert_eq!(expected, update_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("bar")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE login = $1 OR login = $2
+  /// ```
+  pub fn where_or_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, bind::bind(condition, values)));
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `and` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .where_clause("a = 1")
+  ///   .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE a = 1 AND (b = 2 OR c = 3)";
+  /// # assert_eq!(expected, update_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE a = 1 AND (b = 2 OR c = 3)
+  /// ```
+  pub fn where_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::And, group);
+    self
+  }
+
+  /// Adds a group of conditions enclosed in parentheses to the `where` clause, this method will concatenate
+  /// multiples calls using the `or` operator. The groups can be nested to any depth, see [WhereGroup]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .where_clause("a = 1")
+  ///   .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE a = 1 OR (b = 2 AND c = 3)";
+  /// # assert_eq!(expected, update_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE a = 1 OR (b = 2 AND c = 3)
+  /// ```
+  pub fn where_or_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    where_group::push_group(&mut self._where, LogicalOperator::Or, group);
+    self
+  }
+
+  /// The `where` clause with the `exists` operator and a sub-query, this method will concatenate multiples calls
+  /// using the `and` operator. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let orders = sql::Select::new()
+  ///   .select("1")
+  ///   .from("orders")
+  ///   .where_clause("orders.user_id = users.id");
+  ///
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = true")
+  ///   .where_exists(orders)
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = true WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+  /// # assert_eq!(expected, update_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = true
+  /// WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)
+  /// ```
+  pub fn where_exists(mut self, select: impl Into<Arc<Select>>) -> Self {
+    let condition = Fragment::from("EXISTS ").push(Fragment::sub_query(select));
+    push_unique(&mut self._where, (LogicalOperator::And, condition));
+    self
+  }
+
+  /// The `where` clause with the `in` operator and a sub-query, this method will concatenate multiples calls
+  /// using the `and` operator. The sub-query is rendered in the dialect and the format of the outer query
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let admins = sql::Select::new()
+  ///   .select("user_id")
+  ///   .from("admins");
+  ///
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = true")
+  ///   .where_in("id", admins)
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = true WHERE id IN (SELECT user_id FROM admins)";
+  /// # assert_eq!(expected, update_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = true
+  /// WHERE id IN (SELECT user_id FROM admins)
+  /// ```
+  pub fn where_in(mut self, column: impl Into<Fragment>, select: impl Into<Arc<Select>>) -> Self {
+    let condition = column.into().trim().push(" IN ").push(Fragment::sub_query(select));
+    push_unique(&mut self._where, (LogicalOperator::And, condition));
+    self
+  }
+
+  /// Writes the current state of the [Update] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// update.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nUPDATE users SET login = 'foo'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Update] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Update::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'");
+  ///
+  /// let mut output = Vec::new();
+  /// update.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "UPDATE users SET login = 'foo'";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
@@ -335,11 +1069,21 @@ impl Update {
   /// WHERE users_bk.status = 'active'
   /// -- ------------------------------------------------------------------------------
   /// ```
-  pub fn from(mut self, tables: &str) -> Self {
-    push_unique(&mut self._from, tables.trim().to_string());
+  pub fn from(mut self, tables: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._from, tables.into().trim());
     self
   }
 
+  /// Gets the tables of the `from` clause
+  pub fn get_from(&self) -> &[Fragment] {
+    &self._from
+  }
+
+  /// Gets the output names of the `returning` clause
+  pub fn get_returning(&self) -> &[Fragment] {
+    &self._returning
+  }
+
   /// The `returning` clause
   ///
   /// # Example
@@ -362,8 +1106,8 @@ impl Update {
   /// ```sql
   /// RETURNING name, login
   /// ```
-  pub fn returning(mut self, output_name: &str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_string());
+  pub fn returning(mut self, output_name: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._returning, output_name.into().trim());
     self
   }
 
@@ -418,8 +1162,8 @@ impl Update {
   /// -- ------------------------------------------------------------------------------
   /// ```
   #[cfg(any(feature = "postgresql", feature = "sqlite"))]
-  pub fn with(mut self, name: &str, query: impl WithQuery + 'static + Send + Sync) -> Self {
-    self._with.push((name.trim().to_string(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Fragment>, query: impl WithQuery + 'static + Send + Sync) -> Self {
+    self._with.push((name.into().trim(), std::sync::Arc::new(query)));
     self
   }
 }
@@ -428,6 +1172,11 @@ impl Update {
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
 impl Update {
+  /// Gets the columns of the `order by` clause
+  pub fn get_order_by(&self) -> &[Fragment] {
+    &self._order_by
+  }
+
   /// The `order by` clause
   ///
   /// # Example
@@ -440,13 +1189,13 @@ impl Update {
   ///   .order_by("login asc");
   ///
   /// # let expected = "ORDER BY login asc";
-  /// # assert_eq!(expected, update.as_string());
+  /// # assert_eq!(expected, update.to_sql(sql::Dialect::Mysql));
   /// # }
   /// ```
   ///
   /// Note: For crate feature `sqlite` this clause is behind a flag at SQLite, [more info](https://sqlite.org/lang_update.html#optional_limit_and_order_by_clauses).
-  pub fn order_by(mut self, column: &str) -> Self {
-    push_unique(&mut self._order_by, column.trim().to_string());
+  pub fn order_by(mut self, column: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._order_by, column.into().trim());
     self
   }
 }
@@ -454,9 +1203,45 @@ impl Update {
 #[cfg(feature = "sqlite")]
 use crate::structure::UpdateVars;
 
+#[cfg(any(doc, feature = "sqlite"))]
+use crate::structure::Join;
+
 #[cfg(any(doc, feature = "sqlite"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 impl Update {
+  /// The `join` clause with a [Join] builder, the table or sub-query and the conditions of the join are defined
+  /// separately. This method accumulates the joins in the same order of the `*_join` methods
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "sqlite")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let update_query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = true")
+  ///   .join(sql::Join::left("addresses").on("addresses.user_id = users.id"))
+  ///   .join(sql::Join::inner("roles").using("role_id"))
+  ///   .to_sql(sql::Dialect::Sqlite);
+  ///
+  /// # let expected = "UPDATE users SET active = true LEFT JOIN addresses ON addresses.user_id = users.id INNER JOIN roles USING (role_id)";
+  /// # assert_eq!(expected, update_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = true
+  /// LEFT JOIN addresses ON addresses.user_id = users.id
+  /// INNER JOIN roles USING (role_id)
+  /// ```
+  pub fn join(mut self, join: Join) -> Self {
+    push_unique(&mut self._join, join);
+    self
+  }
+
   /// The `cross join` clause
   ///
   /// # Example
@@ -467,7 +1252,7 @@ impl Update {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Update::new()
   ///   .cross_join("orders")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Sqlite);
   ///
   /// # let expected = "CROSS JOIN orders";
   /// # assert_eq!(update_query, expected);
@@ -479,11 +1264,10 @@ impl Update {
   /// ```sql
   /// CROSS JOIN orders
   /// ```
-  pub fn cross_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn cross_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let table = format!("CROSS JOIN {table}");
-      push_unique(&mut self._join, table);
+      push_unique(&mut self._join, Join::cross(table));
     }
     self
   }
@@ -498,7 +1282,7 @@ impl Update {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Update::new()
   ///   .inner_join("orders on orders.owner_login = users.login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Sqlite);
   ///
   /// # let expected = "INNER JOIN orders on orders.owner_login = users.login";
   /// # assert_eq!(update_query, expected);
@@ -510,11 +1294,10 @@ impl Update {
   /// ```sql
   /// INNER JOIN orders on orders.owner_login = users.login
   /// ```
-  pub fn inner_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn inner_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let table = format!("INNER JOIN {table}");
-      push_unique(&mut self._join, table);
+      push_unique(&mut self._join, Join::inner(table));
     }
     self
   }
@@ -529,7 +1312,7 @@ impl Update {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Update::new()
   ///   .left_join("orders on orders.owner_login = users.login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Sqlite);
   ///
   /// # let expected = "LEFT JOIN orders on orders.owner_login = users.login";
   /// # assert_eq!(update_query, expected);
@@ -541,11 +1324,10 @@ impl Update {
   /// ```sql
   /// LEFT JOIN orders on orders.owner_login = users.login
   /// ```
-  pub fn left_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn left_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let table = format!("LEFT JOIN {table}");
-      push_unique(&mut self._join, table);
+      push_unique(&mut self._join, Join::left(table));
     }
     self
   }
@@ -560,7 +1342,7 @@ impl Update {
   /// # use sql_query_builder as sql;
   /// let update_query = sql::Update::new()
   ///   .right_join("orders on orders.owner_login = users.login")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Sqlite);
   ///
   /// # let expected = "RIGHT JOIN orders on orders.owner_login = users.login";
   /// # assert_eq!(update_query, expected);
@@ -572,11 +1354,10 @@ impl Update {
   /// ```sql
   /// RIGHT JOIN orders on orders.owner_login = users.login
   /// ```
-  pub fn right_join(mut self, table: &str) -> Self {
-    let table = table.trim();
+  pub fn right_join(mut self, table: impl Into<Fragment>) -> Self {
+    let table = table.into().trim();
     if table.is_empty() == false {
-      let table = format!("RIGHT JOIN {table}");
-      push_unique(&mut self._join, table);
+      push_unique(&mut self._join, Join::right(table));
     }
     self
   }
@@ -594,8 +1375,8 @@ impl Update {
   /// # let expected = "UPDATE orders";
   /// # assert_eq!(update_query, expected);
   /// ```
-  pub fn update(mut self, table_name: &str) -> Self {
-    self._update = (UpdateVars::Update, table_name.trim().to_string());
+  pub fn update(mut self, table_name: impl Into<Fragment>) -> Self {
+    self._update = (UpdateVars::Update, table_name.into().trim());
     self
   }
 
@@ -621,8 +1402,8 @@ impl Update {
   /// ```sql
   /// UPDATE OR ABORT orders
   /// ```
-  pub fn update_or(mut self, expression: &str) -> Self {
-    self._update = (UpdateVars::UpdateOr, expression.trim().to_string());
+  pub fn update_or(mut self, expression: impl Into<Fragment>) -> Self {
+    self._update = (UpdateVars::UpdateOr, expression.into().trim());
     self
   }
 }
@@ -630,6 +1411,11 @@ impl Update {
 #[cfg(any(doc, feature = "mysql"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "mysql")))]
 impl Update {
+  /// Gets the value of the `limit` clause, `None` when the clause is not defined
+  pub fn get_limit(&self) -> Option<&Fragment> {
+    utils::non_empty(&self._limit)
+  }
+
   /// The `limit` clause, this method overrides the previous value
   ///
   /// # Example
@@ -646,11 +1432,11 @@ impl Update {
   ///   .limit("123");
   ///
   /// # let expected = "LIMIT 123";
-  /// # assert_eq!(expected, update.as_string());
+  /// # assert_eq!(expected, update.to_sql(sql::Dialect::Mysql));
   /// # }
   /// ```
-  pub fn limit(mut self, num: &str) -> Self {
-    self._limit = num.trim().to_string();
+  pub fn limit(mut self, num: impl Into<Fragment>) -> Self {
+    self._limit = num.into().trim();
     self
   }
 }
@@ -664,6 +1450,6 @@ impl std::fmt::Display for Update {
 impl std::fmt::Debug for Update {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,10 +1,13 @@
 use crate::{
+  behavior::Validate,
   concat::{
     sql_standard::{ConcatFrom, ConcatJoin, ConcatSet, ConcatWhere},
     Concat,
   },
   fmt,
-  structure::{Update, UpdateClause},
+  introspection::{CollectTables, Tables},
+  structure::{BuildError, Dialect, Update, UpdateClause},
+  utils,
 };
 
 impl ConcatFrom<UpdateClause> for Update {}
@@ -13,178 +16,180 @@ impl ConcatJoin<UpdateClause> for Update {}
 impl ConcatSet<UpdateClause> for Update {}
 
 impl Concat for Update {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_update(query, &fmts);
-      query = self.concat_set(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Set,
-        &self._set,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Where,
-        &self._where,
-      );
-    }
+    match fmts.dialect {
+      Dialect::Standard => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_update(w, &fmts)?;
+        self.concat_set(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Set,
+          &self._set,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Where,
+          &self._where,
+        )?;
+      }
 
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::With,
-        &self._with,
-      );
-      query = self.concat_update(query, &fmts);
-      query = self.concat_set(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Set,
-        &self._set,
-      );
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::From,
-        &self._from,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Where,
-        &self._where,
-      );
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Returning,
-        &self._returning,
-      );
-    }
+      #[cfg(feature = "postgresql")]
+      Dialect::Postgresql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::With,
+          &self._with,
+        )?;
+        self.concat_update(w, &fmts)?;
+        self.concat_set(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Set,
+          &self._set,
+        )?;
+        self.concat_from(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::From,
+          &self._from,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Where,
+          &self._where,
+        )?;
+        self.concat_returning(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Returning,
+          &self._returning,
+        )?;
+      }
 
-    #[cfg(feature = "sqlite")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::With,
-        &self._with,
-      );
-      query = self.concat_update(&self._raw_before, &self._raw_after, query, &fmts, &self._update);
-      query = self.concat_set(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Set,
-        &self._set,
-      );
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::From,
-        &self._from,
-      );
-      query = self.concat_join(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Join,
-        &self._join,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Where,
-        &self._where,
-      );
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Returning,
-        &self._returning,
-      );
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::OrderBy,
-        &self._order_by,
-      );
-    }
+      #[cfg(feature = "sqlite")]
+      Dialect::Sqlite => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_with(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::With,
+          &self._with,
+        )?;
+        self.concat_update(w, &fmts)?;
+        self.concat_set(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Set,
+          &self._set,
+        )?;
+        self.concat_from(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::From,
+          &self._from,
+        )?;
+        self.concat_join(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Join,
+          &self._join,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Where,
+          &self._where,
+        )?;
+        self.concat_returning(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Returning,
+          &self._returning,
+        )?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::OrderBy,
+          &self._order_by,
+        )?;
+      }
 
-    #[cfg(feature = "mysql")]
-    {
-      query = self.concat_raw(query, &fmts, &self._raw);
-      query = self.concat_update(query, &fmts);
-      query = self.concat_set(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Set,
-        &self._set,
-      );
-      query = self.concat_where(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Where,
-        &self._where,
-      );
-      query = self.concat_order_by(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::OrderBy,
-        &self._order_by,
-      );
-      query = self.concat_limit(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::Limit,
-        &self._limit,
-      );
+      #[cfg(feature = "mysql")]
+      Dialect::Mysql => {
+        self.concat_raw(w, &fmts, &self._raw)?;
+        self.concat_update(w, &fmts)?;
+        self.concat_set(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Set,
+          &self._set,
+        )?;
+        self.concat_where(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Where,
+          &self._where,
+        )?;
+        self.concat_order_by(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::OrderBy,
+          &self._order_by,
+        )?;
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          w,
+          &fmts,
+          UpdateClause::Limit,
+          &self._limit,
+        )?;
+      }
     }
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
@@ -206,31 +211,153 @@ use crate::concat::sqlite::ConcatUpdate;
 impl ConcatUpdate for Update {}
 
 #[cfg(not(feature = "sqlite"))]
-use crate::concat::concat_raw_before_after;
-
+use crate::{concat::concat_raw_before_after, pretty::Body};
 #[cfg(not(feature = "sqlite"))]
+use std::fmt::Write;
+
 impl Update {
-  fn concat_update(&self, query: String, fmts: &fmt::Formatter) -> String {
+  #[cfg(not(feature = "sqlite"))]
+  fn concat_update(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._update.is_empty() == false {
-      let table_name = &self._update;
-      format!("UPDATE{space}{table_name}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       UpdateClause::Update,
-      sql,
+      |w| {
+        if self._update.is_empty() == false {
+          w.write_clause("UPDATE", Body::Text)?;
+          w.write_str(space)?;
+          w.write_fragment(&self._update)?;
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
+
+  #[cfg(feature = "sqlite")]
+  fn concat_update(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    ConcatUpdate::concat_update(self, &self._raw_before, &self._raw_after, w, fmts, &self._update)
+  }
 }
 
 #[cfg(feature = "mysql")]
 use crate::concat::non_standard::ConcatLimit;
 #[cfg(feature = "mysql")]
 impl ConcatLimit<UpdateClause> for Update {}
+
+impl Validate for Update {
+  fn check_clauses(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_support(dialect)?;
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    for (_, query) in &self._with {
+      query.check_clauses(dialect)?;
+    }
+
+    if utils::has_raw(&self._raw, &self._raw_before, &self._raw_after) {
+      return Ok(());
+    }
+
+    #[cfg(not(feature = "sqlite"))]
+    let table = &self._update;
+    #[cfg(feature = "sqlite")]
+    let (_, table) = &self._update;
+
+    utils::require_clause("UPDATE", "UPDATE", table.is_empty())?;
+    utils::require_clause("UPDATE", "SET", self._set.is_empty())
+  }
+}
+
+impl Update {
+  /// Checks if the dialect supports the clauses specific to some dialects
+  #[allow(unused_variables)]
+  fn check_support(&self, dialect: Dialect) -> Result<(), BuildError> {
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      let is_supported = utils::is_postgres(dialect) || utils::is_sqlite(dialect);
+      utils::require_support(dialect, "FROM", self._from.is_empty() == false, is_supported)?;
+      utils::require_support(dialect, "RETURNING", self._returning.is_empty() == false, is_supported)?;
+    }
+
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    utils::require_support(
+      dialect,
+      "ORDER BY",
+      self._order_by.is_empty() == false,
+      utils::is_sqlite(dialect) || utils::is_mysql(dialect),
+    )?;
+
+    #[cfg(feature = "mysql")]
+    utils::require_support(
+      dialect,
+      "LIMIT",
+      self._limit.is_empty() == false,
+      utils::is_sqlite(dialect) || utils::is_mysql(dialect),
+    )?;
+
+    #[cfg(feature = "sqlite")]
+    {
+      utils::require_support(
+        dialect,
+        "UPDATE OR",
+        self._update.0 == crate::structure::UpdateVars::UpdateOr,
+        utils::is_sqlite(dialect),
+      )?;
+      utils::require_support(
+        dialect,
+        "JOIN",
+        self._join.is_empty() == false,
+        utils::is_sqlite(dialect),
+      )?;
+      for join in &self._join {
+        join.check_clauses(dialect)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl CollectTables for Update {
+  fn collect_tables(&self, tables: &mut Tables) {
+    self._raw.iter().for_each(|raw| tables.raw(raw));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    for (name, query) in self._with.iter() {
+      tables.cte(name);
+      query.collect_tables(tables);
+    }
+
+    #[cfg(not(feature = "sqlite"))]
+    tables.clause("UPDATE", &self._update);
+
+    #[cfg(feature = "sqlite")]
+    match &self._update {
+      (crate::structure::UpdateVars::Update, table) => tables.clause("UPDATE", table),
+      (crate::structure::UpdateVars::UpdateOr, table) => tables.clause("UPDATE OR", table),
+    }
+
+    self._set.iter().for_each(|assignment| tables.expr(assignment));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    self._from.iter().for_each(|table| tables.clause("FROM", table));
+
+    #[cfg(feature = "sqlite")]
+    self._join.iter().for_each(|join| join.collect_tables(tables));
+
+    self._where.iter().for_each(|(_, condition)| tables.expr(condition));
+
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    self._order_by.iter().for_each(|column| tables.expr(column));
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    self._returning.iter().for_each(|column| tables.expr(column));
+
+    let raw_before_after = self._raw_before.iter().chain(self._raw_after.iter());
+    raw_before_after.for_each(|(_, raw)| tables.raw(raw));
+  }
+}
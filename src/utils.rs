@@ -1,3 +1,4 @@
+use crate::structure::{BuildError, Dialect, Fragment};
 use std::cmp::PartialEq;
 
 pub(crate) fn push_unique<T: PartialEq>(list: &mut Vec<T>, value: T) {
@@ -7,11 +8,117 @@ pub(crate) fn push_unique<T: PartialEq>(list: &mut Vec<T>, value: T) {
   }
 }
 
-pub(crate) fn join(list: &Vec<String>, sep: &str) -> String {
-  list
+/// Returns true when the raw SQL of the builder is set, the raw SQL can contain any clause
+pub(crate) fn has_raw<Clause>(
+  raw: &[Fragment],
+  raw_before: &[(Clause, Fragment)],
+  raw_after: &[(Clause, Fragment)],
+) -> bool {
+  raw.is_empty() == false || raw_before.is_empty() == false || raw_after.is_empty() == false
+}
+
+/// Returns the error of the required clause when it's missing
+pub(crate) fn require_clause(command: &str, clause: &str, is_missing: bool) -> Result<(), BuildError> {
+  if is_missing {
+    return Err(BuildError::MissingClause {
+      command: command.to_string(),
+      clause: clause.to_string(),
+    });
+  }
+  Ok(())
+}
+
+/// Returns the error of the conflicting clauses when more than one of the mutually exclusive clauses is used
+pub(crate) fn exclusive_clauses(command: &str, clauses: &[(&str, bool)]) -> Result<(), BuildError> {
+  let used = clauses
     .iter()
-    .filter(|item| item.is_empty() == false)
-    .map(|item| item.as_str())
-    .collect::<Vec<_>>()
-    .join(sep)
+    .filter(|(_, is_used)| *is_used)
+    .map(|(clause, _)| clause.to_string())
+    .collect::<Vec<_>>();
+
+  if used.len() > 1 {
+    return Err(BuildError::ConflictingClauses {
+      command: command.to_string(),
+      clauses: used,
+    });
+  }
+  Ok(())
+}
+
+/// Returns the error of the feature when it's used in a dialect that doesn't support it
+pub(crate) fn require_support(
+  dialect: Dialect,
+  feature: &str,
+  is_used: bool,
+  is_supported: bool,
+) -> Result<(), BuildError> {
+  if is_used && is_supported == false {
+    return Err(BuildError::UnsupportedFeature {
+      dialect,
+      feature: feature.to_string(),
+    });
+  }
+  Ok(())
+}
+
+pub(crate) fn is_postgres(dialect: Dialect) -> bool {
+  match dialect {
+    #[cfg(feature = "postgresql")]
+    Dialect::Postgresql => true,
+    _ => false,
+  }
+}
+
+pub(crate) fn is_sqlite(dialect: Dialect) -> bool {
+  match dialect {
+    #[cfg(feature = "sqlite")]
+    Dialect::Sqlite => true,
+    _ => false,
+  }
+}
+
+pub(crate) fn is_mysql(dialect: Dialect) -> bool {
+  match dialect {
+    #[cfg(feature = "mysql")]
+    Dialect::Mysql => true,
+    _ => false,
+  }
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// The dialects enabled by the crate features, the SQL Standard is always the first one
+pub(crate) fn dialects() -> Vec<Dialect> {
+  #[allow(unused_mut)]
+  let mut dialects = vec![Dialect::Standard];
+
+  #[cfg(feature = "postgresql")]
+  dialects.push(Dialect::Postgresql);
+
+  #[cfg(feature = "sqlite")]
+  dialects.push(Dialect::Sqlite);
+
+  #[cfg(feature = "mysql")]
+  dialects.push(Dialect::Mysql);
+
+  dialects
+}
+
+pub(crate) fn join(list: &[Fragment], sep: &str) -> Fragment {
+  let mut items = list.iter().filter(|item| item.is_empty() == false);
+  let mut result = items.next().cloned().unwrap_or_default();
+  for item in items {
+    result = result.push(sep).push(item);
+  }
+  result
+}
+
+/// Returns the fragment when it's not empty, used by the getters of the optional clauses
+pub(crate) fn non_empty(fragment: &Fragment) -> Option<&Fragment> {
+  if fragment.is_empty() {
+    return None;
+  }
+  Some(fragment)
 }
@@ -1,10 +1,13 @@
 use crate::{
-  concat::Concat,
-  fmt,
-  structure::{Values, ValuesClause},
+  behavior::Validate,
+  fmt, pretty,
+  structure::{BuildError, Dialect, FormatOptions, Fragment, Value, Values, ValuesClause},
   utils::push_unique,
 };
 
+#[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+use crate::{behavior::BindValues, bind};
+
 #[cfg(any(feature = "postgresql", feature = "sqlite"))]
 use crate::behavior::WithQuery;
 
@@ -12,6 +15,42 @@ use crate::behavior::WithQuery;
 impl WithQuery for Values {}
 
 impl Values {
+  /// Gets the current state of the [Values] and returns it as string formatted by the [FormatOptions]
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let options = sql::FormatOptions {
+  ///   max_line_length: 20,
+  ///   ..Default::default()
+  /// };
+  /// let values_query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .values("(2, 'two')")
+  ///   .as_pretty_string(&options);
+  ///
+  /// # let expected = "\
+  /// # VALUES\n  \
+  /// # (1, 'one'),\n  \
+  /// # (2, 'two')";
+  /// # assert_eq!(expected, values_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES
+  ///   (1, 'one'),
+  ///   (2, 'two')
+  /// ```
+  pub fn as_pretty_string(&self, options: &FormatOptions) -> String {
+    pretty::format(self, Dialect::default(), options)
+  }
+
   /// Gets the current state of the [Values] and returns it as string
   ///
   /// # Example
@@ -35,8 +74,50 @@ impl Values {
   /// VALUES ('foo', 'Foo')
   /// ```
   pub fn as_string(&self) -> String {
+    let mut query = String::new();
+    self.write_sql(&mut query).unwrap();
+    query
+  }
+
+  /// Gets the current state of the [Values] and returns it as string together with the values bound
+  /// by the methods with the `_bind` suffix, the values are returned in the same order of its placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (query, values) = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "VALUES ($1, $2)";
+  /// # assert_eq!(expected, query);
+  /// # assert_eq!(vec![sql::Value::from(1), sql::Value::from("one")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES ($1, $2)
+  /// ```
+  pub fn as_string_with_binds(&self) -> (String, Vec<Value>) {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the clauses of the [Values] that have content. The raw SQL added by the methods
+  /// [Values::raw], [Values::raw_before] and [Values::raw_after] is not considered
+  pub fn clauses(&self) -> Vec<ValuesClause> {
+    let mut clauses = vec![];
+
+    if self._values.is_empty() == false {
+      clauses.push(ValuesClause::Values);
+    }
+
+    clauses
   }
 
   /// Prints the current state of the [Values] to the standard output in a more ease to read version.
@@ -67,20 +148,69 @@ impl Values {
   /// ```
   pub fn debug(self) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
+  /// Gets the rows of the `values` clause as they were added
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new()
+  ///   .values("('foo', 'Foo')")
+  ///   .values("('bar', 'Bar')");
+  ///
+  /// # assert_eq!(["('foo', 'Foo')", "('bar', 'Bar')"], values.get_values());
+  /// # assert_eq!(vec![sql::ValuesClause::Values], values.clauses());
+  /// # }
+  /// ```
+  pub fn get_values(&self) -> &[Fragment] {
+    &self._values
+  }
+
   /// Creates instance of the Values command
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// Returns the number of parameters of the [Values], that is the highest placeholder `$n` after the renumbering
+  /// of the composed queries plus the values bound by the methods with the `_bind` suffix
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new()
+  ///   .values("($1, $2)")
+  ///   .values("($3, $4)");
+  ///
+  /// # let expected = "VALUES ($1, $2), ($3, $4)";
+  /// # assert_eq!(expected, values.as_string());
+  /// # assert_eq!(4, values.param_count());
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES ($1, $2), ($3, $4)
+  /// ```
+  pub fn param_count(&self) -> usize {
+    let fmts = fmt::one_line();
+    fmt::param_count(self, &fmts)
+  }
+
   /// Prints the current state of the [Values] to the standard output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    println!("{}", fmt::format(&self, &fmts));
     self
   }
 
@@ -110,8 +240,8 @@ impl Values {
   /// insert into my_table(num, txt)
   /// VALUES (1, 'one'), (2, 'two')
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_string());
+  pub fn raw(mut self, raw_sql: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._raw, raw_sql.into().trim());
     self
   }
 
@@ -140,8 +270,8 @@ impl Values {
   /// ```sql
   /// VALUES (1, 'one'), (2, 'two') , (3, 'three')
   /// ```
-  pub fn raw_after(mut self, clause: ValuesClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_after(mut self, clause: ValuesClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim()));
     self
   }
 
@@ -171,11 +301,183 @@ impl Values {
   /// /* the values command */
   /// VALUES (1, 'one'), (2, 'two')
   /// ```
-  pub fn raw_before(mut self, clause: ValuesClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_string()));
+  pub fn raw_before(mut self, clause: ValuesClause, raw_sql: impl Into<Fragment>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim()));
     self
   }
 
+  /// Gets the current state of the [Values] and returns it as string rendered in the specified SQL [Dialect],
+  /// the clauses not supported by the dialect are ignored
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "mysql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new()
+  ///   .row("('foo', 'Foo')");
+  ///
+  /// let standard_query = values.to_sql(sql::Dialect::Standard);
+  /// let mysql_query = values.to_sql(sql::Dialect::Mysql);
+  ///
+  /// # assert_eq!("VALUES ('foo', 'Foo')", standard_query);
+  /// # assert_eq!("VALUES ROW('foo', 'Foo')", mysql_query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// VALUES ('foo', 'Foo')
+  ///
+  /// -- MySQL
+  /// VALUES ROW('foo', 'Foo')
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let (query, _) = self.to_sql_with_binds(dialect);
+    query
+  }
+
+  /// Gets the current state of the [Values] and returns it as string rendered in the specified SQL [Dialect]
+  /// together with the values bound by the methods with the `_bind` suffix,
+  /// the placeholders are rendered as `$1, $2, ...` in Postgres and as `?` in the other dialects
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"));
+  ///
+  /// let (standard_query, bound_values) = values.to_sql_with_binds(sql::Dialect::Standard);
+  /// let (postgresql_query, _) = values.to_sql_with_binds(sql::Dialect::Postgresql);
+  ///
+  /// # assert_eq!("VALUES (?, ?)", standard_query);
+  /// # assert_eq!("VALUES ($1, $2)", postgresql_query);
+  /// # assert_eq!(vec![sql::Value::from(1), sql::Value::from("one")], bound_values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// -- Standard
+  /// VALUES (?, ?)
+  ///
+  /// -- Postgres
+  /// VALUES ($1, $2)
+  /// ```
+  pub fn to_sql_with_binds(&self, dialect: Dialect) -> (String, Vec<Value>) {
+    let fmts = fmt::Formatter {
+      dialect,
+      ..fmt::one_line()
+    };
+    fmt::render(self, &fmts)
+  }
+
+  /// Gets the current state of the [Values] and returns it as string, returns a [BuildError] when
+  /// the [Values::validate] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("VALUES (1, 'one')", query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'one')
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.validate()?;
+    Ok(self.as_string())
+  }
+
+  /// Gets the current state of the [Values] rendered in the specified SQL [Dialect], returns a [BuildError] when
+  /// the [Values::validate_for] method fails
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let query = sql::Values::new()
+  ///   .values("('foo')")
+  ///   .try_to_sql(sql::Dialect::Standard)
+  ///   .unwrap();
+  ///
+  /// # assert_eq!("VALUES ('foo')", query);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES ('foo')
+  /// ```
+  pub fn try_to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.validate_for(dialect)?;
+    Ok(self.to_sql(dialect))
+  }
+
+  /// Checks the clauses of the [Values], returns a [BuildError] when there are no rows and no raw SQL,
+  /// or when a row of the `values_bind` method doesn't have one value for each placeholder
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new();
+  ///
+  /// let error = values.validate().unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "VALUES".to_string(),
+  /// #   clause: "VALUES".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.validate_for(Dialect::default())
+  }
+
+  /// Checks the clauses of the [Values] like the [Values::validate] method does, but in the specified SQL [Dialect],
+  /// returns a [BuildError] when a clause used is not supported by the dialect as well
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new();
+  ///
+  /// let error = values.validate_for(sql::Dialect::Standard).unwrap_err();
+  ///
+  /// # let expected = sql::BuildError::MissingClause {
+  /// #   command: "VALUES".to_string(),
+  /// #   clause: "VALUES".to_string(),
+  /// # };
+  /// # assert_eq!(expected, error);
+  /// ```
+  pub fn validate_for(&self, dialect: Dialect) -> Result<(), BuildError> {
+    self.check_clauses(dialect)?;
+    fmt::check_binds(self, dialect)
+  }
+
   /// The `values` clause
   ///
   /// # Example
@@ -199,11 +501,91 @@ impl Values {
   /// ```sql
   /// VALUES (1, 'one'), (2, 'two'), (3, 'three')
   /// ```
-  #[cfg(not(feature = "mysql"))]
-  pub fn values(mut self, expression: &str) -> Self {
-    push_unique(&mut self._values, expression.trim().to_string());
+  #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+  pub fn values(mut self, expression: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._values, expression.into().trim());
     self
   }
+
+  #[cfg(any(not(feature = "mysql"), feature = "postgresql", feature = "sqlite"))]
+  /// The `values` clause with values bound to its placeholders, each `?` of the expression is replaced
+  /// by a value in the same order. The values are returned by the [Values::as_string_with_binds] method
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(feature = "postgresql")]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let (values_query, values) = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"))
+  ///   .values_bind("(?, ?)", (2, "two"))
+  ///   .as_string_with_binds();
+  ///
+  /// # let expected = "VALUES ($1, $2), ($3, $4)";
+  /// # assert_eq!(expected, values_query);
+  /// # assert_eq!(vec![sql::Value::from(1), sql::Value::from("one"), sql::Value::from(2), sql::Value::from("two")], values);
+  /// # }
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES ($1, $2), ($3, $4)
+  /// ```
+  pub fn values_bind(mut self, expression: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._values, bind::bind(expression, values));
+    self
+  }
+
+  /// Writes the current state of the [Values] into the writer, the text written is the same returned by the
+  /// `as_string` method. The query is rendered into a string and then copied into the writer, the placeholders are
+  /// renumbered only when the rendering finishes, so this method allocates the same as the `as_string` method.
+  /// Returns an error when the number of values of a method with the `_bind` suffix doesn't match the placeholders
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new()
+  ///   .values("('foo', 'Foo')");
+  ///
+  /// let mut query = String::from("-- generated\n");
+  /// values.write_sql(&mut query).unwrap();
+  ///
+  /// # let expected = "-- generated\nVALUES ('foo', 'Foo')";
+  /// # assert_eq!(expected, query);
+  /// # }
+  /// ```
+  pub fn write_sql(&self, output: &mut impl std::fmt::Write) -> std::fmt::Result {
+    let fmts = fmt::one_line();
+    fmt::write(self, &fmts, output)
+  }
+
+  /// Writes the current state of the [Values] into the [std::io::Write], like a file or a socket,
+  /// the same way the [Values::write_sql] does
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # {
+  /// # use sql_query_builder as sql;
+  /// let values = sql::Values::new()
+  ///   .values("('foo', 'Foo')");
+  ///
+  /// let mut output = Vec::new();
+  /// values.write_sql_io(&mut output).unwrap();
+  ///
+  /// # let expected = "VALUES ('foo', 'Foo')";
+  /// # assert_eq!(expected.as_bytes(), output);
+  /// # }
+  /// ```
+  pub fn write_sql_io(&self, output: &mut impl std::io::Write) -> std::io::Result<()> {
+    fmt::write_io(output, |writer| self.write_sql(writer))
+  }
 }
 
 #[cfg(feature = "mysql")]
@@ -218,7 +600,7 @@ impl Values {
   /// let values_query = sql::Values::new()
   ///   .row("(1, 'one'), row(2, 'two')")
   ///   .row("(3, 'three')")
-  ///   .as_string();
+  ///   .to_sql(sql::Dialect::Mysql);
   ///
   /// # let expected = "VALUES ROW(1, 'one'), row(2, 'two'), ROW(3, 'three')";
   /// # assert_eq!(expected, values_query);
@@ -229,8 +611,8 @@ impl Values {
   /// ```sql
   /// VALUES ROW(1, 'one'), row(2, 'two'), ROW(3, 'three')
   /// ```
-  pub fn row(mut self, expression: &str) -> Self {
-    push_unique(&mut self._values, expression.trim().to_string());
+  pub fn row(mut self, expression: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._values, expression.into().trim());
     self
   }
 }
@@ -244,6 +626,6 @@ impl std::fmt::Display for Values {
 impl std::fmt::Debug for Values {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", fmt::format(self, &fmts))
   }
 }
@@ -1,58 +1,75 @@
 use crate::{
+  behavior::Validate,
   concat::{concat_raw_before_after, Concat},
   fmt,
-  structure::{Values, ValuesClause},
+  introspection::{CollectTables, Tables},
+  pretty::Body,
+  structure::{BuildError, Dialect, Values, ValuesClause},
+  utils,
 };
+use std::fmt::Write;
 
 impl Concat for Values {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_string();
+  fn concat(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
+    let start = w.mark();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    query = self.concat_values(query, &fmts);
+    self.concat_raw(w, &fmts, &self._raw)?;
+    self.concat_values(w, &fmts)?;
 
-    query.trim_end().to_string()
+    w.trim_end_since(start);
+    Ok(())
   }
 }
 
 impl Values {
-  fn concat_values(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_values(&self, w: &mut fmt::Writer, fmts: &fmt::Formatter) -> fmt::Result {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._values.is_empty() == false {
-      let sep = format!("{comma}{lb}");
-      let rows = self
-        ._values
-        .iter()
-        .filter(|item| item.is_empty() == false)
-        .map(|item| {
-          #[cfg(not(feature = "mysql"))]
-          {
-            item.clone()
-          }
-          #[cfg(feature = "mysql")]
-          {
-            format!("ROW{item}")
-          }
-        })
-        .collect::<Vec<_>>()
-        .join(&sep);
-
-      if rows.is_empty() == true {
-        return "".to_string();
-      };
-
-      format!("VALUES{space}{lb}{rows}{space}{lb}")
-    } else {
-      "".to_string()
-    };
 
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
-      query,
+      w,
       fmts,
       ValuesClause::Values,
-      sql,
+      |w| {
+        let start = w.mark();
+        let rows = self._values.iter().filter(|item| item.is_empty() == false);
+        for (index, item) in rows.enumerate() {
+          if index == 0 {
+            w.write_clause("VALUES", Body::List)?;
+            write!(w, "{space}{lb}")?;
+          } else {
+            w.write_separator(comma)?;
+            w.write_str(lb)?;
+          }
+          #[cfg(feature = "mysql")]
+          if fmts.dialect == Dialect::Mysql {
+            w.write_str("ROW")?;
+          }
+          w.write_fragment(item)?;
+        }
+        if w.has_written_since(start) {
+          write!(w, "{space}{lb}")?;
+        }
+        Ok(())
+      },
     )
   }
 }
+
+impl Validate for Values {
+  fn check_clauses(&self, _dialect: Dialect) -> Result<(), BuildError> {
+    let has_raw = utils::has_raw(&self._raw, &self._raw_before, &self._raw_after);
+    utils::require_clause("VALUES", "VALUES", has_raw == false && self._values.is_empty())
+  }
+}
+
+impl CollectTables for Values {
+  fn collect_tables(&self, tables: &mut Tables) {
+    self._raw.iter().for_each(|raw| tables.raw(raw));
+    self._values.iter().for_each(|row| tables.expr(row));
+
+    let raw_before_after = self._raw_before.iter().chain(self._raw_after.iter());
+    raw_before_after.for_each(|(_, raw)| tables.raw(raw));
+  }
+}
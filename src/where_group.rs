@@ -0,0 +1,191 @@
+use crate::{
+  behavior::BindValues,
+  bind,
+  structure::{Fragment, LogicalOperator, WhereGroup},
+  utils::push_unique,
+};
+
+impl WhereGroup {
+  /// Adds a condition to the group, this method will concatenate multiples calls using the `and` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_or_group(|group| group.where_clause("login = 'foo'").where_clause("admin = true"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE active = true OR (login = 'foo' AND admin = true)";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE active = true OR (login = 'foo' AND admin = true)
+  /// ```
+  pub fn where_clause(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, condition.into().trim()));
+    self
+  }
+
+  /// Adds a condition to the group, this method will concatenate multiples calls using the `or` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_group(|group| group.where_clause("login = 'foo'").where_or("login = 'bar'"))
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE active = true AND (login = 'foo' OR login = 'bar')";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE active = true AND (login = 'foo' OR login = 'bar')
+  /// ```
+  pub fn where_or(mut self, condition: impl Into<Fragment>) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, condition.into().trim()));
+    self
+  }
+
+  /// Adds a condition with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `and` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let (select_query, values) = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_or_group(|group| group.where_clause_bind("login = ?", "foo").where_clause("admin = true"))
+  ///   .to_sql_with_binds(sql::Dialect::Standard);
+  ///
+  /// # let expected = "WHERE active = true OR (login = ? AND admin = true)";
+  /// # assert_eq!(expected, select_query);
+  /// # assert_eq!(vec![sql::Value::from("foo")], values);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE active = true OR (login = ? AND admin = true)
+  /// ```
+  pub fn where_clause_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::And, bind::bind(condition, values)));
+    self
+  }
+
+  /// Adds a condition with values bound to its placeholders, each `?` of the condition is replaced
+  /// by a value in the same order, this method will concatenate multiples calls using the `or` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let (select_query, values) = sql::Select::new()
+  ///   .where_clause("active = true")
+  ///   .where_group(|group| group.where_clause_bind("login = ?", "foo").where_or_bind("login = ?", "bar"))
+  ///   .to_sql_with_binds(sql::Dialect::Standard);
+  ///
+  /// # let expected = "WHERE active = true AND (login = ? OR login = ?)";
+  /// # assert_eq!(expected, select_query);
+  /// # assert_eq!(vec![sql::Value::from("foo"), sql::Value::from("bar")], values);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE active = true AND (login = ? OR login = ?)
+  /// ```
+  pub fn where_or_bind(mut self, condition: impl Into<Fragment>, values: impl BindValues) -> Self {
+    push_unique(&mut self._where, (LogicalOperator::Or, bind::bind(condition, values)));
+    self
+  }
+
+  /// Adds a nested group of conditions, this method will concatenate multiples calls using the `and` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_group(|group| {
+  ///     group
+  ///       .where_clause("a = 1")
+  ///       .where_or("b = 2")
+  ///       .where_group(|group| group.where_clause("c = 3").where_or("d = 4"))
+  ///   })
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE (a = 1 OR b = 2 AND (c = 3 OR d = 4))";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE (a = 1 OR b = 2 AND (c = 3 OR d = 4))
+  /// ```
+  pub fn where_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    push_group(&mut self._where, LogicalOperator::And, group);
+    self
+  }
+
+  /// Adds a nested group of conditions, this method will concatenate multiples calls using the `or` operator
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use sql_query_builder as sql;
+  /// let select_query = sql::Select::new()
+  ///   .where_group(|group| {
+  ///     group
+  ///       .where_clause("a = 1")
+  ///       .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+  ///   })
+  ///   .as_string();
+  ///
+  /// # let expected = "WHERE (a = 1 OR (b = 2 AND c = 3))";
+  /// # assert_eq!(expected, select_query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// WHERE (a = 1 OR (b = 2 AND c = 3))
+  /// ```
+  pub fn where_or_group(mut self, group: impl FnOnce(WhereGroup) -> WhereGroup) -> Self {
+    push_group(&mut self._where, LogicalOperator::Or, group);
+    self
+  }
+}
+
+/// Builds the group and adds its conditions enclosed in parentheses to the `where` clause,
+/// the groups without conditions are ignored
+pub(crate) fn push_group(
+  items: &mut Vec<(LogicalOperator, Fragment)>,
+  operator: LogicalOperator,
+  group: impl FnOnce(WhereGroup) -> WhereGroup,
+) {
+  let WhereGroup { _where } = group(WhereGroup::default());
+  let conditions = _where
+    .into_iter()
+    .filter(|(_, condition)| condition.is_empty() == false)
+    .enumerate()
+    .fold(Fragment::new(), |acc, (index, (log_op, condition))| match index {
+      0 => condition,
+      _ => acc.push(format!(" {log_op} ")).push(condition),
+    });
+
+  if conditions.is_empty() == false {
+    push_unique(items, (operator, Fragment::from("(").push(conditions).push(")")));
+  }
+}
@@ -0,0 +1,536 @@
+mod bind_values {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_where_clause_bind_should_replace_the_placeholder_and_return_the_value() {
+    let (query, values) = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("id = ?", 42)
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "SELECT id FROM users WHERE id = ?";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(42)], values);
+  }
+
+  #[test]
+  fn method_where_clause_bind_should_accept_a_tuple_of_values() {
+    let (query, values) = sql::Delete::new()
+      .delete_from("users")
+      .where_clause_bind("created_at between ? and ?", ("2024-01-01", "2024-12-31"))
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "DELETE FROM users WHERE created_at between ? and ?";
+    let expected_values = vec![
+      sql::Value::Text("2024-01-01".to_string()),
+      sql::Value::Text("2024-12-31".to_string()),
+    ];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn values_should_be_returned_in_the_order_of_the_placeholders_not_the_order_of_the_method_calls() {
+    let (query, values) = sql::Update::new()
+      .where_clause_bind("id = ?", 42)
+      .set_bind("login = ?", "foo")
+      .update("users")
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "UPDATE users SET login = ? WHERE id = ?";
+    let expected_values = vec![sql::Value::Text("foo".to_string()), sql::Value::Int(42)];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn question_marks_inside_quoted_strings_should_not_be_replaced() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("note = 'why?' and id = ?", 1)
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "WHERE note = 'why?' and id = ?";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(1)], values);
+  }
+
+  #[test]
+  fn question_marks_inside_quoted_identifiers_should_not_be_replaced() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind(r#""why?" = ? and `how?` = ?"#, (1, 2))
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = r#"WHERE "why?" = ? and `how?` = ?"#;
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(1), sql::Value::Int(2)], values);
+  }
+
+  #[test]
+  fn quotes_of_another_kind_inside_a_quoted_string_should_be_ignored() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind(r#"note = 'say "what?' and id = ?"#, 1)
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = r#"WHERE note = 'say "what?' and id = ?"#;
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(1)], values);
+  }
+
+  #[test]
+  fn postgres_jsonb_operators_should_not_be_replaced() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind(
+        "tags ?| array['a'] and tags ?& array['b'] and tags ?? 'c' and id = ?",
+        1,
+      )
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "WHERE tags ?| array['a'] and tags ?& array['b'] and tags ? 'c' and id = ?";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(1)], values);
+  }
+
+  #[test]
+  fn missing_values_should_be_reported_by_the_validation() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("id = ? and login = ?", 42);
+    let expected_error = sql::BuildError::ParamCountMismatch { expected: 2, found: 1 };
+
+    assert_eq!(Err(expected_error.clone()), select.validate());
+    assert_eq!(Err(expected_error), select.try_as_string());
+  }
+
+  #[test]
+  fn mismatched_values_should_fail_the_write_sql() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("id = ? and login = ?", 42);
+
+    let mut query = String::new();
+    let mut output = Vec::new();
+
+    assert!(select.write_sql(&mut query).is_err());
+    assert!(select.write_sql_io(&mut output).is_err());
+    assert!(query.is_empty());
+  }
+
+  #[test]
+  fn extra_values_should_be_reported_by_the_validation() {
+    let update = sql::Update::new().update("users").set_bind("login = ?", ("foo", "bar"));
+    let expected_error = sql::BuildError::ParamCountMismatch { expected: 1, found: 2 };
+
+    assert_eq!(Err(expected_error), update.validate_for(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn values_of_the_transaction_commands_should_be_checked_by_the_validation() {
+    let transaction = sql::Transaction::new().delete(
+      sql::Delete::new()
+        .delete_from("users")
+        .where_clause_bind("id = ?", (1, 2)),
+    );
+    let expected_error = sql::BuildError::ParamCountMismatch { expected: 1, found: 2 };
+
+    assert_eq!(Err(expected_error), transaction.validate());
+  }
+
+  #[test]
+  fn matching_values_should_be_valid() {
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .where_clause_bind("id = ? and note <> '?'", 1);
+
+    assert_eq!(Ok(()), delete.validate());
+  }
+
+  #[test]
+  fn method_as_string_should_render_the_placeholders_without_the_values() {
+    let select = sql::Select::new().where_clause_bind("login = ?", "foo");
+    let (query, _) = select.as_string_with_binds();
+
+    assert_eq!(query, select.as_string());
+  }
+
+  #[test]
+  fn values_should_keep_its_types() {
+    let (_, values) = sql::Insert::new()
+      .insert_into("files (name, size, ratio, public, content, owner)")
+      .values_bind(
+        "(?, ?, ?, ?, ?, ?)",
+        ("a.txt", 1024_i64, 0.5, true, vec![0_u8, 255], None::<i32>),
+      )
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_values = vec![
+      sql::Value::Text("a.txt".to_string()),
+      sql::Value::Int(1024),
+      sql::Value::Float(0.5),
+      sql::Value::Bool(true),
+      sql::Value::Bytes(vec![0, 255]),
+      sql::Value::Null,
+    ];
+
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn values_of_the_insert_select_should_be_returned_in_the_order_of_the_placeholders() {
+    let (query, values) = sql::Insert::new()
+      .insert_into("users_bkp (login)")
+      .select(
+        sql::Select::new()
+          .select("login")
+          .from("users")
+          .where_clause_bind("id > ?", 10),
+      )
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "INSERT INTO users_bkp (login) SELECT login FROM users WHERE id > ?";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(10)], values);
+  }
+
+  #[test]
+  fn values_of_the_transaction_commands_should_be_returned_in_the_order_of_the_commands() {
+    let (query, values) = sql::Transaction::new()
+      .delete(sql::Delete::new().delete_from("users").where_clause_bind("id = ?", 1))
+      .update(sql::Update::new().update("orders").set_bind("status = ?", "canceled"))
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "DELETE FROM users WHERE id = ?; UPDATE orders SET status = ?;";
+    let expected_values = vec![sql::Value::Int(1), sql::Value::Text("canceled".to_string())];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn control_characters_of_the_clauses_should_be_rendered_as_is() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("x = '\u{1}\u{2}'")
+      .where_clause("y = '\u{5}abc\u{6}'")
+      .as_string();
+    let expected_query = "SELECT id FROM users WHERE x = '\u{1}\u{2}' AND y = '\u{5}abc\u{6}'";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn text_of_the_clauses_should_never_be_rendered_as_a_bound_value() {
+    let (query, values) = sql::Select::new()
+      .select("id")
+      .from("t")
+      .where_clause("x = '\u{1}i1; DROP TABLE t\u{2}'")
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "SELECT id FROM t WHERE x = '\u{1}i1; DROP TABLE t\u{2}'";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(Vec::<sql::Value>::new(), values);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_placeholders {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn placeholders_should_be_numbered_in_the_order_they_appear() {
+    let (query, values) = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .where_or_bind("login = ?", "bar")
+      .as_string_with_binds();
+    let expected_query = "SELECT id FROM users WHERE login = $1 OR login = $2";
+    let expected_values = vec![sql::Value::from("foo"), sql::Value::from("bar")];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn placeholders_of_the_with_queries_should_be_numbered_with_the_outer_query() {
+    let active_users = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("status = ?", "active");
+    let (query, values) = sql::Select::new()
+      .with("active_users", active_users)
+      .select("*")
+      .from("orders")
+      .inner_join("active_users on active_users.id = orders.user_id")
+      .where_clause_bind("orders.total > ?", 100)
+      .as_string_with_binds();
+    let expected_query = "\
+      WITH active_users AS (SELECT id FROM users WHERE status = $1) \
+      SELECT * \
+      FROM orders \
+      INNER JOIN active_users on active_users.id = orders.user_id \
+      WHERE orders.total > $2\
+    ";
+    let expected_values = vec![sql::Value::from("active"), sql::Value::from(100)];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn placeholders_of_the_union_should_be_numbered_with_the_outer_query() {
+    let (query, values) = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause_bind("id = ?", 1)
+      .union(
+        sql::Select::new()
+          .select("login")
+          .from("admins")
+          .where_clause_bind("id = ?", 2),
+      )
+      .as_string_with_binds();
+    let expected_query = "\
+      (SELECT login FROM users WHERE id = $1) \
+      UNION \
+      (SELECT login FROM admins WHERE id = $2)\
+    ";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::from(1), sql::Value::from(2)], values);
+  }
+
+  #[test]
+  fn jsonb_operators_should_be_kept_with_the_numbered_placeholders() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("tags ?? 'a' and tags ?| array['b'] and id = ?", 1)
+      .to_sql_with_binds(sql::Dialect::Postgresql);
+    let expected_query = "WHERE tags ? 'a' and tags ?| array['b'] and id = $1";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::Int(1)], values);
+  }
+
+  #[test]
+  fn method_to_sql_with_binds_should_render_the_placeholders_of_the_dialect() {
+    let delete = sql::Delete::new().delete_from("users").where_clause_bind("id = ?", 42);
+
+    assert_eq!(
+      "DELETE FROM users WHERE id = $1",
+      delete.to_sql_with_binds(sql::Dialect::Postgresql).0
+    );
+    assert_eq!(
+      "DELETE FROM users WHERE id = ?",
+      delete.to_sql_with_binds(sql::Dialect::Standard).0
+    );
+  }
+}
+
+mod sub_query_placeholders {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn placeholders_of_the_transaction_commands_should_be_renumbered() {
+    let transaction = sql::Transaction::new()
+      .select(sql::Select::new().select("login").from("users").where_clause("id = $1"))
+      .select(
+        sql::Select::new()
+          .select("name")
+          .from("orders")
+          .where_clause("user_id = $1")
+          .where_clause("status = $2"),
+      );
+    let expected_query = "\
+      SELECT login FROM users WHERE id = $1; \
+      SELECT name FROM orders WHERE user_id = $2 AND status = $3;\
+    ";
+
+    assert_eq!(expected_query, transaction.as_string());
+    assert_eq!(3, transaction.param_count());
+  }
+
+  #[test]
+  fn placeholders_of_the_insert_select_should_be_numbered_after_the_placeholders_of_the_insert() {
+    let insert = sql::Insert::new()
+      .insert_into("users_bkp (login)")
+      .select(sql::Select::new().select("login").from("users").where_clause("id = $1"))
+      .raw_after(sql::InsertClause::Select, "where login <> $1");
+    let expected_query = "INSERT INTO users_bkp (login) SELECT login FROM users WHERE id = $2 where login <> $1";
+
+    assert_eq!(expected_query, insert.as_string());
+    assert_eq!(2, insert.param_count());
+  }
+
+  #[test]
+  fn placeholders_inside_quoted_strings_and_identifiers_should_not_be_renumbered() {
+    let transaction = sql::Transaction::new()
+      .select(sql::Select::new().select("login").from("users").where_clause("id = $1"))
+      .select(
+        sql::Select::new()
+          .select("\"price$1\"")
+          .from("orders")
+          .where_clause("note = 'costs $1'")
+          .where_clause("id = $1"),
+      );
+    let expected_query = "\
+      SELECT login FROM users WHERE id = $1; \
+      SELECT \"price$1\" FROM orders WHERE note = 'costs $1' AND id = $2;\
+    ";
+
+    assert_eq!(expected_query, transaction.as_string());
+  }
+
+  #[test]
+  fn method_param_count_should_return_zero_when_the_query_has_no_parameters() {
+    let select = sql::Select::new().select("login").from("users");
+
+    assert_eq!(0, select.param_count());
+  }
+
+  #[test]
+  fn method_param_count_should_count_the_placeholders_and_the_bound_values() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("login = $1")
+      .where_clause_bind("id = ?", 42);
+
+    assert_eq!(2, update.param_count());
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+mod composed_query_placeholders {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn placeholders_of_the_with_queries_should_be_numbered_after_the_placeholders_of_the_outer_query() {
+    let select = sql::Select::new()
+      .with(
+        "active_users",
+        sql::Select::new()
+          .select("id")
+          .from("users")
+          .where_clause("status = $1")
+          .where_clause("created_at > $2"),
+      )
+      .select("*")
+      .from("orders")
+      .where_clause("total > $1");
+    let expected_query = "\
+      WITH active_users AS (SELECT id FROM users WHERE status = $2 AND created_at > $3) \
+      SELECT * \
+      FROM orders \
+      WHERE total > $1\
+    ";
+
+    assert_eq!(expected_query, select.as_string());
+    assert_eq!(3, select.param_count());
+  }
+
+  #[test]
+  fn placeholders_of_each_with_query_should_continue_the_numbering_of_the_previous_one() {
+    let select = sql::Select::new()
+      .with(
+        "a",
+        sql::Select::new().select("id").from("users").where_clause("id = $1"),
+      )
+      .with(
+        "b",
+        sql::Select::new().select("id").from("orders").where_clause("id = $1"),
+      )
+      .select("*")
+      .from("a, b");
+    let expected_query = "\
+      WITH \
+        a AS (SELECT id FROM users WHERE id = $1), \
+        b AS (SELECT id FROM orders WHERE id = $2) \
+      SELECT * \
+      FROM a, b\
+    ";
+
+    assert_eq!(expected_query, select.as_string());
+  }
+
+  #[test]
+  fn placeholders_of_the_union_should_be_numbered_after_the_placeholders_of_the_outer_query() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("id = $1")
+      .union(
+        sql::Select::new()
+          .select("login")
+          .from("admins")
+          .where_clause("id = $1"),
+      );
+    let expected_query = "\
+      (SELECT login FROM users WHERE id = $1) \
+      UNION \
+      (SELECT login FROM admins WHERE id = $2)\
+    ";
+
+    assert_eq!(expected_query, select.as_string());
+    assert_eq!(2, select.param_count());
+  }
+
+  #[test]
+  fn placeholders_of_nested_queries_should_be_renumbered_at_each_level() {
+    let union = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("id = $1")
+      .union(sql::Select::new().select("id").from("admins").where_clause("id = $1"));
+    let select = sql::Select::new()
+      .with("ids", union)
+      .select("*")
+      .from("ids")
+      .where_clause("id <> $1");
+    let expected_query = "\
+      WITH ids AS (\
+        (SELECT id FROM users WHERE id = $2) \
+        UNION \
+        (SELECT id FROM admins WHERE id = $3)\
+      ) \
+      SELECT * \
+      FROM ids \
+      WHERE id <> $1\
+    ";
+
+    assert_eq!(expected_query, select.as_string());
+    assert_eq!(3, select.param_count());
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_sub_query_placeholders {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn bound_values_should_be_numbered_after_the_placeholders_written_in_the_clauses() {
+    let (query, values) = sql::Select::new()
+      .with(
+        "a",
+        sql::Select::new().select("id").from("users").where_clause("id = $1"),
+      )
+      .select("*")
+      .from("a")
+      .where_clause_bind("status = ?", "active")
+      .where_clause("id <> $1")
+      .as_string_with_binds();
+    let expected_query = "\
+      WITH a AS (SELECT id FROM users WHERE id = $2) \
+      SELECT * \
+      FROM a \
+      WHERE status = $3 AND id <> $1\
+    ";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::from("active")], values);
+  }
+}
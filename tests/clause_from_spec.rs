@@ -5,7 +5,7 @@ mod delete_command {
 
   #[test]
   fn method_from_should_add_the_from_clause() {
-    let query = sql::Delete::new().from("users").as_string();
+    let query = sql::Delete::new().from("users").to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM users";
 
     assert_eq!(expected_query, query);
@@ -13,7 +13,10 @@ mod delete_command {
 
   #[test]
   fn method_from_should_accumulate_values_on_consecutive_calls() {
-    let query = sql::Delete::new().from("users").from("addresses").as_string();
+    let query = sql::Delete::new()
+      .from("users")
+      .from("addresses")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM users, addresses";
 
     assert_eq!(expected_query, query);
@@ -21,7 +24,11 @@ mod delete_command {
 
   #[test]
   fn method_from_should_not_accumulate_values_when_table_name_is_empty() {
-    let query = sql::Delete::new().from("").from("users").from("").as_string();
+    let query = sql::Delete::new()
+      .from("")
+      .from("users")
+      .from("")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM users";
 
     assert_eq!(expected_query, query);
@@ -29,7 +36,7 @@ mod delete_command {
 
   #[test]
   fn method_from_should_trim_space_of_the_argument() {
-    let query = sql::Delete::new().from("  users  ").as_string();
+    let query = sql::Delete::new().from("  users  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM users";
 
     assert_eq!(expected_query, query);
@@ -37,7 +44,10 @@ mod delete_command {
 
   #[test]
   fn method_from_should_not_accumulate_arguments_with_the_same_content() {
-    let query = sql::Delete::new().from("addresses").from("addresses").as_string();
+    let query = sql::Delete::new()
+      .from("addresses")
+      .from("addresses")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM addresses";
 
     assert_eq!(expected_query, query);
@@ -45,7 +55,10 @@ mod delete_command {
 
   #[test]
   fn clause_from_should_be_after_select_clause() {
-    let query = sql::Delete::new().delete("QUICK").from("users").as_string();
+    let query = sql::Delete::new()
+      .delete("QUICK")
+      .from("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE QUICK FROM users";
 
     assert_eq!(expected_query, query);
@@ -56,7 +69,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .raw_before(sql::DeleteClause::From, "DELETE QUICK")
       .from("orders")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE QUICK FROM orders";
 
     assert_eq!(expected_query, query);
@@ -67,7 +80,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .from("users")
       .raw_after(sql::DeleteClause::From, "WHERE id = '123'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM users WHERE id = '123'";
 
     assert_eq!(expected_query, query);
@@ -9,7 +9,7 @@ mod raw_methods {
       let query = sql::Delete::new()
         .inner_join("orders ON orders.user_id = user.id")
         .raw_after(sql::DeleteClause::Join, "WHERE user.id = $1")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "INNER JOIN orders ON orders.user_id = user.id WHERE user.id = $1";
 
       assert_eq!(expected_query, query);
@@ -20,7 +20,7 @@ mod raw_methods {
       let query = sql::Delete::new()
         .raw_before(sql::DeleteClause::Join, "FROM users")
         .inner_join("orders ON orders.user_id = user.id")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "FROM users INNER JOIN orders ON orders.user_id = user.id";
 
       assert_eq!(expected_query, query);
@@ -64,7 +64,7 @@ mod raw_methods {
       let query = sql::Update::new()
         .inner_join("orders ON orders.user_id = user.id")
         .raw_after(sql::UpdateClause::Join, "WHERE user.id = $1")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "INNER JOIN orders ON orders.user_id = user.id WHERE user.id = $1";
 
       assert_eq!(expected_query, query);
@@ -75,7 +75,7 @@ mod raw_methods {
       let query = sql::Update::new()
         .raw_before(sql::UpdateClause::Join, "FROM users")
         .inner_join("orders ON orders.user_id = user.id")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "FROM users INNER JOIN orders ON orders.user_id = user.id";
 
       assert_eq!(expected_query, query);
@@ -91,7 +91,7 @@ mod cross_join_clause {
 
     #[test]
     fn method_cross_join_should_add_the_cross_join_clause() {
-      let query = sql::Delete::new().cross_join("addresses").as_string();
+      let query = sql::Delete::new().cross_join("addresses").to_sql(sql::Dialect::Mysql);
       let expected_query = "CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -102,7 +102,7 @@ mod cross_join_clause {
       let query = sql::Delete::new()
         .cross_join("addresses")
         .cross_join("orders")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "\
         CROSS JOIN addresses \
         CROSS JOIN orders\
@@ -117,7 +117,7 @@ mod cross_join_clause {
         .cross_join("")
         .cross_join("orders")
         .cross_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "CROSS JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -125,7 +125,7 @@ mod cross_join_clause {
 
     #[test]
     fn method_cross_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Delete::new().cross_join("  orders  ").as_string();
+      let query = sql::Delete::new().cross_join("  orders  ").to_sql(sql::Dialect::Mysql);
       let expected_query = "CROSS JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -136,7 +136,7 @@ mod cross_join_clause {
       let query = sql::Delete::new()
         .cross_join("addresses")
         .cross_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -144,7 +144,10 @@ mod cross_join_clause {
 
     #[test]
     fn clause_cross_join_should_be_after_from_clause() {
-      let query = sql::Delete::new().from("users").cross_join("addresses").as_string();
+      let query = sql::Delete::new()
+        .from("users")
+        .cross_join("addresses")
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "FROM users CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -224,7 +227,7 @@ mod cross_join_clause {
 
     #[test]
     fn method_cross_join_should_add_the_cross_join_clause() {
-      let query = sql::Update::new().cross_join("addresses").as_string();
+      let query = sql::Update::new().cross_join("addresses").to_sql(sql::Dialect::Sqlite);
       let expected_query = "CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -235,7 +238,7 @@ mod cross_join_clause {
       let query = sql::Update::new()
         .cross_join("addresses")
         .cross_join("orders")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "\
         CROSS JOIN addresses \
         CROSS JOIN orders\
@@ -250,7 +253,7 @@ mod cross_join_clause {
         .cross_join("")
         .cross_join("orders")
         .cross_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "CROSS JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -258,7 +261,7 @@ mod cross_join_clause {
 
     #[test]
     fn method_cross_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().cross_join("  orders  ").as_string();
+      let query = sql::Update::new().cross_join("  orders  ").to_sql(sql::Dialect::Sqlite);
       let expected_query = "CROSS JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -269,7 +272,7 @@ mod cross_join_clause {
       let query = sql::Update::new()
         .cross_join("addresses")
         .cross_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -277,7 +280,10 @@ mod cross_join_clause {
 
     #[test]
     fn clause_cross_join_should_be_after_from_clause() {
-      let query = sql::Update::new().from("users").cross_join("addresses").as_string();
+      let query = sql::Update::new()
+        .from("users")
+        .cross_join("addresses")
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "FROM users CROSS JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -295,7 +301,7 @@ mod inner_join_clause {
     fn method_inner_join_should_add_the_inner_join_clause() {
       let query = sql::Delete::new()
         .inner_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "INNER JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -306,7 +312,7 @@ mod inner_join_clause {
       let query = sql::Delete::new()
         .inner_join("addresses ON users.login = addresses.login")
         .inner_join("orders ON users.login = orders.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "\
         INNER JOIN addresses ON users.login = addresses.login \
         INNER JOIN orders ON users.login = orders.login\
@@ -321,7 +327,7 @@ mod inner_join_clause {
         .inner_join("")
         .inner_join("orders ON users.login = orders.login")
         .inner_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "INNER JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
@@ -329,7 +335,7 @@ mod inner_join_clause {
 
     #[test]
     fn method_inner_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Delete::new().inner_join("  orders  ").as_string();
+      let query = sql::Delete::new().inner_join("  orders  ").to_sql(sql::Dialect::Mysql);
       let expected_query = "INNER JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -340,7 +346,7 @@ mod inner_join_clause {
       let query = sql::Delete::new()
         .inner_join("addresses")
         .inner_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "INNER JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -351,7 +357,7 @@ mod inner_join_clause {
       let query = sql::Delete::new()
         .from("users")
         .inner_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "FROM users INNER JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -438,7 +444,7 @@ mod inner_join_clause {
     fn method_inner_join_should_add_the_inner_join_clause() {
       let query = sql::Update::new()
         .inner_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "INNER JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -449,7 +455,7 @@ mod inner_join_clause {
       let query = sql::Update::new()
         .inner_join("addresses ON users.login = addresses.login")
         .inner_join("orders ON users.login = orders.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "\
         INNER JOIN addresses ON users.login = addresses.login \
         INNER JOIN orders ON users.login = orders.login\
@@ -464,7 +470,7 @@ mod inner_join_clause {
         .inner_join("")
         .inner_join("orders ON users.login = orders.login")
         .inner_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "INNER JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
@@ -472,7 +478,7 @@ mod inner_join_clause {
 
     #[test]
     fn method_inner_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().inner_join("  orders  ").as_string();
+      let query = sql::Update::new().inner_join("  orders  ").to_sql(sql::Dialect::Sqlite);
       let expected_query = "INNER JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -483,7 +489,7 @@ mod inner_join_clause {
       let query = sql::Update::new()
         .inner_join("addresses")
         .inner_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "INNER JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -494,7 +500,7 @@ mod inner_join_clause {
       let query = sql::Update::new()
         .from("users")
         .inner_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "FROM users INNER JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -512,7 +518,7 @@ mod left_join_clause {
     fn method_left_join_should_add_the_left_join_clause() {
       let query = sql::Delete::new()
         .left_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "LEFT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -523,7 +529,7 @@ mod left_join_clause {
       let query = sql::Delete::new()
         .left_join("addresses ON users.login = addresses.login")
         .left_join("orders ON users.login = orders.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "\
         LEFT JOIN addresses ON users.login = addresses.login \
         LEFT JOIN orders ON users.login = orders.login\
@@ -538,7 +544,7 @@ mod left_join_clause {
         .left_join("")
         .left_join("orders ON users.login = orders.login")
         .left_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "LEFT JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
@@ -546,7 +552,7 @@ mod left_join_clause {
 
     #[test]
     fn method_left_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Delete::new().left_join("  orders  ").as_string();
+      let query = sql::Delete::new().left_join("  orders  ").to_sql(sql::Dialect::Mysql);
       let expected_query = "LEFT JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -557,7 +563,7 @@ mod left_join_clause {
       let query = sql::Delete::new()
         .left_join("addresses")
         .left_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "LEFT JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -568,7 +574,7 @@ mod left_join_clause {
       let query = sql::Delete::new()
         .from("users")
         .left_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "FROM users LEFT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -655,7 +661,7 @@ mod left_join_clause {
     fn method_left_join_should_add_the_left_join_clause() {
       let query = sql::Update::new()
         .left_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "LEFT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -666,7 +672,7 @@ mod left_join_clause {
       let query = sql::Update::new()
         .left_join("addresses ON users.login = addresses.login")
         .left_join("orders ON users.login = orders.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "\
         LEFT JOIN addresses ON users.login = addresses.login \
         LEFT JOIN orders ON users.login = orders.login\
@@ -681,7 +687,7 @@ mod left_join_clause {
         .left_join("")
         .left_join("orders ON users.login = orders.login")
         .left_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "LEFT JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
@@ -689,7 +695,7 @@ mod left_join_clause {
 
     #[test]
     fn method_left_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().left_join("  orders  ").as_string();
+      let query = sql::Update::new().left_join("  orders  ").to_sql(sql::Dialect::Sqlite);
       let expected_query = "LEFT JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -700,7 +706,7 @@ mod left_join_clause {
       let query = sql::Update::new()
         .left_join("addresses")
         .left_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "LEFT JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -711,7 +717,7 @@ mod left_join_clause {
       let query = sql::Update::new()
         .from("users")
         .left_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "FROM users LEFT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -729,7 +735,7 @@ mod right_join_clause {
     fn method_right_join_should_add_the_right_join_clause() {
       let query = sql::Delete::new()
         .right_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "RIGHT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -740,7 +746,7 @@ mod right_join_clause {
       let query = sql::Delete::new()
         .right_join("addresses ON users.login = addresses.login")
         .right_join("orders ON users.login = orders.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "\
         RIGHT JOIN addresses ON users.login = addresses.login \
         RIGHT JOIN orders ON users.login = orders.login\
@@ -755,7 +761,7 @@ mod right_join_clause {
         .right_join("")
         .right_join("orders ON users.login = orders.login")
         .right_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "RIGHT JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
@@ -763,7 +769,7 @@ mod right_join_clause {
 
     #[test]
     fn method_right_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Delete::new().right_join("  orders  ").as_string();
+      let query = sql::Delete::new().right_join("  orders  ").to_sql(sql::Dialect::Mysql);
       let expected_query = "RIGHT JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -774,7 +780,7 @@ mod right_join_clause {
       let query = sql::Delete::new()
         .right_join("addresses")
         .right_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "RIGHT JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -785,7 +791,7 @@ mod right_join_clause {
       let query = sql::Delete::new()
         .from("users")
         .right_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "FROM users RIGHT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -872,7 +878,7 @@ mod right_join_clause {
     fn method_right_join_should_add_the_right_join_clause() {
       let query = sql::Update::new()
         .right_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "RIGHT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
@@ -883,7 +889,7 @@ mod right_join_clause {
       let query = sql::Update::new()
         .right_join("addresses ON users.login = addresses.login")
         .right_join("orders ON users.login = orders.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "\
         RIGHT JOIN addresses ON users.login = addresses.login \
         RIGHT JOIN orders ON users.login = orders.login\
@@ -898,7 +904,7 @@ mod right_join_clause {
         .right_join("")
         .right_join("orders ON users.login = orders.login")
         .right_join("")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "RIGHT JOIN orders ON users.login = orders.login";
 
       assert_eq!(expected_query, query);
@@ -906,7 +912,7 @@ mod right_join_clause {
 
     #[test]
     fn method_right_join_by_should_trim_space_of_the_argument() {
-      let query = sql::Update::new().right_join("  orders  ").as_string();
+      let query = sql::Update::new().right_join("  orders  ").to_sql(sql::Dialect::Sqlite);
       let expected_query = "RIGHT JOIN orders";
 
       assert_eq!(expected_query, query);
@@ -917,7 +923,7 @@ mod right_join_clause {
       let query = sql::Update::new()
         .right_join("addresses")
         .right_join("addresses")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "RIGHT JOIN addresses";
 
       assert_eq!(expected_query, query);
@@ -928,10 +934,369 @@ mod right_join_clause {
       let query = sql::Update::new()
         .from("users")
         .right_join("addresses ON users.login = addresses.login")
-        .as_string();
+        .to_sql(sql::Dialect::Sqlite);
       let expected_query = "FROM users RIGHT JOIN addresses ON users.login = addresses.login";
 
       assert_eq!(expected_query, query);
     }
   }
 }
+
+mod join_clause {
+  #[cfg(feature = "mysql")]
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_join_should_add_the_join_clause() {
+      let query = sql::Delete::new()
+        .delete("users")
+        .from("users")
+        .join(sql::Join::inner("orders").on("orders.user_id = users.id"))
+        .to_sql(sql::Dialect::Mysql);
+      let expected_query = "DELETE users FROM users INNER JOIN orders ON orders.user_id = users.id";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_join_should_accept_lateral_sub_queries() {
+      let query = sql::Delete::new()
+        .join(
+          sql::Join::left("last_order")
+            .sub_query(
+              sql::Select::new()
+                .select("id")
+                .from("orders")
+                .where_clause("orders.user_id = users.id"),
+            )
+            .lateral()
+            .on("true"),
+        )
+        .to_sql(sql::Dialect::Mysql);
+      let expected_query =
+        "LEFT JOIN LATERAL (SELECT id FROM orders WHERE orders.user_id = users.id) AS last_order ON true";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_join_should_add_the_join_clause() {
+      let query = sql::Select::new()
+        .from("users")
+        .join(sql::Join::left("addresses").on("addresses.user_id = users.id"))
+        .as_string();
+      let expected_query = "FROM users LEFT JOIN addresses ON addresses.user_id = users.id";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_join_should_keep_the_order_of_the_joins_added_by_the_other_methods() {
+      let query = sql::Select::new()
+        .from("users")
+        .inner_join("roles ON roles.id = users.role_id")
+        .join(sql::Join::cross("colors"))
+        .right_join("orders ON orders.user_id = users.id")
+        .as_string();
+      let expected_query = "\
+        FROM users \
+        INNER JOIN roles ON roles.id = users.role_id \
+        CROSS JOIN colors \
+        RIGHT JOIN orders ON orders.user_id = users.id\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_join_should_not_accumulate_joins_with_the_same_content() {
+      let query = sql::Select::new()
+        .inner_join("roles USING (role_id)")
+        .join(sql::Join::inner("roles").using("role_id"))
+        .join(sql::Join::inner("roles").using("role_id"))
+        .as_string();
+      let expected_query = "INNER JOIN roles USING (role_id)";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_join_should_trim_the_table_and_the_conditions() {
+      let query = sql::Select::new()
+        .join(sql::Join::inner("  roles  ").on("  roles.id = users.role_id  "))
+        .as_string();
+      let expected_query = "INNER JOIN roles ON roles.id = users.role_id";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_on_should_concatenate_the_conditions_using_the_and_and_or_operators() {
+      let join = sql::Join::left("orders")
+        .on("orders.user_id = users.id")
+        .on("orders.status = 'paid'")
+        .on_or("orders.guest = true")
+        .on("");
+      let expected_query = "\
+        LEFT JOIN orders \
+        ON orders.user_id = users.id \
+        AND orders.status = 'paid' \
+        OR orders.guest = true\
+      ";
+
+      assert_eq!(expected_query, join.as_string());
+    }
+
+    #[test]
+    fn method_on_should_accept_expressions_with_bound_values() {
+      let (query, values) = sql::Select::new()
+        .from("users")
+        .join(
+          sql::Join::inner("orders")
+            .on(sql::col("orders.user_id").eq(sql::col("users.id")))
+            .on(sql::col("orders.total").gt(100)),
+        )
+        .where_clause_bind("users.id = ?", 1)
+        .to_sql_with_binds(sql::Dialect::Standard);
+      let expected_query =
+        "FROM users INNER JOIN orders ON orders.user_id = users.id AND orders.total > ? WHERE users.id = ?";
+
+      assert_eq!(expected_query, query);
+      assert_eq!(vec![sql::Value::Int(100), sql::Value::Int(1)], values);
+    }
+
+    #[test]
+    fn method_using_should_accumulate_the_columns() {
+      let join = sql::Join::inner("orders")
+        .using("user_id")
+        .using("account_id")
+        .using("user_id");
+
+      assert_eq!("INNER JOIN orders USING (user_id, account_id)", join.as_string());
+    }
+
+    #[test]
+    fn method_on_should_not_discard_the_using_columns() {
+      let join = sql::Join::inner("orders")
+        .using("user_id")
+        .on("orders.user_id = users.id");
+
+      assert_eq!(
+        "INNER JOIN orders ON orders.user_id = users.id USING (user_id)",
+        join.as_string()
+      );
+    }
+
+    #[test]
+    fn method_natural_should_add_the_natural_modifier() {
+      let query = sql::Select::new()
+        .from("users")
+        .join(sql::Join::left("addresses").natural())
+        .as_string();
+      let expected_query = "FROM users NATURAL LEFT JOIN addresses";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_sub_query_should_join_the_sub_query_with_the_alias() {
+      let last_orders = sql::Select::new()
+        .select("user_id, max(created_at) as created_at")
+        .from("orders")
+        .group_by("user_id");
+      let query = sql::Select::new()
+        .select("users.login, last_orders.created_at")
+        .from("users")
+        .join(
+          sql::Join::inner("last_orders")
+            .sub_query(last_orders)
+            .on("last_orders.user_id = users.id"),
+        )
+        .as_string();
+      let expected_query = "\
+        SELECT users.login, last_orders.created_at \
+        FROM users \
+        INNER JOIN (\
+          SELECT user_id, max(created_at) as created_at \
+          FROM orders \
+          GROUP BY user_id\
+        ) AS last_orders ON last_orders.user_id = users.id\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_sub_query_should_accept_a_sub_query_shared_between_joins() {
+      let user_ids = std::sync::Arc::new(sql::Select::new().select("id").from("users"));
+      let query = sql::Select::new()
+        .from("orders")
+        .join(sql::Join::inner("buyers").sub_query(user_ids.clone()).using("id"))
+        .join(sql::Join::inner("sellers").sub_query(user_ids).using("id"))
+        .as_string();
+      let expected_query = "\
+        FROM orders \
+        INNER JOIN (SELECT id FROM users) AS buyers USING (id) \
+        INNER JOIN (SELECT id FROM users) AS sellers USING (id)\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_sub_query_should_keep_the_bound_values_of_the_sub_query() {
+      let (query, values) = sql::Select::new()
+        .from("users")
+        .join(
+          sql::Join::inner("paid")
+            .sub_query(
+              sql::Select::new()
+                .select("user_id")
+                .from("orders")
+                .where_clause_bind("status = ?", "paid"),
+            )
+            .using("user_id"),
+        )
+        .where_clause_bind("users.active = ?", true)
+        .to_sql_with_binds(sql::Dialect::Standard);
+      let expected_query = "\
+        FROM users \
+        INNER JOIN (SELECT user_id FROM orders WHERE status = ?) AS paid USING (user_id) \
+        WHERE users.active = ?\
+      ";
+
+      assert_eq!(expected_query, query);
+      assert_eq!(vec![sql::Value::from("paid"), sql::Value::Bool(true)], values);
+    }
+
+    #[test]
+    fn method_full_should_create_a_full_join() {
+      let query = sql::Select::new()
+        .from("users")
+        .join(sql::Join::full("addresses").on("addresses.user_id = users.id"))
+        .as_string();
+      let expected_query = "FROM users FULL JOIN addresses ON addresses.user_id = users.id";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_lateral_should_add_the_lateral_modifier() {
+      let query = sql::Select::new()
+        .from("users")
+        .join(
+          sql::Join::cross("last_order")
+            .sub_query(
+              sql::Select::new()
+                .select("id")
+                .from("orders")
+                .where_clause("orders.user_id = users.id"),
+            )
+            .lateral(),
+        )
+        .as_string();
+      let expected_query =
+        "FROM users CROSS JOIN LATERAL (SELECT id FROM orders WHERE orders.user_id = users.id) AS last_order";
+
+      assert_eq!(expected_query, query);
+    }
+
+    #[test]
+    fn method_full_should_be_rendered_by_the_dialect() {
+      let select = sql::Select::new()
+        .from("users")
+        .join(sql::Join::full("addresses").on("addresses.user_id = users.id"))
+        .where_clause("users.active = true");
+
+      let expected_query = "FROM users FULL JOIN addresses ON addresses.user_id = users.id WHERE users.active = true";
+      assert_eq!(expected_query, select.to_sql(sql::Dialect::Standard));
+
+      #[cfg(feature = "mysql")]
+      assert_eq!(expected_query, select.to_sql(sql::Dialect::Mysql));
+    }
+
+    #[test]
+    fn method_lateral_should_be_rendered_by_the_dialect() {
+      let select = sql::Select::new().from("users").join(
+        sql::Join::cross("last_order")
+          .sub_query(sql::Select::new().select("id").from("orders"))
+          .lateral(),
+      );
+
+      let expected_query = "FROM users CROSS JOIN LATERAL (SELECT id FROM orders) AS last_order";
+      assert_eq!(expected_query, select.to_sql(sql::Dialect::Standard));
+
+      #[cfg(feature = "sqlite")]
+      assert_eq!(expected_query, select.to_sql(sql::Dialect::Sqlite));
+    }
+
+    #[test]
+    fn method_full_should_not_be_repeated_when_the_join_is_the_same() {
+      let query = sql::Select::new()
+        .from("users")
+        .join(sql::Join::full("addresses").using("user_id"))
+        .join(sql::Join::full("orders").using("user_id"))
+        .join(sql::Join::full("orders").using("user_id"))
+        .to_sql(sql::Dialect::Standard);
+      let expected_query = "FROM users FULL JOIN addresses USING (user_id) FULL JOIN orders USING (user_id)";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(feature = "postgresql")]
+  mod postgres_select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn placeholders_of_the_sub_query_should_be_renumbered() {
+      let query = sql::Select::new()
+        .from("users")
+        .join(
+          sql::Join::inner("paid")
+            .sub_query(
+              sql::Select::new()
+                .select("user_id")
+                .from("orders")
+                .where_clause("status = $1"),
+            )
+            .using("user_id"),
+        )
+        .where_clause("users.id = $1")
+        .as_string();
+      let expected_query = "\
+        FROM users \
+        INNER JOIN (SELECT user_id FROM orders WHERE status = $2) AS paid USING (user_id) \
+        WHERE users.id = $1\
+      ";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+
+  #[cfg(feature = "sqlite")]
+  mod update_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_join_should_add_the_join_clause() {
+      let query = sql::Update::new()
+        .update("users")
+        .set("active = true")
+        .join(sql::Join::left("orders").using("user_id"))
+        .to_sql(sql::Dialect::Sqlite);
+      let expected_query = "UPDATE users SET active = true LEFT JOIN orders USING (user_id)";
+
+      assert_eq!(expected_query, query);
+    }
+  }
+}
@@ -65,7 +65,7 @@ mod delete_command {
 
   #[test]
   fn method_limit_should_add_the_limit_clause() {
-    let query = sql::Delete::new().limit("3").as_string();
+    let query = sql::Delete::new().limit("3").to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 3";
 
     assert_eq!(expected_query, query);
@@ -73,7 +73,7 @@ mod delete_command {
 
   #[test]
   fn method_limit_should_override_the_current_value() {
-    let query = sql::Delete::new().limit("3").limit("4").as_string();
+    let query = sql::Delete::new().limit("3").limit("4").to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 4";
 
     assert_eq!(expected_query, query);
@@ -81,7 +81,7 @@ mod delete_command {
 
   #[test]
   fn method_limit_should_trim_space_of_the_argument() {
-    let query = sql::Delete::new().limit("  50  ").as_string();
+    let query = sql::Delete::new().limit("  50  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 50";
 
     assert_eq!(expected_query, query);
@@ -89,7 +89,10 @@ mod delete_command {
 
   #[test]
   fn clause_limit_should_be_after_order_by_clause() {
-    let query = sql::Delete::new().order_by("created_at desc").limit("42").as_string();
+    let query = sql::Delete::new()
+      .order_by("created_at desc")
+      .limit("42")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ORDER BY created_at desc LIMIT 42";
 
     assert_eq!(expected_query, query);
@@ -100,7 +103,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .raw_before(sql::DeleteClause::Limit, "order by id")
       .limit("10")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "order by id LIMIT 10";
 
     assert_eq!(expected_query, query);
@@ -111,7 +114,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .limit("10")
       .raw_after(sql::DeleteClause::Limit, "/* uncommon argument */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 10 /* uncommon argument */";
 
     assert_eq!(expected_query, query);
@@ -125,7 +128,7 @@ mod update_command {
 
   #[test]
   fn method_limit_should_add_the_limit_clause() {
-    let query = sql::Update::new().limit("3").as_string();
+    let query = sql::Update::new().limit("3").to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 3";
 
     assert_eq!(expected_query, query);
@@ -133,7 +136,7 @@ mod update_command {
 
   #[test]
   fn method_limit_should_override_the_current_value() {
-    let query = sql::Update::new().limit("3").limit("4").as_string();
+    let query = sql::Update::new().limit("3").limit("4").to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 4";
 
     assert_eq!(expected_query, query);
@@ -141,7 +144,7 @@ mod update_command {
 
   #[test]
   fn method_limit_should_trim_space_of_the_argument() {
-    let query = sql::Update::new().limit("  50  ").as_string();
+    let query = sql::Update::new().limit("  50  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 50";
 
     assert_eq!(expected_query, query);
@@ -149,7 +152,10 @@ mod update_command {
 
   #[test]
   fn clause_limit_should_be_after_order_by_clause() {
-    let query = sql::Update::new().order_by("created_at desc").limit("42").as_string();
+    let query = sql::Update::new()
+      .order_by("created_at desc")
+      .limit("42")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ORDER BY created_at desc LIMIT 42";
 
     assert_eq!(expected_query, query);
@@ -160,7 +166,7 @@ mod update_command {
     let query = sql::Update::new()
       .raw_before(sql::UpdateClause::Limit, "order by id")
       .limit("10")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "order by id LIMIT 10";
 
     assert_eq!(expected_query, query);
@@ -171,7 +177,7 @@ mod update_command {
     let query = sql::Update::new()
       .limit("10")
       .raw_after(sql::UpdateClause::Limit, "/* uncommon argument */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "LIMIT 10 /* uncommon argument */";
 
     assert_eq!(expected_query, query);
@@ -96,9 +96,14 @@ mod delete_command {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[cfg(feature = "sqlite")]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Sqlite;
+  #[cfg(all(feature = "mysql", not(feature = "sqlite")))]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Mysql;
+
   #[test]
   fn method_order_by_should_add_the_order_by_clause() {
-    let query = sql::Delete::new().order_by("id asc").as_string();
+    let query = sql::Delete::new().order_by("id asc").to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id asc";
 
     assert_eq!(expected_query, query);
@@ -109,7 +114,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .order_by("login asc")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY login asc, created_at desc";
 
     assert_eq!(expected_query, query);
@@ -121,7 +126,7 @@ mod delete_command {
       .order_by("")
       .order_by("created_at desc")
       .order_by("")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -129,7 +134,7 @@ mod delete_command {
 
   #[test]
   fn method_order_by_should_trim_space_of_the_argument() {
-    let query = sql::Delete::new().order_by("  id desc  ").as_string();
+    let query = sql::Delete::new().order_by("  id desc  ").to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id desc";
 
     assert_eq!(expected_query, query);
@@ -137,7 +142,10 @@ mod delete_command {
 
   #[test]
   fn method_order_by_should_not_accumulate_arguments_with_the_same_content() {
-    let query = sql::Delete::new().order_by("id desc").order_by("id desc").as_string();
+    let query = sql::Delete::new()
+      .order_by("id desc")
+      .order_by("id desc")
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id desc";
 
     assert_eq!(expected_query, query);
@@ -148,7 +156,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .where_clause("active = true")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "WHERE active = true ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -159,7 +167,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .delete_from("foo")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "DELETE FROM foo ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -170,7 +178,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .raw_before(sql::DeleteClause::OrderBy, "where user_login = $1")
       .order_by("id desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "where user_login = $1 ORDER BY id desc";
 
     assert_eq!(expected_query, query);
@@ -181,7 +189,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .order_by("id desc")
       .raw_after(sql::DeleteClause::OrderBy, "limit 20")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id desc limit 20";
 
     assert_eq!(expected_query, query);
@@ -198,7 +206,7 @@ mod delete_command_sqlite {
     let query = sql::Delete::new()
       .returning("*")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "RETURNING * ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -210,9 +218,14 @@ mod update_command {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[cfg(feature = "sqlite")]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Sqlite;
+  #[cfg(all(feature = "mysql", not(feature = "sqlite")))]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Mysql;
+
   #[test]
   fn method_order_by_should_add_the_order_by_clause() {
-    let query = sql::Update::new().order_by("id asc").as_string();
+    let query = sql::Update::new().order_by("id asc").to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id asc";
 
     assert_eq!(expected_query, query);
@@ -223,7 +236,7 @@ mod update_command {
     let query = sql::Update::new()
       .order_by("login asc")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY login asc, created_at desc";
 
     assert_eq!(expected_query, query);
@@ -235,7 +248,7 @@ mod update_command {
       .order_by("")
       .order_by("created_at desc")
       .order_by("")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -243,7 +256,7 @@ mod update_command {
 
   #[test]
   fn method_order_by_should_trim_space_of_the_argument() {
-    let query = sql::Update::new().order_by("  id desc  ").as_string();
+    let query = sql::Update::new().order_by("  id desc  ").to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id desc";
 
     assert_eq!(expected_query, query);
@@ -251,7 +264,10 @@ mod update_command {
 
   #[test]
   fn method_order_by_should_not_accumulate_arguments_with_the_same_content() {
-    let query = sql::Update::new().order_by("id desc").order_by("id desc").as_string();
+    let query = sql::Update::new()
+      .order_by("id desc")
+      .order_by("id desc")
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id desc";
 
     assert_eq!(expected_query, query);
@@ -262,7 +278,7 @@ mod update_command {
     let query = sql::Update::new()
       .where_clause("active = true")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "WHERE active = true ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -270,7 +286,10 @@ mod update_command {
 
   #[test]
   fn clause_order_by_should_be_after_update_clause() {
-    let query = sql::Update::new().update("foo").order_by("created_at desc").as_string();
+    let query = sql::Update::new()
+      .update("foo")
+      .order_by("created_at desc")
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "UPDATE foo ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -281,7 +300,7 @@ mod update_command {
     let query = sql::Update::new()
       .raw_before(sql::UpdateClause::OrderBy, "where user_login = $1")
       .order_by("id desc")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "where user_login = $1 ORDER BY id desc";
 
     assert_eq!(expected_query, query);
@@ -292,7 +311,7 @@ mod update_command {
     let query = sql::Update::new()
       .order_by("id desc")
       .raw_after(sql::UpdateClause::OrderBy, "limit 20")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
     let expected_query = "ORDER BY id desc limit 20";
 
     assert_eq!(expected_query, query);
@@ -309,7 +328,7 @@ mod update_command_sqlite {
     let query = sql::Update::new()
       .returning("*")
       .order_by("created_at desc")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "RETURNING * ORDER BY created_at desc";
 
     assert_eq!(expected_query, query);
@@ -5,7 +5,7 @@ mod delete_command {
 
   #[test]
   fn method_partition_should_define_the_partition_clause() {
-    let query = sql::Delete::new().partition("p0").as_string();
+    let query = sql::Delete::new().partition("p0").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -21,7 +21,10 @@ mod delete_command {
 
   #[test]
   fn method_partition_should_accumulate_names_on_consecutive_calls() {
-    let query = sql::Delete::new().partition("p0").partition("p1").as_string();
+    let query = sql::Delete::new()
+      .partition("p0")
+      .partition("p1")
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0, p1)";
 
@@ -34,7 +37,7 @@ mod delete_command {
       .partition("")
       .partition("p0")
       .partition("")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0)";
 
@@ -43,7 +46,10 @@ mod delete_command {
 
   #[test]
   fn method_partition_should_not_accumulate_names_with_the_same_content() {
-    let query = sql::Delete::new().partition("p0").partition("p0").as_string();
+    let query = sql::Delete::new()
+      .partition("p0")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -51,7 +57,7 @@ mod delete_command {
 
   #[test]
   fn method_partition_should_trim_space_of_the_argument() {
-    let query = sql::Delete::new().partition("  p0  ").as_string();
+    let query = sql::Delete::new().partition("  p0  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -59,7 +65,10 @@ mod delete_command {
 
   #[test]
   fn method_partition_should_be_defined_after_delete_from_clause() {
-    let query = sql::Delete::new().delete_from("employees").partition("p0").as_string();
+    let query = sql::Delete::new()
+      .delete_from("employees")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE FROM employees PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -67,7 +76,10 @@ mod delete_command {
 
   #[test]
   fn method_partition_should_be_defined_after_from_clause() {
-    let query = sql::Delete::new().from("employees").partition("p0").as_string();
+    let query = sql::Delete::new()
+      .from("employees")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM employees PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -79,7 +91,7 @@ mod delete_command {
       .delete_from("employees")
       .inner_join("addresses ON employees.login = addresses.login")
       .partition("p0")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       DELETE FROM employees \
@@ -95,7 +107,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .partition("name")
       .raw_after(sql::DeleteClause::Partition, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (name) /* uncommon parameter */";
 
@@ -107,7 +119,7 @@ mod delete_command {
     let query = sql::Delete::new()
       .raw_before(sql::DeleteClause::Partition, "/* uncommon parameter */")
       .partition("name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "/* uncommon parameter */ PARTITION (name)";
 
@@ -122,7 +134,7 @@ mod insert_command {
 
   #[test]
   fn method_partition_should_define_the_partition_clause() {
-    let query = sql::Insert::new().partition("p0").as_string();
+    let query = sql::Insert::new().partition("p0").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -138,7 +150,10 @@ mod insert_command {
 
   #[test]
   fn method_partition_should_accumulate_names_on_consecutive_calls() {
-    let query = sql::Insert::new().partition("p0").partition("p1").as_string();
+    let query = sql::Insert::new()
+      .partition("p0")
+      .partition("p1")
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0, p1)";
 
@@ -151,7 +166,7 @@ mod insert_command {
       .partition("")
       .partition("p0")
       .partition("")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0)";
 
@@ -160,7 +175,10 @@ mod insert_command {
 
   #[test]
   fn method_partition_should_not_accumulate_names_with_the_same_content() {
-    let query = sql::Insert::new().partition("p0").partition("p0").as_string();
+    let query = sql::Insert::new()
+      .partition("p0")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -168,7 +186,7 @@ mod insert_command {
 
   #[test]
   fn method_partition_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().partition("  p0  ").as_string();
+    let query = sql::Insert::new().partition("  p0  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -176,7 +194,10 @@ mod insert_command {
 
   #[test]
   fn method_partition_should_be_defined_after_insert_clause() {
-    let query = sql::Insert::new().insert("employees").partition("p0").as_string();
+    let query = sql::Insert::new()
+      .insert("employees")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT employees PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -184,7 +205,10 @@ mod insert_command {
 
   #[test]
   fn method_partition_should_be_defined_after_into_clause() {
-    let query = sql::Insert::new().into("employees").partition("p0").as_string();
+    let query = sql::Insert::new()
+      .into("employees")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO employees PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -195,7 +219,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .partition("name")
       .raw_after(sql::InsertClause::Partition, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (name) /* uncommon parameter */";
 
@@ -207,7 +231,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::Partition, "/* uncommon parameter */")
       .partition("name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "/* uncommon parameter */ PARTITION (name)";
 
@@ -222,7 +246,7 @@ mod select_command {
 
   #[test]
   fn method_partition_should_define_the_partition_clause() {
-    let query = sql::Select::new().partition("p0").as_string();
+    let query = sql::Select::new().partition("p0").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -238,7 +262,10 @@ mod select_command {
 
   #[test]
   fn method_partition_should_accumulate_names_on_consecutive_calls() {
-    let query = sql::Select::new().partition("p0").partition("p1").as_string();
+    let query = sql::Select::new()
+      .partition("p0")
+      .partition("p1")
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0, p1)";
 
@@ -251,7 +278,7 @@ mod select_command {
       .partition("")
       .partition("p0")
       .partition("")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0)";
 
@@ -260,7 +287,10 @@ mod select_command {
 
   #[test]
   fn method_partition_should_not_accumulate_names_with_the_same_content() {
-    let query = sql::Select::new().partition("p0").partition("p0").as_string();
+    let query = sql::Select::new()
+      .partition("p0")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -268,7 +298,7 @@ mod select_command {
 
   #[test]
   fn method_partition_should_trim_space_of_the_argument() {
-    let query = sql::Select::new().partition("  p0  ").as_string();
+    let query = sql::Select::new().partition("  p0  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -276,7 +306,10 @@ mod select_command {
 
   #[test]
   fn method_partition_should_be_defined_after_from_clause() {
-    let query = sql::Select::new().from("employees").partition("p0").as_string();
+    let query = sql::Select::new()
+      .from("employees")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM employees PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -288,7 +321,7 @@ mod select_command {
       .from("employees")
       .inner_join("addresses ON employees.login = addresses.login")
       .partition("p0")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       FROM employees \
@@ -304,7 +337,7 @@ mod select_command {
     let query = sql::Select::new()
       .partition("name")
       .raw_after(sql::SelectClause::Partition, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (name) /* uncommon parameter */";
 
@@ -316,7 +349,7 @@ mod select_command {
     let query = sql::Select::new()
       .raw_before(sql::SelectClause::Partition, "/* uncommon parameter */")
       .partition("name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "/* uncommon parameter */ PARTITION (name)";
 
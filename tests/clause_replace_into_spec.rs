@@ -5,7 +5,9 @@ mod insert_command {
 
   #[test]
   fn method_replace_into_should_add_a_replace_into_clause() {
-    let query = sql::Insert::new().replace_into("users (login, name)").as_string();
+    let query = sql::Insert::new()
+      .replace_into("users (login, name)")
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "REPLACE INTO users (login, name)";
 
     assert_eq!(query, expected_query);
@@ -16,7 +18,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .replace_into("users (login, name)")
       .replace_into("orders (product_name, price)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "REPLACE INTO orders (product_name, price)";
 
     assert_eq!(query, expected_query);
@@ -32,7 +34,9 @@ mod insert_command {
 
   #[test]
   fn method_replace_into_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().replace_into("  users (name)  ").as_string();
+    let query = sql::Insert::new()
+      .replace_into("  users (name)  ")
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "REPLACE INTO users (name)";
 
     assert_eq!(query, expected_query);
@@ -43,7 +47,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::ReplaceInto, "/* replace into users */")
       .replace_into("users")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "/* replace into users */ REPLACE INTO users";
 
     assert_eq!(query, expected_query);
@@ -54,7 +58,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .replace_into("users (name)")
       .raw_after(sql::InsertClause::ReplaceInto, "values ('foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "REPLACE INTO users (name) values ('foo')";
 
     assert_eq!(query, expected_query);
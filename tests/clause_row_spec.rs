@@ -5,7 +5,7 @@ mod insert_command {
 
   #[test]
   fn method_row_should_add_a_row_clause() {
-    let query = sql::Insert::new().row("('foo', 'Foo')").as_string();
+    let query = sql::Insert::new().row("('foo', 'Foo')").to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -16,7 +16,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .row("('foo', 'Foo')")
       .row("('bar', 'Bar')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -24,7 +24,11 @@ mod insert_command {
 
   #[test]
   fn method_row_should_not_accumulate_row_when_expression_is_empty() {
-    let query = sql::Insert::new().row("").row("('bar', 'Bar')").row("").as_string();
+    let query = sql::Insert::new()
+      .row("")
+      .row("('bar', 'Bar')")
+      .row("")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -40,7 +44,7 @@ mod insert_command {
 
   #[test]
   fn method_row_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().row("   ('Bar')  ").as_string();
+    let query = sql::Insert::new().row("   ('Bar')  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('Bar')";
 
     assert_eq!(expected_query, query);
@@ -51,7 +55,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .row("('bar', 'Bar')")
       .row("('bar', 'Bar')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -62,7 +66,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::Values, "insert into users (login, name)")
       .row("('foo', 'Foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "insert into users (login, name) VALUES ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -73,7 +77,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .row("('baz', 'Baz')")
       .raw_after(sql::InsertClause::Values, ", ROW('foo', 'Foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('baz', 'Baz') , ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -84,7 +88,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .row("('bar', 'Bar')")
       .insert_into("users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT INTO users (login, name) VALUES ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -98,7 +102,7 @@ mod values_command {
 
   #[test]
   fn method_row_should_add_a_values_statement_with_the_row_constructor_clause() {
-    let query = sql::Values::new().row("('foo', 'Foo')").as_string();
+    let query = sql::Values::new().row("('foo', 'Foo')").to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -109,7 +113,7 @@ mod values_command {
     let query = sql::Values::new()
       .row("('foo', 'Foo')")
       .row("('bar', 'Bar')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -117,7 +121,11 @@ mod values_command {
 
   #[test]
   fn method_row_should_not_accumulate_row_when_expression_is_empty() {
-    let query = sql::Values::new().row("").row("('foo', 'Foo')").row("").as_string();
+    let query = sql::Values::new()
+      .row("")
+      .row("('foo', 'Foo')")
+      .row("")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -133,7 +141,7 @@ mod values_command {
 
   #[test]
   fn method_row_should_trim_space_of_the_argument() {
-    let query = sql::Values::new().row("   ('Bar')  ").as_string();
+    let query = sql::Values::new().row("   ('Bar')  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('Bar')";
 
     assert_eq!(expected_query, query);
@@ -144,7 +152,7 @@ mod values_command {
     let query = sql::Values::new()
       .row("('bar', 'Bar')")
       .row("('bar', 'Bar')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -156,7 +164,7 @@ mod values_command {
       .row("('foo', 'Foo')")
       .row("('bar', 'Bar')")
       .raw_before(sql::ValuesClause::Values, "insert into users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "\
       insert into users (login, name) \
       VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')\
@@ -171,7 +179,7 @@ mod values_command {
       .row("('foo', 'Foo')")
       .row("('bar', 'Bar')")
       .raw_after(sql::ValuesClause::Values, ", ROW('ros', 'Ros')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar') , ROW('ros', 'Ros')";
 
     assert_eq!(expected_query, query);
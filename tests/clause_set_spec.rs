@@ -80,7 +80,7 @@ mod insert_command {
 
   #[test]
   fn method_set_should_add_a_set_clause() {
-    let query = sql::Insert::new().set("login = 'Foo'").as_string();
+    let query = sql::Insert::new().set("login = 'Foo'").to_sql(sql::Dialect::Mysql);
     let expected_query = "SET login = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -88,7 +88,10 @@ mod insert_command {
 
   #[test]
   fn method_set_should_accumulate_values_on_consecutive_calls() {
-    let query = sql::Insert::new().set("login = 'foo'").set("name = 'Foo'").as_string();
+    let query = sql::Insert::new()
+      .set("login = 'foo'")
+      .set("name = 'Foo'")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "SET login = 'foo', name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -96,7 +99,11 @@ mod insert_command {
 
   #[test]
   fn method_set_should_not_accumulate_values_when_expression_is_empty() {
-    let query = sql::Insert::new().set("").set("name = 'Foo'").set("").as_string();
+    let query = sql::Insert::new()
+      .set("")
+      .set("name = 'Foo'")
+      .set("")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "SET name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -104,7 +111,7 @@ mod insert_command {
 
   #[test]
   fn method_set_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().set("  name = 'Bar'  ").as_string();
+    let query = sql::Insert::new().set("  name = 'Bar'  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "SET name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -112,7 +119,10 @@ mod insert_command {
 
   #[test]
   fn method_set_should_not_accumulate_arguments_with_the_same_content() {
-    let query = sql::Insert::new().set("name = 'Bar'").set("name = 'Bar'").as_string();
+    let query = sql::Insert::new()
+      .set("name = 'Bar'")
+      .set("name = 'Bar'")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "SET name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -120,7 +130,10 @@ mod insert_command {
 
   #[test]
   fn clause_set_should_be_after_into_clause() {
-    let query = sql::Insert::new().set("name = 'Bar'").into("users").as_string();
+    let query = sql::Insert::new()
+      .set("name = 'Bar'")
+      .into("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO users SET name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -128,7 +141,10 @@ mod insert_command {
 
   #[test]
   fn clause_set_should_be_after_partition_clause() {
-    let query = sql::Insert::new().set("name = 'Bar'").partition("p1").as_string();
+    let query = sql::Insert::new()
+      .set("name = 'Bar'")
+      .partition("p1")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p1) SET name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -139,7 +155,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::Set, "insert into users")
       .set("login = 'Bar'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "insert into users SET login = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -150,7 +166,7 @@ mod insert_command {
     let query = sql::Insert::new()
       .set("name = 'Bar'")
       .raw_after(sql::InsertClause::Set, ", login = 'bar'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "SET name = 'Bar' , login = 'bar'";
 
     assert_eq!(expected_query, query);
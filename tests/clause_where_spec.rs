@@ -875,3 +875,190 @@ mod where_or {
     }
   }
 }
+
+mod where_group {
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  mod create_index_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_group_should_add_the_conditions_enclosed_in_parentheses() {
+      let query = sql::CreateIndex::new()
+        .where_clause("status = 'active'")
+        .where_group(|group| group.where_clause("age > 18").where_or("vip = true"))
+        .as_string();
+      let expected_query = "WHERE status = 'active' AND (age > 18 OR vip = true)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_or_group_should_concatenate_the_group_using_the_or_operator() {
+      let query = sql::CreateIndex::new()
+        .where_clause("status = 'active'")
+        .where_or_group(|group| group.where_clause("age > 18").where_clause("vip = true"))
+        .as_string();
+      let expected_query = "WHERE status = 'active' OR (age > 18 AND vip = true)";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod delete_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_group_should_add_the_conditions_enclosed_in_parentheses() {
+      let query = sql::Delete::new()
+        .where_clause("a = 1")
+        .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+        .as_string();
+      let expected_query = "WHERE a = 1 AND (b = 2 OR c = 3)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_or_group_should_concatenate_the_group_using_the_or_operator() {
+      let query = sql::Delete::new()
+        .where_clause("a = 1")
+        .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+        .as_string();
+      let expected_query = "WHERE a = 1 OR (b = 2 AND c = 3)";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod select_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_group_should_add_the_conditions_enclosed_in_parentheses() {
+      let query = sql::Select::new()
+        .where_clause("a = 1")
+        .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+        .as_string();
+      let expected_query = "WHERE a = 1 AND (b = 2 OR c = 3)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_omit_the_operation_when_was_the_first_clause() {
+      let query = sql::Select::new()
+        .where_or_group(|group| group.where_clause("a = 1").where_or("b = 2"))
+        .where_clause("c = 3")
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR b = 2) AND c = 3";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_compose_nested_groups() {
+      let query = sql::Select::new()
+        .where_clause("a = 1")
+        .where_group(|group| {
+          group.where_clause("b = 2").where_or_group(|group| {
+            group
+              .where_clause("c = 3")
+              .where_group(|group| group.where_clause("d = 4").where_or("e = 5"))
+          })
+        })
+        .as_string();
+      let expected_query = "WHERE a = 1 AND (b = 2 OR (c = 3 AND (d = 4 OR e = 5)))";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_not_add_the_group_without_conditions() {
+      let query = sql::Select::new()
+        .where_clause("a = 1")
+        .where_group(|group| group)
+        .where_or_group(|group| group.where_clause("").where_or("  "))
+        .as_string();
+      let expected_query = "WHERE a = 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_trim_the_conditions() {
+      let query = sql::Select::new()
+        .where_group(|group| group.where_clause("  a = 1  ").where_or("  b = 2  "))
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR b = 2)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_not_accumulate_conditions_with_the_same_content() {
+      let query = sql::Select::new()
+        .where_group(|group| group.where_clause("a = 1").where_clause("a = 1").where_or("b = 2"))
+        .where_group(|group| group.where_clause("a = 1").where_or("b = 2"))
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR b = 2)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_group_should_keep_the_bound_values_in_the_order_of_the_placeholders() {
+      let (query, values) = sql::Select::new()
+        .where_clause_bind("a = ?", 1)
+        .where_group(|group| group.where_clause_bind("b = ?", 2).where_or_bind("c = ?", 3))
+        .to_sql_with_binds(sql::Dialect::Standard);
+      let expected_query = "WHERE a = ? AND (b = ? OR c = ?)";
+
+      assert_eq!(query, expected_query);
+      assert_eq!(values, vec![sql::Value::Int(1), sql::Value::Int(2), sql::Value::Int(3)]);
+    }
+
+    #[test]
+    fn method_where_group_should_accept_expressions() {
+      let query = sql::Select::new()
+        .where_group(|group| {
+          group
+            .where_clause(sql::col("a").eq(sql::col("1")))
+            .where_or(sql::col("b").eq(sql::col("2")).or(sql::col("c").eq(sql::col("3"))))
+        })
+        .as_string();
+      let expected_query = "WHERE (a = 1 OR (b = 2 OR c = 3))";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_command {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_where_group_should_add_the_conditions_enclosed_in_parentheses() {
+      let query = sql::Update::new()
+        .where_clause("a = 1")
+        .where_group(|group| group.where_clause("b = 2").where_or("c = 3"))
+        .as_string();
+      let expected_query = "WHERE a = 1 AND (b = 2 OR c = 3)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_where_or_group_should_concatenate_the_group_using_the_or_operator() {
+      let query = sql::Update::new()
+        .where_clause("a = 1")
+        .where_or_group(|group| group.where_clause("b = 2").where_clause("c = 3"))
+        .as_string();
+      let expected_query = "WHERE a = 1 OR (b = 2 AND c = 3)";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
@@ -0,0 +1,308 @@
+mod column_def {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_render_the_name_and_the_data_type() {
+    let column = sql::ColumnDef::new("  login  ", sql::DataType::Varchar(40));
+
+    assert_eq!("login VARCHAR(40)", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_not_null_should_override_the_method_null() {
+    let column = sql::ColumnDef::new("login", sql::DataType::Text).null().not_null();
+
+    assert_eq!("login TEXT NOT NULL", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_null_should_override_the_method_not_null() {
+    let column = sql::ColumnDef::new("login", sql::DataType::Text).not_null().null();
+
+    assert_eq!("login TEXT NULL", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_default_should_override_the_previous_value() {
+    let column = sql::ColumnDef::new("age", sql::DataType::SmallInt)
+      .default("1")
+      .default("0");
+
+    assert_eq!("age SMALLINT DEFAULT 0", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_default_should_accept_literals() {
+    let column = sql::ColumnDef::new("bio", sql::DataType::Text).default(sql::Literal::new("it's me"));
+
+    assert_eq!("bio TEXT DEFAULT 'it''s me'", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_check_should_enclose_the_condition_in_parentheses() {
+    let column = sql::ColumnDef::new("age", sql::DataType::Integer).check("age >= 0");
+
+    assert_eq!("age INTEGER CHECK (age >= 0)", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_references_should_render_the_table_and_the_column() {
+    let column = sql::ColumnDef::new("user_id", sql::DataType::BigInt).references("users", "id");
+
+    assert_eq!(
+      "user_id BIGINT REFERENCES users (id)",
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_references_should_render_only_the_table_when_the_column_is_empty() {
+    let column = sql::ColumnDef::new("user_id", sql::DataType::BigInt).references("users", "");
+
+    assert_eq!("user_id BIGINT REFERENCES users", column.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_generated_should_render_the_generated_column() {
+    let column = sql::ColumnDef::new("total", sql::DataType::Integer).generated("price * quantity");
+
+    assert_eq!(
+      "total INTEGER GENERATED ALWAYS AS (price * quantity)",
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_generated_stored_should_omit_the_storage_in_the_sql_standard() {
+    let column = sql::ColumnDef::new("total", sql::DataType::Integer).generated_stored("price * quantity");
+
+    assert_eq!(
+      "total INTEGER GENERATED ALWAYS AS (price * quantity)",
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_identity_should_render_an_identity_column_in_the_sql_standard() {
+    let column = sql::ColumnDef::new("id", sql::DataType::Integer).identity();
+
+    assert_eq!(
+      "id INTEGER GENERATED BY DEFAULT AS IDENTITY",
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn all_constraints_should_be_rendered_in_a_consistent_order() {
+    let column = sql::ColumnDef::new("code", sql::DataType::Varchar(3))
+      .references("codes", "code")
+      .check("code <> ''")
+      .unique()
+      .primary_key()
+      .default("'AAA'")
+      .not_null();
+
+    assert_eq!(
+      "code VARCHAR(3) NOT NULL DEFAULT 'AAA' PRIMARY KEY UNIQUE CHECK (code <> '') REFERENCES codes (code)",
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn column_def_should_be_cloneable() {
+    let login = sql::ColumnDef::new("login", sql::DataType::Text);
+    let login_not_null = login.clone().not_null();
+
+    assert_eq!("login TEXT", login.to_sql(sql::Dialect::Standard));
+    assert_eq!("login TEXT NOT NULL", login_not_null.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_as_string_should_render_in_the_default_dialect() {
+    let column = sql::ColumnDef::new("id", sql::DataType::Integer).identity();
+
+    assert_eq!(column.to_sql(sql::Dialect::default()), column.as_string());
+    assert_eq!(column.as_string(), column.to_string());
+  }
+
+  #[test]
+  fn column_def_should_be_accepted_by_create_table() {
+    let id = sql::ColumnDef::new("id", sql::DataType::Integer)
+      .identity()
+      .primary_key();
+    let login = sql::ColumnDef::new("login", sql::DataType::Varchar(40)).not_null();
+
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column(&id)
+      .column(&login)
+      .to_sql(sql::Dialect::Standard);
+    let expected = "\
+      CREATE TABLE users (\
+        id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY, \
+        login VARCHAR(40) NOT NULL\
+      )\
+    ";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn column_def_should_be_accepted_by_alter_table() {
+    let login = sql::ColumnDef::new("login", sql::DataType::Varchar(40)).not_null();
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add(sql::Fragment::from("COLUMN ").push(&login))
+      .to_sql(sql::Dialect::Standard);
+    let expected = "ALTER TABLE users ADD COLUMN login VARCHAR(40) NOT NULL";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_inside_the_column_definition() {
+    let column = sql::ColumnDef::new(sql::Ident::new("order"), sql::DataType::Integer)
+      .references(sql::Ident::new("orders"), sql::Ident::new("id"));
+
+    assert_eq!(
+      r#""order" INTEGER REFERENCES "orders" ("id")"#,
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_column_def {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_identity_should_render_an_identity_column() {
+    let column = sql::ColumnDef::new("id", sql::DataType::BigInt)
+      .identity()
+      .primary_key();
+
+    assert_eq!(
+      "id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY",
+      column.to_sql(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[test]
+  fn method_generated_should_render_the_column_as_stored() {
+    let column = sql::ColumnDef::new("total", sql::DataType::Integer).generated("price * quantity");
+
+    assert_eq!(
+      "total INTEGER GENERATED ALWAYS AS (price * quantity) STORED",
+      column.to_sql(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_in_the_syntax_of_postgres() {
+    let column = sql::ColumnDef::new(sql::Ident::new("order"), sql::DataType::Integer);
+
+    assert_eq!(r#""order" INTEGER"#, column.to_sql(sql::Dialect::Postgresql));
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_column_def {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_identity_should_render_the_rowid_with_autoincrement() {
+    let column = sql::ColumnDef::new("id", sql::DataType::BigInt)
+      .identity()
+      .primary_key()
+      .not_null();
+
+    assert_eq!(
+      "id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT",
+      column.to_sql(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[test]
+  fn method_identity_should_render_the_primary_key_even_when_not_defined() {
+    let column = sql::ColumnDef::new("id", sql::DataType::Integer).identity();
+
+    assert_eq!(
+      "id INTEGER PRIMARY KEY AUTOINCREMENT",
+      column.to_sql(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[test]
+  fn method_generated_should_render_a_virtual_column() {
+    let column = sql::ColumnDef::new("total", sql::DataType::Integer).generated("price * quantity");
+
+    assert_eq!(
+      "total INTEGER GENERATED ALWAYS AS (price * quantity)",
+      column.to_sql(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[test]
+  fn method_generated_stored_should_render_a_stored_column() {
+    let column = sql::ColumnDef::new("total", sql::DataType::Integer).generated_stored("price * quantity");
+
+    assert_eq!(
+      "total INTEGER GENERATED ALWAYS AS (price * quantity) STORED",
+      column.to_sql(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[test]
+  fn column_def_should_be_rendered_in_the_dialect_of_the_create_table() {
+    let id = sql::ColumnDef::new("id", sql::DataType::Integer).identity();
+
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column(&id)
+      .to_sql(sql::Dialect::Sqlite);
+    let expected = "CREATE TABLE users (id INTEGER PRIMARY KEY AUTOINCREMENT)";
+
+    assert_eq!(expected, query);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_column_def {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_identity_should_render_auto_increment() {
+    let column = sql::ColumnDef::new("id", sql::DataType::Integer)
+      .identity()
+      .primary_key();
+
+    assert_eq!("id INT AUTO_INCREMENT PRIMARY KEY", column.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_in_the_syntax_of_mysql() {
+    let column = sql::ColumnDef::new(sql::Ident::new("order"), sql::DataType::Integer);
+
+    assert_eq!("`order` INT", column.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn column_def_should_be_rendered_in_the_dialect_of_the_alter_table() {
+    let id = sql::ColumnDef::new("id", sql::DataType::Integer)
+      .identity()
+      .primary_key();
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add(sql::Fragment::from("COLUMN ").push(&id))
+      .to_sql(sql::Dialect::Mysql);
+    let expected = "ALTER TABLE users ADD COLUMN id INT AUTO_INCREMENT PRIMARY KEY";
+
+    assert_eq!(expected, query);
+  }
+}
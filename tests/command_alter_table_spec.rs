@@ -58,7 +58,7 @@ mod full_api {
       .drop("column login")
       .rename("COLUMN address TO city")
       .rename_to("users_old")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
 
     let expected_query = "\
       ALTER TABLE users \
@@ -81,7 +81,7 @@ mod full_api {
       .alter("COLUMN created_at SET DEFAULT now()")
       .drop("column login")
       .rename("COLUMN address TO city")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       ALTER TABLE users \
@@ -412,7 +412,7 @@ mod method_rename {
     let query = sql::AlterTable::new()
       .rename("TO users_old")
       .rename("COLUMN name TO full_name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       RENAME TO users_old, \
@@ -429,7 +429,7 @@ mod method_rename {
       .rename("")
       .rename("COLUMN name TO full_name")
       .rename("")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "RENAME COLUMN name TO full_name";
 
@@ -442,7 +442,7 @@ mod method_rename {
     let query = sql::AlterTable::new()
       .rename("TO users_one")
       .rename("TO users_two")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       RENAME TO users_one, \
@@ -812,3 +812,642 @@ mod method_alter {
     assert_eq!(expected_query, query);
   }
 }
+
+mod typed_actions {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_add_column_should_accept_a_column_def() {
+    let login = sql::ColumnDef::new("login", sql::DataType::Varchar(40)).not_null();
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column(&login)
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "ALTER TABLE users ADD COLUMN login VARCHAR(40) NOT NULL";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_add_column_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new()
+      .add_column("  age integer  ")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "ADD COLUMN age integer";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_add_constraint_should_prefix_the_raw_constraint_with_the_keyword_constraint() {
+    let query = sql::AlterTable::new()
+      .add_constraint("users_login_key UNIQUE (login)")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "ADD CONSTRAINT users_login_key UNIQUE (login)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_add_constraint_should_accept_a_constraint_builder() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references("users", &["id"])
+      .name("orders_user_id_fkey");
+    let query = sql::AlterTable::new()
+      .alter_table("orders")
+      .add_constraint(&foreign_key)
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      ALTER TABLE orders \
+      ADD CONSTRAINT orders_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id)\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_alter_column_type_should_render_the_sql_standard_syntax() {
+    let query = sql::AlterTable::new()
+      .alter_column_type("age", sql::DataType::BigInt)
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "ALTER COLUMN age SET DATA TYPE BIGINT";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_column_should_define_a_drop_column_action() {
+    let query = sql::AlterTable::new()
+      .drop_column("login")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP COLUMN login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_constraint_should_define_a_drop_constraint_action() {
+    let query = sql::AlterTable::new()
+      .drop_constraint("users_login_key")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP CONSTRAINT users_login_key";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn methods_set_default_and_drop_default_should_alter_the_column() {
+    let set_default = sql::AlterTable::new()
+      .set_default("active", "true")
+      .to_sql(sql::Dialect::Standard);
+    let drop_default = sql::AlterTable::new()
+      .drop_default("active")
+      .to_sql(sql::Dialect::Standard);
+
+    assert_eq!("ALTER COLUMN active SET DEFAULT true", set_default);
+    assert_eq!("ALTER COLUMN active DROP DEFAULT", drop_default);
+  }
+
+  #[test]
+  fn methods_set_not_null_and_drop_not_null_should_alter_the_column() {
+    let set_not_null = sql::AlterTable::new()
+      .set_not_null("login")
+      .to_sql(sql::Dialect::Standard);
+    let drop_not_null = sql::AlterTable::new()
+      .drop_not_null("login")
+      .to_sql(sql::Dialect::Standard);
+
+    assert_eq!("ALTER COLUMN login SET NOT NULL", set_not_null);
+    assert_eq!("ALTER COLUMN login DROP NOT NULL", drop_not_null);
+  }
+
+  #[test]
+  fn method_rename_column_should_be_ignored_in_the_sql_standard() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address", "city")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "ALTER TABLE users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn sql_standard_should_render_only_the_last_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .drop("COLUMN bio")
+      .drop_column("login")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "ALTER TABLE users DROP COLUMN login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_return_the_query_when_the_action_is_supported() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .set_not_null("login")
+      .try_to_sql(sql::Dialect::Standard);
+    let expected_query = "ALTER TABLE users ALTER COLUMN login SET NOT NULL".to_string();
+
+    assert_eq!(Ok(expected_query), query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_refuse_multiple_actions_in_the_sql_standard() {
+    let error = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .drop("COLUMN login")
+      .try_to_sql(sql::Dialect::Standard)
+      .unwrap_err();
+    let expected_error = sql::BuildError::IncompatibleActions {
+      dialect: sql::Dialect::Standard,
+      actions: vec!["ADD COLUMN".to_string(), "DROP".to_string()],
+    };
+
+    assert_eq!(expected_error, error);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_report_unsupported_actions() {
+    let error = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address", "city")
+      .try_to_sql(sql::Dialect::Standard)
+      .unwrap_err();
+    let expected_error = sql::BuildError::UnsupportedFeature {
+      dialect: sql::Dialect::Standard,
+      feature: "RENAME COLUMN".to_string(),
+    };
+
+    assert_eq!(expected_error, error);
+    assert_eq!(
+      "RENAME COLUMN is not supported by the SQL Standard dialect",
+      error.to_string()
+    );
+  }
+
+  #[test]
+  fn method_try_to_sql_should_report_the_using_expression_outside_postgres() {
+    let error = sql::AlterTable::new()
+      .alter_column_type_using("age", sql::DataType::Integer, "age::integer")
+      .try_to_sql(sql::Dialect::Standard)
+      .unwrap_err();
+    let expected_error = sql::BuildError::UnsupportedFeature {
+      dialect: sql::Dialect::Standard,
+      feature: "ALTER COLUMN TYPE USING".to_string(),
+    };
+
+    assert_eq!(expected_error, error);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_typed_actions {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn typed_actions_should_be_rendered_in_the_order_of_the_calls() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .drop("COLUMN bio")
+      .set_not_null("login")
+      .alter_column_type_using("code", sql::DataType::Integer, "code::integer")
+      .to_sql(sql::Dialect::Postgresql);
+    let expected_query = "\
+      ALTER TABLE users \
+      ADD COLUMN age integer, \
+      DROP COLUMN bio, \
+      ALTER COLUMN login SET NOT NULL, \
+      ALTER COLUMN code TYPE INTEGER USING code::integer\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn postgres_only_actions_should_be_rendered() {
+    let query = sql::AlterTable::new()
+      .alter_table("orders")
+      .validate_constraint("orders_user_id_fkey")
+      .owner_to("admin")
+      .set_tablespace("fast_storage")
+      .to_sql(sql::Dialect::Postgresql);
+    let expected_query = "\
+      ALTER TABLE orders \
+      VALIDATE CONSTRAINT orders_user_id_fkey, \
+      OWNER TO admin, \
+      SET TABLESPACE fast_storage\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_accept_multiple_actions() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .drop_column("login")
+      .try_to_sql(sql::Dialect::Postgresql);
+    let expected_query = "ALTER TABLE users ADD COLUMN age integer, DROP COLUMN login".to_string();
+
+    assert_eq!(Ok(expected_query), query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_refuse_to_combine_rename_column_with_other_actions() {
+    let error = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address", "city")
+      .drop_column("login")
+      .try_to_sql(sql::Dialect::Postgresql)
+      .unwrap_err();
+    let expected_error = sql::BuildError::IncompatibleActions {
+      dialect: sql::Dialect::Postgresql,
+      actions: vec!["RENAME COLUMN".to_string(), "DROP COLUMN".to_string()],
+    };
+
+    assert_eq!(expected_error, error);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_refuse_to_combine_set_schema_with_rename_to() {
+    let error = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_to("users_old")
+      .set_schema("archive")
+      .try_to_sql(sql::Dialect::Postgresql)
+      .unwrap_err();
+    let expected_error = sql::BuildError::IncompatibleActions {
+      dialect: sql::Dialect::Postgresql,
+      actions: vec!["RENAME TO".to_string(), "SET SCHEMA".to_string()],
+    };
+
+    assert_eq!(expected_error, error);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_accept_the_using_expression() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .alter_column_type_using("age", sql::DataType::Integer, "age::integer")
+      .try_to_sql(sql::Dialect::Postgresql);
+    let expected_query = "ALTER TABLE users ALTER COLUMN age TYPE INTEGER USING age::integer".to_string();
+
+    assert_eq!(Ok(expected_query), query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_typed_actions {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn supported_actions_should_be_rendered() {
+    let add_column = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .to_sql(sql::Dialect::Sqlite);
+    let rename_column = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address", "city")
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert_eq!("ALTER TABLE users ADD COLUMN age integer", add_column);
+    assert_eq!("ALTER TABLE users RENAME COLUMN address TO city", rename_column);
+  }
+
+  #[test]
+  fn unsupported_actions_should_be_ignored_by_to_sql() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .drop_column("login")
+      .set_not_null("age")
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "ALTER TABLE users DROP COLUMN login";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_report_unsupported_actions() {
+    let alter_table = sql::AlterTable::new().alter_table("users");
+    let feature = |alter_table: sql::AlterTable| match alter_table.try_to_sql(sql::Dialect::Sqlite) {
+      Err(sql::BuildError::UnsupportedFeature { feature, .. }) => feature,
+      result => panic!("unexpected result {result:?}"),
+    };
+
+    assert_eq!(
+      "ADD CONSTRAINT",
+      feature(alter_table.clone().add_constraint("c CHECK (a > 0)"))
+    );
+    assert_eq!("DROP CONSTRAINT", feature(alter_table.clone().drop_constraint("c")));
+    assert_eq!(
+      "ALTER COLUMN TYPE",
+      feature(alter_table.clone().alter_column_type("age", sql::DataType::Text))
+    );
+    assert_eq!(
+      "ALTER COLUMN SET DEFAULT",
+      feature(alter_table.clone().set_default("a", "1"))
+    );
+    assert_eq!(
+      "ALTER COLUMN DROP DEFAULT",
+      feature(alter_table.clone().drop_default("a"))
+    );
+    assert_eq!(
+      "ALTER COLUMN SET NOT NULL",
+      feature(alter_table.clone().set_not_null("a"))
+    );
+    assert_eq!("ALTER COLUMN DROP NOT NULL", feature(alter_table.drop_not_null("a")));
+  }
+
+  #[test]
+  fn method_try_to_sql_should_allow_a_single_action() {
+    let error = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_to("users_old")
+      .add_column("age integer")
+      .try_to_sql(sql::Dialect::Sqlite)
+      .unwrap_err();
+    let expected_error = sql::BuildError::IncompatibleActions {
+      dialect: sql::Dialect::Sqlite,
+      actions: vec!["RENAME TO".to_string(), "ADD COLUMN".to_string()],
+    };
+
+    assert_eq!(expected_error, error);
+    assert_eq!(
+      "the actions RENAME TO, ADD COLUMN cannot be executed in a single statement by the SQLite dialect",
+      error.to_string()
+    );
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_typed_actions {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn typed_actions_should_be_rendered_in_the_syntax_of_mysql() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .alter_column_type("age", sql::DataType::Integer)
+      .rename_column("address", "city")
+      .set_default("active", "1")
+      .set_tablespace("fast_storage")
+      .to_sql(sql::Dialect::Mysql);
+    let expected_query = "\
+      ALTER TABLE users \
+      MODIFY COLUMN age INT, \
+      RENAME COLUMN address TO city, \
+      ALTER COLUMN active SET DEFAULT 1, \
+      TABLESPACE fast_storage\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_report_not_null_changes() {
+    let error = sql::AlterTable::new()
+      .set_not_null("login")
+      .try_to_sql(sql::Dialect::Mysql)
+      .unwrap_err();
+    let expected_error = sql::BuildError::UnsupportedFeature {
+      dialect: sql::Dialect::Mysql,
+      feature: "ALTER COLUMN SET NOT NULL".to_string(),
+    };
+
+    assert_eq!(expected_error, error);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_accept_any_combination_of_actions() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename("TO users_old")
+      .add_column("age INT")
+      .drop_column("login")
+      .try_to_sql(sql::Dialect::Mysql);
+    let expected_query = "ALTER TABLE users RENAME TO users_old, ADD COLUMN age INT, DROP COLUMN login".to_string();
+
+    assert_eq!(Ok(expected_query), query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod method_sqlite_rebuild {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_sqlite_rebuild_should_create_copy_drop_and_rename_the_table() {
+    let orders = sql::CreateTable::new()
+      .create_table("orders")
+      .column("id INTEGER PRIMARY KEY")
+      .column("total NUMERIC NOT NULL");
+
+    let query = sql::AlterTable::new()
+      .alter_table("orders")
+      .alter_column_type("total", sql::DataType::Numeric(10, 2))
+      .drop_constraint("orders_total_check")
+      .sqlite_rebuild(orders)
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "\
+      BEGIN; \
+      CREATE TABLE new_orders (id INTEGER PRIMARY KEY, total NUMERIC NOT NULL); \
+      INSERT INTO new_orders (id, total) SELECT id, total FROM orders; \
+      DROP TABLE orders; \
+      ALTER TABLE new_orders RENAME TO orders; \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn renamed_columns_should_be_copied_from_the_old_name() {
+    let users = sql::CreateTable::new()
+      .create_table("users")
+      .column("city TEXT")
+      .column("login TEXT");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address", "city")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains("INSERT INTO new_users (city, login) SELECT address, login FROM users;"));
+  }
+
+  #[test]
+  fn added_columns_should_not_be_copied() {
+    let age = sql::ColumnDef::new("age", sql::DataType::Integer)
+      .not_null()
+      .default("0");
+    let users = sql::CreateTable::new()
+      .create_table("users")
+      .column("login TEXT")
+      .column(&age)
+      .column("bio TEXT");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column(&age)
+      .add("COLUMN bio TEXT")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "\
+      BEGIN; \
+      CREATE TABLE new_users (login TEXT, age INTEGER NOT NULL DEFAULT 0, bio TEXT); \
+      INSERT INTO new_users (login) SELECT login FROM users; \
+      DROP TABLE users; \
+      ALTER TABLE new_users RENAME TO users; \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn columns_should_be_matched_unquoted_and_ignoring_the_case() {
+    let users = sql::CreateTable::new()
+      .create_table("users")
+      .column(r#""City" TEXT"#)
+      .column("login TEXT")
+      .column("Age INTEGER");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("address", "city")
+      .add(r#"COLUMN "age" INTEGER"#)
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains(r#"INSERT INTO new_users ("City", login) SELECT address, login FROM users;"#));
+  }
+
+  #[test]
+  fn columns_added_without_the_column_keyword_should_not_be_copied() {
+    let users = sql::CreateTable::new()
+      .create_table("users")
+      .column("login TEXT")
+      .column("age INTEGER")
+      .constraint("users_login_key UNIQUE (login)");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add("age INTEGER")
+      .add("CONSTRAINT users_login_key UNIQUE (login)")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains("INSERT INTO new_users (login) SELECT login FROM users;"));
+  }
+
+  #[test]
+  fn schema_qualified_tables_should_keep_the_schema_in_the_new_table() {
+    let users = sql::CreateTable::new().create_table("main.users").column("login TEXT");
+
+    let query = sql::AlterTable::new()
+      .alter_table("main.users")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "\
+      BEGIN; \
+      CREATE TABLE main.new_users (login TEXT); \
+      INSERT INTO main.new_users (login) SELECT login FROM main.users; \
+      DROP TABLE main.users; \
+      ALTER TABLE main.new_users RENAME TO users; \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn quoted_table_names_should_have_the_prefix_inside_the_quotes() {
+    let order = sql::Ident::qualified(&["main", "order"]);
+    let orders = sql::CreateTable::new().create_table(&order).column("id INTEGER");
+
+    let query = sql::AlterTable::new()
+      .alter_table(&order)
+      .sqlite_rebuild(orders)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains(r#"CREATE TABLE "main"."new_order" (id INTEGER);"#));
+    assert!(query.contains(r#"ALTER TABLE "main"."new_order" RENAME TO "order";"#));
+  }
+
+  #[test]
+  fn the_if_not_exists_of_the_create_table_should_not_be_part_of_the_table_name() {
+    let users = sql::CreateTable::new()
+      .create_table("if not exists users")
+      .column("login TEXT");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains("CREATE TABLE new_users (login TEXT);"));
+    assert!(query.contains("ALTER TABLE new_users RENAME TO users;"));
+  }
+
+  #[test]
+  fn quoted_column_names_should_be_copied_with_the_quotes() {
+    let users = sql::CreateTable::new()
+      .create_table("users")
+      .column(r#""full name" TEXT"#)
+      .column(sql::ColumnDef::new(sql::Ident::new("order"), sql::DataType::Integer));
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains(r#"INSERT INTO new_users ("full name", "order") SELECT "full name", "order" FROM users;"#));
+  }
+
+  #[test]
+  fn the_new_table_should_be_renamed_to_the_name_of_the_create_table() {
+    let users = sql::CreateTable::new().create_table("accounts").column("login TEXT");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_to("accounts")
+      .sqlite_rebuild(users)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.contains("INSERT INTO new_accounts (login) SELECT login FROM users;"));
+    assert!(query.contains("DROP TABLE users;"));
+    assert!(query.contains("ALTER TABLE new_accounts RENAME TO accounts;"));
+  }
+
+  #[test]
+  fn more_commands_can_be_added_to_the_transaction_before_the_commit() {
+    let users = sql::CreateTable::new().create_table("users").column("login TEXT");
+    let login_index = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .sqlite_rebuild(users)
+      .create_index(login_index)
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.ends_with("CREATE INDEX users_login_idx ON users (login); COMMIT;"));
+  }
+}
@@ -84,7 +84,7 @@ mod full_api {
       .unique()
       .using("btree")
       .lock("exclusive")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       CREATE UNIQUE INDEX users_name_idx \
@@ -411,6 +411,11 @@ mod method_create_index {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[cfg(feature = "sqlite")]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Sqlite;
+  #[cfg(all(feature = "mysql", not(feature = "sqlite")))]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Mysql;
+
   #[test]
   fn method_create_index_should_define_a_create_index_parameter() {
     let query = sql::CreateIndex::new().create_index("users_login_idx").as_string();
@@ -486,7 +491,7 @@ mod method_create_index {
   #[cfg(any(feature = "sqlite", feature = "mysql"))]
   #[test]
   fn method_create_index_should_define_the_parameter_only_with_name_of_the_index() {
-    let query = sql::CreateIndex::new().create_index("").as_string();
+    let query = sql::CreateIndex::new().create_index("").to_sql(SQLITE_OR_MYSQL);
     let expected_query = "";
 
     assert_eq!(expected_query, query);
@@ -561,6 +566,11 @@ mod method_unique {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[cfg(feature = "sqlite")]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Sqlite;
+  #[cfg(all(feature = "mysql", not(feature = "sqlite")))]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Mysql;
+
   #[test]
   fn method_unique_should_define_a_create_index_parameter_with_the_modifier_unique() {
     let query = sql::CreateIndex::new()
@@ -658,7 +668,7 @@ mod method_unique {
   #[cfg(any(feature = "sqlite", feature = "mysql"))]
   #[test]
   fn method_unique_should_not_define_a_create_index_parameter_when_the_method_create_index_was_not_called() {
-    let query = sql::CreateIndex::new().unique().as_string();
+    let query = sql::CreateIndex::new().unique().to_sql(SQLITE_OR_MYSQL);
     let expected_query = "";
 
     assert_eq!(expected_query, query);
@@ -831,7 +841,10 @@ mod method_using {
   #[cfg(feature = "mysql")]
   #[test]
   fn method_using_should_be_before_on_clause() {
-    let query = sql::CreateIndex::new().using("btree").on("users").as_string();
+    let query = sql::CreateIndex::new()
+      .using("btree")
+      .on("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "USING btree ON users";
 
     assert_eq!(expected_query, query);
@@ -1146,7 +1159,7 @@ mod method_fulltext {
     let query = sql::CreateIndex::new()
       .create_index("users_login_idx")
       .fulltext()
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "CREATE FULLTEXT INDEX users_login_idx";
 
     assert_eq!(expected_query, query);
@@ -1158,7 +1171,7 @@ mod method_fulltext {
       .create_index("users_login_idx")
       .fulltext()
       .fulltext()
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "CREATE FULLTEXT INDEX users_login_idx";
 
@@ -1171,7 +1184,7 @@ mod method_fulltext {
       .create_index("users_name_idx")
       .fulltext()
       .raw_after(sql::CreateIndexParams::Fulltext, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "CREATE FULLTEXT /* uncommon parameter */ INDEX users_name_idx";
 
@@ -1196,7 +1209,7 @@ mod method_fulltext {
       .create_index("users_name_idx")
       .raw_before(sql::CreateIndexParams::Fulltext, "/* uncommon parameter */")
       .fulltext()
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "CREATE /* uncommon parameter */ FULLTEXT INDEX users_name_idx";
 
@@ -1231,7 +1244,7 @@ mod method_lock {
 
   #[test]
   fn method_lock_should_define_a_lock_option() {
-    let query = sql::CreateIndex::new().lock("exclusive").as_string();
+    let query = sql::CreateIndex::new().lock("exclusive").to_sql(sql::Dialect::Mysql);
     let expected_query = "LOCK exclusive";
 
     assert_eq!(expected_query, query);
@@ -1239,7 +1252,10 @@ mod method_lock {
 
   #[test]
   fn method_lock_should_overrides_the_current_value_on_consecutive_calls() {
-    let query = sql::CreateIndex::new().lock("exclusive").lock("default").as_string();
+    let query = sql::CreateIndex::new()
+      .lock("exclusive")
+      .lock("default")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "LOCK default";
 
     assert_eq!(expected_query, query);
@@ -1247,7 +1263,9 @@ mod method_lock {
 
   #[test]
   fn method_lock_should_trim_space_of_the_argument() {
-    let query = sql::CreateIndex::new().lock("  exclusive  ").as_string();
+    let query = sql::CreateIndex::new()
+      .lock("  exclusive  ")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "LOCK exclusive";
 
     assert_eq!(expected_query, query);
@@ -1258,7 +1276,7 @@ mod method_lock {
     let query = sql::CreateIndex::new()
       .lock("exclusive")
       .raw_after(sql::CreateIndexParams::Lock, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "LOCK exclusive /* uncommon parameter */";
 
@@ -1270,7 +1288,7 @@ mod method_lock {
     let query = sql::CreateIndex::new()
       .raw_before(sql::CreateIndexParams::Lock, "/* uncommon parameter */")
       .lock("default")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "/* uncommon parameter */ LOCK default";
 
@@ -1282,7 +1300,7 @@ mod method_lock {
     let query = sql::CreateIndex::new()
       .create_index("idx_name")
       .lock("exclusive")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "CREATE INDEX idx_name LOCK exclusive";
 
     assert_eq!(expected_query, query);
@@ -1290,7 +1308,10 @@ mod method_lock {
 
   #[test]
   fn method_lock_should_be_after_on_clause() {
-    let query = sql::CreateIndex::new().lock("exclusive").on("users").as_string();
+    let query = sql::CreateIndex::new()
+      .lock("exclusive")
+      .on("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON users LOCK exclusive";
 
     assert_eq!(expected_query, query);
@@ -1302,7 +1323,7 @@ mod method_lock {
       .lock("exclusive")
       .on("users")
       .column("name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON users (name) LOCK exclusive";
 
     assert_eq!(expected_query, query);
@@ -1319,7 +1340,7 @@ mod method_spatial {
     let query = sql::CreateIndex::new()
       .create_index("users_login_idx")
       .spatial()
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "CREATE SPATIAL INDEX users_login_idx";
 
     assert_eq!(expected_query, query);
@@ -1331,7 +1352,7 @@ mod method_spatial {
       .create_index("users_login_idx")
       .spatial()
       .spatial()
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "CREATE SPATIAL INDEX users_login_idx";
 
@@ -1344,7 +1365,7 @@ mod method_spatial {
       .create_index("users_name_idx")
       .spatial()
       .raw_after(sql::CreateIndexParams::Spatial, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "CREATE SPATIAL /* uncommon parameter */ INDEX users_name_idx";
 
@@ -1369,7 +1390,7 @@ mod method_spatial {
       .create_index("users_name_idx")
       .raw_before(sql::CreateIndexParams::Spatial, "/* uncommon parameter */")
       .spatial()
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "CREATE /* uncommon parameter */ SPATIAL INDEX users_name_idx";
 
@@ -0,0 +1,559 @@
+mod full_api {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn active_users() -> sql::Select {
+    sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause("active = true")
+  }
+
+  #[test]
+  fn sql_standard_with_all_methods() {
+    let query = sql::CreateView::new()
+      // required
+      .create_view("active_users")
+      .as_select(active_users())
+      // optional
+      .or_replace()
+      .temporary()
+      .column("user_id")
+      .column("user_login")
+      .with_check_option()
+      .to_sql(sql::Dialect::Standard);
+
+    let expected_query = "\
+      CREATE OR REPLACE TEMPORARY VIEW active_users \
+      (user_id, user_login) \
+      AS SELECT id, login FROM users WHERE active = true \
+      WITH CHECK OPTION\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn postgres_with_all_methods() {
+    let query = sql::CreateView::new()
+      // required
+      .create_view("active_users")
+      .as_select(active_users())
+      // optional
+      .or_replace()
+      .temporary()
+      .column("user_id")
+      .column("user_login")
+      .with_check_option()
+      .as_string();
+
+    let expected_query = "\
+      CREATE OR REPLACE TEMPORARY VIEW active_users \
+      (user_id, user_login) \
+      AS SELECT id, login FROM users WHERE active = true \
+      WITH CHECK OPTION\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn postgres_materialized_with_all_methods() {
+    let query = sql::CreateView::new()
+      // required
+      .create_view("active_users")
+      .as_select(active_users())
+      .materialized()
+      // optional
+      .column("user_id")
+      .column("user_login")
+      .as_string();
+
+    let expected_query = "\
+      CREATE MATERIALIZED VIEW active_users \
+      (user_id, user_login) \
+      AS SELECT id, login FROM users WHERE active = true\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn sqlite_with_all_methods() {
+    let query = sql::CreateView::new()
+      // required
+      .create_view("active_users")
+      .as_select(active_users())
+      // optional
+      .temporary()
+      .column("user_id")
+      .column("user_login")
+      .as_string();
+
+    let expected_query = "\
+      CREATE TEMPORARY VIEW active_users \
+      (user_id, user_login) \
+      AS SELECT id, login FROM users WHERE active = true\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn mysql_with_all_methods() {
+    let query = sql::CreateView::new()
+      // required
+      .create_view("active_users")
+      .as_select(active_users())
+      // optional
+      .or_replace()
+      .column("user_id")
+      .column("user_login")
+      .with_check_option()
+      .as_string();
+
+    let expected_query = "\
+      CREATE OR REPLACE VIEW active_users \
+      (user_id, user_login) \
+      AS SELECT id, login FROM users WHERE active = true \
+      WITH CHECK OPTION\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn create_view_builder_should_be_displayable() {
+    let create_view = sql::CreateView::new().create_view("active_users");
+
+    println!("{}", create_view);
+
+    let query = create_view.as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn create_view_builder_should_be_debuggable() {
+    let create_view = sql::CreateView::new()
+      .create_view("active_users")
+      .as_select(sql::Select::new().select("*").from("users"));
+
+    println!("{:?}", create_view);
+
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users";
+    let query = create_view.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn create_view_builder_should_be_cloneable() {
+    let create_view = sql::CreateView::new()
+      .raw("/* test raw */")
+      .create_view("active_users")
+      .raw_after(sql::CreateViewParams::CreateView, "/* test raw_after */");
+
+    let create_view_or_replace = create_view.clone().or_replace();
+
+    let expected_query = "/* test raw */ CREATE VIEW active_users /* test raw_after */";
+    let expected_query_or_replace = "/* test raw */ CREATE OR REPLACE VIEW active_users /* test raw_after */";
+
+    assert_eq!(expected_query, create_view.to_sql(sql::Dialect::Standard));
+    assert_eq!(
+      expected_query_or_replace,
+      create_view_or_replace.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn create_view_builder_should_be_composable() {
+    fn add_comment(create_view: sql::CreateView) -> sql::CreateView {
+      create_view.raw("/* active users */")
+    }
+
+    fn create_active_users(create_view: sql::CreateView) -> sql::CreateView {
+      create_view.create_view("active_users").as_select(
+        sql::Select::new()
+          .select("*")
+          .from("users")
+          .where_clause("active = true"),
+      )
+    }
+
+    fn as_string(create_view: sql::CreateView) -> String {
+      create_view.as_string()
+    }
+
+    let query = Some(sql::CreateView::new())
+      .map(add_comment)
+      .map(create_active_users)
+      .map(as_string)
+      .unwrap();
+
+    let expected_query = "/* active users */ CREATE VIEW active_users AS SELECT * FROM users WHERE active = true";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn create_view_builder_should_be_used_in_a_transaction() {
+    let create_view = sql::CreateView::new()
+      .create_view("active_users")
+      .as_select(sql::Select::new().select("*").from("users"));
+    let query = sql::Transaction::new().create_view(create_view).as_string();
+
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  /** This test can fail only at compile time
+   * [More context](https://github.com/belchior/sql_query_builder/pull/53)
+   */
+  #[test]
+  fn create_view_builder_should_impl_send_and_sync() {
+    fn assert_impl_sync_send(_builder: impl Sync + Send) {}
+    assert_impl_sync_send(sql::CreateView::new());
+  }
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateView::new().create_view("active_users").debug().as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateView::new().create_view("active_users").print().as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateView::new()
+      .raw("create view active_users as select * from users")
+      .as_string();
+    let expected_query = "create view active_users as select * from users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::CreateView::new()
+      .raw("/* views */")
+      .create_view("active_users")
+      .as_string();
+    let expected_query = "/* views */ CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateView::new().raw("/* views */").raw("/* views */").as_string();
+    let expected_query = "/* views */";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .raw_after(sql::CreateViewParams::CreateView, "  /* views */  ")
+      .as_string();
+    let expected_query = "CREATE VIEW active_users /* views */";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewParams::CreateView, "  /* views */  ")
+      .create_view("active_users")
+      .as_string();
+    let expected_query = "/* views */ CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod method_create_view {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_view_should_add_the_create_view_signature() {
+    let query = sql::CreateView::new().create_view("active_users").as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_create_view_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new().create_view("  active_users  ").as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_create_view_should_override_the_previous_value() {
+    let query = sql::CreateView::new()
+      .create_view("users_view")
+      .create_view("active_users")
+      .as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_create_view() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .raw_after(sql::CreateViewParams::CreateView, "AS SELECT * FROM users")
+      .as_string();
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod method_column {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_column_should_add_the_column_list() {
+    let query = sql::CreateView::new().column("id").column("login").as_string();
+    let expected_query = "(id, login)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_values_when_expression_is_empty() {
+    let query = sql::CreateView::new().column("").column("id").column("").as_string();
+    let expected_query = "(id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateView::new().column("  id  ").column("id").as_string();
+    let expected_query = "(id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_column() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewParams::Column, "create view active_users")
+      .column("id")
+      .as_string();
+    let expected_query = "create view active_users (id)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod method_as_select {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_select_should_add_the_query_of_the_view() {
+    let query = sql::CreateView::new()
+      .as_select(sql::Select::new().select("*").from("users"))
+      .as_string();
+    let expected_query = "AS SELECT * FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_as_select_should_override_the_previous_value() {
+    let query = sql::CreateView::new()
+      .as_select(sql::Select::new().select("*").from("orders"))
+      .as_select(sql::Select::new().select("*").from("users"))
+      .as_string();
+    let expected_query = "AS SELECT * FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_as_select() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .raw_before(sql::CreateViewParams::AsSelect, "/* query */")
+      .as_select(sql::Select::new().select("*").from("users"))
+      .as_string();
+    let expected_query = "CREATE VIEW active_users /* query */ AS SELECT * FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_as_select() {
+    let query = sql::CreateView::new()
+      .as_select(sql::Select::new().select("*").from("users"))
+      .raw_after(sql::CreateViewParams::AsSelect, "WITH LOCAL CHECK OPTION")
+      .as_string();
+    let expected_query = "AS SELECT * FROM users WITH LOCAL CHECK OPTION";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_as_select_should_render_the_placeholders_of_the_dialect() {
+    let query = sql::CreateView::new()
+      .create_view("recent_orders")
+      .as_select(
+        sql::Select::new()
+          .select("*")
+          .from("orders")
+          .where_clause_bind("created_at > ?", "2024-01-01"),
+      )
+      .to_sql(sql::Dialect::Postgresql);
+    let expected_query = "CREATE VIEW recent_orders AS SELECT * FROM orders WHERE created_at > $1";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod modifiers {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_or_replace_should_be_rendered_in_the_standard_dialect() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .or_replace()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "CREATE OR REPLACE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_temporary_should_be_rendered_in_the_standard_dialect() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .temporary()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "CREATE TEMPORARY VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_with_check_option_should_be_rendered_in_the_standard_dialect() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .with_check_option()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "CREATE VIEW active_users WITH CHECK OPTION";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_with_check_option() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewParams::WithCheckOption, "/* check */")
+      .with_check_option()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "/* check */ WITH CHECK OPTION";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn methods_or_replace_and_with_check_option_should_be_ignored_by_sqlite() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .or_replace()
+      .with_check_option()
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn method_temporary_should_be_ignored_by_mysql() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .temporary()
+      .to_sql(sql::Dialect::Mysql);
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_materialized_should_ignore_the_modifiers_not_supported_by_materialized_views() {
+    let query = sql::CreateView::new()
+      .create_view("monthly_sales")
+      .materialized()
+      .or_replace()
+      .temporary()
+      .with_check_option()
+      .to_sql(sql::Dialect::Postgresql);
+    let expected_query = "CREATE MATERIALIZED VIEW monthly_sales";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_materialized_should_be_ignored_by_the_standard_dialect() {
+    let query = sql::CreateView::new()
+      .create_view("monthly_sales")
+      .materialized()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "CREATE VIEW monthly_sales";
+
+    assert_eq!(expected_query, query);
+  }
+}
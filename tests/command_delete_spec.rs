@@ -114,7 +114,7 @@ mod full_api {
       .partition("p1")
       .right_join("addresses on addresses.user_login = users.login")
       .with("deactivated_users", deactivated_users)
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       WITH deactivated_users AS (SELECT id FROM users WHERE ative = false) \
@@ -452,7 +452,7 @@ mod delete_method {
 
   #[test]
   fn method_delete_should_add_the_delete_clause() {
-    let query = sql::Delete::new().delete("users").as_string();
+    let query = sql::Delete::new().delete("users").to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE users";
 
     assert_eq!(expected_query, query);
@@ -460,7 +460,10 @@ mod delete_method {
 
   #[test]
   fn method_delete_should_accumulate_values_on_consecutive_calls() {
-    let query = sql::Delete::new().delete("users").delete("employees").as_string();
+    let query = sql::Delete::new()
+      .delete("users")
+      .delete("employees")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE users, employees";
 
     assert_eq!(expected_query, query);
@@ -468,7 +471,11 @@ mod delete_method {
 
   #[test]
   fn method_delete_should_not_accumulate_values_when_table_name_is_empty() {
-    let query = sql::Delete::new().delete("").delete("users").delete("").as_string();
+    let query = sql::Delete::new()
+      .delete("")
+      .delete("users")
+      .delete("")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE users";
 
     assert_eq!(expected_query, query);
@@ -476,7 +483,7 @@ mod delete_method {
 
   #[test]
   fn method_delete_should_trim_space_of_the_argument() {
-    let query = sql::Delete::new().delete("  users  ").as_string();
+    let query = sql::Delete::new().delete("  users  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE users";
 
     assert_eq!(expected_query, query);
@@ -484,7 +491,10 @@ mod delete_method {
 
   #[test]
   fn method_delete_should_not_accumulate_arguments_with_the_same_content() {
-    let query = sql::Delete::new().delete("employees").delete("employees").as_string();
+    let query = sql::Delete::new()
+      .delete("employees")
+      .delete("employees")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE employees";
 
     assert_eq!(expected_query, query);
@@ -495,7 +505,7 @@ mod delete_method {
     let query = sql::Delete::new()
       .raw_before(sql::DeleteClause::Delete, "/* uncommon parameter */")
       .delete("orders")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "/* uncommon parameter */ DELETE orders";
 
     assert_eq!(expected_query, query);
@@ -506,7 +516,7 @@ mod delete_method {
     let query = sql::Delete::new()
       .delete("LOW_PRIORITY")
       .raw_after(sql::DeleteClause::Delete, "FROM users")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE LOW_PRIORITY FROM users";
 
     assert_eq!(expected_query, query);
@@ -520,7 +530,10 @@ mod relation_between_delete_and_from {
 
   #[test]
   fn when_delete_from_method_was_called_with_delete_method_the_values_of_delete_method_should_be_preserved() {
-    let query = sql::Delete::new().delete("quick").delete_from("users").as_string();
+    let query = sql::Delete::new()
+      .delete("quick")
+      .delete_from("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE quick FROM users";
 
     assert_eq!(expected_query, query);
@@ -529,7 +542,11 @@ mod relation_between_delete_and_from {
   #[test]
   fn when_delete_from_method_was_called_with_from_method_the_values_both_methods_should_be_preserved() {
     // the value of delete_from should be concatenated first
-    let query = sql::Delete::new().from("t1").delete_from("t2").from("t3").as_string();
+    let query = sql::Delete::new()
+      .from("t1")
+      .delete_from("t2")
+      .from("t3")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE FROM t2, t1, t3";
 
     assert_eq!(expected_query, query);
@@ -543,7 +560,7 @@ mod relation_between_delete_and_from {
       .from("t1")
       .delete_from("t2")
       .from("t3")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "DELETE low_priority FROM t2, t1, t3";
 
     assert_eq!(expected_query, query);
@@ -24,7 +24,7 @@ mod full_api {
       // at least one of methods
       .drop_index("users_name_idx")
       .drop_index_if_exists("users_login_idx")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
 
     let expected_query = "DROP INDEX IF EXISTS users_login_idx";
 
@@ -281,6 +281,11 @@ mod method_drop_index {
   use pretty_assertions::assert_eq;
   use sql_query_builder as sql;
 
+  #[cfg(feature = "sqlite")]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Sqlite;
+  #[cfg(all(feature = "mysql", not(feature = "sqlite")))]
+  const SQLITE_OR_MYSQL: sql::Dialect = sql::Dialect::Mysql;
+
   #[test]
   fn method_drop_index_should_add_the_drop_index_signature() {
     let query = sql::DropIndex::new().drop_index("films_title_idx").as_string();
@@ -325,7 +330,7 @@ mod method_drop_index {
     let query = sql::DropIndex::new()
       .drop_index("films_title_idx")
       .drop_index("films_published_at_idx")
-      .as_string();
+      .to_sql(SQLITE_OR_MYSQL);
 
     let expected_query = "DROP INDEX films_published_at_idx";
 
@@ -452,7 +457,7 @@ mod method_drop_index_if_exists {
     let query = sql::DropIndex::new()
       .drop_index_if_exists("films_title_idx")
       .drop_index_if_exists("films_published_at_idx")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
 
     let expected_query = "DROP INDEX IF EXISTS films_published_at_idx";
 
@@ -33,7 +33,7 @@ mod full_api {
       // at least one of methods
       .drop_table("users_name_idx")
       .drop_table_if_exists("users_login_idx")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
 
     let expected_query = "DROP TABLE IF EXISTS users_login_idx";
 
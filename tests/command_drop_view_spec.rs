@@ -0,0 +1,358 @@
+mod full_api {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  // SQL Standard
+  #[test]
+  fn sql_standard_with_all_methods() {
+    let query = sql::DropView::new()
+      // at least one of methods
+      .drop_view("active_users")
+      .drop_view_if_exists("paid_orders")
+      // optional
+      .cascade()
+      .to_sql(sql::Dialect::Standard);
+
+    let expected_query = "DROP VIEW IF EXISTS paid_orders CASCADE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn postgres_with_all_methods() {
+    let query = sql::DropView::new()
+      // at least one of methods
+      .drop_view("active_users")
+      .drop_view_if_exists("paid_orders")
+      // optional
+      .materialized()
+      .restrict()
+      .as_string();
+
+    let expected_query = "DROP MATERIALIZED VIEW IF EXISTS active_users, paid_orders RESTRICT";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn sqlite_with_all_methods() {
+    let query = sql::DropView::new()
+      // at least one of methods
+      .drop_view("active_users")
+      .drop_view_if_exists("paid_orders")
+      .to_sql(sql::Dialect::Sqlite);
+
+    let expected_query = "DROP VIEW IF EXISTS paid_orders";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn mysql_with_all_methods() {
+    let query = sql::DropView::new()
+      // at least one of methods
+      .drop_view("active_users")
+      .drop_view_if_exists("paid_orders")
+      // optional
+      .cascade()
+      .as_string();
+
+    let expected_query = "DROP VIEW IF EXISTS active_users, paid_orders CASCADE";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn drop_view_builder_should_be_displayable() {
+    let drop_view = sql::DropView::new().drop_view("active_users");
+
+    println!("{}", drop_view);
+
+    let query = drop_view.as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn drop_view_builder_should_be_debuggable() {
+    let drop_view = sql::DropView::new().drop_view("active_users");
+
+    println!("{:?}", drop_view);
+
+    let expected_query = "DROP VIEW active_users";
+    let query = drop_view.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn drop_view_builder_should_be_cloneable() {
+    let drop_users = sql::DropView::new().drop_view("active_users");
+    let drop_users_if_exists = drop_users.clone().drop_view_if_exists("active_users");
+
+    assert_eq!("DROP VIEW active_users", drop_users.as_string());
+    assert_eq!("DROP VIEW IF EXISTS active_users", drop_users_if_exists.as_string());
+  }
+
+  #[test]
+  fn drop_view_builder_should_be_composable() {
+    fn add_comment(drop_view: sql::DropView) -> sql::DropView {
+      drop_view.raw("/* drop views */")
+    }
+
+    fn drop_active_users(drop_view: sql::DropView) -> sql::DropView {
+      drop_view.drop_view("active_users")
+    }
+
+    fn as_string(drop_view: sql::DropView) -> String {
+      drop_view.as_string()
+    }
+
+    let query = Some(sql::DropView::new())
+      .map(add_comment)
+      .map(drop_active_users)
+      .map(as_string)
+      .unwrap();
+
+    let expected_query = "/* drop views */ DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn drop_view_builder_should_be_used_in_a_transaction() {
+    let drop_view = sql::DropView::new().drop_view("active_users");
+    let query = sql::Transaction::new().drop_view(drop_view).as_string();
+
+    let expected_query = "DROP VIEW active_users;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  /** This test can fail only at compile time
+   * [More context](https://github.com/belchior/sql_query_builder/pull/53)
+   */
+  #[test]
+  fn drop_view_builder_should_impl_send_and_sync() {
+    fn assert_impl_sync_send(_builder: impl Sync + Send) {}
+    assert_impl_sync_send(sql::DropView::new());
+  }
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropView::new().drop_view("active_users").debug().as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropView::new().drop_view("active_users").print().as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropView::new().raw("drop view active_users").as_string();
+    let expected_query = "drop view active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropView::new()
+      .raw("drop view active_users")
+      .raw("drop view active_users")
+      .as_string();
+    let expected_query = "drop view active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_method_drop_view() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .raw_after(sql::DropViewParams::DropView, "  /* drop */  ")
+      .as_string();
+    let expected_query = "DROP VIEW active_users /* drop */";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_method_drop_view() {
+    let query = sql::DropView::new()
+      .raw_before(sql::DropViewParams::DropView, "  /* drop */  ")
+      .drop_view("active_users")
+      .as_string();
+    let expected_query = "/* drop */ DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod method_drop_view {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_view_should_add_the_drop_view_signature() {
+    let query = sql::DropView::new().drop_view("active_users").as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_view_should_trim_space_of_the_argument() {
+    let query = sql::DropView::new().drop_view("  active_users  ").as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_view_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view("active_users")
+      .as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(not(any(feature = "postgresql", feature = "mysql")))]
+  #[test]
+  fn method_drop_view_should_overrides_previous_value_on_consecutive_calls() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view("paid_orders")
+      .as_string();
+    let expected_query = "DROP VIEW paid_orders";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  #[test]
+  fn method_drop_view_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view("paid_orders")
+      .as_string();
+    let expected_query = "DROP VIEW active_users, paid_orders";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_drop_view_if_exists_should_add_the_if_exists_modifier() {
+    let query = sql::DropView::new().drop_view_if_exists("active_users").as_string();
+    let expected_query = "DROP VIEW IF EXISTS active_users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod drop_behavior {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_behavior() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .cascade()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP VIEW active_users CASCADE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_restrict_should_add_the_restrict_behavior() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .restrict()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP VIEW active_users RESTRICT";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn the_last_drop_behavior_should_override_the_previous_one() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .cascade()
+      .restrict()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP VIEW active_users RESTRICT";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_drop_behavior() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .cascade()
+      .raw_after(sql::DropViewParams::DropView, "/* drop */")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP VIEW active_users CASCADE /* drop */";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn drop_behavior_should_be_ignored_by_sqlite() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .cascade()
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn method_materialized_should_be_ignored_by_the_standard_dialect() {
+    let query = sql::DropView::new()
+      .drop_view("monthly_sales")
+      .materialized()
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DROP VIEW monthly_sales";
+
+    assert_eq!(expected_query, query);
+  }
+}
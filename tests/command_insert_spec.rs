@@ -70,7 +70,7 @@ mod full_api {
       .on_conflict("do nothing")
       .returning("login, name")
       .with("foo", sql::Select::new().select("login, name"))
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
 
     let expected_query = "\
       WITH foo AS (SELECT login, name) \
@@ -101,7 +101,7 @@ mod full_api {
       // optional
       .partition("p1")
       .on_duplicate_key_update("c = c+1")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       INSERT LOW_PRIORITY \
@@ -569,7 +569,7 @@ mod insert_or_method {
   fn method_insert_or_should_add_the_insert_or_clause() {
     let query = sql::Insert::new()
       .insert_or("ABORT INTO users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "INSERT OR ABORT INTO users (login, name)";
 
     assert_eq!(expected_query, query);
@@ -580,7 +580,7 @@ mod insert_or_method {
     let query = sql::Insert::new()
       .insert_or("FAIL INTO users (login, name)")
       .insert_or("FAIL INTO orders (product_name, price)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "INSERT OR FAIL INTO orders (product_name, price)";
 
     assert_eq!(expected_query, query);
@@ -596,7 +596,9 @@ mod insert_or_method {
 
   #[test]
   fn method_insert_or_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().insert_or("  IGNORE INTO users (name)  ").as_string();
+    let query = sql::Insert::new()
+      .insert_or("  IGNORE INTO users (name)  ")
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "INSERT OR IGNORE INTO users (name)";
 
     assert_eq!(expected_query, query);
@@ -607,7 +609,7 @@ mod insert_or_method {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::InsertOr, "/* insert or replace */")
       .insert_or("REPLACE INTO users (login)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "/* insert or replace */ INSERT OR REPLACE INTO users (login)";
 
     assert_eq!(expected_query, query);
@@ -618,7 +620,7 @@ mod insert_or_method {
     let query = sql::Insert::new()
       .insert_or("ROLLBACK INTO users (name)")
       .raw_after(sql::InsertClause::InsertOr, "values ('foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "INSERT OR ROLLBACK INTO users (name) values ('foo')";
 
     assert_eq!(expected_query, query);
@@ -636,7 +638,7 @@ mod sqlite_insert_variances {
       .insert_into("users (login, name)")
       .insert_or("ABORT INTO users (login, name)")
       .replace_into("users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "REPLACE INTO users (login, name)";
     assert_eq!(expected_query, query);
 
@@ -644,7 +646,7 @@ mod sqlite_insert_variances {
       .replace_into("users (login, name)")
       .insert_into("users (login, name)")
       .insert_or("ABORT INTO users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "INSERT OR ABORT INTO users (login, name)";
     assert_eq!(expected_query, query);
 
@@ -652,7 +654,7 @@ mod sqlite_insert_variances {
       .insert_or("ABORT INTO users (login, name)")
       .replace_into("users (login, name)")
       .insert_into("users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
     let expected_query = "INSERT INTO users (login, name)";
     assert_eq!(expected_query, query);
   }
@@ -720,7 +722,7 @@ mod column_method {
 
   #[test]
   fn method_column_should_define_the_columns_of_the_table() {
-    let query = sql::Insert::new().column("login").as_string();
+    let query = sql::Insert::new().column("login").to_sql(sql::Dialect::Mysql);
     let expected_query = "(login)";
 
     assert_eq!(expected_query, query);
@@ -736,7 +738,10 @@ mod column_method {
 
   #[test]
   fn method_column_should_accumulate_column_names_on_consecutive_calls() {
-    let query = sql::Insert::new().column("login").column("name").as_string();
+    let query = sql::Insert::new()
+      .column("login")
+      .column("name")
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "(login, name)";
 
@@ -745,7 +750,11 @@ mod column_method {
 
   #[test]
   fn method_column_should_not_accumulate_values_when_expression_is_empty() {
-    let query = sql::Insert::new().column("").column("login").column("").as_string();
+    let query = sql::Insert::new()
+      .column("")
+      .column("login")
+      .column("")
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "(login)";
 
@@ -754,7 +763,10 @@ mod column_method {
 
   #[test]
   fn method_column_should_not_accumulate_columns_with_the_same_content() {
-    let query = sql::Insert::new().column("login").column("login").as_string();
+    let query = sql::Insert::new()
+      .column("login")
+      .column("login")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "(login)";
 
     assert_eq!(expected_query, query);
@@ -762,7 +774,7 @@ mod column_method {
 
   #[test]
   fn method_column_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().column("  login  ").as_string();
+    let query = sql::Insert::new().column("  login  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "(login)";
 
     assert_eq!(expected_query, query);
@@ -770,7 +782,10 @@ mod column_method {
 
   #[test]
   fn parameter_column_should_be_after_insert_clause() {
-    let query = sql::Insert::new().column("name").insert("LOW_PRIORITY").as_string();
+    let query = sql::Insert::new()
+      .column("name")
+      .insert("LOW_PRIORITY")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT LOW_PRIORITY (name)";
 
     assert_eq!(expected_query, query);
@@ -778,7 +793,10 @@ mod column_method {
 
   #[test]
   fn parameter_column_should_be_after_into_clause() {
-    let query = sql::Insert::new().column("name").into("users").as_string();
+    let query = sql::Insert::new()
+      .column("name")
+      .into("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO users (name)";
 
     assert_eq!(expected_query, query);
@@ -786,7 +804,10 @@ mod column_method {
 
   #[test]
   fn parameter_column_should_be_after_partition_clause() {
-    let query = sql::Insert::new().column("name").partition("p1").as_string();
+    let query = sql::Insert::new()
+      .column("name")
+      .partition("p1")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p1) (name)";
 
     assert_eq!(expected_query, query);
@@ -797,7 +818,7 @@ mod column_method {
     let query = sql::Insert::new()
       .column("name")
       .raw_after(sql::InsertClause::Column, "/* uncommon clause */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "(name) /* uncommon clause */";
 
@@ -809,7 +830,7 @@ mod column_method {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::Column, "into users")
       .column("name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "into users (name)";
 
@@ -824,7 +845,7 @@ mod insert_method {
 
   #[test]
   fn method_insert_should_add_the_insert_clause() {
-    let query = sql::Insert::new().insert("LOW_PRIORITY").as_string();
+    let query = sql::Insert::new().insert("LOW_PRIORITY").to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT LOW_PRIORITY";
 
     assert_eq!(expected_query, query);
@@ -835,7 +856,7 @@ mod insert_method {
     let query = sql::Insert::new()
       .insert("LOW_PRIORITY")
       .insert("HIGH_PRIORITY")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT HIGH_PRIORITY";
 
     assert_eq!(expected_query, query);
@@ -843,7 +864,9 @@ mod insert_method {
 
   #[test]
   fn method_insert_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().insert("  LOW_PRIORITY  ").as_string();
+    let query = sql::Insert::new()
+      .insert("  LOW_PRIORITY  ")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT LOW_PRIORITY";
 
     assert_eq!(expected_query, query);
@@ -851,7 +874,10 @@ mod insert_method {
 
   #[test]
   fn clause_insert_should_be_before_into_clause() {
-    let query = sql::Insert::new().insert("LOW_PRIORITY").into("users").as_string();
+    let query = sql::Insert::new()
+      .insert("LOW_PRIORITY")
+      .into("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT LOW_PRIORITY INTO users";
 
     assert_eq!(expected_query, query);
@@ -862,7 +888,7 @@ mod insert_method {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::Insert, "/* comment */")
       .insert("IGNORE")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "/* comment */ INSERT IGNORE";
 
     assert_eq!(expected_query, query);
@@ -873,7 +899,7 @@ mod insert_method {
     let query = sql::Insert::new()
       .insert("IGNORE")
       .raw_after(sql::InsertClause::Insert, "into users")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT IGNORE into users";
 
     assert_eq!(expected_query, query);
@@ -887,7 +913,7 @@ mod into_method {
 
   #[test]
   fn method_into_should_add_the_into_clause() {
-    let query = sql::Insert::new().into("users").as_string();
+    let query = sql::Insert::new().into("users").to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO users";
 
     assert_eq!(expected_query, query);
@@ -895,7 +921,10 @@ mod into_method {
 
   #[test]
   fn method_into_should_override_the_current_value() {
-    let query = sql::Insert::new().into("users").into("employees").as_string();
+    let query = sql::Insert::new()
+      .into("users")
+      .into("employees")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO employees";
 
     assert_eq!(expected_query, query);
@@ -903,7 +932,7 @@ mod into_method {
 
   #[test]
   fn method_into_should_trim_space_of_the_argument() {
-    let query = sql::Insert::new().into("  users  ").as_string();
+    let query = sql::Insert::new().into("  users  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO users";
 
     assert_eq!(expected_query, query);
@@ -911,7 +940,10 @@ mod into_method {
 
   #[test]
   fn clause_into_should_be_after_insert_clause() {
-    let query = sql::Insert::new().insert("LOW_PRIORITY").into("users").as_string();
+    let query = sql::Insert::new()
+      .insert("LOW_PRIORITY")
+      .into("users")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT LOW_PRIORITY INTO users";
 
     assert_eq!(expected_query, query);
@@ -922,7 +954,7 @@ mod into_method {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::Into, "order by id")
       .into("users")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "order by id INTO users";
 
     assert_eq!(expected_query, query);
@@ -933,7 +965,7 @@ mod into_method {
     let query = sql::Insert::new()
       .into("users")
       .raw_after(sql::InsertClause::Into, "/* uncommon argument */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO users /* uncommon argument */";
 
     assert_eq!(expected_query, query);
@@ -947,7 +979,9 @@ mod on_duplicate_key_update_method {
 
   #[test]
   fn method_on_duplicate_key_update_should_add_a_on_duplicate_key_update_clause() {
-    let query = sql::Insert::new().on_duplicate_key_update("login = 'Foo'").as_string();
+    let query = sql::Insert::new()
+      .on_duplicate_key_update("login = 'Foo'")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON DUPLICATE KEY UPDATE login = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -958,7 +992,7 @@ mod on_duplicate_key_update_method {
     let query = sql::Insert::new()
       .on_duplicate_key_update("login = 'foo'")
       .on_duplicate_key_update("name = 'Foo'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON DUPLICATE KEY UPDATE login = 'foo', name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -970,7 +1004,7 @@ mod on_duplicate_key_update_method {
       .on_duplicate_key_update("")
       .on_duplicate_key_update("name = 'Foo'")
       .on_duplicate_key_update("")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON DUPLICATE KEY UPDATE name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -980,7 +1014,7 @@ mod on_duplicate_key_update_method {
   fn method_on_duplicate_key_update_should_trim_space_of_the_argument() {
     let query = sql::Insert::new()
       .on_duplicate_key_update("  name = 'Bar'  ")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON DUPLICATE KEY UPDATE name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -991,7 +1025,7 @@ mod on_duplicate_key_update_method {
     let query = sql::Insert::new()
       .on_duplicate_key_update("name = 'Bar'")
       .on_duplicate_key_update("name = 'Bar'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON DUPLICATE KEY UPDATE name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -1002,7 +1036,7 @@ mod on_duplicate_key_update_method {
     let query = sql::Insert::new()
       .on_duplicate_key_update("name = 'Bar'")
       .into("users")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INTO users ON DUPLICATE KEY UPDATE name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -1013,7 +1047,7 @@ mod on_duplicate_key_update_method {
     let query = sql::Insert::new()
       .on_duplicate_key_update("name = 'Bar'")
       .partition("p1")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p1) ON DUPLICATE KEY UPDATE name = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -1024,7 +1058,7 @@ mod on_duplicate_key_update_method {
     let query = sql::Insert::new()
       .raw_before(sql::InsertClause::OnDuplicateKeyUpdate, "insert into users")
       .on_duplicate_key_update("login = 'Bar'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "insert into users ON DUPLICATE KEY UPDATE login = 'Bar'";
 
     assert_eq!(expected_query, query);
@@ -1035,7 +1069,7 @@ mod on_duplicate_key_update_method {
     let query = sql::Insert::new()
       .on_duplicate_key_update("name = 'Bar'")
       .raw_after(sql::InsertClause::OnDuplicateKeyUpdate, ", login = 'bar'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "ON DUPLICATE KEY UPDATE name = 'Bar' , login = 'bar'";
 
     assert_eq!(expected_query, query);
@@ -1063,7 +1097,7 @@ mod mysql_insert_variances {
     let query = sql::Insert::new()
       .insert_into("users (login, name)")
       .row("('foo', 'Foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT INTO users (login, name) VALUES ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -1075,7 +1109,7 @@ mod mysql_insert_variances {
       .insert_into("users")
       .set("login = 'foo'")
       .set("name = 'Foo'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT INTO users SET login = 'foo', name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -1104,7 +1138,7 @@ mod mysql_insert_variances {
       .into("users")
       .column("login, name")
       .values("('foo', 'Foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT high_priority INTO users (login, name) VALUES ('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -1117,7 +1151,7 @@ mod mysql_insert_variances {
       .into("users")
       .column("login, name")
       .row("('foo', 'Foo')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT high_priority INTO users (login, name) VALUES ROW('foo', 'Foo')";
 
     assert_eq!(expected_query, query);
@@ -1130,7 +1164,7 @@ mod mysql_insert_variances {
       .into("users")
       .set("login = 'foo'")
       .set("name = 'Foo'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT high_priority INTO users SET login = 'foo', name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -1148,7 +1182,7 @@ mod mysql_insert_variances {
           .from("users_old")
           .where_clause("login = 'foo'"),
       )
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "\
       INSERT high_priority INTO users (login, name) \
       SELECT login, name FROM users_old WHERE login = 'foo'\
@@ -1170,7 +1204,7 @@ mod mysql_insert_variances {
       .insert("high_priority")
       .into("users")
       .column("login, name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT high_priority INTO users (login, name)";
 
     assert_eq!(expected_query, query);
@@ -1182,7 +1216,7 @@ mod mysql_insert_variances {
       .column("login, name")
       // basic
       .insert_into("users (login, name)")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "INSERT INTO users (login, name)";
 
     assert_eq!(expected_query, query);
@@ -1194,7 +1228,7 @@ mod mysql_insert_variances {
       .values("('foo', 'Foo')")
       .values("('bar', 'Bar')")
       .row("('max', 'Max')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar'), ROW('max', 'Max')";
 
     assert_eq!(expected_query, query);
@@ -1216,7 +1250,7 @@ mod mysql_insert_variances {
       // insert_set
       .set("login = 'foo'")
       .set("name = 'Foo'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "SET login = 'foo', name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -1238,7 +1272,7 @@ mod mysql_insert_variances {
       // insert_values/insert_values_row
       .values("('foo', 'Foo')")
       .row("('bar', 'Bar')")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
 
     assert_eq!(expected_query, query);
@@ -1276,7 +1310,7 @@ mod mysql_insert_variances {
       // insert_set
       .set("login = 'foo'")
       .set("name = 'Foo'")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "SET login = 'foo', name = 'Foo'";
 
     assert_eq!(expected_query, query);
@@ -0,0 +1,663 @@
+mod full_api {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn sql_standard_with_all_methods() {
+    let query = sql::Merge::new()
+      // required
+      .merge_into("customers c")
+      .using("staging_customers s")
+      .on("c.id = s.id")
+      // at least one
+      .when_matched_then_update("name = s.name")
+      .when_matched_then_delete()
+      .when_not_matched_then_insert("(id, name)", "(s.id, s.name)")
+      .as_string();
+
+    let expected_query = "\
+      MERGE INTO customers c \
+      USING staging_customers s \
+      ON c.id = s.id \
+      WHEN MATCHED THEN UPDATE SET name = s.name \
+      WHEN MATCHED THEN DELETE \
+      WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn postgres_with_all_methods() {
+    let valid_imports = sql::Select::new()
+      .select("id, name")
+      .from("imports")
+      .where_clause("valid = true");
+
+    let query = sql::Merge::new()
+      // required
+      .merge_into("customers c")
+      .using("valid_imports s")
+      .on("c.id = s.id")
+      // at least one
+      .when_matched_then_update("name = s.name")
+      .when_matched_then_delete()
+      .when_not_matched_then_insert("(id, name)", "(s.id, s.name)")
+      // optional
+      .with("valid_imports", valid_imports)
+      .as_string();
+
+    let expected_query = "\
+      WITH valid_imports AS (SELECT id, name FROM imports WHERE valid = true) \
+      MERGE INTO customers c \
+      USING valid_imports s \
+      ON c.id = s.id \
+      WHEN MATCHED THEN UPDATE SET name = s.name \
+      WHEN MATCHED THEN DELETE \
+      WHEN NOT MATCHED THEN INSERT (id, name) VALUES (s.id, s.name)\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod builder_features {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn merge_builder_should_be_displayable() {
+    let merge = sql::Merge::new().merge_into("customers").using("imports");
+
+    println!("{}", merge);
+
+    let query = merge.as_string();
+    let expected_query = "MERGE INTO customers USING imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_debuggable() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("imports i")
+      .on("c.id = i.id");
+
+    println!("{:?}", merge);
+
+    let expected_query = "MERGE INTO customers c USING imports i ON c.id = i.id";
+    let query = merge.as_string();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_cloneable() {
+    let merge_foo = sql::Merge::new()
+      .raw("/* test raw */")
+      .merge_into("customers c")
+      .using("imports i")
+      .raw_before(sql::MergeClause::On, "/* test raw_before */")
+      .on("c.id = i.id")
+      .raw_after(sql::MergeClause::On, "/* test raw_after */");
+
+    let merge_foo_bar = merge_foo.clone().when_matched_then_delete();
+
+    let query_foo = merge_foo.as_string();
+    let query_foo_bar = merge_foo_bar.as_string();
+
+    let expected_query_foo = "\
+      /* test raw */ \
+      MERGE INTO customers c \
+      USING imports i \
+      /* test raw_before */ \
+      ON c.id = i.id \
+      /* test raw_after */\
+    ";
+    let expected_query_foo_bar = "\
+      /* test raw */ \
+      MERGE INTO customers c \
+      USING imports i \
+      /* test raw_before */ \
+      ON c.id = i.id \
+      /* test raw_after */ \
+      WHEN MATCHED THEN DELETE\
+    ";
+
+    assert_eq!(query_foo, expected_query_foo);
+    assert_eq!(query_foo_bar, expected_query_foo_bar);
+  }
+
+  #[test]
+  fn merge_builder_should_be_able_to_conditionally_add_clauses() {
+    let mut merge = sql::Merge::new().merge_into("customers c").using("imports i");
+
+    if true {
+      merge = merge.on("c.id = i.id");
+    }
+
+    let query = merge.as_string();
+    let expected_query = "MERGE INTO customers c USING imports i ON c.id = i.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_composable() {
+    fn target(merge: sql::Merge) -> sql::Merge {
+      merge.merge_into("customers c").using("imports i").on("c.id = i.id")
+    }
+
+    fn actions(merge: sql::Merge) -> sql::Merge {
+      merge
+        .when_matched_then_update("name = i.name")
+        .when_not_matched_then_insert("", "(i.id, i.name)")
+    }
+
+    fn as_string(merge: sql::Merge) -> String {
+      merge.as_string()
+    }
+
+    let query = Some(sql::Merge::new()).map(target).map(actions).map(as_string).unwrap();
+
+    let expected_query = "\
+      MERGE INTO customers c \
+      USING imports i \
+      ON c.id = i.id \
+      WHEN MATCHED THEN UPDATE SET name = i.name \
+      WHEN NOT MATCHED THEN INSERT VALUES (i.id, i.name)\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn all_standard_clauses_concatenated_in_order() {
+    let query = sql::Merge::new()
+      .when_matched_then_delete()
+      .on("c.id = i.id")
+      .using("imports i")
+      .merge_into("customers c")
+      .as_string();
+
+    let expected_query = "\
+      MERGE INTO customers c \
+      USING imports i \
+      ON c.id = i.id \
+      WHEN MATCHED THEN DELETE\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_be_used_in_a_transaction() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("imports i")
+      .on("c.id = i.id")
+      .when_matched_then_delete();
+    let query = sql::Transaction::new().merge(merge).as_string();
+
+    let expected_query = "MERGE INTO customers c USING imports i ON c.id = i.id WHEN MATCHED THEN DELETE;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_builder_should_bind_the_values_of_the_conditions() {
+    let (query, values) = sql::Merge::new()
+      .merge_into("customers c")
+      .using("imports i")
+      .on(sql::col("c.id").eq(sql::col("i.id")).and(sql::col("i.region").eq("eu")))
+      .when_matched_then_delete()
+      .to_sql_with_binds(sql::Dialect::Standard);
+
+    let expected_query =
+      "MERGE INTO customers c USING imports i ON c.id = i.id AND i.region = ? WHEN MATCHED THEN DELETE";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::from("eu")], values);
+  }
+
+  /** This test can fail only at compile time
+   * [More context](https://github.com/belchior/sql_query_builder/pull/53)
+   */
+  #[test]
+  fn merge_builder_should_impl_send_and_sync() {
+    fn assert_impl_sync_send(_builder: impl Sync + Send) {}
+    assert_impl_sync_send(sql::Merge::new());
+  }
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_string_should_convert_the_current_state_into_string() {
+    let query = sql::Merge::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Merge::new().merge_into("customers").debug().as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Merge::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Merge::new().merge_into("customers").print().as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Merge::new().raw("merge into customers").as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .raw("merge into customers")
+      .raw("using imports")
+      .as_string();
+    let expected_query = "merge into customers using imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Merge::new()
+      .raw("merge into customers")
+      .using("imports")
+      .as_string();
+    let expected_query = "merge into customers USING imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().raw("  merge into customers  ").as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new()
+      .raw("merge into customers")
+      .raw("merge into customers")
+      .as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new()
+      .merge_into("customers")
+      .raw_after(sql::MergeClause::MergeInto, "  using imports  ")
+      .as_string();
+    let expected_query = "MERGE INTO customers using imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::Using, "  merge into customers  ")
+      .as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod merge_into_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_merge_into_should_add_a_merge_into_clause() {
+    let query = sql::Merge::new().merge_into("customers").as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_merge_into_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .merge_into("orders")
+      .merge_into("customers")
+      .as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_merge_into_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().merge_into("  customers  ").as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_merge_into_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::MergeInto, "/* sync customers */")
+      .merge_into("customers")
+      .as_string();
+    let expected_query = "/* sync customers */ MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_merge_into_clause() {
+    let query = sql::Merge::new()
+      .merge_into("customers")
+      .raw_after(sql::MergeClause::MergeInto, "using imports")
+      .as_string();
+    let expected_query = "MERGE INTO customers using imports";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod using_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_using_should_add_a_using_clause() {
+    let query = sql::Merge::new().using("imports").as_string();
+    let expected_query = "USING imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_using_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new().using("orders").using("imports").as_string();
+    let expected_query = "USING imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_using_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().using("  imports  ").as_string();
+    let expected_query = "USING imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_using_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::Using, "merge into customers")
+      .using("imports")
+      .as_string();
+    let expected_query = "merge into customers USING imports";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_using_clause() {
+    let query = sql::Merge::new()
+      .using("imports")
+      .raw_after(sql::MergeClause::Using, "on customers.id = imports.id")
+      .as_string();
+    let expected_query = "USING imports on customers.id = imports.id";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod on_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_should_add_a_on_clause() {
+    let query = sql::Merge::new().on("c.id = i.id").as_string();
+    let expected_query = "ON c.id = i.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_on_should_accumulate_values_using_the_and_operator() {
+    let query = sql::Merge::new()
+      .on("c.id = i.id")
+      .on("c.region = i.region")
+      .as_string();
+    let expected_query = "ON c.id = i.id AND c.region = i.region";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_on_should_not_accumulate_values_when_condition_is_empty() {
+    let query = sql::Merge::new().on("").on("c.id = i.id").on("").as_string();
+    let expected_query = "ON c.id = i.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_on_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().on("  c.id = i.id  ").as_string();
+    let expected_query = "ON c.id = i.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_on_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new().on("c.id = i.id").on("c.id = i.id").as_string();
+    let expected_query = "ON c.id = i.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_on_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::On, "using imports i")
+      .on("c.id = i.id")
+      .as_string();
+    let expected_query = "using imports i ON c.id = i.id";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_on_clause() {
+    let query = sql::Merge::new()
+      .on("c.id = i.id")
+      .raw_after(sql::MergeClause::On, "when matched then do nothing")
+      .as_string();
+    let expected_query = "ON c.id = i.id when matched then do nothing";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod when_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_when_matched_then_update_should_add_the_update_action() {
+    let query = sql::Merge::new()
+      .when_matched_then_update("  name = i.name, email = i.email  ")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = i.name, email = i.email";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_matched_then_delete_should_add_the_delete_action() {
+    let query = sql::Merge::new().when_matched_then_delete().as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_not_matched_then_insert_should_add_the_insert_action() {
+    let query = sql::Merge::new()
+      .when_not_matched_then_insert("  (id, name)  ", "  (i.id, i.name)  ")
+      .as_string();
+    let expected_query = "WHEN NOT MATCHED THEN INSERT (id, name) VALUES (i.id, i.name)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_when_not_matched_then_insert_should_omit_the_columns_when_empty() {
+    let query = sql::Merge::new()
+      .when_not_matched_then_insert("", "(i.id, i.name)")
+      .as_string();
+    let expected_query = "WHEN NOT MATCHED THEN INSERT VALUES (i.id, i.name)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn when_methods_should_keep_the_order_of_the_calls() {
+    let query = sql::Merge::new()
+      .when_not_matched_then_insert("", "(i.id)")
+      .when_matched_then_delete()
+      .when_matched_then_update("name = i.name")
+      .as_string();
+    let expected_query = "\
+      WHEN NOT MATCHED THEN INSERT VALUES (i.id) \
+      WHEN MATCHED THEN DELETE \
+      WHEN MATCHED THEN UPDATE SET name = i.name\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn when_methods_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new()
+      .when_matched_then_delete()
+      .when_matched_then_delete()
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_when_clauses() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::When, "on c.id = i.id")
+      .when_matched_then_delete()
+      .as_string();
+    let expected_query = "on c.id = i.id WHEN MATCHED THEN DELETE";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_when_clauses() {
+    let query = sql::Merge::new()
+      .when_matched_then_delete()
+      .raw_after(sql::MergeClause::When, "/* end */")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE /* end */";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod with_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_with_should_add_the_with_clause() {
+    let imports = sql::Select::new().select("id").from("imports");
+    let query = sql::Merge::new()
+      .with("valid_imports", imports)
+      .merge_into("customers")
+      .as_string();
+    let expected_query = "WITH valid_imports AS (SELECT id FROM imports) MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_with_should_not_be_rendered_in_the_standard_dialect() {
+    let imports = sql::Select::new().select("id").from("imports");
+    let query = sql::Merge::new()
+      .with("valid_imports", imports)
+      .merge_into("customers")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_with_clause() {
+    let imports = sql::Select::new().select("id").from("imports");
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::With, "/* sync */")
+      .with("valid_imports", imports)
+      .as_string();
+    let expected_query = "/* sync */ WITH valid_imports AS (SELECT id FROM imports)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn merge_should_be_used_inside_the_with_clause() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("imports i")
+      .on("c.id = i.id")
+      .when_matched_then_delete();
+    let query = sql::Select::new().with("merged", merge).select("1").as_string();
+    let expected_query =
+      "WITH merged AS (MERGE INTO customers c USING imports i ON c.id = i.id WHEN MATCHED THEN DELETE) SELECT 1";
+
+    assert_eq!(expected_query, query);
+  }
+}
@@ -185,7 +185,7 @@ mod full_api {
       .intersect(sql::Select::new().select("login, name, status"))
       .union(sql::Select::new().select("login, name, status"))
       .with("foo", sql::Select::new().select("login, name, status"))
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       (((\
@@ -519,7 +519,7 @@ mod partition_method {
 
   #[test]
   fn method_partition_should_define_the_partition_clause() {
-    let query = sql::Select::new().partition("p0").as_string();
+    let query = sql::Select::new().partition("p0").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -535,7 +535,10 @@ mod partition_method {
 
   #[test]
   fn method_partition_should_accumulate_names_on_consecutive_calls() {
-    let query = sql::Select::new().partition("p0").partition("p1").as_string();
+    let query = sql::Select::new()
+      .partition("p0")
+      .partition("p1")
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0, p1)";
 
@@ -548,7 +551,7 @@ mod partition_method {
       .partition("")
       .partition("p0")
       .partition("")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (p0)";
 
@@ -557,7 +560,10 @@ mod partition_method {
 
   #[test]
   fn method_partition_should_not_accumulate_names_with_the_same_content() {
-    let query = sql::Select::new().partition("p0").partition("p0").as_string();
+    let query = sql::Select::new()
+      .partition("p0")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -565,7 +571,7 @@ mod partition_method {
 
   #[test]
   fn method_partition_should_trim_space_of_the_argument() {
-    let query = sql::Select::new().partition("  p0  ").as_string();
+    let query = sql::Select::new().partition("  p0  ").to_sql(sql::Dialect::Mysql);
     let expected_query = "PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -573,7 +579,10 @@ mod partition_method {
 
   #[test]
   fn method_partition_should_be_defined_after_from_clause() {
-    let query = sql::Select::new().from("employees").partition("p0").as_string();
+    let query = sql::Select::new()
+      .from("employees")
+      .partition("p0")
+      .to_sql(sql::Dialect::Mysql);
     let expected_query = "FROM employees PARTITION (p0)";
 
     assert_eq!(expected_query, query);
@@ -585,7 +594,7 @@ mod partition_method {
       .from("employees")
       .inner_join("addresses ON employees.login = addresses.login")
       .partition("p0")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       FROM employees \
@@ -601,7 +610,7 @@ mod partition_method {
     let query = sql::Select::new()
       .partition("name")
       .raw_after(sql::SelectClause::Partition, "/* uncommon parameter */")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "PARTITION (name) /* uncommon parameter */";
 
@@ -613,7 +622,7 @@ mod partition_method {
     let query = sql::Select::new()
       .raw_before(sql::SelectClause::Partition, "/* uncommon parameter */")
       .partition("name")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "/* uncommon parameter */ PARTITION (name)";
 
@@ -75,7 +75,7 @@ mod full_api {
       .where_and("product_id = $2")
       .where_or("p.ref_id = $3")
       .returning("*")
-      .as_string();
+      .to_sql(sql::Dialect::Sqlite);
 
     let expected_query = "\
       WITH foo AS (SELECT login) \
@@ -108,7 +108,7 @@ mod full_api {
       .where_or("ref_id = $3")
       .order_by("id desc")
       .limit("1")
-      .as_string();
+      .to_sql(sql::Dialect::Mysql);
 
     let expected_query = "\
       UPDATE orders \
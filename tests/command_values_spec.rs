@@ -25,7 +25,7 @@ mod full_api {
   #[cfg(feature = "mysql")]
   #[test]
   fn mysql_with_all_methods() {
-    let query = sql::Values::new().row("(1, 'one')").as_string();
+    let query = sql::Values::new().row("(1, 'one')").to_sql(sql::Dialect::Mysql);
 
     let expected_query = "VALUES ROW(1, 'one')";
 
@@ -57,7 +57,7 @@ mod builder_features {
 
       println!("{}", values);
 
-      let query = values.as_string();
+      let query = values.to_sql(sql::Dialect::Mysql);
       let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
 
       assert_eq!(expected_query, query);
@@ -85,7 +85,7 @@ mod builder_features {
       println!("{:?}", values);
 
       let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
-      let query = values.as_string();
+      let query = values.to_sql(sql::Dialect::Mysql);
 
       assert_eq!(expected_query, query);
     }
@@ -133,8 +133,8 @@ mod builder_features {
 
       let values_foo_bar = values_foo.clone().row("('bar', 'Bar')");
 
-      let query_foo = values_foo.as_string();
-      let query_foo_bar = values_foo_bar.as_string();
+      let query_foo = values_foo.to_sql(sql::Dialect::Mysql);
+      let query_foo_bar = values_foo_bar.to_sql(sql::Dialect::Mysql);
 
       let expected_query_foo = "\
         /* test raw */ \
@@ -178,7 +178,7 @@ mod builder_features {
         values = values.row("('bar', 'Bar')");
       }
 
-      let query = values.as_string();
+      let query = values.to_sql(sql::Dialect::Mysql);
       let expected_query = "VALUES ROW('foo', 'Foo'), ROW('bar', 'Bar')";
 
       assert_eq!(expected_query, query);
@@ -223,7 +223,7 @@ mod builder_features {
       }
 
       fn as_string(values: sql::Values) -> String {
-        values.as_string()
+        values.to_sql(sql::Dialect::Mysql)
       }
 
       let query = Some(sql::Values::new())
@@ -279,7 +279,7 @@ mod builder_methods {
         .row("(1, 'one')")
         .row("(2, 'two')")
         .debug()
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "VALUES ROW(1, 'one'), ROW(2, 'two')";
 
       assert_eq!(expected_query, query);
@@ -306,7 +306,7 @@ mod builder_methods {
         .row("(1, 'one')")
         .row("(2, 'two')")
         .print()
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "VALUES ROW(1, 'one'), ROW(2, 'two')";
 
       assert_eq!(expected_query, query);
@@ -331,7 +331,7 @@ mod builder_methods {
       let query = sql::Values::new()
         .raw("/* the values command */")
         .row("(1, 'one')")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "/* the values command */ VALUES ROW(1, 'one')";
 
       assert_eq!(expected_query, query);
@@ -372,7 +372,7 @@ mod builder_methods {
       let query = sql::Values::new()
         .row("(1, 'one')")
         .raw("insert into my_table(num, txt)")
-        .as_string();
+        .to_sql(sql::Dialect::Mysql);
       let expected_query = "insert into my_table(num, txt) VALUES ROW(1, 'one')";
 
       assert_eq!(expected_query, query);
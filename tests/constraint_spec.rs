@@ -0,0 +1,401 @@
+mod check {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_enclose_the_condition_in_parentheses() {
+    let check = sql::Check::new("  price > 0  ");
+
+    assert_eq!("CHECK (price > 0)", check.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_name_should_override_the_previous_value() {
+    let check = sql::Check::new("price > 0").name("foo").name("products_price_check");
+
+    assert_eq!(
+      "CONSTRAINT products_price_check CHECK (price > 0)",
+      check.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn check_should_be_rendered_as_is_by_create_table_constraint() {
+    let query = sql::CreateTable::new()
+      .create_table("products")
+      .column("price integer")
+      .constraint(sql::Check::new("price > 0"))
+      .constraint("products_price_key UNIQUE(price)")
+      .to_sql(sql::Dialect::Standard);
+    let expected = "\
+      CREATE TABLE products (\
+        price integer, \
+        CHECK (price > 0), \
+        CONSTRAINT products_price_key UNIQUE(price)\
+      )\
+    ";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn trait_display_should_render_the_default_dialect() {
+    let check = sql::Check::new("price > 0");
+
+    assert_eq!(check.as_string(), check.to_string());
+  }
+}
+
+mod foreign_key {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_references_should_render_the_referenced_columns() {
+    let foreign_key = sql::ForeignKey::new(&["country", "city"]).references("cities", &["country", "name"]);
+
+    assert_eq!(
+      "FOREIGN KEY (country, city) REFERENCES cities (country, name)",
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_references_should_render_only_the_table_when_the_columns_are_empty() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"]).references("users", &[] as &[&str]);
+
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users",
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_references_table_should_render_only_the_table() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"]).references_table("users");
+
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users",
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_references_table_should_override_the_referenced_columns() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references("users", &["id"])
+      .references_table("accounts");
+
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES accounts",
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn referential_actions_should_be_rendered_after_the_match_type() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .on_update(sql::ReferentialAction::NoAction)
+      .on_delete(sql::ReferentialAction::SetDefault)
+      .match_type(sql::MatchType::Simple)
+      .references("users", &["id"]);
+
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users (id) MATCH SIMPLE ON DELETE SET DEFAULT ON UPDATE NO ACTION",
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn all_referential_actions_should_be_rendered() {
+    let on_delete = |action| {
+      sql::ForeignKey::new(&["a"])
+        .references_table("t")
+        .on_delete(action)
+        .to_sql(sql::Dialect::Standard)
+    };
+
+    assert_eq!(
+      "FOREIGN KEY (a) REFERENCES t ON DELETE CASCADE",
+      on_delete(sql::ReferentialAction::Cascade)
+    );
+    assert_eq!(
+      "FOREIGN KEY (a) REFERENCES t ON DELETE NO ACTION",
+      on_delete(sql::ReferentialAction::NoAction)
+    );
+    assert_eq!(
+      "FOREIGN KEY (a) REFERENCES t ON DELETE RESTRICT",
+      on_delete(sql::ReferentialAction::Restrict)
+    );
+    assert_eq!(
+      "FOREIGN KEY (a) REFERENCES t ON DELETE SET DEFAULT",
+      on_delete(sql::ReferentialAction::SetDefault)
+    );
+    assert_eq!(
+      "FOREIGN KEY (a) REFERENCES t ON DELETE SET NULL",
+      on_delete(sql::ReferentialAction::SetNull)
+    );
+  }
+
+  #[test]
+  fn method_initially_deferred_should_override_the_method_deferrable() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references("users", &["id"])
+      .deferrable()
+      .initially_deferred()
+      .name("orders_user_fk");
+
+    assert_eq!(
+      "CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users (id) DEFERRABLE INITIALLY DEFERRED",
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn foreign_key_should_be_rendered_as_is_by_create_table_foreign_key() {
+    let query = sql::CreateTable::new()
+      .create_table("orders")
+      .foreign_key(sql::ForeignKey::new(&["user_id"]).references("users", &["id"]))
+      .foreign_key("(address_id) REFERENCES addresses")
+      .to_sql(sql::Dialect::Standard);
+    let expected = "\
+      CREATE TABLE orders (\
+        FOREIGN KEY (user_id) REFERENCES users (id), \
+        FOREIGN KEY(address_id) REFERENCES addresses\
+      )\
+    ";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn foreign_key_should_be_accepted_by_alter_table_add() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .name("orders_user_fk")
+      .references("users", &["id"])
+      .on_delete(sql::ReferentialAction::Cascade);
+
+    let query = sql::AlterTable::new()
+      .alter_table("orders")
+      .add(&foreign_key)
+      .to_sql(sql::Dialect::Standard);
+    let expected =
+      "ALTER TABLE orders ADD CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_inside_the_constraint() {
+    let foreign_key = sql::ForeignKey::new(&[&sql::Ident::new("user")]).references_table(sql::Ident::new("users"));
+
+    assert_eq!(
+      r#"FOREIGN KEY ("user") REFERENCES "users""#,
+      foreign_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+}
+
+mod primary_key {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_render_the_columns() {
+    let primary_key = sql::PrimaryKey::new(&["order_id", " product_id "]);
+
+    assert_eq!(
+      "PRIMARY KEY (order_id, product_id)",
+      primary_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn method_deferrable_should_be_rendered_after_the_columns() {
+    let primary_key = sql::PrimaryKey::new(&["id"]).deferrable().name("users_pk");
+
+    assert_eq!(
+      "CONSTRAINT users_pk PRIMARY KEY (id) DEFERRABLE",
+      primary_key.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn primary_key_should_be_rendered_as_is_by_create_table_primary_key() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id integer")
+      .primary_key(sql::PrimaryKey::new(&["id"]).name("users_pk"))
+      .to_sql(sql::Dialect::Standard);
+    let expected = "CREATE TABLE users (id integer, CONSTRAINT users_pk PRIMARY KEY (id))";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn primary_key_should_be_accepted_by_alter_table_add() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add(sql::PrimaryKey::new(&["id"]))
+      .to_sql(sql::Dialect::Standard);
+    let expected = "ALTER TABLE users ADD PRIMARY KEY (id)";
+
+    assert_eq!(expected, query);
+  }
+}
+
+mod unique {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_render_the_columns() {
+    let unique = sql::Unique::new(&["tenant_id", "login"]);
+
+    assert_eq!("UNIQUE (tenant_id, login)", unique.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_initially_deferred_should_be_rendered_after_the_columns() {
+    let unique = sql::Unique::new(&["position"]).initially_deferred();
+
+    assert_eq!(
+      "UNIQUE (position) DEFERRABLE INITIALLY DEFERRED",
+      unique.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn unique_should_be_cloneable() {
+    let unique = sql::Unique::new(&["login"]);
+    let named_unique = unique.clone().name("users_login_key");
+
+    assert_eq!("UNIQUE (login)", unique.to_sql(sql::Dialect::Standard));
+    assert_eq!(
+      "CONSTRAINT users_login_key UNIQUE (login)",
+      named_unique.to_sql(sql::Dialect::Standard)
+    );
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_constraint {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn exclude_should_render_the_elements_with_the_operators() {
+    let exclude = sql::Exclude::new()
+      .name("bookings_no_overlap")
+      .using("gist")
+      .element("room_id", "=")
+      .element("during", "&&")
+      .where_clause("cancelled = false")
+      .deferrable();
+
+    assert_eq!(
+      "CONSTRAINT bookings_no_overlap EXCLUDE USING gist (room_id WITH =, during WITH &&) WHERE (cancelled = false) DEFERRABLE",
+      exclude.to_sql(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[test]
+  fn exclude_should_be_rendered_as_is_by_create_table_constraint() {
+    let query = sql::CreateTable::new()
+      .create_table("bookings")
+      .column("during tsrange")
+      .constraint(sql::Exclude::new().using("gist").element("during", "&&"))
+      .to_sql(sql::Dialect::Postgresql);
+    let expected = "CREATE TABLE bookings (during tsrange, EXCLUDE USING gist (during WITH &&))";
+
+    assert_eq!(expected, query);
+  }
+
+  #[test]
+  fn deferrable_constraints_should_be_rendered_in_postgres() {
+    let unique = sql::Unique::new(&["position"]).deferrable();
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references_table("users")
+      .initially_deferred();
+
+    assert_eq!("UNIQUE (position) DEFERRABLE", unique.to_sql(sql::Dialect::Postgresql));
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users DEFERRABLE INITIALLY DEFERRED",
+      foreign_key.to_sql(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_in_the_syntax_of_postgres() {
+    let primary_key = sql::PrimaryKey::new(&[&sql::Ident::new("id")]);
+
+    assert_eq!(r#"PRIMARY KEY ("id")"#, primary_key.to_sql(sql::Dialect::Postgresql));
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_constraint {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn deferrable_should_be_omitted_in_primary_keys_and_unique_constraints() {
+    let primary_key = sql::PrimaryKey::new(&["id"]).deferrable();
+    let unique = sql::Unique::new(&["login"]).initially_deferred();
+
+    assert_eq!("PRIMARY KEY (id)", primary_key.to_sql(sql::Dialect::Sqlite));
+    assert_eq!("UNIQUE (login)", unique.to_sql(sql::Dialect::Sqlite));
+  }
+
+  #[test]
+  fn deferrable_should_be_rendered_in_foreign_keys() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references("users", &["id"])
+      .initially_deferred();
+
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users (id) DEFERRABLE INITIALLY DEFERRED",
+      foreign_key.to_sql(sql::Dialect::Sqlite)
+    );
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_constraint {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn deferrable_should_be_omitted() {
+    let primary_key = sql::PrimaryKey::new(&["id"]).deferrable();
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references("users", &["id"])
+      .initially_deferred();
+
+    assert_eq!("PRIMARY KEY (id)", primary_key.to_sql(sql::Dialect::Mysql));
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users (id)",
+      foreign_key.to_sql(sql::Dialect::Mysql)
+    );
+  }
+
+  #[test]
+  fn match_type_should_be_omitted_to_keep_the_referential_actions() {
+    let foreign_key = sql::ForeignKey::new(&["user_id"])
+      .references("users", &["id"])
+      .match_type(sql::MatchType::Full)
+      .on_delete(sql::ReferentialAction::Cascade);
+
+    assert_eq!(
+      "FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE",
+      foreign_key.to_sql(sql::Dialect::Mysql)
+    );
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_in_the_syntax_of_mysql() {
+    let unique = sql::Unique::new(&[&sql::Ident::new("key")]);
+
+    assert_eq!("UNIQUE (`key`)", unique.to_sql(sql::Dialect::Mysql));
+  }
+}
@@ -0,0 +1,190 @@
+mod data_type {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn data_types_should_be_rendered_in_the_sql_standard() {
+    let data_type = |data_type: sql::DataType| data_type.to_sql(sql::Dialect::Standard);
+
+    assert_eq!("SMALLINT", data_type(sql::DataType::SmallInt));
+    assert_eq!("INTEGER", data_type(sql::DataType::Integer));
+    assert_eq!("BIGINT", data_type(sql::DataType::BigInt));
+    assert_eq!("NUMERIC(10, 2)", data_type(sql::DataType::Numeric(10, 2)));
+    assert_eq!("BOOLEAN", data_type(sql::DataType::Boolean));
+    assert_eq!("TEXT", data_type(sql::DataType::Text));
+    assert_eq!("VARCHAR(40)", data_type(sql::DataType::Varchar(40)));
+    assert_eq!("DATE", data_type(sql::DataType::Date));
+    assert_eq!("TIMESTAMP", data_type(sql::DataType::Timestamp));
+    assert_eq!("TIMESTAMP WITH TIME ZONE", data_type(sql::DataType::TimestampTz));
+    assert_eq!("JSON", data_type(sql::DataType::Json));
+    assert_eq!("JSON", data_type(sql::DataType::Jsonb));
+    assert_eq!("BLOB", data_type(sql::DataType::Blob));
+    assert_eq!(
+      "INTEGER ARRAY",
+      data_type(sql::DataType::Array(Box::new(sql::DataType::Integer)))
+    );
+  }
+
+  #[test]
+  fn types_without_equivalent_in_the_sql_standard_should_be_reported() {
+    let mood = sql::DataType::Enum("mood".to_string(), vec!["happy".to_string(), "sad".to_string()]);
+    let ids = sql::DataType::Array(Box::new(sql::DataType::Uuid));
+
+    assert_eq!(None, sql::DataType::Uuid.try_to_sql(sql::Dialect::Standard));
+    assert_eq!(None, mood.try_to_sql(sql::Dialect::Standard));
+    assert_eq!(None, ids.try_to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn types_without_equivalent_should_be_rendered_with_the_postgres_name() {
+    let mood = sql::DataType::Enum("mood".to_string(), vec!["happy".to_string(), "sad".to_string()]);
+
+    assert_eq!("UUID", sql::DataType::Uuid.to_sql(sql::Dialect::Standard));
+    assert_eq!("mood", mood.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn supported_types_should_be_returned_by_try_to_sql() {
+    assert_eq!(
+      Some("VARCHAR(3)".to_string()),
+      sql::DataType::Varchar(3).try_to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn data_type_should_be_accepted_by_column_def() {
+    let column = sql::ColumnDef::new("created_at", sql::DataType::TimestampTz).not_null();
+
+    assert_eq!(
+      "created_at TIMESTAMP WITH TIME ZONE NOT NULL",
+      column.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn trait_display_should_render_the_default_dialect() {
+    let data_type = sql::DataType::Integer;
+
+    assert_eq!(data_type.to_sql(sql::Dialect::default()), data_type.to_string());
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_data_type {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn data_types_should_be_rendered_as_the_postgres_types() {
+    let data_type = |data_type: sql::DataType| data_type.to_sql(sql::Dialect::Postgresql);
+
+    assert_eq!("INTEGER", data_type(sql::DataType::Integer));
+    assert_eq!("NUMERIC(10, 2)", data_type(sql::DataType::Numeric(10, 2)));
+    assert_eq!("TIMESTAMP", data_type(sql::DataType::Timestamp));
+    assert_eq!("TIMESTAMPTZ", data_type(sql::DataType::TimestampTz));
+    assert_eq!("UUID", data_type(sql::DataType::Uuid));
+    assert_eq!("JSON", data_type(sql::DataType::Json));
+    assert_eq!("JSONB", data_type(sql::DataType::Jsonb));
+    assert_eq!("BYTEA", data_type(sql::DataType::Blob));
+  }
+
+  #[test]
+  fn arrays_should_be_rendered_with_brackets() {
+    let matrix = sql::DataType::Array(Box::new(sql::DataType::Array(Box::new(sql::DataType::Integer))));
+
+    assert_eq!("INTEGER[][]", matrix.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn enums_should_be_rendered_as_the_name_of_the_type() {
+    let mood = sql::DataType::Enum("mood".to_string(), vec!["happy".to_string(), "sad".to_string()]);
+
+    assert_eq!(Some("mood".to_string()), mood.try_to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn data_type_should_be_rendered_in_the_dialect_of_the_create_table() {
+    let tags = sql::ColumnDef::new("tags", sql::DataType::Array(Box::new(sql::DataType::Text)));
+
+    let query = sql::CreateTable::new()
+      .create_table("posts")
+      .column(&tags)
+      .to_sql(sql::Dialect::Postgresql);
+
+    assert_eq!("CREATE TABLE posts (tags TEXT[])", query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_data_type {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn data_types_should_be_rendered_as_the_sqlite_types() {
+    let data_type = |data_type: sql::DataType| data_type.to_sql(sql::Dialect::Sqlite);
+
+    assert_eq!("INTEGER", data_type(sql::DataType::SmallInt));
+    assert_eq!("INTEGER", data_type(sql::DataType::BigInt));
+    assert_eq!("INTEGER", data_type(sql::DataType::Boolean));
+    assert_eq!("NUMERIC", data_type(sql::DataType::Numeric(10, 2)));
+    assert_eq!("TEXT", data_type(sql::DataType::Varchar(40)));
+    assert_eq!("TEXT", data_type(sql::DataType::TimestampTz));
+    assert_eq!("TEXT", data_type(sql::DataType::Uuid));
+    assert_eq!("TEXT", data_type(sql::DataType::Json));
+    assert_eq!("BLOB", data_type(sql::DataType::Jsonb));
+    assert_eq!("BLOB", data_type(sql::DataType::Blob));
+  }
+
+  #[test]
+  fn arrays_should_be_reported_as_without_equivalent() {
+    let tags = sql::DataType::Array(Box::new(sql::DataType::Text));
+
+    assert_eq!(None, tags.try_to_sql(sql::Dialect::Sqlite));
+  }
+
+  #[test]
+  fn data_type_should_be_rendered_in_the_dialect_of_the_alter_table() {
+    let active = sql::ColumnDef::new("active", sql::DataType::Boolean).default("1");
+
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add(sql::Fragment::from("COLUMN ").push(&active))
+      .to_sql(sql::Dialect::Sqlite);
+
+    assert_eq!("ALTER TABLE users ADD COLUMN active INTEGER DEFAULT 1", query);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_data_type {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn data_types_should_be_rendered_as_the_mysql_types() {
+    let data_type = |data_type: sql::DataType| data_type.to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("INT", data_type(sql::DataType::Integer));
+    assert_eq!("DECIMAL(10, 2)", data_type(sql::DataType::Numeric(10, 2)));
+    assert_eq!("DATETIME", data_type(sql::DataType::Timestamp));
+    assert_eq!("TIMESTAMP", data_type(sql::DataType::TimestampTz));
+    assert_eq!("CHAR(36)", data_type(sql::DataType::Uuid));
+    assert_eq!("JSON", data_type(sql::DataType::Jsonb));
+    assert_eq!("BLOB", data_type(sql::DataType::Blob));
+  }
+
+  #[test]
+  fn enums_should_be_rendered_with_the_labels() {
+    let mood = sql::DataType::Enum("mood".to_string(), vec!["happy".to_string(), "it's ok".to_string()]);
+
+    assert_eq!("ENUM('happy', 'it''s ok')", mood.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn arrays_should_be_reported_as_without_equivalent() {
+    let tags = sql::DataType::Array(Box::new(sql::DataType::Text));
+
+    assert_eq!(None, tags.try_to_sql(sql::Dialect::Mysql));
+  }
+}
@@ -0,0 +1,239 @@
+mod standard_dialect {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_to_sql_should_render_the_sql_standard_syntax() {
+    let query = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause("login = $1")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "SELECT id, login FROM users WHERE login = $1";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_to_sql_should_be_propagated_to_the_inner_commands_of_a_transaction() {
+    let query = sql::Transaction::new()
+      .select(sql::Select::new().select("login").from("users"))
+      .commit("")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "SELECT login FROM users; COMMIT;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
+  #[test]
+  fn default_dialect_should_be_the_sql_standard_when_no_feature_is_enabled() {
+    assert_eq!(sql::Dialect::Standard, sql::Dialect::default());
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  #[test]
+  fn method_to_sql_should_ignore_the_clauses_not_supported_by_the_sql_standard() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .limit("10")
+      .offset("20")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "SELECT login FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  #[test]
+  fn method_to_sql_should_ignore_the_with_clause_in_the_sql_standard() {
+    let query = sql::Delete::new()
+      .with("old_users", sql::Select::new().select("id").from("users"))
+      .delete_from("users")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "DELETE FROM users";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_dialect {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn default_dialect_should_be_postgres_when_the_feature_is_enabled() {
+    assert_eq!(sql::Dialect::Postgresql, sql::Dialect::default());
+  }
+
+  #[test]
+  fn method_to_sql_should_render_the_same_query_as_the_method_as_string() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .limit("10")
+      .offset("20");
+
+    assert_eq!(select.as_string(), select.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn method_to_sql_should_render_multiple_actions_of_the_alter_table() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .add("COLUMN age int")
+      .drop("COLUMN login");
+
+    let postgres_query = alter_table.to_sql(sql::Dialect::Postgresql);
+    let standard_query = alter_table.to_sql(sql::Dialect::Standard);
+
+    assert_eq!(
+      "ALTER TABLE users ADD COLUMN age int, DROP COLUMN login",
+      postgres_query
+    );
+    assert_eq!("ALTER TABLE users DROP COLUMN login", standard_query);
+  }
+
+  #[test]
+  fn method_to_sql_should_render_the_clauses_of_the_with_queries_in_the_same_dialect() {
+    let query = sql::Select::new()
+      .with("logins", sql::Select::new().select("login").from("users").limit("10"))
+      .select("*")
+      .from("logins")
+      .to_sql(sql::Dialect::Postgresql);
+    let expected_query = "WITH logins AS (SELECT login FROM users LIMIT 10) SELECT * FROM logins";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_dialect {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[cfg(not(feature = "postgresql"))]
+  #[test]
+  fn default_dialect_should_be_sqlite_when_postgres_feature_is_disabled() {
+    assert_eq!(sql::Dialect::Sqlite, sql::Dialect::default());
+  }
+
+  #[test]
+  fn method_to_sql_should_render_the_sqlite_update_or_clause() {
+    let query = sql::Update::new()
+      .update_or("REPLACE users")
+      .set("login = 'foo'")
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "UPDATE OR REPLACE users SET login = 'foo'";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_to_sql_should_render_only_the_last_table_of_the_drop_table() {
+    let query = sql::DropTable::new()
+      .drop_table("films")
+      .drop_table("series")
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "DROP TABLE series";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_dialect {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[cfg(not(any(feature = "postgresql", feature = "sqlite")))]
+  #[test]
+  fn default_dialect_should_be_mysql_when_postgres_and_sqlite_features_are_disabled() {
+    assert_eq!(sql::Dialect::Mysql, sql::Dialect::default());
+  }
+
+  #[test]
+  fn method_to_sql_should_render_values_as_row_constructors() {
+    let values = sql::Values::new().row("('foo', 'Foo')");
+
+    assert_eq!("VALUES ROW('foo', 'Foo')", values.to_sql(sql::Dialect::Mysql));
+    assert_eq!("VALUES ('foo', 'Foo')", values.to_sql(sql::Dialect::Standard));
+  }
+}
+
+#[cfg(all(feature = "postgresql", feature = "sqlite"))]
+mod postgres_and_sqlite_dialects {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_to_sql_should_render_the_same_builder_in_each_dialect() {
+    let drop_index = sql::DropIndex::new()
+      .drop_index("users_name_idx")
+      .drop_index("users_login_idx");
+
+    let postgres_query = drop_index.to_sql(sql::Dialect::Postgresql);
+    let sqlite_query = drop_index.to_sql(sql::Dialect::Sqlite);
+
+    assert_eq!("DROP INDEX users_name_idx, users_login_idx", postgres_query);
+    assert_eq!("DROP INDEX users_login_idx", sqlite_query);
+  }
+
+  #[test]
+  fn method_to_sql_should_render_the_create_index_of_each_dialect() {
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_name_idx")
+      .concurrently()
+      .on("users")
+      .column("name");
+
+    let postgres_query = create_index.to_sql(sql::Dialect::Postgresql);
+    let sqlite_query = create_index.to_sql(sql::Dialect::Sqlite);
+
+    assert_eq!(
+      "CREATE INDEX CONCURRENTLY users_name_idx ON users (name)",
+      postgres_query
+    );
+    assert_eq!("CREATE INDEX users_name_idx ON users (name)", sqlite_query);
+  }
+
+  #[test]
+  fn method_as_string_should_render_postgres_when_both_features_are_enabled() {
+    let select = sql::Select::new().select("login").from("users").limit("1");
+
+    assert_eq!(select.to_sql(sql::Dialect::Postgresql), select.as_string());
+  }
+}
+
+#[cfg(all(feature = "postgresql", feature = "mysql"))]
+mod postgres_and_mysql_dialects {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_to_sql_should_render_the_rename_action_of_each_dialect() {
+    let alter_table = sql::AlterTable::new().alter_table("users").rename("TO users_old");
+
+    let postgres_query = alter_table.to_sql(sql::Dialect::Postgresql);
+    let mysql_query = alter_table.to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("ALTER TABLE users RENAME TO users_old", postgres_query);
+    assert_eq!("ALTER TABLE users RENAME TO users_old", mysql_query);
+  }
+
+  #[test]
+  fn method_to_sql_should_ignore_the_returning_clause_in_mysql() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .returning("id");
+
+    let postgres_query = insert.to_sql(sql::Dialect::Postgresql);
+    let mysql_query = insert.to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("INSERT INTO users (login) VALUES ('foo') RETURNING id", postgres_query);
+    assert_eq!("INSERT INTO users (login) VALUES ('foo')", mysql_query);
+  }
+}
@@ -0,0 +1,264 @@
+mod expr {
+  use pretty_assertions::assert_eq;
+  use sql::{col, val};
+  use sql_query_builder as sql;
+
+  #[test]
+  fn comparison_operators_should_bind_the_values() {
+    let (query, values) = sql::Select::new()
+      .where_clause(col("a").eq(1))
+      .where_clause(col("b").ne(2))
+      .where_clause(col("c").gt(3))
+      .where_clause(col("d").gte(4))
+      .where_clause(col("e").lt(5))
+      .where_clause(col("f").lte(6))
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "WHERE a = ? AND b <> ? AND c > ? AND d >= ? AND e < ? AND f <= ?";
+    let expected_values = (1..=6).map(sql::Value::Int).collect::<Vec<_>>();
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn operators_and_or_should_render_the_parentheses_by_precedence() {
+    let condition = col("a")
+      .eq(col("1"))
+      .or(col("b").eq(col("2")))
+      .and(col("c").eq(col("3")).or(col("d").eq(col("4"))));
+
+    assert_eq!("(a = 1 OR b = 2) AND (c = 3 OR d = 4)", condition.as_string());
+  }
+
+  #[test]
+  fn operator_and_inside_or_should_not_render_parentheses() {
+    let condition = col("a")
+      .eq(col("1"))
+      .and(col("b").eq(col("2")))
+      .or(col("c").eq(col("3")));
+
+    assert_eq!("a = 1 AND b = 2 OR c = 3", condition.as_string());
+  }
+
+  #[test]
+  fn chained_or_operators_should_not_render_nested_parentheses() {
+    let condition = col("a").is_null().or(col("b").is_null()).or(col("c").is_null());
+
+    assert_eq!("a IS NULL OR b IS NULL OR c IS NULL", condition.as_string());
+  }
+
+  #[test]
+  fn operator_or_should_be_enclosed_in_parentheses_when_used_in_a_clause() {
+    let query = sql::Select::new()
+      .where_clause("active = true")
+      .where_clause(col("a").is_null().or(col("b").is_null()))
+      .as_string();
+    let expected_query = "WHERE active = true AND (a IS NULL OR b IS NULL)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn operator_not_should_enclose_the_operand_in_parentheses() {
+    let condition = !col("a").eq(col("1"));
+
+    assert_eq!("NOT (a = 1)", condition.as_string());
+  }
+
+  #[test]
+  fn operator_not_should_not_enclose_a_single_column() {
+    let condition = !col("active");
+
+    assert_eq!("NOT active", condition.as_string());
+  }
+
+  #[test]
+  fn method_like_should_bind_the_pattern() {
+    let (query, values) = sql::Select::new()
+      .where_clause(col("name").like("a%").and(col("name").not_like("%z")))
+      .to_sql_with_binds(sql::Dialect::Standard);
+
+    assert_eq!("WHERE name LIKE ? AND name NOT LIKE ?", query);
+    assert_eq!(vec![sql::Value::from("a%"), sql::Value::from("%z")], values);
+  }
+
+  #[test]
+  fn method_is_in_should_bind_each_value() {
+    let (query, values) = sql::Select::new()
+      .where_clause(col("id").is_in([1, 2]).and(col("id").not_in(vec![3])))
+      .to_sql_with_binds(sql::Dialect::Standard);
+
+    assert_eq!("WHERE id IN (?, ?) AND id NOT IN (?)", query);
+    assert_eq!(vec![sql::Value::Int(1), sql::Value::Int(2), sql::Value::Int(3)], values);
+  }
+
+  #[test]
+  fn method_is_in_with_an_empty_list_should_be_always_false() {
+    let condition = col("id").is_in(Vec::<i32>::new());
+
+    assert_eq!("1 = 0", condition.as_string());
+  }
+
+  #[test]
+  fn method_not_in_with_an_empty_list_should_be_always_true() {
+    let condition = col("id").not_in(Vec::<i32>::new());
+
+    assert_eq!("1 = 1", condition.as_string());
+  }
+
+  #[test]
+  fn method_between_should_bind_the_limits() {
+    let (query, values) = sql::Select::new()
+      .where_clause(col("age").between(18, 65))
+      .to_sql_with_binds(sql::Dialect::Standard);
+
+    assert_eq!("WHERE age BETWEEN ? AND ?", query);
+    assert_eq!(vec![sql::Value::Int(18), sql::Value::Int(65)], values);
+  }
+
+  #[test]
+  fn function_val_should_bind_the_value_on_the_left_side() {
+    let (query, values) = sql::Select::new()
+      .where_clause(val("foo").eq(col("login")))
+      .to_sql_with_binds(sql::Dialect::Standard);
+
+    assert_eq!("WHERE ? = login", query);
+    assert_eq!(vec![sql::Value::from("foo")], values);
+  }
+
+  #[test]
+  fn predicate_used_as_operand_should_be_enclosed_in_parentheses() {
+    let condition = col("a").gt(col("b")).eq(col("true"));
+
+    assert_eq!("(a > b) = true", condition.as_string());
+  }
+
+  #[test]
+  fn identifiers_and_literals_should_be_accepted_as_operands() {
+    let condition = col(sql::Ident::new("Name"))
+      .eq(sql::Literal::new("o'neil"))
+      .and(col("id").eq(sql::Ident::new("Id")));
+
+    assert_eq!(
+      "\"Name\" = 'o''neil' AND id = \"Id\"",
+      condition.to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn expression_should_be_accepted_by_the_having_and_where_or_methods() {
+    let query = sql::Select::new()
+      .select("login, count(*)")
+      .group_by("login")
+      .having(col("count(*)").gt(col("1")).or(col("login").eq(col("'admin'"))))
+      .as_string();
+    let expected_query = "SELECT login, count(*) GROUP BY login HAVING (count(*) > 1 OR login = 'admin')";
+
+    assert_eq!(expected_query, query);
+
+    let query = sql::Delete::new()
+      .where_clause("a = 1")
+      .where_or(col("b").eq(col("2")).and(col("c").eq(col("3"))))
+      .as_string();
+
+    assert_eq!("WHERE a = 1 OR b = 2 AND c = 3", query);
+  }
+
+  #[test]
+  fn expression_should_be_accepted_by_the_update_methods() {
+    let (query, values) = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .where_clause(col("last_login").lt("2024-01-01").or(col("last_login").is_null()))
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query = "UPDATE users SET active = false WHERE (last_login < ? OR last_login IS NULL)";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::from("2024-01-01")], values);
+  }
+
+  #[test]
+  fn trait_display_should_render_the_expression() {
+    let condition = col("a").eq(col("b")).or(col("c").eq(col("d")));
+
+    assert_eq!("a = b OR c = d", format!("{condition}"));
+  }
+
+  #[test]
+  fn method_cast_should_render_the_cast_expression() {
+    let expr = col("price").cast(sql::DataType::Numeric(10, 2));
+
+    assert_eq!("CAST(price AS NUMERIC(10, 2))", expr.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_cast_should_not_enclose_the_operand_in_parentheses() {
+    let expr = col("a").eq(col("b")).or(col("c")).cast(sql::DataType::Boolean);
+
+    assert_eq!("CAST(a = b OR c AS BOOLEAN)", expr.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_cast_should_be_usable_as_operand() {
+    let expr = col("age").cast(sql::DataType::Integer).gt(18);
+
+    assert_eq!("CAST(age AS INTEGER) > ?", expr.to_sql(sql::Dialect::Standard));
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_expr {
+  use pretty_assertions::assert_eq;
+  use sql::col;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn values_should_be_numbered_in_the_order_of_the_query() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause(col("age").gt(18).and(col("name").like("a%").or(col("vip").eq(true))))
+      .where_clause("id <> $1")
+      .as_string_with_binds();
+    let expected_query = "SELECT * FROM users WHERE age > $2 AND (name LIKE $3 OR vip = $4) AND id <> $1";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(
+      vec![sql::Value::Int(18), sql::Value::from("a%"), sql::Value::Bool(true)],
+      values
+    );
+  }
+  #[test]
+  fn method_cast_should_keep_the_numbering_of_the_values() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause(sql::val("42").cast(sql::DataType::Integer).eq(col("id")))
+      .where_clause(col("tags").eq(sql::val("a").cast(sql::DataType::Array(Box::new(sql::DataType::Text)))))
+      .as_string_with_binds();
+    let expected_query = "SELECT * FROM users WHERE CAST($1 AS INTEGER) = id AND tags = CAST($2 AS TEXT[])";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(vec![sql::Value::from("42"), sql::Value::from("a")], values);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_expr {
+  use pretty_assertions::assert_eq;
+  use sql::col;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_cast_should_render_the_cast_types_of_mysql() {
+    let cast = |data_type| col("x").cast(data_type).to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("CAST(x AS SIGNED)", cast(sql::DataType::BigInt));
+    assert_eq!("CAST(x AS DECIMAL(10, 2))", cast(sql::DataType::Numeric(10, 2)));
+    assert_eq!("CAST(x AS CHAR(3))", cast(sql::DataType::Varchar(3)));
+    assert_eq!("CAST(x AS CHAR)", cast(sql::DataType::Text));
+    assert_eq!("CAST(x AS DATETIME)", cast(sql::DataType::TimestampTz));
+    assert_eq!("CAST(x AS JSON)", cast(sql::DataType::Jsonb));
+    assert_eq!("CAST(x AS BINARY)", cast(sql::DataType::Blob));
+  }
+}
@@ -0,0 +1,172 @@
+mod tokens {
+  use pretty_assertions::assert_eq;
+  use sql::{HighlightBackend, TokenKind};
+  use sql_query_builder as sql;
+
+  struct Tagged;
+
+  impl HighlightBackend for Tagged {
+    fn paint(&self, kind: TokenKind, text: &str) -> String {
+      match kind {
+        TokenKind::Keyword => format!("<k>{text}</k>"),
+        TokenKind::Identifier => format!("<i>{text}</i>"),
+        TokenKind::Literal => format!("<l>{text}</l>"),
+        TokenKind::Comment => format!("<c>{text}</c>"),
+        TokenKind::Placeholder => format!("<p>{text}</p>"),
+        TokenKind::Operator => format!("<o>{text}</o>"),
+        TokenKind::Whitespace => text.to_string(),
+      }
+    }
+  }
+
+  #[test]
+  fn keywords_inside_identifiers_should_not_be_highlighted() {
+    let query = sql::highlight("SELECT addition, last_sign_in_at FROM orders", &Tagged);
+    let expected_query = "<k>SELECT</k> <i>addition</i><o>,</o> <i>last_sign_in_at</i> <k>FROM</k> <i>orders</i>";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn keywords_should_be_matched_in_any_case() {
+    let query = sql::highlight("select id from users", &Tagged);
+    let expected_query = "<k>select</k> <i>id</i> <k>from</k> <i>users</i>";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn words_after_a_dot_should_be_identifiers() {
+    let query = sql::highlight("orders.order", &Tagged);
+    let expected_query = "<i>orders</i><o>.</o><i>order</i>";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn string_literals_should_keep_their_content_untouched() {
+    let query = sql::highlight("WHERE note = 'select it''s -- not a comment'", &Tagged);
+    let expected_query = "<k>WHERE</k> <i>note</i> <o>=</o> <l>'select it''s -- not a comment'</l>";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn quoted_identifiers_should_be_identifiers() {
+    let query = sql::highlight("SELECT \"order\", `from`", &Tagged);
+    let expected_query = "<k>SELECT</k> <i>\"order\"</i><o>,</o> <i>`from`</i>";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn numbers_and_literal_words_should_be_literals() {
+    let query = sql::highlight("SET a = 1.5, b = null, c = TRUE", &Tagged);
+    let expected_query = "\
+      <k>SET</k> <i>a</i> <o>=</o> <l>1.5</l><o>,</o> \
+      <i>b</i> <o>=</o> <l>null</l><o>,</o> \
+      <i>c</i> <o>=</o> <l>TRUE</l>\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn comments_should_be_highlighted_as_a_whole() {
+    let query = sql::highlight("/* select all */ SELECT * -- from users\nFROM users", &Tagged);
+    let expected_query = "\
+      <c>/* select all */</c> <k>SELECT</k> <o>*</o> <c>-- from users</c>\n\
+      <k>FROM</k> <i>users</i>\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn placeholders_with_any_number_should_be_highlighted() {
+    let query = sql::highlight("WHERE a = $1 AND b = $11 AND c = ? AND d = ?3", &Tagged);
+    let expected_query = "\
+      <k>WHERE</k> <i>a</i> <o>=</o> <p>$1</p> \
+      <k>AND</k> <i>b</i> <o>=</o> <p>$11</p> \
+      <k>AND</k> <i>c</i> <o>=</o> <p>?</p> \
+      <k>AND</k> <i>d</i> <o>=</o> <p>?3</p>\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn operators_should_be_grouped_until_a_comment_starts() {
+    let query = sql::highlight("a<>b>=c--x", &Tagged);
+    let expected_query = "<i>a</i><o><></o><i>b</i><o>>=</o><i>c</i><c>--x</c>";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod ansi_backend {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn keywords_literals_comments_and_placeholders_should_be_colored() {
+    let query = sql::highlight("SELECT 'a' -- b\nWHERE id = $1", &sql::AnsiBackend);
+    let expected_query = "\
+      \x1b[34;1mSELECT\x1b[0m \x1b[33m'a'\x1b[0m \x1b[32;2m-- b\x1b[0m\n\
+      \x1b[34;1mWHERE\x1b[0m id = \x1b[0;1m$1\x1b[0m\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod html_backend {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn tokens_should_be_wrapped_in_spans_with_the_class_of_the_kind() {
+    let query = sql::highlight("SELECT id\nFROM users", &sql::HtmlBackend);
+    let expected_query = "\
+      <span class=\"sql-keyword\">SELECT</span> <span class=\"sql-identifier\">id</span>\n\
+      <span class=\"sql-keyword\">FROM</span> <span class=\"sql-identifier\">users</span>\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn html_special_characters_should_be_escaped() {
+    let query = sql::highlight("a < b && \"c\" > '&'", &sql::HtmlBackend);
+    let expected_query = "\
+      <span class=\"sql-identifier\">a</span> \
+      <span class=\"sql-operator\">&lt;</span> \
+      <span class=\"sql-identifier\">b</span> \
+      <span class=\"sql-operator\">&amp;&amp;</span> \
+      <span class=\"sql-identifier\">&quot;c&quot;</span> \
+      <span class=\"sql-operator\">&gt;</span> \
+      <span class=\"sql-literal\">&#39;&amp;&#39;</span>\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod plain_backend {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn the_query_should_be_returned_unchanged() {
+    let query = "SELECT id, 'x''y' /* c */ FROM users -- done\nWHERE id = $1";
+
+    assert_eq!(query, sql::highlight(query, &sql::PlainBackend));
+  }
+
+  #[test]
+  fn unterminated_strings_and_comments_should_be_returned_unchanged() {
+    let query = "SELECT 'abc /* def";
+
+    assert_eq!(query, sql::highlight(query, &sql::PlainBackend));
+  }
+}
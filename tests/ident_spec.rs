@@ -0,0 +1,190 @@
+mod ident {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifier_should_be_quoted_with_double_quotes_in_the_sql_standard() {
+    let ident = sql::Ident::new("order");
+
+    assert_eq!("\"order\"", ident.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn qualified_identifier_should_quote_each_part() {
+    let ident = sql::Ident::qualified(&["public", "users", "login"]);
+
+    assert_eq!("\"public\".\"users\".\"login\"", ident.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_new_should_not_split_the_name_on_dots() {
+    let ident = sql::Ident::new("file.name");
+
+    assert_eq!("\"file.name\"", ident.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn quotes_inside_the_identifier_should_be_escaped() {
+    let ident = sql::Ident::new("user \"name\"");
+
+    assert_eq!("\"user \"\"name\"\"\"", ident.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn identifier_should_be_accepted_by_the_select_methods() {
+    let query = sql::Select::new()
+      .select(sql::Ident::new("Name"))
+      .from(sql::Ident::new("order"))
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "SELECT \"Name\" FROM \"order\"";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn identifier_should_be_accepted_by_the_insert_methods() {
+    let query = sql::Insert::new()
+      .insert_into(sql::Ident::qualified(&["public", "user"]))
+      .values("('foo')")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "INSERT INTO \"public\".\"user\" VALUES ('foo')";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn identifier_should_be_accepted_by_the_update_and_delete_methods() {
+    let table = sql::Ident::new("group");
+    let update_query = sql::Update::new()
+      .update(&table)
+      .set("name = 'foo'")
+      .to_sql(sql::Dialect::Standard);
+    let delete_query = sql::Delete::new().delete_from(&table).to_sql(sql::Dialect::Standard);
+
+    assert_eq!("UPDATE \"group\" SET name = 'foo'", update_query);
+    assert_eq!("DELETE FROM \"group\"", delete_query);
+  }
+
+  #[test]
+  fn identifier_should_be_accepted_by_the_ddl_builders() {
+    let table = sql::Ident::new("order");
+    let column = sql::Ident::new("Key");
+    let create_table = sql::CreateTable::new()
+      .create_table(&table)
+      .column(sql::Fragment::from(&column).push(" int"));
+    let drop_table = sql::DropTable::new().drop_table(&table);
+
+    assert_eq!(
+      "CREATE TABLE \"order\" (\"Key\" int)",
+      create_table.to_sql(sql::Dialect::Standard)
+    );
+    assert_eq!("DROP TABLE \"order\"", drop_table.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn identifier_should_be_quoted_in_the_inner_queries() {
+    let query = sql::Transaction::new()
+      .select(sql::Select::new().select("*").from(sql::Ident::new("user")))
+      .commit("")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "SELECT * FROM \"user\"; COMMIT;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn identifier_composed_into_a_clause_should_be_quoted_in_the_dialect_of_the_query() {
+    let column = sql::Ident::new("Key");
+    let select = sql::Select::new().where_clause(sql::Fragment::from(&column).push(" = 1"));
+
+    assert_eq!("WHERE \"Key\" = 1", select.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn placeholders_inside_the_identifier_should_not_be_replaced_by_the_bound_values() {
+    let condition = sql::Fragment::from(&sql::Ident::new("why?")).push(" = ?");
+    let (query, values) = sql::Select::new()
+      .where_clause_bind(condition, "yes")
+      .to_sql_with_binds(sql::Dialect::Standard);
+
+    assert_eq!("WHERE \"why?\" = ?", query);
+    assert_eq!(vec![sql::Value::from("yes")], values);
+  }
+
+  #[test]
+  fn trait_display_should_render_the_identifier_quoted_in_the_default_dialect() {
+    let ident = sql::Ident::new("order");
+
+    assert_eq!(ident.as_string(), format!("{ident}"));
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_ident {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifier_should_be_quoted_with_double_quotes() {
+    let query = sql::Select::new()
+      .select("*")
+      .from(sql::Ident::qualified(&["public", "order"]))
+      .as_string();
+    let expected_query = "SELECT * FROM \"public\".\"order\"";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_ident {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifier_should_be_quoted_with_double_quotes() {
+    let query = sql::CreateIndex::new()
+      .create_index(sql::Ident::new("order_idx"))
+      .on(sql::Ident::new("order"))
+      .column("id")
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_query = "CREATE INDEX \"order_idx\" ON \"order\" (id)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_ident {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifier_should_be_quoted_with_backticks() {
+    let query = sql::Select::new()
+      .select("*")
+      .from(sql::Ident::qualified(&["shop", "order"]))
+      .to_sql(sql::Dialect::Mysql);
+    let expected_query = "SELECT * FROM `shop`.`order`";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn backticks_inside_the_identifier_should_be_escaped() {
+    let ident = sql::Ident::new("my`table");
+
+    assert_eq!("`my``table`", ident.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn identifier_should_be_quoted_in_the_alter_table() {
+    let query = sql::AlterTable::new()
+      .alter_table(sql::Ident::new("order"))
+      .drop(sql::Fragment::from("COLUMN ").push(sql::Ident::new("key")))
+      .to_sql(sql::Dialect::Mysql);
+    let expected_query = "ALTER TABLE `order` DROP COLUMN `key`";
+
+    assert_eq!(expected_query, query);
+  }
+}
@@ -0,0 +1,509 @@
+mod clauses {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_clauses_should_list_the_clauses_in_the_order_they_are_rendered() {
+    let select = sql::Select::new()
+      .order_by("login")
+      .where_clause("active = true")
+      .from("users")
+      .select("id, login");
+
+    let expected = vec![
+      sql::SelectClause::Select,
+      sql::SelectClause::From,
+      sql::SelectClause::Where,
+      sql::SelectClause::OrderBy,
+    ];
+
+    assert_eq!(expected, select.clauses());
+  }
+
+  #[test]
+  fn method_clauses_should_not_consider_the_raw_sql() {
+    let select = sql::Select::new()
+      .raw("SELECT id FROM users")
+      .raw_after(sql::SelectClause::Where, "LIMIT 1");
+
+    assert_eq!(Vec::<sql::SelectClause>::new(), select.clauses());
+  }
+
+  #[test]
+  fn method_clauses_should_be_empty_when_the_builder_is_empty() {
+    assert_eq!(Vec::<sql::UpdateClause>::new(), sql::Update::new().clauses());
+    assert_eq!(Vec::<sql::DeleteClause>::new(), sql::Delete::new().clauses());
+    assert_eq!(Vec::<sql::CreateTableParams>::new(), sql::CreateTable::new().clauses());
+  }
+
+  #[test]
+  fn method_clauses_should_list_the_select_of_the_insert() {
+    let insert = sql::Insert::new()
+      .insert_into("users_archive (id, login)")
+      .select(sql::Select::new().select("id, login").from("users"));
+
+    let expected = vec![sql::InsertClause::InsertInto, sql::InsertClause::Select];
+
+    assert_eq!(expected, insert.clauses());
+  }
+
+  #[test]
+  fn method_clauses_should_list_the_typed_actions_of_the_alter_table_by_kind() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age int")
+      .add_constraint("users_age_check CHECK (age > 0)");
+
+    let expected = vec![sql::AlterTableAction::AlterTable, sql::AlterTableAction::Add];
+
+    assert_eq!(expected, alter_table.clauses());
+  }
+}
+
+mod getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn getters_of_the_select_should_return_the_items_of_each_clause() {
+    let select = sql::Select::new()
+      .select("status, count(*)")
+      .from("orders")
+      .group_by("status")
+      .having("count(*) > 1")
+      .order_by("status");
+
+    assert_eq!(["status, count(*)"], select.get_select());
+    assert_eq!(["orders"], select.get_from());
+    assert_eq!(["status"], select.get_group_by());
+    assert_eq!(["count(*) > 1"], select.get_having());
+    assert_eq!(["status"], select.get_order_by());
+    assert_eq!(0, select.get_window().len());
+  }
+
+  #[test]
+  fn method_get_where_should_return_the_logical_operator_of_each_condition() {
+    let select = sql::Select::new()
+      .where_clause("tenant_id = 1")
+      .where_or("public = true")
+      .where_and("deleted = false");
+
+    let expected = [
+      (sql::LogicalOperator::And, sql::Fragment::from("tenant_id = 1")),
+      (sql::LogicalOperator::Or, sql::Fragment::from("public = true")),
+      (sql::LogicalOperator::And, sql::Fragment::from("deleted = false")),
+    ];
+
+    assert_eq!(expected, select.get_where());
+  }
+
+  #[test]
+  fn getters_of_optional_clauses_should_return_none_when_the_clause_is_not_defined() {
+    assert_eq!(None, sql::Insert::new().get_insert_into());
+    assert_eq!(None, sql::Update::new().get_update());
+    assert_eq!(None, sql::Delete::new().get_delete_from());
+    assert_eq!(None, sql::Merge::new().get_using());
+    assert!(sql::Insert::new().get_select().is_none());
+  }
+
+  #[test]
+  fn getters_should_borrow_the_state_of_the_builder() {
+    let update = sql::Update::new().update("users").set("login = 'foo'");
+
+    let first = update.get_set();
+    let second = update.get_set();
+
+    assert_eq!(first.as_ptr(), second.as_ptr());
+  }
+
+  #[test]
+  fn method_get_select_of_the_insert_should_return_the_select() {
+    let insert = sql::Insert::new()
+      .insert_into("users_archive (id)")
+      .select(sql::Select::new().select("id").from("users"));
+
+    let select = insert.get_select().unwrap();
+
+    assert_eq!("SELECT id FROM users", select.as_string());
+  }
+
+  #[test]
+  fn getters_of_the_ddl_builders_should_return_the_items_of_each_param() {
+    let create_table = sql::CreateTable::new()
+      .create_table_if_not_exists("users")
+      .column("id serial")
+      .primary_key("(id)");
+    let drop_table = sql::DropTable::new().drop_table("users").drop_table("orders");
+
+    assert_eq!(
+      Some(&sql::Fragment::from("IF NOT EXISTS users")),
+      create_table.get_create_table()
+    );
+    assert_eq!(Some(&sql::Fragment::from("(id)")), create_table.get_primary_key());
+    assert_eq!(["users", "orders"], drop_table.get_drop_table());
+    assert_eq!(false, drop_table.get_if_exists());
+  }
+
+  #[test]
+  fn getters_should_return_the_identifiers_and_the_values_bound_rendered_in_the_dialect() {
+    let select = sql::Select::new()
+      .from(sql::Ident::new("users"))
+      .where_clause_bind("id = ?", 1);
+
+    assert_eq!(r#""users""#, select.get_from()[0].to_sql(sql::Dialect::Standard));
+    assert_eq!("id = ?", select.get_where()[0].1.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn method_get_join_should_return_the_joins_of_the_select() {
+    let select = sql::Select::new()
+      .from("users")
+      .left_join("addresses ON addresses.user_id = users.id")
+      .join(sql::Join::inner("roles").using("role_id"));
+
+    let joins = select
+      .get_join()
+      .iter()
+      .map(|join| join.to_string())
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      vec![
+        "LEFT JOIN addresses ON addresses.user_id = users.id",
+        "INNER JOIN roles USING (role_id)"
+      ],
+      joins
+    );
+  }
+
+  #[test]
+  fn getters_of_the_transaction_should_return_the_argument_of_each_command() {
+    let transaction = sql::Transaction::new()
+      .select(sql::Select::new().select("1"))
+      .commit("WORK");
+
+    assert_eq!(Some(&sql::Fragment::from("WORK")), transaction.get_commit());
+    assert_eq!(None, sql::Transaction::new().get_commit());
+  }
+}
+
+mod tables {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_tables_should_ignore_the_aliases_of_the_tables() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users u, orders AS o")
+      .from("public.addresses a");
+
+    assert_eq!(vec!["users", "orders", "public.addresses"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_include_the_tables_of_the_joins() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .left_join("addresses ON addresses.user_id = users.id")
+      .join(sql::Join::inner("roles").using("role_id"));
+
+    assert_eq!(vec!["users", "addresses", "roles"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_include_the_tables_of_the_sub_queries() {
+    let select = sql::Select::new()
+      .select("u.login")
+      .from_subquery("u", sql::Select::new().select("*").from("users"))
+      .where_exists(sql::Select::new().select("1").from("admins"))
+      .where_clause("u.id IN (SELECT user_id FROM sessions)");
+
+    assert_eq!(vec!["users", "admins", "sessions"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_list_each_table_once() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_in("manager_id", sql::Select::new().select("id").from("users"));
+
+    assert_eq!(vec!["users"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_return_the_quoted_names_without_quotes() {
+    let select = sql::Select::new()
+      .select("*")
+      .from(sql::Ident::qualified(&["shop", "order"]))
+      .inner_join("\"user \"\"x\"\"\" ON true");
+
+    assert_eq!(vec!["shop.order", "user \"x\""], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_ignore_the_functions_and_the_keyword_from_inside_them() {
+    let select = sql::Select::new()
+      .select("extract(year FROM created_at), substring(login FROM 2)")
+      .from("generate_series(1, 10) g, users");
+
+    assert_eq!(vec!["users"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_ignore_the_string_literals_and_comments() {
+    let select = sql::Select::new()
+      .select("'FROM secrets'")
+      .from("users /* JOIN secrets */");
+
+    assert_eq!(vec!["users"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_ignore_the_from_of_the_is_distinct_from_operator() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("orders")
+      .where_clause("status IS DISTINCT FROM previous_status")
+      .where_clause("total IS NOT DISTINCT FROM (SELECT total FROM invoices WHERE paid IS DISTINCT FROM false)");
+
+    assert_eq!(vec!["orders", "invoices"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_read_only_the_sub_queries_of_the_clauses_that_are_not_tables() {
+    let select = sql::Select::new()
+      .select("trim(BOTH ' ' FROM login)")
+      .from("users")
+      .order_by("position(':' IN login)");
+
+    assert_eq!(vec!["users"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_include_the_tables_named_as_keywords() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("work")
+      .from("key AS k, view")
+      .inner_join("index ON index.id = work.index_id");
+
+    assert_eq!(vec!["work", "key", "view", "index"], select.tables());
+    assert_eq!(vec!["work"], sql::Select::new().from("work").tables());
+  }
+
+  #[test]
+  fn method_tables_of_the_insert_should_include_the_table_written() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values("('foo', 'Foo')");
+
+    assert_eq!(vec!["users"], insert.tables());
+  }
+
+  #[test]
+  fn method_tables_of_the_insert_should_include_the_table_followed_by_the_columns() {
+    let insert = sql::Insert::new().insert_into("users(login)").values("('foo')");
+
+    assert_eq!(vec!["users"], insert.tables());
+  }
+
+  #[test]
+  fn method_tables_of_the_update_should_include_the_table_written() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("login = (SELECT login FROM staging WHERE staging.id = users.id)");
+
+    assert_eq!(vec!["users", "staging"], update.tables());
+  }
+
+  #[test]
+  fn method_tables_of_the_merge_should_not_include_the_update_of_the_when_clause() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("staging_customers s")
+      .on("c.id = s.id")
+      .when_matched_then_update("name = s.name")
+      .when_not_matched_then_insert("(id, name)", "(s.id, s.name)");
+
+    assert_eq!(vec!["customers", "staging_customers"], merge.tables());
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn getters_of_the_dialect_clauses_should_return_the_items_of_each_clause() {
+    let select = sql::Select::new().select("id").from("users").limit("10").offset("20");
+    let delete = sql::Delete::new().delete_from("users").returning("id");
+
+    assert_eq!(Some(&sql::Fragment::from("10")), select.get_limit());
+    assert_eq!(Some(&sql::Fragment::from("20")), select.get_offset());
+    assert_eq!(["id"], delete.get_returning());
+  }
+
+  #[test]
+  fn method_get_with_should_return_the_names_of_the_common_table_expressions() {
+    let select = sql::Select::new()
+      .with("admins", sql::Select::new().select("id").from("users"))
+      .with("guests", sql::Select::new().select("id").from("visitors"))
+      .select("id")
+      .from("admins");
+
+    assert_eq!(
+      vec![&sql::Fragment::from("admins"), &sql::Fragment::from("guests")],
+      select.get_with()
+    );
+  }
+
+  #[test]
+  fn getters_of_the_transaction_should_return_the_mode_of_the_begin_and_end() {
+    let transaction = sql::Transaction::new().begin("ISOLATION LEVEL SERIALIZABLE").end("");
+
+    assert_eq!(
+      Some(&sql::Fragment::from("ISOLATION LEVEL SERIALIZABLE")),
+      transaction.get_begin()
+    );
+    assert_eq!(Some(&sql::Fragment::from("")), transaction.get_end());
+  }
+
+  #[test]
+  fn method_clauses_should_list_the_with_and_the_combinators() {
+    let select = sql::Select::new()
+      .with("admins", sql::Select::new().select("id").from("users"))
+      .select("id")
+      .from("admins")
+      .limit("1")
+      .union(sql::Select::new().select("id").from("guests"));
+
+    let expected = vec![
+      sql::SelectClause::With,
+      sql::SelectClause::Select,
+      sql::SelectClause::From,
+      sql::SelectClause::Limit,
+      sql::SelectClause::Union,
+    ];
+
+    assert_eq!(expected, select.clauses());
+  }
+
+  #[test]
+  fn method_tables_should_not_include_the_common_table_expressions() {
+    let select = sql::Select::new()
+      .with("admins", sql::Select::new().select("user_id").from("roles"))
+      .select("*")
+      .from("admins")
+      .inner_join("users ON users.id = admins.user_id");
+
+    assert_eq!(vec!["roles", "users"], select.tables());
+  }
+
+  #[test]
+  fn method_tables_should_not_include_the_columns_of_the_on_conflict_update() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_conflict("(login) DO UPDATE SET login = excluded.login");
+
+    assert_eq!(vec!["users"], insert.tables());
+  }
+
+  #[test]
+  fn method_tables_should_include_the_tables_of_the_update_from() {
+    let update = sql::Update::new()
+      .update("ONLY users")
+      .set("name = staging.name")
+      .from("staging")
+      .where_clause("staging.id = users.id");
+
+    assert_eq!(vec!["users", "staging"], update.tables());
+    assert_eq!(["staging"], update.get_from());
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_clauses_should_list_the_update_or() {
+    let update = sql::Update::new().update_or("REPLACE users").set("login = 'foo'");
+
+    let expected = vec![sql::UpdateClause::UpdateOr, sql::UpdateClause::Set];
+
+    assert_eq!(expected, update.clauses());
+    assert_eq!(Some(&sql::Fragment::from("REPLACE users")), update.get_update());
+  }
+
+  #[test]
+  fn method_tables_should_skip_the_conflict_resolution_of_the_update_or() {
+    let update = sql::Update::new().update_or("REPLACE users").set("login = 'foo'");
+
+    assert_eq!(vec!["users"], update.tables());
+  }
+
+  #[test]
+  fn method_tables_should_include_the_table_of_the_insert_or() {
+    let insert = sql::Insert::new()
+      .insert_or("REPLACE INTO users (login)")
+      .values("('foo')");
+
+    assert_eq!(
+      vec![sql::InsertClause::InsertOr, sql::InsertClause::Values],
+      insert.clauses()
+    );
+    assert_eq!(vec!["users"], insert.tables());
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_clauses_should_list_the_split_insert() {
+    let insert = sql::Insert::new()
+      .insert("low_priority")
+      .into("users")
+      .column("login")
+      .row("('foo')");
+
+    let expected = vec![
+      sql::InsertClause::Insert,
+      sql::InsertClause::Into,
+      sql::InsertClause::Column,
+      sql::InsertClause::Values,
+    ];
+
+    assert_eq!(expected, insert.clauses());
+    assert_eq!(["login"], insert.get_column());
+  }
+
+  #[test]
+  fn method_tables_should_not_include_the_columns_of_the_on_duplicate_key_update() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_duplicate_key_update("login = VALUES(login)");
+
+    assert_eq!(vec!["users"], insert.tables());
+  }
+
+  #[test]
+  fn getters_of_the_delete_should_return_the_limit_and_the_order_by() {
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .order_by("created_at")
+      .limit("10");
+
+    assert_eq!(Some(&sql::Fragment::from("10")), delete.get_limit());
+    assert_eq!(["created_at"], delete.get_order_by());
+  }
+}
@@ -0,0 +1,271 @@
+mod literal {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn text_literal_should_escape_the_single_quotes() {
+    let literal = sql::Literal::new("o'neil");
+
+    assert_eq!("'o''neil'", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn owned_text_should_be_rendered_as_text_literal() {
+    let literal = sql::Literal::new("foo".to_string());
+
+    assert_eq!("'foo'", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn numbers_should_be_rendered_without_quotes() {
+    assert_eq!("42", sql::Literal::new(42).to_sql(sql::Dialect::Standard));
+    assert_eq!("-7", sql::Literal::new(-7_i64).to_sql(sql::Dialect::Standard));
+    assert_eq!("0.5", sql::Literal::new(0.5).to_sql(sql::Dialect::Standard));
+    assert_eq!("100.0", sql::Literal::new(100_f32).to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn not_finite_floats_should_be_rendered_as_null_in_the_sql_standard() {
+    let literal = sql::Literal::new(f64::NAN);
+
+    assert_eq!("NULL", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn booleans_should_be_rendered_as_keywords_in_the_sql_standard() {
+    assert_eq!("TRUE", sql::Literal::new(true).to_sql(sql::Dialect::Standard));
+    assert_eq!("FALSE", sql::Literal::new(false).to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn none_should_be_rendered_as_null() {
+    let literal = sql::Literal::new(None::<&str>);
+
+    assert_eq!("NULL", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn some_should_be_rendered_as_the_inner_value() {
+    let literal = sql::Literal::new(Some("foo"));
+
+    assert_eq!("'foo'", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn bytes_should_be_rendered_as_hexadecimal_string_in_the_sql_standard() {
+    let bytes: &[u8] = &[0xca, 0xfe];
+    let literal = sql::Literal::new(bytes);
+
+    assert_eq!("X'cafe'", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn value_should_be_rendered_as_literal() {
+    let literal = sql::Literal::new(sql::Value::Text("foo".to_string()));
+
+    assert_eq!("'foo'", literal.to_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn literal_should_be_accepted_by_the_builder_methods_that_takes_expressions() {
+    let login = sql::Literal::new("o'neil");
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause(sql::Fragment::from("login = ").push(&login))
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "SELECT id FROM users WHERE login = 'o''neil'";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn literal_should_be_rendered_in_the_inner_queries() {
+    let query = sql::Transaction::new()
+      .insert(
+        sql::Insert::new()
+          .insert_into("users (login)")
+          .values(sql::Fragment::from("(").push(sql::Literal::new("foo")).push(")")),
+      )
+      .commit("")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "INSERT INTO users (login) VALUES ('foo'); COMMIT;";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn markers_inside_the_text_should_not_be_interpreted() {
+    let text = "\u{5}6e616d65\u{6}";
+    let literal = sql::Literal::new(text);
+    let query = sql::Select::new().select(&literal).to_sql(sql::Dialect::Standard);
+    let expected_query = format!("SELECT '{text}'");
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn trait_display_should_render_the_literal_in_the_default_dialect() {
+    let literal = sql::Literal::new("foo");
+
+    assert_eq!(literal.as_string(), format!("{literal}"));
+  }
+
+  #[test]
+  fn trait_to_sql_literal_should_be_implementable_by_custom_types() {
+    struct Money(i64);
+
+    impl sql::ToSqlLiteral for Money {
+      fn to_sql_literal(&self, _dialect: sql::Dialect) -> String {
+        format!("{}.{:02}", self.0 / 100, self.0 % 100)
+      }
+    }
+
+    let literal = sql::Literal::new(Money(1050));
+
+    assert_eq!("10.50", literal.to_sql(sql::Dialect::Standard));
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_literal {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn text_with_backslashes_should_be_rendered_as_escape_string() {
+    let literal = sql::Literal::new("c:\\temp 'x'");
+
+    assert_eq!("E'c:\\\\temp ''x'''", literal.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn text_without_backslashes_should_be_rendered_as_simple_string() {
+    let literal = sql::Literal::new("it's");
+
+    assert_eq!("'it''s'", literal.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn bytes_should_be_rendered_as_bytea_hexadecimal_format() {
+    let literal = sql::Literal::new(vec![0xde_u8, 0xad]);
+
+    assert_eq!("'\\xdead'", literal.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn not_finite_floats_should_be_rendered_as_the_special_values() {
+    assert_eq!("'NaN'", sql::Literal::new(f64::NAN).to_sql(sql::Dialect::Postgresql));
+    assert_eq!(
+      "'Infinity'",
+      sql::Literal::new(f64::INFINITY).to_sql(sql::Dialect::Postgresql)
+    );
+    assert_eq!(
+      "'-Infinity'",
+      sql::Literal::new(f64::NEG_INFINITY).to_sql(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[test]
+  fn literal_should_be_rendered_in_the_dialect_of_the_query() {
+    let query = sql::Select::new()
+      .where_clause(sql::Fragment::from("path = ").push(sql::Literal::new("a\\b")))
+      .as_string();
+    let expected_query = "WHERE path = E'a\\\\b'";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_literal {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn bytes_should_be_rendered_as_blob_literal() {
+    let literal = sql::Literal::new(vec![0xde_u8, 0xad]);
+
+    assert_eq!("X'dead'", literal.to_sql(sql::Dialect::Sqlite));
+  }
+
+  #[test]
+  fn booleans_should_be_rendered_as_integers() {
+    assert_eq!("1", sql::Literal::new(true).to_sql(sql::Dialect::Sqlite));
+    assert_eq!("0", sql::Literal::new(false).to_sql(sql::Dialect::Sqlite));
+  }
+
+  #[test]
+  fn text_with_backslashes_should_not_be_escaped() {
+    let literal = sql::Literal::new("c:\\temp");
+
+    assert_eq!("'c:\\temp'", literal.to_sql(sql::Dialect::Sqlite));
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_literal {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn backslashes_should_be_escaped() {
+    let literal = sql::Literal::new("c:\\temp 'x'");
+
+    assert_eq!("'c:\\\\temp ''x'''", literal.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn bytes_should_be_rendered_as_hexadecimal_literal() {
+    let literal = sql::Literal::new(vec![0xde_u8, 0xad]);
+
+    assert_eq!("X'dead'", literal.to_sql(sql::Dialect::Mysql));
+  }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_literal {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn date_should_be_rendered_as_typed_literal() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+
+    assert_eq!(
+      "DATE '2024-01-31'",
+      sql::Literal::new(date).to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn time_should_be_rendered_as_typed_literal() {
+    let time = chrono::NaiveTime::from_hms_opt(13, 45, 0).unwrap();
+
+    assert_eq!(
+      "TIME '13:45:00'",
+      sql::Literal::new(time).to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[test]
+  fn date_time_should_be_rendered_as_timestamp_literal() {
+    let date_time = chrono::NaiveDate::from_ymd_opt(2024, 1, 31)
+      .unwrap()
+      .and_hms_opt(13, 45, 0)
+      .unwrap();
+
+    assert_eq!(
+      "TIMESTAMP '2024-01-31 13:45:00'",
+      sql::Literal::new(date_time).to_sql(sql::Dialect::Standard)
+    );
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn date_should_be_rendered_as_text_in_sqlite() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+
+    assert_eq!("'2024-01-31'", sql::Literal::new(date).to_sql(sql::Dialect::Sqlite));
+  }
+}
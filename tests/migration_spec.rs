@@ -0,0 +1,325 @@
+mod migration {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn create_users() -> sql::Migration {
+    sql::Migration::new(
+      1,
+      "create_users",
+      sql::Transaction::new().create_table(sql::CreateTable::new().create_table("users").column("login text")),
+      sql::Transaction::new().drop_table(sql::DropTable::new().drop_table("users")),
+    )
+  }
+
+  #[test]
+  fn method_up_sql_should_apply_the_migration_and_register_the_version() {
+    let query = create_users().up_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      START TRANSACTION; \
+      CREATE TABLE users (login text); \
+      INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users'); \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_down_sql_should_revert_the_migration_and_remove_the_version() {
+    let query = create_users().down_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      START TRANSACTION; \
+      DROP TABLE users; \
+      DELETE FROM schema_migrations WHERE version = 1; \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_up_sql_should_register_the_version_when_the_transaction_is_empty() {
+    let migration = sql::Migration::new(7, "noop", sql::Transaction::new(), sql::Transaction::new());
+    let expected_query = "\
+      START TRANSACTION; \
+      INSERT INTO schema_migrations (version, name) VALUES (7, 'noop'); \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, migration.up_sql(sql::Dialect::Standard));
+  }
+
+  #[cfg(not(feature = "sqlite"))]
+  #[test]
+  fn method_up_sql_should_not_nest_the_block_of_the_transaction() {
+    let migration = sql::Migration::new(
+      1,
+      "create_users",
+      sql::Transaction::new()
+        .start_transaction("")
+        .create_table(sql::CreateTable::new().create_table("users").column("login text"))
+        .commit("WORK"),
+      sql::Transaction::new(),
+    );
+    let expected_query = "\
+      START TRANSACTION; \
+      CREATE TABLE users (login text); \
+      INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users'); \
+      COMMIT WORK;\
+    ";
+
+    assert_eq!(expected_query, migration.up_sql(sql::Dialect::Standard));
+  }
+
+  #[cfg(not(feature = "sqlite"))]
+  #[test]
+  fn method_down_sql_should_close_the_block_opened_by_the_transaction() {
+    let migration = sql::Migration::new(
+      1,
+      "create_users",
+      sql::Transaction::new(),
+      sql::Transaction::new()
+        .start_transaction("")
+        .drop_table(sql::DropTable::new().drop_table("users")),
+    );
+    let expected_query = "\
+      START TRANSACTION; \
+      DROP TABLE users; \
+      DELETE FROM schema_migrations WHERE version = 1; \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, migration.down_sql(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn the_name_of_the_migration_should_be_escaped() {
+    let migration = sql::Migration::new(2, "fix user's login", sql::Transaction::new(), sql::Transaction::new());
+    let expected_query = "INSERT INTO schema_migrations (version, name) VALUES (2, 'fix user''s login');";
+
+    assert_eq!(expected_query, migration.insert_version_sql(sql::Dialect::Standard));
+  }
+}
+
+mod migration_set {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn migration(version: u64, table_name: &str) -> sql::Migration {
+    sql::Migration::new(
+      version,
+      &format!("create_{table_name}"),
+      sql::Transaction::new().create_table(sql::CreateTable::new().create_table(table_name).column("id int")),
+      sql::Transaction::new().drop_table(sql::DropTable::new().drop_table(table_name)),
+    )
+  }
+
+  #[test]
+  fn method_create_table_sql_should_create_the_bookkeeping_table() {
+    let query = sql::MigrationSet::new().create_table_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      CREATE TABLE IF NOT EXISTS schema_migrations (\
+        version BIGINT NOT NULL PRIMARY KEY, \
+        name VARCHAR(255) NOT NULL, \
+        applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP\
+      );\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_migration_should_sort_the_migrations_by_version() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(2, "orders"))
+      .migration(migration(1, "users"));
+
+    let query = migrations.up_sql(sql::Dialect::Standard, 0).unwrap();
+    let expected_query = "\
+      START TRANSACTION; \
+      CREATE TABLE users (id int); \
+      INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users'); \
+      COMMIT; \
+      START TRANSACTION; \
+      CREATE TABLE orders (id int); \
+      INSERT INTO schema_migrations (version, name) VALUES (2, 'create_orders'); \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_up_sql_should_render_only_the_pending_migrations() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(1, "users"))
+      .migration(migration(2, "orders"))
+      .migration(migration(3, "items"));
+
+    let query = migrations.up_sql(sql::Dialect::Standard, 2).unwrap();
+
+    assert!(query.contains("CREATE TABLE items"));
+    assert!(query.contains("CREATE TABLE users") == false);
+    assert!(query.contains("CREATE TABLE orders") == false);
+  }
+
+  #[test]
+  fn method_up_sql_should_render_nothing_when_all_migrations_are_applied() {
+    let migrations = sql::MigrationSet::new().migration(migration(1, "users"));
+
+    assert_eq!(Ok("".to_string()), migrations.up_sql(sql::Dialect::Standard, 1));
+  }
+
+  #[test]
+  fn method_down_sql_should_revert_the_migrations_in_descending_order() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(1, "users"))
+      .migration(migration(2, "orders"))
+      .migration(migration(3, "items"));
+
+    let query = migrations.down_sql(sql::Dialect::Standard, 3, 1).unwrap();
+    let expected_query = "\
+      START TRANSACTION; \
+      DROP TABLE items; \
+      DELETE FROM schema_migrations WHERE version = 3; \
+      COMMIT; \
+      START TRANSACTION; \
+      DROP TABLE orders; \
+      DELETE FROM schema_migrations WHERE version = 2; \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_validate_should_accept_consecutive_versions_starting_at_any_version() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(20, "users"))
+      .migration(migration(21, "orders"));
+
+    assert_eq!(Ok(()), migrations.validate());
+  }
+
+  #[test]
+  fn method_validate_should_report_duplicated_versions() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(1, "users"))
+      .migration(migration(2, "orders"))
+      .migration(migration(2, "items"));
+    let error = migrations.validate().unwrap_err();
+
+    assert_eq!(sql::BuildError::DuplicateMigration { version: 2 }, error);
+    assert_eq!("the migration version 2 is duplicated", error.to_string());
+  }
+
+  #[test]
+  fn method_validate_should_report_gaps_between_versions() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(1, "users"))
+      .migration(migration(4, "orders"));
+    let error = migrations.validate().unwrap_err();
+
+    assert_eq!(sql::BuildError::MissingMigration { version: 2 }, error);
+    assert_eq!("the migration version 2 is missing", error.to_string());
+  }
+
+  #[test]
+  fn methods_up_sql_and_down_sql_should_validate_the_versions() {
+    let migrations = sql::MigrationSet::new()
+      .migration(migration(1, "users"))
+      .migration(migration(3, "orders"));
+    let expected_error = Err(sql::BuildError::MissingMigration { version: 2 });
+
+    assert_eq!(expected_error, migrations.up_sql(sql::Dialect::Standard, 0));
+    assert_eq!(expected_error, migrations.down_sql(sql::Dialect::Standard, 3, 0));
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_migration {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn migrations_should_be_wrapped_by_begin_and_commit() {
+    let migration = sql::Migration::new(1, "noop", sql::Transaction::new(), sql::Transaction::new());
+    let expected_query = "BEGIN; DELETE FROM schema_migrations WHERE version = 1; COMMIT;";
+
+    assert_eq!(expected_query, migration.down_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn migrations_should_use_the_begin_and_end_of_the_transaction() {
+    let migration = sql::Migration::new(
+      1,
+      "create_users",
+      sql::Transaction::new()
+        .begin("")
+        .create_table(sql::CreateTable::new().create_table("users").column("login text"))
+        .end(""),
+      sql::Transaction::new(),
+    );
+    let expected_query = "\
+      BEGIN; \
+      CREATE TABLE users (login text); \
+      INSERT INTO schema_migrations (version, name) VALUES (1, 'create_users'); \
+      END;\
+    ";
+
+    assert_eq!(expected_query, migration.up_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn method_create_table_sql_should_render_the_postgres_types() {
+    let query = sql::MigrationSet::new().create_table_sql(sql::Dialect::Postgresql);
+
+    assert!(query.contains("applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP"));
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_migration {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_table_sql_should_render_the_sqlite_types() {
+    let query = sql::MigrationSet::new().create_table_sql(sql::Dialect::Sqlite);
+    let expected_query = "\
+      CREATE TABLE IF NOT EXISTS schema_migrations (\
+        version INTEGER NOT NULL PRIMARY KEY, \
+        name TEXT NOT NULL, \
+        applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP\
+      );\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn migrations_should_be_wrapped_by_begin_and_commit() {
+    let migration = sql::Migration::new(1, "noop", sql::Transaction::new(), sql::Transaction::new());
+    let expected_query = "BEGIN; INSERT INTO schema_migrations (version, name) VALUES (1, 'noop'); COMMIT;";
+
+    assert_eq!(expected_query, migration.up_sql(sql::Dialect::Sqlite));
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_migration {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn migrations_should_be_wrapped_by_start_transaction_and_commit() {
+    let migration = sql::Migration::new(1, "noop", sql::Transaction::new(), sql::Transaction::new());
+    let expected_query = "\
+      START TRANSACTION; \
+      INSERT INTO schema_migrations (version, name) VALUES (1, 'noop'); \
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, migration.up_sql(sql::Dialect::Mysql));
+  }
+}
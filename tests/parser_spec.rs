@@ -0,0 +1,565 @@
+mod select_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn clauses_should_be_parsed_into_the_builder() {
+    let query = "\
+      SELECT id, login \
+      FROM users \
+      WHERE active = true AND age > 18 \
+      GROUP BY id, login \
+      HAVING count(*) > 1 \
+      WINDOW w AS (PARTITION BY login) \
+      ORDER BY login, id desc\
+    ";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!(query, select.as_string());
+  }
+
+  #[test]
+  fn parsed_select_should_be_extended_by_the_builder_methods() {
+    let select = sql::Select::parse("SELECT id FROM users WHERE active = true")
+      .unwrap()
+      .select("login")
+      .where_clause("login = $1")
+      .order_by("id");
+
+    let expected = "SELECT id, login FROM users WHERE active = true AND login = $1 ORDER BY id";
+
+    assert_eq!(expected, select.as_string());
+  }
+
+  #[test]
+  fn keywords_should_be_case_insensitive() {
+    let select = sql::Select::parse("select id from users where id = 1 order by id").unwrap();
+
+    assert_eq!("SELECT id FROM users WHERE id = 1 ORDER BY id", select.as_string());
+  }
+
+  #[test]
+  fn comments_whitespaces_and_the_trailing_semicolon_should_be_removed() {
+    let query = "
+      -- active users
+      SELECT id,   login
+      FROM users /* the table */
+      WHERE login = 'a  b';
+    ";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!("SELECT id, login FROM users WHERE login = 'a  b'", select.as_string());
+  }
+
+  #[test]
+  fn keywords_inside_quotes_parentheses_and_case_expressions_should_not_split_the_clauses() {
+    let query = "\
+      SELECT 'from here', \"where\", (SELECT max(id) FROM orders WHERE orders.user_id = users.id) \
+      FROM users \
+      WHERE CASE WHEN active AND admin THEN 1 ELSE 0 END = 1 AND login <> ''\
+    ";
+    let select = sql::Select::parse(query).unwrap().where_clause("id = 1");
+
+    let expected = format!("{query} AND id = 1");
+
+    assert_eq!(expected, select.as_string());
+  }
+
+  #[test]
+  fn conditions_with_the_or_operator_should_be_wrapped_in_parentheses() {
+    let select = sql::Select::parse("SELECT id FROM users WHERE login = 'foo' OR login = 'bar'")
+      .unwrap()
+      .where_clause("active = true");
+
+    let expected = "SELECT id FROM users WHERE (login = 'foo' OR login = 'bar') AND active = true";
+
+    assert_eq!(expected, select.as_string());
+  }
+
+  #[test]
+  fn the_and_operator_of_the_between_predicate_should_be_kept() {
+    let select = sql::Select::parse("SELECT id FROM orders WHERE total BETWEEN 10 AND 20 AND paid = true")
+      .unwrap()
+      .where_or("total = 0");
+
+    let expected = "SELECT id FROM orders WHERE total BETWEEN 10 AND 20 AND paid = true OR total = 0";
+
+    assert_eq!(expected, select.as_string());
+  }
+
+  #[test]
+  fn the_from_of_the_is_distinct_from_predicate_should_not_split_the_clauses() {
+    let query = "SELECT id FROM users WHERE login IS DISTINCT FROM name";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!(query, select.as_string());
+  }
+
+  #[test]
+  fn joins_should_be_parsed_into_the_builder() {
+    let query = "\
+      SELECT * \
+      FROM users u \
+      LEFT OUTER JOIN addresses a ON a.user_id = u.id AND a.main = true \
+      JOIN roles USING (role_id, app_id) \
+      NATURAL RIGHT JOIN teams\
+    ";
+    let select = sql::Select::parse(query).unwrap();
+
+    let expected = "\
+      SELECT * \
+      FROM users u \
+      LEFT JOIN addresses a ON a.user_id = u.id AND a.main = true \
+      INNER JOIN roles USING (role_id, app_id) \
+      NATURAL RIGHT JOIN teams\
+    ";
+
+    assert_eq!(expected, select.as_string());
+  }
+
+  #[test]
+  fn parsed_joins_should_be_compared_with_the_joins_of_the_builder() {
+    let select = sql::Select::parse("SELECT * FROM users INNER JOIN roles USING (role_id)")
+      .unwrap()
+      .join(sql::Join::inner("roles").using("role_id"));
+
+    assert_eq!(
+      "SELECT * FROM users INNER JOIN roles USING (role_id)",
+      select.as_string()
+    );
+  }
+
+  #[test]
+  fn text_of_other_commands_should_return_an_error() {
+    let error = sql::Select::parse("UPDATE users SET login = 'foo'").unwrap_err();
+
+    let expected = sql::ParseError::UnexpectedStatement {
+      expected: "SELECT".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn text_before_the_first_clause_should_return_an_error() {
+    let error = sql::Select::parse("EXPLAIN SELECT id FROM users").unwrap_err();
+
+    let expected = sql::ParseError::UnexpectedStatement {
+      expected: "SELECT".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn clauses_out_of_order_should_return_an_error() {
+    let error = sql::Select::parse("SELECT id WHERE id = 1 FROM users").unwrap_err();
+
+    let expected = sql::ParseError::MisplacedClause {
+      clause: "FROM".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn repeated_clauses_should_return_an_error() {
+    let error = sql::Select::parse("SELECT id FROM users WHERE id = 1 WHERE id = 2").unwrap_err();
+
+    let expected = sql::ParseError::MisplacedClause {
+      clause: "WHERE".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn clauses_without_builder_method_should_return_an_error() {
+    let locking = sql::Select::parse("SELECT id FROM users FOR UPDATE").unwrap_err();
+    let fetch = sql::Select::parse("SELECT id FROM users FETCH FIRST 10 ROWS ONLY").unwrap_err();
+
+    let unsupported = |clause: &str| sql::ParseError::UnsupportedClause {
+      clause: clause.to_string(),
+    };
+
+    assert_eq!(unsupported("FOR UPDATE"), locking);
+    assert_eq!(unsupported("FETCH FIRST"), fetch);
+  }
+
+  #[test]
+  fn qualified_names_should_not_be_keywords() {
+    let select = sql::Select::parse("SELECT t.limit, t.offset FROM t WHERE t.from = 1").unwrap();
+
+    let expected_query = "SELECT t.limit, t.offset FROM t WHERE t.from = 1";
+
+    assert_eq!(expected_query, select.as_string());
+  }
+
+  #[test]
+  fn clauses_without_body_should_return_an_error() {
+    let error = sql::Select::parse("SELECT").unwrap_err();
+
+    let expected = sql::ParseError::EmptyClause {
+      clause: "SELECT".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn unbalanced_parentheses_should_return_an_error_with_its_position() {
+    let error = sql::Select::parse("SELECT count(id FROM users").unwrap_err();
+
+    assert_eq!(sql::ParseError::Unbalanced { position: 12 }, error);
+
+    let error = sql::Select::parse("SELECT id) FROM users").unwrap_err();
+
+    assert_eq!(sql::ParseError::Unbalanced { position: 9 }, error);
+  }
+
+  #[test]
+  fn unterminated_quotes_and_comments_should_return_an_error_with_its_position() {
+    let error = sql::Select::parse("SELECT id FROM users WHERE login = 'foo").unwrap_err();
+
+    assert_eq!(sql::ParseError::Unbalanced { position: 35 }, error);
+
+    let error = sql::Select::parse("SELECT id /* FROM users").unwrap_err();
+
+    assert_eq!(sql::ParseError::Unbalanced { position: 10 }, error);
+  }
+
+  #[test]
+  fn trait_display_should_describe_the_error() {
+    let error = sql::Select::parse("SELECT id WHERE id = 1 FROM users").unwrap_err();
+
+    assert_eq!("the FROM clause is repeated or out of order", error.to_string());
+  }
+}
+
+#[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql")))]
+mod standard_select_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn clauses_without_builder_method_should_return_an_error() {
+    let error = sql::Select::parse("SELECT id FROM users LIMIT 10").unwrap_err();
+
+    let expected = sql::ParseError::UnsupportedClause {
+      clause: "LIMIT".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn combinators_should_return_an_error() {
+    let error = sql::Select::parse("SELECT id FROM users UNION SELECT id FROM admins").unwrap_err();
+
+    let expected = sql::ParseError::UnsupportedClause {
+      clause: "UNION".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+mod non_standard_select_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn limit_and_offset_should_be_parsed_into_the_builder() {
+    let query = "SELECT id FROM users ORDER BY id LIMIT 10 OFFSET 20";
+    let select = sql::Select::parse(query).unwrap().limit("5");
+
+    assert_eq!("SELECT id FROM users ORDER BY id LIMIT 5 OFFSET 20", select.as_string());
+  }
+
+  #[test]
+  fn with_queries_should_be_parsed_into_the_builder() {
+    let query = "\
+      WITH active AS (SELECT id FROM users WHERE active = true), \
+      admins AS (SELECT id FROM admins) \
+      SELECT id FROM active\
+    ";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!(query, select.as_string());
+  }
+
+  #[test]
+  fn recursive_with_queries_should_return_an_error() {
+    let error = sql::Select::parse("WITH RECURSIVE t AS (SELECT 1) SELECT * FROM t").unwrap_err();
+
+    let expected = sql::ParseError::UnsupportedClause {
+      clause: "WITH RECURSIVE".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn combinators_should_be_parsed_into_the_builder() {
+    let select = sql::Select::parse("SELECT login FROM users UNION SELECT login FROM admins UNION SELECT 'root'")
+      .unwrap()
+      .where_clause("active = true");
+
+    let expected = "\
+      (SELECT login FROM users WHERE active = true) \
+      UNION (SELECT login FROM admins) \
+      UNION (SELECT 'root')\
+    ";
+
+    assert_eq!(expected, select.as_string());
+  }
+
+  #[test]
+  fn different_combinators_should_return_an_error() {
+    let error =
+      sql::Select::parse("SELECT login FROM users UNION SELECT login FROM admins EXCEPT SELECT 'root'").unwrap_err();
+
+    let expected = sql::ParseError::UnsupportedClause {
+      clause: "EXCEPT".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn rendered_combinators_should_be_parsed_back() {
+    let query = "(SELECT login FROM users) INTERSECT (SELECT login FROM admins)";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!(query, select.as_string());
+  }
+
+  #[test]
+  fn combinators_with_all_should_return_an_error() {
+    let error = sql::Select::parse("SELECT id FROM users UNION ALL SELECT id FROM admins").unwrap_err();
+
+    let expected = sql::ParseError::UnsupportedClause {
+      clause: "UNION ALL".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+}
+
+mod insert_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn values_should_be_parsed_into_the_builder() {
+    let insert = sql::Insert::parse("INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')")
+      .unwrap()
+      .values("('baz', 'Baz')");
+
+    let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar'), ('baz', 'Baz')";
+
+    assert_eq!(expected, insert.as_string());
+  }
+
+  #[test]
+  fn select_should_be_parsed_into_the_builder() {
+    let query = "INSERT INTO users (login) SELECT login FROM users_bk WHERE active = true";
+    let insert = sql::Insert::parse(query).unwrap();
+
+    assert_eq!(query, insert.as_string());
+  }
+
+  #[test]
+  fn text_of_other_commands_should_return_an_error() {
+    let error = sql::Insert::parse("SELECT id FROM users").unwrap_err();
+
+    let expected = sql::ParseError::UnexpectedStatement {
+      expected: "INSERT INTO".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+mod non_standard_insert_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn select_with_combinators_should_be_parsed_into_the_builder() {
+    let insert =
+      sql::Insert::parse("INSERT INTO users (login) SELECT login FROM admins UNION SELECT login FROM guests").unwrap();
+
+    let expected = "INSERT INTO users (login) (SELECT login FROM admins) UNION (SELECT login FROM guests)";
+
+    assert_eq!(expected, insert.as_string());
+  }
+}
+
+mod update_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn clauses_should_be_parsed_into_the_builder() {
+    let update = sql::Update::parse("UPDATE users SET login = 'foo', name = 'Foo' WHERE id = $1")
+      .unwrap()
+      .set("active = true")
+      .where_clause("active = false");
+
+    let expected = "UPDATE users SET login = 'foo', name = 'Foo', active = true WHERE id = $1 AND active = false";
+
+    assert_eq!(expected, update.as_string());
+  }
+
+  #[test]
+  fn text_of_other_commands_should_return_an_error() {
+    let error = sql::Update::parse("DELETE FROM users").unwrap_err();
+
+    let expected = sql::ParseError::UnexpectedStatement {
+      expected: "UPDATE".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+}
+
+mod delete_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn clauses_should_be_parsed_into_the_builder() {
+    let delete = sql::Delete::parse("DELETE FROM orders WHERE created_at < $1 AND paid = false")
+      .unwrap()
+      .where_clause("id <> $2");
+
+    let expected = "DELETE FROM orders WHERE created_at < $1 AND paid = false AND id <> $2";
+
+    assert_eq!(expected, delete.as_string());
+  }
+
+  #[test]
+  fn using_clause_should_return_an_error() {
+    let error = sql::Delete::parse("DELETE FROM users USING sessions WHERE sessions.user_id = users.id").unwrap_err();
+
+    let expected = sql::ParseError::UnsupportedClause {
+      clause: "USING".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+
+  #[test]
+  fn text_of_other_commands_should_return_an_error() {
+    let error = sql::Delete::parse("DELETE orders").unwrap_err();
+
+    let expected = sql::ParseError::UnexpectedStatement {
+      expected: "DELETE FROM".to_string(),
+    };
+
+    assert_eq!(expected, error);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn insert_on_conflict_and_returning_should_be_parsed_into_the_builder() {
+    let query = "INSERT INTO users (login) VALUES ('foo') ON CONFLICT (login) DO NOTHING RETURNING id";
+    let insert = sql::Insert::parse(query).unwrap().returning("login");
+
+    let expected = "INSERT INTO users (login) VALUES ('foo') ON CONFLICT (login) DO NOTHING RETURNING id, login";
+
+    assert_eq!(expected, insert.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn insert_default_values_should_be_parsed_into_the_builder() {
+    let query = "INSERT INTO users DEFAULT VALUES";
+    let insert = sql::Insert::parse(query).unwrap();
+
+    assert_eq!(query, insert.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn update_from_and_returning_should_be_parsed_into_the_builder() {
+    let query = "UPDATE users SET role = r.name FROM roles r WHERE r.id = users.role_id RETURNING id";
+    let update = sql::Update::parse(query).unwrap();
+
+    assert_eq!(query, update.to_sql(sql::Dialect::Postgresql));
+  }
+
+  #[test]
+  fn with_queries_of_other_commands_should_be_parsed_into_the_builder() {
+    let query = "\
+      WITH deleted AS (DELETE FROM users WHERE active = false RETURNING id) \
+      SELECT count(*) FROM deleted\
+    ";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!(query, select.to_sql(sql::Dialect::Postgresql));
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn delete_returning_and_order_by_should_be_parsed_into_the_builder() {
+    let query = "DELETE FROM orders WHERE paid = false RETURNING id ORDER BY created_at";
+    let delete = sql::Delete::parse(query).unwrap();
+
+    assert_eq!(query, delete.to_sql(sql::Dialect::Sqlite));
+  }
+
+  #[test]
+  fn update_order_by_should_be_parsed_into_the_builder() {
+    let query = "UPDATE users SET active = false WHERE active = true ORDER BY id";
+    let update = sql::Update::parse(query).unwrap();
+
+    assert_eq!(query, update.to_sql(sql::Dialect::Sqlite));
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn insert_on_duplicate_key_update_should_be_parsed_into_the_builder() {
+    let query = "INSERT INTO users (login, total) VALUES ('foo', 1) ON DUPLICATE KEY UPDATE total = total + 1";
+    let insert = sql::Insert::parse(query).unwrap();
+
+    assert_eq!(query, insert.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn limit_of_update_and_delete_should_be_parsed_into_the_builder() {
+    let query = "UPDATE users SET active = false ORDER BY id LIMIT 10";
+    let update = sql::Update::parse(query).unwrap();
+
+    assert_eq!(query, update.to_sql(sql::Dialect::Mysql));
+
+    let query = "DELETE FROM users WHERE active = false ORDER BY id LIMIT 10";
+    let delete = sql::Delete::parse(query).unwrap();
+
+    assert_eq!(query, delete.to_sql(sql::Dialect::Mysql));
+  }
+
+  #[test]
+  fn backtick_quoted_identifiers_should_not_split_the_clauses() {
+    let query = "SELECT `from`, `order` FROM `users`";
+    let select = sql::Select::parse(query).unwrap();
+
+    assert_eq!(query, select.to_sql(sql::Dialect::Mysql));
+  }
+}
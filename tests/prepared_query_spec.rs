@@ -0,0 +1,178 @@
+mod prepare {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_prepare_should_render_the_sql_of_the_builder() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("login = ?", "foo");
+
+    let prepared = select.prepare(sql::Dialect::Standard);
+
+    assert_eq!(select.to_sql(sql::Dialect::Standard), prepared.sql());
+    assert_eq!(sql::Dialect::Standard, prepared.dialect());
+  }
+
+  #[test]
+  fn method_prepare_should_keep_the_values_bound_in_the_builder() {
+    let prepared = sql::Insert::new()
+      .insert_into("users (login, active)")
+      .values_bind("(?, ?)", ("foo", true))
+      .prepare(sql::Dialect::Standard);
+
+    let expected_values = vec![sql::Value::from("foo"), sql::Value::from(true)];
+
+    assert_eq!(expected_values, prepared.values());
+    assert_eq!(2, prepared.param_count());
+  }
+
+  #[test]
+  fn method_prepare_should_count_the_placeholders_written_in_the_clauses() {
+    let prepared = sql::Update::new()
+      .update("users")
+      .set("login = ?")
+      .where_clause("id = ?")
+      .prepare(sql::Dialect::Standard);
+
+    assert_eq!(2, prepared.param_count());
+    assert_eq!(0, prepared.values().len());
+  }
+
+  #[test]
+  fn method_prepare_should_be_available_in_the_delete_builder() {
+    let prepared = sql::Delete::new()
+      .delete_from("users")
+      .where_clause_bind("id = ?", 1)
+      .prepare(sql::Dialect::Standard);
+
+    assert_eq!("DELETE FROM users WHERE id = ?", prepared.sql());
+  }
+}
+
+mod bind {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_bind_should_return_the_values_in_the_order_of_the_parameters() {
+    let prepared = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = ?")
+      .where_clause("active = ?")
+      .prepare(sql::Dialect::Standard);
+
+    let values = prepared.bind(("foo", false)).unwrap();
+    let expected_values = vec![sql::Value::from("foo"), sql::Value::from(false)];
+
+    assert_eq!(expected_values, values);
+  }
+
+  #[test]
+  fn method_bind_should_return_an_error_when_the_number_of_values_is_wrong() {
+    let prepared = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id = ?")
+      .prepare(sql::Dialect::Standard);
+
+    let error = prepared.bind((1, 2)).unwrap_err();
+    let expected_error = sql::BuildError::ParamCountMismatch { expected: 1, found: 2 };
+
+    assert_eq!(expected_error, error);
+    assert_eq!("the query has 1 parameters but 2 values were bound", error.to_string());
+  }
+}
+
+mod identity {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+  use std::collections::HashMap;
+
+  fn find_user(id: i32) -> sql::Select {
+    sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause_bind("id = ?", id)
+  }
+
+  #[test]
+  fn queries_of_the_same_shape_should_have_the_same_sql_hash() {
+    let first = find_user(1).prepare(sql::Dialect::Standard);
+    let second = find_user(2).prepare(sql::Dialect::Standard);
+
+    assert_eq!(first.sql_hash(), second.sql_hash());
+    assert_eq!(first, second);
+  }
+
+  #[test]
+  fn queries_of_different_shapes_should_have_different_sql_hashes() {
+    let first = find_user(1).prepare(sql::Dialect::Standard);
+    let second = find_user(1).order_by("login").prepare(sql::Dialect::Standard);
+
+    assert_ne!(first.sql_hash(), second.sql_hash());
+    assert_ne!(first, second);
+  }
+
+  #[test]
+  fn sql_hash_should_be_stable_between_runs() {
+    let prepared = sql::Select::new()
+      .select("id")
+      .from("users")
+      .prepare(sql::Dialect::Standard);
+
+    assert_eq!(0xc841_3f03_8d70_3486, prepared.sql_hash());
+  }
+
+  #[test]
+  fn prepared_queries_should_be_usable_as_keys_of_a_statement_cache() {
+    let mut cache = HashMap::new();
+
+    for id in 0..3 {
+      let prepared = find_user(id).prepare(sql::Dialect::Standard);
+      *cache.entry(prepared).or_insert(0) += 1;
+    }
+
+    assert_eq!(1, cache.len());
+    assert_eq!(Some(&3), cache.values().next());
+  }
+
+  #[test]
+  fn clones_should_share_the_sql() {
+    let prepared = find_user(1).prepare(sql::Dialect::Standard);
+    let cloned = prepared.clone();
+
+    assert_eq!(prepared.sql().as_ptr(), cloned.sql().as_ptr());
+    assert_eq!("SELECT id, login FROM users WHERE id = ?", cloned.to_string());
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_prepare_should_number_the_parameters_in_the_postgres_syntax() {
+    let prepared = sql::Update::new()
+      .update("users")
+      .set_bind("login = ?", "foo")
+      .where_clause("id = $1")
+      .prepare(sql::Dialect::Postgresql);
+
+    assert_eq!("UPDATE users SET login = $2 WHERE id = $1", prepared.sql());
+    assert_eq!(2, prepared.param_count());
+  }
+
+  #[test]
+  fn the_same_query_in_different_dialects_should_not_be_equal() {
+    let select = sql::Select::new().select("id").from("users");
+
+    let standard = select.prepare(sql::Dialect::Standard);
+    let postgres = select.prepare(sql::Dialect::Postgresql);
+
+    assert_eq!(standard.sql_hash(), postgres.sql_hash());
+    assert_ne!(standard, postgres);
+  }
+}
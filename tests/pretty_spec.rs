@@ -0,0 +1,584 @@
+mod default_options {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn each_clause_should_start_in_its_own_line() {
+    let query = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .inner_join("roles ON roles.id = users.role_id")
+      .where_clause("active = true")
+      .order_by("login")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT id, login\n\
+      FROM users\n\
+      INNER JOIN roles ON roles.id = users.role_id\n\
+      WHERE active = true\n\
+      ORDER BY login\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn long_lists_should_be_wrapped_one_item_per_line() {
+    let query = sql::Select::new()
+      .select("id, login, name, email, created_at, updated_at, last_sign_in_at, confirmed_at")
+      .from("users")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT\n  \
+        id,\n  \
+        login,\n  \
+        name,\n  \
+        email,\n  \
+        created_at,\n  \
+        updated_at,\n  \
+        last_sign_in_at,\n  \
+        confirmed_at\n\
+      FROM users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn long_conditions_should_be_wrapped_one_condition_per_line() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("created_at BETWEEN '2024-01-01' AND '2024-12-31'")
+      .where_clause("login LIKE 'foo%'")
+      .where_or("name = 'Foo'")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT *\n\
+      FROM users\n\
+      WHERE\n  \
+        created_at BETWEEN '2024-01-01' AND '2024-12-31'\n  \
+        AND login LIKE 'foo%'\n  \
+        OR name = 'Foo'\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn sub_queries_should_be_indented_one_level_deeper() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("id IN (SELECT user_id FROM orders WHERE total > 100)")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT login\n\
+      FROM users\n\
+      WHERE id IN (\n  \
+        SELECT user_id\n  \
+        FROM orders\n  \
+        WHERE total > 100\n\
+      )\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn sub_queries_of_the_list_items_should_be_indented_at_the_item() {
+    let query = sql::Select::new()
+      .select("login, (SELECT count(*) FROM orders WHERE orders.user_id = users.id) AS total")
+      .from("users")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT\n  \
+        login,\n  \
+        (\n    \
+          SELECT count(*)\n    \
+          FROM orders\n    \
+          WHERE orders.user_id = users.id\n  \
+        ) AS total\n\
+      FROM users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn text_inside_quotes_should_not_be_changed() {
+    let query = sql::Select::new()
+      .select("'select  (from' AS label")
+      .from("users")
+      .as_pretty_string(&sql::FormatOptions {
+        keyword_case: sql::KeywordCase::Lower,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      select 'select  (from' as label\n\
+      from users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn text_with_the_nul_character_should_not_be_truncated() {
+    let query = sql::Select::new()
+      .select("'a\0b' AS label")
+      .from("users")
+      .as_pretty_string(&Default::default());
+
+    let expected_query = "\
+      SELECT 'a\0b' AS label\n\
+      FROM users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn clauses_after_the_numbered_placeholders_should_not_be_shifted() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .where_clause_bind("active = ?", true)
+      .order_by("login")
+      .as_pretty_string(&Default::default());
+
+    let expected_query = "\
+      SELECT id\n\
+      FROM users\n\
+      WHERE login = $1 AND active = $2\n\
+      ORDER BY login\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn line_comments_should_not_comment_out_the_text_after_them() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("active = true -- only the active users\n  AND deleted = false")
+      .as_pretty_string(&Default::default());
+
+    let expected_query = "\
+      SELECT login\n\
+      FROM users\n\
+      WHERE active = true /* only the active users */ AND deleted = false\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn long_column_definitions_should_be_wrapped() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial PRIMARY KEY")
+      .column("login varchar(40) NOT NULL")
+      .column("created_at timestamp NOT NULL DEFAULT now()")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      CREATE TABLE users (\n  \
+        id serial PRIMARY KEY,\n  \
+        login varchar(40) NOT NULL,\n  \
+        created_at timestamp NOT NULL DEFAULT now()\n\
+      )\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn query_of_the_view_should_start_in_its_own_line() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .as_select(
+        sql::Select::new()
+          .select("id")
+          .from("users")
+          .where_clause("active = true"),
+      )
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      CREATE VIEW active_users AS\n\
+      SELECT id\n\
+      FROM users\n\
+      WHERE active = true\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn select_of_the_insert_should_be_formatted_as_a_query() {
+    let query = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(
+        sql::Select::new()
+          .select("login")
+          .from("users")
+          .where_clause("active = false"),
+      )
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      INSERT INTO users_bk (login)\n\
+      SELECT login\n\
+      FROM users\n\
+      WHERE active = false\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn update_should_be_formatted_by_clause() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("login = 'foo'")
+      .where_clause("id = 1")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      UPDATE users\n\
+      SET login = 'foo'\n\
+      WHERE id = 1\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod option_max_line_length {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn lists_should_be_wrapped_from_the_max_line_length() {
+    let select = sql::Select::new().select("id, login").from("users");
+    let options = sql::FormatOptions {
+      max_line_length: 15,
+      ..Default::default()
+    };
+
+    let expected_query = "\
+      SELECT\n  \
+        id,\n  \
+        login\n\
+      FROM users\
+    ";
+
+    assert_eq!(expected_query, select.as_pretty_string(&options));
+  }
+
+  #[test]
+  fn rows_of_the_values_should_be_wrapped() {
+    let options = sql::FormatOptions {
+      max_line_length: 30,
+      ..Default::default()
+    };
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .values("('baz')")
+      .as_pretty_string(&options);
+
+    let expected_query = "\
+      INSERT INTO users (login)\n\
+      VALUES\n  \
+        ('foo'),\n  \
+        ('bar'),\n  \
+        ('baz')\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod option_indent_width {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn nested_lines_should_use_the_indent_width() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id IN (SELECT user_id FROM bans)")
+      .as_pretty_string(&sql::FormatOptions {
+        indent_width: 4,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      DELETE FROM users\n\
+      WHERE id IN (\n    \
+          SELECT user_id\n    \
+          FROM bans\n\
+      )\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod option_keyword_case {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn keywords_should_be_printed_in_lower_case() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("name IS NOT NULL")
+      .as_pretty_string(&sql::FormatOptions {
+        keyword_case: sql::KeywordCase::Lower,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      select id\n\
+      from users\n\
+      where name is not null\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn keywords_should_be_printed_in_upper_case() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("name is not null")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT id\n\
+      FROM users\n\
+      WHERE name IS NOT NULL\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn identifiers_should_keep_its_case() {
+    let query = sql::Select::new()
+      .select("Login, \"Order\"")
+      .from("Users")
+      .as_pretty_string(&sql::FormatOptions {
+        keyword_case: sql::KeywordCase::Lower,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      select Login, \"Order\"\n\
+      from Users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod option_comma_style {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn comma_first_should_start_the_line_of_the_next_item() {
+    let query = sql::Select::new()
+      .select("id, login, name")
+      .from("users")
+      .as_pretty_string(&sql::FormatOptions {
+        comma_style: sql::CommaStyle::First,
+        max_line_length: 15,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      SELECT\n  \
+        id\n\
+      , login\n\
+      , name\n\
+      FROM users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn comma_first_should_be_aligned_with_the_river() {
+    let query = sql::Select::new()
+      .select("id, login, name")
+      .from("users")
+      .as_pretty_string(&sql::FormatOptions {
+        comma_style: sql::CommaStyle::First,
+        max_line_length: 15,
+        align: true,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      SELECT id\n     \
+           , login\n     \
+           , name\n  \
+        FROM users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod option_align {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn keywords_should_be_right_aligned() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = true")
+      .order_by("id")
+      .as_pretty_string(&sql::FormatOptions {
+        align: true,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      \u{20} SELECT id\n    \
+          FROM users\n   \
+         WHERE active = true\n\
+      ORDER BY id\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn wrapped_items_should_start_after_the_river() {
+    let query = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause("active = true")
+      .where_clause("login <> 'root'")
+      .as_pretty_string(&sql::FormatOptions {
+        align: true,
+        max_line_length: 15,
+        ..Default::default()
+      });
+
+    let expected_query = "\
+      SELECT id,\n       \
+             login\n  \
+        FROM users\n \
+       WHERE active = true\n   \
+         AND login <> 'root'\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod transaction {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn each_command_should_be_formatted_in_its_own_lines() {
+    let query = sql::Transaction::new()
+      .update(sql::Update::new().update("users").set("active = false"))
+      .commit("")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      UPDATE users\n\
+      SET active = false;\n\
+      COMMIT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+mod non_standard_queries {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn operands_of_the_union_should_be_indented() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("admins"))
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      (\n  \
+        SELECT login\n  \
+        FROM users\n\
+      )\n\
+      UNION\n\
+      (\n  \
+        SELECT login\n  \
+        FROM admins\n\
+      )\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn queries_of_the_with_clause_should_be_indented() {
+    let query = sql::Select::new()
+      .with("active_users", sql::Select::new().select("id").from("users"))
+      .select("count(*)")
+      .from("active_users")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      WITH active_users AS (\n  \
+        SELECT id\n  \
+        FROM users\n\
+      )\n\
+      SELECT count(*)\n\
+      FROM active_users\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn limit_should_be_printed_in_its_own_line() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .limit("10")
+      .as_pretty_string(&sql::FormatOptions::default());
+
+    let expected_query = "\
+      SELECT id\n\
+      FROM users\n\
+      LIMIT 10\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
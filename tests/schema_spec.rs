@@ -0,0 +1,354 @@
+mod schema_diff {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn users() -> sql::Table {
+    sql::Table::new("users")
+      .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+      .column(sql::Column::new("login", sql::DataType::Varchar(40)).not_null())
+  }
+
+  #[test]
+  fn equal_schemas_should_produce_an_empty_transaction() {
+    let schema = sql::Schema::new().table(users());
+
+    let query = sql::Schema::diff(&schema, &schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+
+    assert_eq!("", query);
+  }
+
+  #[test]
+  fn tables_added_should_be_created() {
+    let old_schema = sql::Schema::new();
+    let new_schema = sql::Schema::new().table(users().primary_key(&["id"]));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      CREATE TABLE users (\
+        id INTEGER NOT NULL, \
+        login VARCHAR(40) NOT NULL, \
+        CONSTRAINT users_pkey PRIMARY KEY (id)\
+      );\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn tables_removed_should_be_dropped_before_the_tables_added_are_created() {
+    let old_schema = sql::Schema::new().table(sql::Table::new("accounts"));
+    let new_schema = sql::Schema::new().table(users());
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      DROP TABLE accounts; \
+      CREATE TABLE users (id INTEGER NOT NULL, login VARCHAR(40) NOT NULL);\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn columns_removed_should_be_dropped_before_the_columns_added() {
+    let old_schema = sql::Schema::new().table(users());
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+        .column(sql::Column::new("email", sql::DataType::Text).not_null().default("''")),
+    );
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      ALTER TABLE users DROP COLUMN login; \
+      ALTER TABLE users ADD COLUMN email TEXT NOT NULL DEFAULT '';\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn columns_changed_should_be_altered_in_the_order_type_default_and_nullability() {
+    let old_schema = sql::Schema::new().table(users());
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+        .column(sql::Column::new("login", sql::DataType::Text).default("'guest'")),
+    );
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      ALTER TABLE users ALTER COLUMN login SET DATA TYPE TEXT; \
+      ALTER TABLE users ALTER COLUMN login SET DEFAULT 'guest'; \
+      ALTER TABLE users ALTER COLUMN login DROP NOT NULL;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn default_removed_and_not_null_added_should_be_altered() {
+    let old_schema = sql::Schema::new()
+      .table(sql::Table::new("users").column(sql::Column::new("age", sql::DataType::Integer).default("0")));
+    let new_schema = sql::Schema::new()
+      .table(sql::Table::new("users").column(sql::Column::new("age", sql::DataType::Integer).not_null()));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      ALTER TABLE users ALTER COLUMN age DROP DEFAULT; \
+      ALTER TABLE users ALTER COLUMN age SET NOT NULL;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn primary_key_changed_should_be_dropped_and_added_again() {
+    let old_schema = sql::Schema::new().table(users().primary_key(&["id"]));
+    let new_schema = sql::Schema::new().table(users().primary_key(&["id", "login"]));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "\
+      ALTER TABLE users DROP CONSTRAINT users_pkey; \
+      ALTER TABLE users ADD CONSTRAINT users_pkey PRIMARY KEY (id, login);\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_table_should_replace_the_table_with_the_same_name() {
+    let schema = sql::Schema::new().table(sql::Table::new("users")).table(users());
+
+    let query = sql::Schema::diff(&sql::Schema::new(), &schema, sql::Dialect::Standard).to_sql(sql::Dialect::Standard);
+    let expected_query = "CREATE TABLE users (id INTEGER NOT NULL, login VARCHAR(40) NOT NULL);";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_column_should_replace_the_column_with_the_same_name() {
+    let users = sql::Table::new("users")
+      .column(sql::Column::new("login", sql::DataType::Text))
+      .column(sql::Column::new("login", sql::DataType::Varchar(40)));
+
+    let query = users.to_create_table().to_sql(sql::Dialect::Standard);
+
+    assert_eq!("CREATE TABLE users (login VARCHAR(40))", query);
+  }
+
+  #[cfg(not(feature = "sqlite"))]
+  #[test]
+  fn the_transaction_can_be_wrapped_by_the_transaction_commands() {
+    let old_schema = sql::Schema::new().table(users());
+    let new_schema = sql::Schema::new().table(users().column(sql::Column::new("bio", sql::DataType::Text)));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Standard)
+      .start_transaction("")
+      .commit("")
+      .to_sql(sql::Dialect::Standard);
+    let expected_query = "START TRANSACTION; ALTER TABLE users ADD COLUMN bio TEXT; COMMIT;";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_schema_diff {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn indexes_removed_should_be_dropped_first_and_indexes_added_created_last() {
+    let old_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("login", sql::DataType::Text))
+        .index(sql::Index::new("users_login_idx", &["login"])),
+    );
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("login", sql::DataType::Text))
+        .column(sql::Column::new("email", sql::DataType::Text))
+        .index(sql::Index::new("users_login_idx", &["login"]).unique())
+        .index(sql::Index::new("users_email_idx", &["email"])),
+    );
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Postgresql).to_sql(sql::Dialect::Postgresql);
+    let expected_query = "\
+      DROP INDEX users_login_idx; \
+      ALTER TABLE users ADD COLUMN email TEXT; \
+      CREATE UNIQUE INDEX users_login_idx ON users (login); \
+      CREATE INDEX users_email_idx ON users (email);\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn indexes_of_the_tables_added_should_be_created_after_the_table() {
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("orders")
+        .column(sql::Column::new("user_id", sql::DataType::BigInt))
+        .index(sql::Index::new("orders_user_id_idx", &["user_id"])),
+    );
+
+    let query =
+      sql::Schema::diff(&sql::Schema::new(), &new_schema, sql::Dialect::Postgresql).to_sql(sql::Dialect::Postgresql);
+    let expected_query = "\
+      CREATE TABLE orders (user_id BIGINT); \
+      CREATE INDEX orders_user_id_idx ON orders (user_id);\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn data_types_should_be_rendered_in_the_syntax_of_postgres() {
+    let old_schema =
+      sql::Schema::new().table(sql::Table::new("events").column(sql::Column::new("at", sql::DataType::Timestamp)));
+    let new_schema =
+      sql::Schema::new().table(sql::Table::new("events").column(sql::Column::new("at", sql::DataType::TimestampTz)));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Postgresql).to_sql(sql::Dialect::Postgresql);
+
+    assert_eq!("ALTER TABLE events ALTER COLUMN at TYPE TIMESTAMPTZ;", query);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_schema_diff {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn indexes_removed_should_be_dropped_with_the_table_name() {
+    let old_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("login", sql::DataType::Text))
+        .index(sql::Index::new("users_login_idx", &["login"])),
+    );
+    let new_schema =
+      sql::Schema::new().table(sql::Table::new("users").column(sql::Column::new("login", sql::DataType::Text)));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Mysql).to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("DROP INDEX users_login_idx ON users;", query);
+  }
+
+  #[test]
+  fn data_types_should_be_rendered_in_the_syntax_of_mysql() {
+    let old_schema =
+      sql::Schema::new().table(sql::Table::new("users").column(sql::Column::new("id", sql::DataType::SmallInt)));
+    let new_schema =
+      sql::Schema::new().table(sql::Table::new("users").column(sql::Column::new("id", sql::DataType::Integer)));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Mysql).to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("ALTER TABLE users MODIFY COLUMN id INT;", query);
+  }
+
+  #[test]
+  fn columns_changed_should_be_modified_with_the_full_definition() {
+    let old_schema = sql::Schema::new()
+      .table(sql::Table::new("users").column(sql::Column::new("id", sql::DataType::Integer).not_null()));
+    let new_schema = sql::Schema::new()
+      .table(sql::Table::new("users").column(sql::Column::new("id", sql::DataType::BigInt).not_null().default("0")));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Mysql).to_sql(sql::Dialect::Mysql);
+
+    assert_eq!("ALTER TABLE users MODIFY COLUMN id BIGINT NOT NULL DEFAULT 0;", query);
+  }
+
+  #[test]
+  fn primary_key_changed_should_drop_the_primary_key() {
+    let users = || {
+      sql::Table::new("users")
+        .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+        .column(sql::Column::new("login", sql::DataType::Varchar(40)).not_null())
+    };
+    let old_schema = sql::Schema::new().table(users().primary_key(&["id"]));
+    let new_schema = sql::Schema::new().table(users().primary_key(&["login"]));
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Mysql).to_sql(sql::Dialect::Mysql);
+    let expected_query = "\
+      ALTER TABLE users DROP PRIMARY KEY; \
+      ALTER TABLE users ADD CONSTRAINT users_pkey PRIMARY KEY (login);\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_schema_diff {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn users() -> sql::Table {
+    sql::Table::new("users")
+      .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+      .column(sql::Column::new("login", sql::DataType::Varchar(40)).not_null())
+  }
+
+  #[test]
+  fn columns_added_and_removed_should_be_altered() {
+    let old_schema = sql::Schema::new().table(users());
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+        .column(sql::Column::new("bio", sql::DataType::Text)),
+    );
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Sqlite).to_sql(sql::Dialect::Sqlite);
+    let expected_query = "\
+      ALTER TABLE users DROP COLUMN login; \
+      ALTER TABLE users ADD COLUMN bio TEXT;\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn primary_key_and_columns_changed_should_rebuild_the_table() {
+    let old_schema = sql::Schema::new().table(users().primary_key(&["id"]));
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("id", sql::DataType::BigInt).not_null())
+        .column(sql::Column::new("login", sql::DataType::Text).not_null())
+        .column(sql::Column::new("bio", sql::DataType::Text))
+        .primary_key(&["login"]),
+    );
+
+    let transaction = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Sqlite);
+    let query = transaction.to_sql(sql::Dialect::Sqlite);
+    let expected_query = "\
+      CREATE TABLE new_users (\
+        id INTEGER NOT NULL, \
+        login TEXT NOT NULL, \
+        bio TEXT, \
+        CONSTRAINT users_pkey PRIMARY KEY (login)\
+      ); \
+      INSERT INTO new_users (id, login) SELECT id, login FROM users; \
+      DROP TABLE users; \
+      ALTER TABLE new_users RENAME TO users;\
+    ";
+
+    assert_eq!(expected_query, query);
+    assert_eq!(Ok(()), transaction.validate_for(sql::Dialect::Sqlite));
+  }
+
+  #[test]
+  fn indexes_of_the_rebuilt_tables_should_be_created_again() {
+    let old_schema = sql::Schema::new().table(users().index(sql::Index::new("users_login_idx", &["login"])));
+    let new_schema = sql::Schema::new().table(
+      sql::Table::new("users")
+        .column(sql::Column::new("id", sql::DataType::Integer).not_null())
+        .column(sql::Column::new("login", sql::DataType::Text).not_null())
+        .index(sql::Index::new("users_login_idx", &["login"])),
+    );
+
+    let query = sql::Schema::diff(&old_schema, &new_schema, sql::Dialect::Sqlite).to_sql(sql::Dialect::Sqlite);
+
+    assert!(query.starts_with("CREATE TABLE new_users (id INTEGER NOT NULL, login TEXT NOT NULL);"));
+    assert!(query.ends_with("ALTER TABLE new_users RENAME TO users; CREATE INDEX users_login_idx ON users (login);"));
+  }
+}
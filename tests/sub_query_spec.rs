@@ -0,0 +1,293 @@
+mod from_subquery {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_from_subquery_should_add_the_sub_query_with_alias() {
+    let active_users = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("active = true");
+    let query = sql::Select::new()
+      .select("u.id")
+      .from_subquery("u", active_users)
+      .as_string();
+    let expected_query = "SELECT u.id FROM (SELECT id FROM users WHERE active = true) AS u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_not_add_the_alias_when_empty() {
+    let users = sql::Select::new().select("id").from("users");
+    let query = sql::Select::new().from_subquery("", users).as_string();
+    let expected_query = "FROM (SELECT id FROM users)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_trim_the_alias() {
+    let users = sql::Select::new().select("id").from("users");
+    let query = sql::Select::new().from_subquery("  u  ", users).as_string();
+    let expected_query = "FROM (SELECT id FROM users) AS u";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_be_combined_with_the_from_method() {
+    let orders = sql::Select::new().select("user_id").from("orders");
+    let query = sql::Select::new().from("users").from_subquery("o", orders).as_string();
+    let expected_query = "FROM users, (SELECT user_id FROM orders) AS o";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_render_the_nested_sub_queries() {
+    let inner = sql::Select::new().select("id").from("users");
+    let middle = sql::Select::new().select("id").from_subquery("a", inner);
+    let query = sql::Select::new().select("id").from_subquery("b", middle).as_string();
+    let expected_query = "SELECT id FROM (SELECT id FROM (SELECT id FROM users) AS a) AS b";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_from_subquery_should_render_the_deeply_nested_sub_queries() {
+    let query = (0..64).fold(sql::Select::new().select("id").from("users"), |select, _| {
+      sql::Select::new().select("id").from_subquery("s", select)
+    });
+    let expected_query = format!(
+      "{}SELECT id FROM users{}",
+      "SELECT id FROM (".repeat(64),
+      ") AS s".repeat(64)
+    );
+
+    assert_eq!(expected_query, query.as_string());
+  }
+
+  #[test]
+  fn method_from_subquery_should_indent_the_sub_query_in_the_multiline_format() {
+    let inner = sql::Select::new().select("id").from("users");
+    let middle = sql::Select::new().select("id").from_subquery("a", inner);
+    let select = sql::Select::new().select("id").from_subquery("b", middle);
+    let query = strip_colors(format!("{select:?}"))
+      .lines()
+      .filter(|line| line.starts_with("--") == false)
+      .collect::<Vec<_>>()
+      .join("\n");
+    let expected_query = "\
+      SELECT id\n\
+      FROM (\n  \
+        SELECT id\n  \
+        FROM (\n    \
+          SELECT id\n    \
+          FROM users\n  \
+        ) AS a\n\
+      ) AS b";
+
+    assert_eq!(expected_query, query.trim());
+  }
+
+  fn strip_colors(text: String) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+      if c == '\x1b' {
+        chars.by_ref().find(|c| *c == 'm');
+      } else {
+        result.push(c);
+      }
+    }
+    result
+  }
+}
+
+mod where_exists {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn orders() -> sql::Select {
+    sql::Select::new()
+      .select("1")
+      .from("orders")
+      .where_clause("orders.user_id = users.id")
+  }
+
+  #[test]
+  fn method_where_exists_should_add_the_exists_condition() {
+    let query = sql::Select::new().from("users").where_exists(orders()).as_string();
+    let expected_query = "FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_where_exists_should_concatenate_with_the_and_operator() {
+    let query = sql::Select::new()
+      .where_clause("active = true")
+      .where_exists(orders())
+      .as_string();
+    let expected_query = "WHERE active = true AND EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_where_exists_should_be_available_in_the_update_command() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("vip = true")
+      .where_exists(orders())
+      .as_string();
+    let expected_query =
+      "UPDATE users SET vip = true WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_where_exists_should_be_available_in_the_delete_command() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .where_exists(orders())
+      .as_string();
+    let expected_query = "DELETE FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn function_exists_should_be_negated_by_the_not_operator() {
+    let query = sql::Select::new().where_clause(&!sql::exists(orders())).as_string();
+    let expected_query = "WHERE NOT EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.id)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod where_in {
+  use pretty_assertions::assert_eq;
+  use sql::col;
+  use sql_query_builder as sql;
+
+  fn admins() -> sql::Select {
+    sql::Select::new().select("user_id").from("admins")
+  }
+
+  #[test]
+  fn method_where_in_should_add_the_in_condition() {
+    let query = sql::Select::new().where_in("id", admins()).as_string();
+    let expected_query = "WHERE id IN (SELECT user_id FROM admins)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_where_in_should_trim_the_column() {
+    let query = sql::Select::new().where_in("  id  ", admins()).as_string();
+    let expected_query = "WHERE id IN (SELECT user_id FROM admins)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_where_in_should_be_available_in_the_update_command() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("admin = true")
+      .where_in("id", admins())
+      .as_string();
+    let expected_query = "UPDATE users SET admin = true WHERE id IN (SELECT user_id FROM admins)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_where_in_should_be_available_in_the_delete_command() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .where_in("id", admins())
+      .as_string();
+    let expected_query = "DELETE FROM users WHERE id IN (SELECT user_id FROM admins)";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn methods_in_query_and_not_in_query_should_add_the_sub_query() {
+    let query = sql::Select::new()
+      .where_clause(col("id").in_query(admins()))
+      .where_clause(col("id").not_in_query(admins()))
+      .as_string();
+    let expected_query = "WHERE id IN (SELECT user_id FROM admins) AND id NOT IN (SELECT user_id FROM admins)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod scalar_sub_query {
+  use pretty_assertions::assert_eq;
+  use sql::col;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn select_should_be_used_as_an_operand_of_the_expressions() {
+    let average = sql::Select::new().select("avg(price)").from("products");
+    let query = sql::Select::new()
+      .from("products")
+      .where_clause(col("price").gt(average))
+      .as_string();
+    let expected_query = "FROM products WHERE price > (SELECT avg(price) FROM products)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod bind_values {
+  use pretty_assertions::assert_eq;
+  use sql::col;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn values_of_the_sub_queries_should_be_returned_in_the_order_of_the_query() {
+    let orders = sql::Select::new()
+      .select("user_id")
+      .from("orders")
+      .where_clause(col("total").gt(100));
+    let (query, values) = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause(col("active").eq(true))
+      .where_in("id", orders)
+      .where_clause(col("age").gte(18))
+      .to_sql_with_binds(sql::Dialect::Standard);
+    let expected_query =
+      "SELECT id FROM users WHERE active = ? AND id IN (SELECT user_id FROM orders WHERE total > ?) AND age >= ?";
+    let expected_values = vec![sql::Value::Bool(true), sql::Value::Int(100), sql::Value::Int(18)];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+
+  #[cfg(feature = "postgresql")]
+  #[test]
+  fn placeholders_of_the_sub_queries_should_be_numbered_in_the_order_of_the_query() {
+    let inner = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause(col("login").eq("foo"));
+    let (query, values) = sql::Select::new()
+      .select("id")
+      .from_subquery("u", inner)
+      .where_clause(col("id").gt(1))
+      .to_sql_with_binds(sql::Dialect::Postgresql);
+    let expected_query = "SELECT id FROM (SELECT id FROM users WHERE login = $1) AS u WHERE id > $2";
+    let expected_values = vec![sql::Value::from("foo"), sql::Value::Int(1)];
+
+    assert_eq!(expected_query, query);
+    assert_eq!(expected_values, values);
+  }
+}
@@ -0,0 +1,800 @@
+mod select_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn missing(clause: &str) -> sql::BuildError {
+    sql::BuildError::MissingClause {
+      command: "SELECT".to_string(),
+      clause: clause.to_string(),
+    }
+  }
+
+  #[test]
+  fn complete_select_should_be_valid() {
+    let select = sql::Select::new()
+      .select("login, count(*)")
+      .from("users")
+      .inner_join("roles ON roles.id = users.role_id")
+      .group_by("login")
+      .having("count(*) > 1");
+
+    assert_eq!(Ok(()), select.validate());
+  }
+
+  #[test]
+  fn select_without_the_select_clause_should_be_invalid() {
+    let select = sql::Select::new().from("users");
+
+    assert_eq!(Err(missing("SELECT")), select.validate());
+  }
+
+  #[test]
+  fn having_without_group_by_should_be_invalid() {
+    let select = sql::Select::new()
+      .select("count(*)")
+      .from("users")
+      .having("count(*) > 1");
+
+    assert_eq!(Err(missing("GROUP BY")), select.validate());
+  }
+
+  #[test]
+  fn join_without_from_should_be_invalid() {
+    let select = sql::Select::new().select("*").inner_join("roles USING (role_id)");
+
+    assert_eq!(Err(missing("FROM")), select.validate());
+  }
+
+  #[test]
+  fn natural_join_with_conditions_should_be_conflicting_clauses() {
+    let on = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::inner("roles").natural().on("roles.id = users.role_id"));
+    let using = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::inner("roles").natural().using("role_id"));
+
+    let conflict = |clause: &str| sql::BuildError::ConflictingClauses {
+      command: "JOIN".to_string(),
+      clauses: vec!["NATURAL".to_string(), clause.to_string()],
+    };
+
+    assert_eq!(Err(conflict("ON")), on.validate());
+    assert_eq!(Err(conflict("USING")), using.validate());
+  }
+
+  #[test]
+  fn join_with_on_and_using_should_be_conflicting_clauses() {
+    let select = sql::Select::new().select("*").from("users").join(
+      sql::Join::inner("roles")
+        .on("roles.id = users.role_id")
+        .using("role_id"),
+    );
+
+    let expected = sql::BuildError::ConflictingClauses {
+      command: "JOIN".to_string(),
+      clauses: vec!["ON".to_string(), "USING".to_string()],
+    };
+
+    assert_eq!(Err(expected), select.validate());
+  }
+
+  #[test]
+  fn natural_cross_join_should_be_conflicting_clauses() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::cross("roles").natural());
+
+    let expected = sql::BuildError::ConflictingClauses {
+      command: "JOIN".to_string(),
+      clauses: vec!["NATURAL".to_string(), "CROSS".to_string()],
+    };
+
+    assert_eq!(Err(expected), select.validate());
+  }
+
+  #[test]
+  fn required_clauses_should_not_be_checked_when_the_raw_sql_is_set() {
+    let select = sql::Select::new().raw("SELECT login").from("users");
+
+    assert_eq!(Ok(()), select.validate());
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_valid() {
+    let query = sql::Select::new().select("login").from("users").try_as_string();
+
+    assert_eq!(Ok("SELECT login FROM users".to_string()), query);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_error_when_invalid() {
+    let query = sql::Select::new().from("users").try_as_string();
+
+    assert_eq!(Err(missing("SELECT")), query);
+  }
+
+  #[test]
+  fn trait_display_should_describe_the_error() {
+    assert_eq!(
+      "the SELECT command requires the GROUP BY clause",
+      missing("GROUP BY").to_string()
+    );
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+mod non_standard_select_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn operands_of_the_combinators_should_be_checked() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().from("admins"));
+
+    let expected = sql::BuildError::MissingClause {
+      command: "SELECT".to_string(),
+      clause: "SELECT".to_string(),
+    };
+
+    assert_eq!(Err(expected), select.validate());
+  }
+}
+
+mod insert_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn values_and_select_together_should_be_conflicting_clauses() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .select(sql::Select::new().select("login").from("users_bk"));
+
+    let expected = sql::BuildError::ConflictingClauses {
+      command: "INSERT".to_string(),
+      clauses: vec!["VALUES".to_string(), "SELECT".to_string()],
+    };
+
+    assert_eq!(Err(expected), insert.validate());
+  }
+
+  #[test]
+  fn insert_without_table_should_be_invalid() {
+    let insert = sql::Insert::new().values("('foo')");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "INSERT".to_string(),
+      clause: "INSERT INTO".to_string(),
+    };
+
+    assert_eq!(Err(expected), insert.validate());
+  }
+
+  #[test]
+  fn insert_without_values_should_be_invalid() {
+    let insert = sql::Insert::new().insert_into("users (login)");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "INSERT".to_string(),
+      clause: "VALUES".to_string(),
+    };
+
+    assert_eq!(Err(expected), insert.validate());
+  }
+
+  #[test]
+  fn select_of_the_insert_should_be_checked() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(sql::Select::new().from("users_bk"));
+
+    let expected = sql::BuildError::MissingClause {
+      command: "SELECT".to_string(),
+      clause: "SELECT".to_string(),
+    };
+
+    assert_eq!(Err(expected), insert.validate());
+  }
+
+  #[test]
+  fn complete_insert_should_be_valid() {
+    let insert = sql::Insert::new().insert_into("users (login)").values("('foo')");
+
+    assert_eq!(
+      Ok("INSERT INTO users (login) VALUES ('foo')".to_string()),
+      insert.try_as_string()
+    );
+  }
+}
+
+mod update_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn update_without_set_should_be_invalid() {
+    let update = sql::Update::new().update("users").where_clause("id = $1");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "UPDATE".to_string(),
+      clause: "SET".to_string(),
+    };
+
+    assert_eq!(Err(expected), update.validate());
+  }
+
+  #[test]
+  fn update_without_table_should_be_invalid() {
+    let update = sql::Update::new().set("login = 'foo'");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "UPDATE".to_string(),
+      clause: "UPDATE".to_string(),
+    };
+
+    assert_eq!(Err(expected), update.validate());
+  }
+
+  #[test]
+  fn complete_update_should_be_valid() {
+    let update = sql::Update::new().update("users").set("login = 'foo'");
+
+    assert_eq!(Ok(()), update.validate());
+  }
+}
+
+mod delete_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn delete_without_table_should_be_invalid() {
+    let delete = sql::Delete::new().where_clause("id = $1");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "DELETE".to_string(),
+      clause: "DELETE FROM".to_string(),
+    };
+
+    assert_eq!(Err(expected), delete.validate());
+  }
+
+  #[test]
+  fn complete_delete_should_be_valid() {
+    let delete = sql::Delete::new().delete_from("users");
+
+    assert_eq!(Ok("DELETE FROM users".to_string()), delete.try_as_string());
+  }
+}
+
+mod values_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn values_without_rows_should_be_invalid() {
+    let expected = sql::BuildError::MissingClause {
+      command: "VALUES".to_string(),
+      clause: "VALUES".to_string(),
+    };
+
+    assert_eq!(Err(expected), sql::Values::new().validate());
+  }
+}
+
+mod merge_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn merge_without_actions_should_be_invalid() {
+    let merge = sql::Merge::new()
+      .merge_into("users")
+      .using("users_bk")
+      .on("users.id = users_bk.id");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "MERGE".to_string(),
+      clause: "WHEN".to_string(),
+    };
+
+    assert_eq!(Err(expected), merge.validate_for(sql::Dialect::Standard));
+  }
+
+  #[test]
+  fn complete_merge_should_be_valid() {
+    let merge = sql::Merge::new()
+      .merge_into("users")
+      .using("users_bk")
+      .on("users.id = users_bk.id")
+      .when_matched_then_delete();
+
+    assert_eq!(Ok(()), merge.validate_for(sql::Dialect::Standard));
+  }
+}
+
+mod ddl_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn missing(command: &str, clause: &str) -> sql::BuildError {
+    sql::BuildError::MissingClause {
+      command: command.to_string(),
+      clause: clause.to_string(),
+    }
+  }
+
+  #[test]
+  fn create_table_without_columns_should_be_invalid() {
+    let create_table = sql::CreateTable::new().create_table("users");
+
+    assert_eq!(Err(missing("CREATE TABLE", "COLUMN")), create_table.validate());
+  }
+
+  #[test]
+  fn alter_table_without_actions_should_be_invalid() {
+    let alter_table = sql::AlterTable::new().alter_table("users");
+
+    assert_eq!(Err(missing("ALTER TABLE", "ACTION")), alter_table.validate());
+  }
+
+  #[test]
+  fn alter_table_actions_should_be_checked_in_the_default_dialect() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .add_column("name text");
+
+    assert_eq!(
+      alter_table.try_to_sql(sql::Dialect::default()).map(|_| ()),
+      alter_table.validate()
+    );
+  }
+
+  #[test]
+  fn drop_commands_without_names_should_be_invalid() {
+    assert_eq!(
+      Err(missing("DROP TABLE", "DROP TABLE")),
+      sql::DropTable::new().validate()
+    );
+    assert_eq!(Err(missing("DROP VIEW", "DROP VIEW")), sql::DropView::new().validate());
+  }
+
+  #[test]
+  fn create_view_without_select_should_be_invalid() {
+    let create_view = sql::CreateView::new().create_view("active_users");
+
+    assert_eq!(Err(missing("CREATE VIEW", "AS")), create_view.validate());
+  }
+
+  #[test]
+  fn select_of_the_create_view_should_be_checked() {
+    let create_view = sql::CreateView::new()
+      .create_view("active_users")
+      .as_select(sql::Select::new().from("users"));
+
+    assert_eq!(Err(missing("SELECT", "SELECT")), create_view.validate());
+  }
+
+  #[test]
+  fn complete_ddl_commands_should_be_valid() {
+    let create_table = sql::CreateTable::new().create_table("users").column("login text");
+    let drop_table = sql::DropTable::new().drop_table("users");
+
+    assert_eq!(Ok(()), create_table.validate());
+    assert_eq!(Ok("DROP TABLE users".to_string()), drop_table.try_as_string());
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+mod index_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn create_index_without_table_should_be_invalid() {
+    let create_index = sql::CreateIndex::new().create_index("users_login_idx").column("login");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "CREATE INDEX".to_string(),
+      clause: "ON".to_string(),
+    };
+
+    assert_eq!(Err(expected), create_index.validate());
+  }
+
+  #[test]
+  fn create_index_without_columns_should_be_invalid() {
+    let create_index = sql::CreateIndex::new().create_index("users_login_idx").on("users");
+
+    let expected = sql::BuildError::MissingClause {
+      command: "CREATE INDEX".to_string(),
+      clause: "COLUMN".to_string(),
+    };
+
+    assert_eq!(Err(expected), create_index.validate());
+  }
+
+  #[test]
+  fn drop_index_without_names_should_be_invalid() {
+    let expected = sql::BuildError::MissingClause {
+      command: "DROP INDEX".to_string(),
+      clause: "DROP INDEX".to_string(),
+    };
+
+    assert_eq!(Err(expected), sql::DropIndex::new().validate());
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn materialized_view_should_not_be_replaced() {
+    let create_view = sql::CreateView::new()
+      .create_view("active_users")
+      .or_replace()
+      .materialized()
+      .as_select(sql::Select::new().select("*").from("users"));
+
+    let expected = sql::BuildError::ConflictingClauses {
+      command: "CREATE VIEW".to_string(),
+      clauses: vec!["OR REPLACE".to_string(), "MATERIALIZED".to_string()],
+    };
+
+    assert_eq!(Err(expected), create_view.validate());
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod mysql_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn values_and_set_together_should_be_conflicting_clauses() {
+    let insert = sql::Insert::new()
+      .insert_into("users")
+      .values("('foo')")
+      .set("login = 'foo'");
+
+    let expected = sql::BuildError::ConflictingClauses {
+      command: "INSERT".to_string(),
+      clauses: vec!["VALUES".to_string(), "SET".to_string()],
+    };
+
+    assert_eq!(Err(expected), insert.validate());
+  }
+}
+
+mod transaction_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn commands_of_the_transaction_should_be_checked() {
+    let transaction = sql::Transaction::new()
+      .select(sql::Select::new().select("1"))
+      .update(sql::Update::new().update("users"));
+
+    let expected = sql::BuildError::MissingClause {
+      command: "UPDATE".to_string(),
+      clause: "SET".to_string(),
+    };
+
+    assert_eq!(Err(expected), transaction.validate());
+  }
+
+  #[test]
+  fn transaction_with_valid_commands_should_be_valid() {
+    let transaction = sql::Transaction::new()
+      .commit("")
+      .delete(sql::Delete::new().delete_from("users"));
+
+    assert_eq!(Ok(()), transaction.validate());
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn alter_table_commands_of_the_transaction_should_be_checked_in_the_dialect() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age integer")
+      .drop_column("login");
+    let transaction = sql::Transaction::new().alter_table(alter_table);
+
+    let expected = sql::BuildError::IncompatibleActions {
+      dialect: sql::Dialect::Sqlite,
+      actions: vec!["ADD COLUMN".to_string(), "DROP COLUMN".to_string()],
+    };
+
+    assert_eq!(Err(expected), transaction.validate_for(sql::Dialect::Sqlite));
+  }
+
+  #[cfg(all(feature = "mysql", any(feature = "postgresql", feature = "sqlite")))]
+  #[test]
+  fn transaction_commands_should_be_checked_in_the_dialect() {
+    let transaction = sql::Transaction::new().begin("").end("");
+
+    let expected = sql::BuildError::UnsupportedFeature {
+      dialect: sql::Dialect::Mysql,
+      feature: "END".to_string(),
+    };
+
+    assert_eq!(Err(expected), transaction.validate_for(sql::Dialect::Mysql));
+  }
+}
+
+mod dialect_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[allow(dead_code)]
+  fn unsupported(dialect: sql::Dialect, feature: &str) -> sql::BuildError {
+    sql::BuildError::UnsupportedFeature {
+      dialect,
+      feature: feature.to_string(),
+    }
+  }
+
+  #[test]
+  fn method_try_to_sql_should_return_the_query_in_the_dialect() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .try_to_sql(sql::Dialect::Standard);
+
+    assert_eq!(Ok("SELECT login FROM users".to_string()), query);
+  }
+
+  #[test]
+  fn method_try_to_sql_should_return_the_error_of_the_clauses() {
+    let query = sql::Update::new().update("users").try_to_sql(sql::Dialect::Standard);
+
+    let expected = sql::BuildError::MissingClause {
+      command: "UPDATE".to_string(),
+      clause: "SET".to_string(),
+    };
+
+    assert_eq!(Err(expected), query);
+  }
+
+  #[cfg(all(feature = "sqlite", feature = "postgresql"))]
+  #[test]
+  fn joins_of_the_update_should_not_be_supported_by_postgres() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("active = true")
+      .inner_join("roles USING (role_id)");
+
+    assert_eq!(Ok(()), update.validate_for(sql::Dialect::Sqlite));
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Postgresql, "JOIN")),
+      update.try_to_sql(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[cfg(all(feature = "mysql", any(feature = "postgresql", feature = "sqlite")))]
+  #[test]
+  fn full_join_should_not_be_supported_by_mysql() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::full("addresses").on("addresses.user_id = users.id"));
+
+    assert_eq!(Ok(()), select.validate_for(sql::Dialect::Standard));
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Mysql, "FULL JOIN")),
+      select.validate_for(sql::Dialect::Mysql)
+    );
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Mysql, "FULL JOIN")),
+      select.try_to_sql(sql::Dialect::Mysql)
+    );
+  }
+
+  #[cfg(all(feature = "sqlite", any(feature = "postgresql", feature = "mysql")))]
+  #[test]
+  fn lateral_join_should_not_be_supported_by_sqlite() {
+    let last_order = sql::Select::new()
+      .select("created_at")
+      .from("orders")
+      .where_clause("orders.user_id = users.id");
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::left("last_order").sub_query(last_order).lateral().on("true"));
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Sqlite, "LATERAL")),
+      select.try_to_sql(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[cfg(all(feature = "mysql", any(feature = "postgresql", feature = "sqlite")))]
+  #[test]
+  fn joins_of_the_sub_queries_should_be_checked() {
+    let union = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::full("addresses").using("user_id"));
+    let select = sql::Select::new().select("*").from("admins").union(union);
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Mysql, "FULL JOIN")),
+      select.validate_for(sql::Dialect::Mysql)
+    );
+  }
+
+  #[cfg(all(feature = "mysql", any(feature = "postgresql", feature = "sqlite")))]
+  #[test]
+  fn returning_should_not_be_supported_by_mysql() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .returning("id");
+    let update = sql::Update::new().update("users").set("login = 'foo'").returning("id");
+    let delete = sql::Delete::new().delete_from("users").returning("id");
+
+    let expected = Err(unsupported(sql::Dialect::Mysql, "RETURNING"));
+
+    assert_eq!(expected, insert.validate_for(sql::Dialect::Mysql));
+    assert_eq!(expected, update.validate_for(sql::Dialect::Mysql));
+    assert_eq!(expected, delete.validate_for(sql::Dialect::Mysql));
+  }
+
+  #[cfg(all(feature = "mysql", any(feature = "postgresql", feature = "sqlite")))]
+  #[test]
+  fn queries_of_the_with_clause_should_be_checked() {
+    let active_users = sql::Select::new()
+      .select("*")
+      .from("users")
+      .join(sql::Join::full("sessions").using("user_id"));
+    let select = sql::Select::new()
+      .with("active_users", active_users)
+      .select("*")
+      .from("active_users");
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Mysql, "FULL JOIN")),
+      select.validate_for(sql::Dialect::Mysql)
+    );
+  }
+
+  #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  #[test]
+  fn limit_of_the_delete_should_not_be_supported_by_postgres() {
+    let delete = sql::Delete::new().delete_from("users").limit("10");
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Postgresql, "LIMIT")),
+      delete.validate_for(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[cfg(all(feature = "postgresql", feature = "mysql"))]
+  #[test]
+  fn fulltext_index_should_not_be_supported_by_postgres() {
+    let create_index = sql::CreateIndex::new()
+      .create_index("bio_idx")
+      .fulltext()
+      .on("users")
+      .column("bio");
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Postgresql, "FULLTEXT")),
+      create_index.validate_for(sql::Dialect::Postgresql)
+    );
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  #[test]
+  fn create_index_should_not_be_supported_by_the_standard() {
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_name_idx")
+      .on("users")
+      .column("name");
+
+    assert_eq!(Ok(()), create_index.validate());
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Standard, "CREATE INDEX")),
+      create_index.validate_for(sql::Dialect::Standard)
+    );
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql"))]
+  #[test]
+  fn non_standard_clauses_of_the_select_should_not_be_supported_by_the_standard() {
+    let select = || sql::Select::new().select("login").from("users");
+    let admins = || sql::Select::new().select("login").from("admins");
+
+    let cases = [
+      (select().with("admins", admins()), "WITH"),
+      (select().limit("10"), "LIMIT"),
+      (select().offset("10"), "OFFSET"),
+      (select().union(admins()), "UNION"),
+      (select().except(admins()), "EXCEPT"),
+      (select().intersect(admins()), "INTERSECT"),
+    ];
+
+    for (select, clause) in cases {
+      let expected = Err(unsupported(sql::Dialect::Standard, clause));
+
+      assert_eq!(expected, select.validate_for(sql::Dialect::Standard));
+      assert_eq!(
+        expected.map(|_| String::new()),
+        select.try_to_sql(sql::Dialect::Standard)
+      );
+    }
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn data_types_without_equivalent_should_not_be_supported_by_sqlite() {
+    let tags = || sql::ColumnDef::new("tags", sql::DataType::Array(Box::new(sql::DataType::Text)));
+    let create_table = sql::CreateTable::new().create_table("posts").column(tags());
+    let alter_table = sql::AlterTable::new().alter_table("posts").add_column(tags());
+
+    assert_eq!(Ok(()), create_table.validate_for(sql::Dialect::Standard));
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Sqlite, "TEXT[]")),
+      create_table.try_to_sql(sql::Dialect::Sqlite)
+    );
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Sqlite, "TEXT[]")),
+      alter_table.try_to_sql(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn data_types_without_equivalent_should_not_be_supported_by_mysql() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("posts")
+      .alter_column_type("tags", sql::DataType::Array(Box::new(sql::DataType::Text)));
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Mysql, "TEXT[]")),
+      alter_table.validate_for(sql::Dialect::Mysql)
+    );
+  }
+
+  #[cfg(feature = "sqlite")]
+  #[test]
+  fn replaced_view_should_not_be_supported_by_sqlite() {
+    let create_view = sql::CreateView::new()
+      .create_view("active_users")
+      .or_replace()
+      .as_select(sql::Select::new().select("*").from("users"));
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Sqlite, "OR REPLACE")),
+      create_view.validate_for(sql::Dialect::Sqlite)
+    );
+  }
+
+  #[cfg(feature = "mysql")]
+  #[test]
+  fn merge_should_not_be_supported_by_mysql() {
+    let merge = sql::Merge::new()
+      .merge_into("users")
+      .using("users_bk")
+      .on("users.id = users_bk.id")
+      .when_matched_then_delete();
+
+    assert_eq!(
+      Err(unsupported(sql::Dialect::Mysql, "MERGE")),
+      merge.validate_for(sql::Dialect::Mysql)
+    );
+  }
+}
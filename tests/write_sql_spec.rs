@@ -0,0 +1,164 @@
+mod method_write_sql {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_write_sql_should_write_the_same_query_of_the_method_as_string() {
+    let select = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause("login = 'foo'")
+      .where_in("id", sql::Select::new().select("user_id").from("admins"))
+      .order_by("login");
+
+    let mut query = String::new();
+    select.write_sql(&mut query).unwrap();
+
+    assert_eq!(select.as_string(), query);
+  }
+
+  #[test]
+  fn method_write_sql_should_append_the_query_to_the_content_of_the_writer() {
+    let mut query = String::from("-- users\n");
+    sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id = 1")
+      .write_sql(&mut query)
+      .unwrap();
+
+    let expected_query = "-- users\nDELETE FROM users WHERE id = 1";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_write_sql_should_render_the_bound_values_as_placeholders() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("login = 'foo'")
+      .where_clause_bind("id = ?", 42);
+
+    let mut query = String::new();
+    update.write_sql(&mut query).unwrap();
+
+    let (expected_query, _) = update.as_string_with_binds();
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_write_sql_should_render_the_raw_sql_around_the_clauses() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .raw_before(sql::InsertClause::Values, "/* rows */")
+      .values("('foo')")
+      .values("('bar')");
+
+    let mut query = String::new();
+    insert.write_sql(&mut query).unwrap();
+
+    let expected_query = "INSERT INTO users (login) /* rows */ VALUES ('foo'), ('bar')";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_write_sql_should_render_the_ddl_commands() {
+    let mut query = String::new();
+    sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial primary key")
+      .column("login varchar(40) not null")
+      .write_sql(&mut query)
+      .unwrap();
+
+    let expected_query = "CREATE TABLE users (id serial primary key, login varchar(40) not null)";
+
+    assert_eq!(expected_query, query);
+  }
+}
+
+mod method_write_sql_io {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+  use std::io;
+
+  struct FailingWriter;
+
+  impl io::Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+      Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn method_write_sql_io_should_write_the_query_as_bytes() {
+    let select = sql::Select::new().select("id").from("users");
+
+    let mut output = Vec::new();
+    select.write_sql_io(&mut output).unwrap();
+
+    assert_eq!(select.as_string().as_bytes(), output);
+  }
+
+  #[test]
+  fn method_write_sql_io_should_return_the_error_of_the_writer() {
+    let error = sql::DropTable::new()
+      .drop_table("users")
+      .write_sql_io(&mut FailingWriter)
+      .unwrap_err();
+
+    assert_eq!(io::ErrorKind::BrokenPipe, error.kind());
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgres_feature {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_write_sql_should_number_the_placeholders_of_the_sub_queries() {
+    let select = sql::Select::new()
+      .with(
+        "admins",
+        sql::Select::new()
+          .select("user_id")
+          .from("roles")
+          .where_clause("name = $1"),
+      )
+      .select("id")
+      .from("users")
+      .where_clause("login = $1")
+      .where_clause_bind("active = ?", true);
+
+    let mut query = String::new();
+    select.write_sql(&mut query).unwrap();
+
+    let expected_query = "\
+      WITH admins AS (SELECT user_id FROM roles WHERE name = $2) \
+      SELECT id FROM users WHERE login = $1 AND active = $3\
+    ";
+
+    assert_eq!(expected_query, query);
+  }
+
+  #[test]
+  fn method_write_sql_should_render_the_combinators() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .union(sql::Select::new().select("id").from("admins"));
+
+    let mut query = String::new();
+    select.write_sql(&mut query).unwrap();
+
+    let expected_query = "(SELECT id FROM users) UNION (SELECT id FROM admins)";
+
+    assert_eq!(expected_query, query);
+  }
+}